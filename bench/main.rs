@@ -9,25 +9,24 @@
  * @Description:
  */
 
-use rusty_talib::{self,ErrorMsg};
+use rusty_talib::{self,TalibError};
 use polars::prelude::*;
 
 
 fn main(){
     let random_data: [i32; 7] = [23, 25, 12, 28, 33, 31, 35];
     let close = Series::new("data",random_data);
-    let res = rusty_talib::moving_average(&close,Some(3));
+    let res = rusty_talib::moving_average(&close,Some(3),None);
     match res {
         Ok(ma) => {
             assert_eq!(ma.len(),close.len());
             eprintln!("{:?}",ma);
         },
+        Err(TalibError::InsufficientData { needed, got }) => {
+            eprintln!("needed {} values, got {}", needed, got);
+        },
         Err(e) => {
-            if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-                eprintln!("{}", my_error.0);
-            } else {
-                eprintln!("An error occurred");
-            }
+            eprintln!("{}", e);
         }
     }
 }
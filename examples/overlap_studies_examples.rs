@@ -8,3 +8,25 @@
  * @Project_Name: rusty-talib
  * @Description:
  */
+
+use polars::prelude::*;
+use rusty_talib::{self, Ema};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let history: [f64; 7] = [23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0];
+    let close = Series::new("data", history);
+
+    // backfill with the batch function
+    let ema = rusty_talib::exponential_moving_average(&close, Some(3))?;
+    eprintln!("{:?}", ema);
+
+    // then continue tick by tick with the streaming state
+    let mut state = Ema::new(3)?;
+    for price in history {
+        state.update(price);
+    }
+    for tick in [36.0, 34.5, 37.2] {
+        eprintln!("{:?}", state.update(tick));
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 19:10
+ * @Email: uyplayer@qq.com
+ * @File: dominant_cycle_phase.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The dominant cycle phase shared by HT_DCPHASE, HT_SINE and HT_TRENDMODE.
+//!
+//! - the smoothed prices of the last `smooth_period + 0.5` bars are correlated with one sine and
+//!   one cosine cycle, phase = atan(real / imaginary) in degrees
+//! - then TA-Lib shifts it by 90 degrees, by 360 / smooth_period for the lag of the WMA and by
+//!   180 degrees when the imaginary part is negative, and wraps it below 315
+
+use std::f64::consts::TAU;
+use crate::helper::{HilbertTransform, LagBuffer, HT_START_63};
+
+/// Dominant cycle phase of one bar.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PhaseStep {
+    /// The smoothed dominant cycle period, HT_DCPERIOD.
+    pub(crate) smooth_period: f64,
+    /// The dominant cycle phase in degrees, HT_DCPHASE.
+    pub(crate) phase: f64,
+    /// WMA of the price of this bar.
+    pub(crate) smoothed: f64,
+}
+
+/// Streaming dominant cycle phase, one price per bar.
+#[derive(Debug, Clone)]
+pub(crate) struct DominantCyclePhase {
+    hilbert: HilbertTransform,
+    smoothed: LagBuffer<50>,
+    phase: f64,
+}
+
+impl DominantCyclePhase {
+    pub(crate) fn new() -> Self {
+        DominantCyclePhase {
+            hilbert: HilbertTransform::new(HT_START_63),
+            smoothed: LagBuffer::new(),
+            phase: 0.0,
+        }
+    }
+
+    /// Index of the next bar, the number of prices seen so far.
+    pub(crate) fn bar_index(&self) -> usize {
+        self.hilbert.bar_index()
+    }
+
+    /// Feeds the next price, returns `None` while the WMA is warming up.
+    pub(crate) fn update(&mut self, price: f64) -> Option<PhaseStep> {
+        let step = self.hilbert.update(price)?;
+        self.smoothed.push(step.smoothed);
+
+        // the dominant cycle period is limited to 50, so are the smoothed prices it spans
+        let dc_period = (step.smooth_period + 0.5) as usize;
+        let mut real_part = 0.0;
+        let mut imag_part = 0.0;
+        for i in 0..dc_period {
+            let angle = i as f64 * TAU / dc_period as f64;
+            real_part += angle.sin() * self.smoothed.get(i);
+            imag_part += angle.cos() * self.smoothed.get(i);
+        }
+
+        // with no imaginary part TA-Lib moves the previous phase by 90 degrees toward the real part
+        if imag_part.abs() > 0.0 {
+            self.phase = (real_part / imag_part).atan().to_degrees();
+        } else if real_part < 0.0 {
+            self.phase -= 90.0;
+        } else if real_part > 0.0 {
+            self.phase += 90.0;
+        }
+        self.phase += 90.0;
+        self.phase += 360.0 / step.smooth_period;
+        if imag_part < 0.0 {
+            self.phase += 180.0;
+        }
+        if self.phase > 315.0 {
+            self.phase -= 360.0;
+        }
+
+        Some(PhaseStep {
+            smooth_period: step.smooth_period,
+            phase: self.phase,
+            smoothed: step.smoothed,
+        })
+    }
+}
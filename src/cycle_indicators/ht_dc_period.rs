@@ -0,0 +1,148 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 19:40
+ * @Email: uyplayer@qq.com
+ * @File: ht_dc_period.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform - Dominant Cycle Period
+//!
+//! The period of the homodyne discriminator, smoothed once more by `0.33 * period + 0.67 * previous`.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::{HilbertTransform, HT_START_32};
+use crate::TalibError;
+
+/// Number of leading bars without a dominant cycle period, 32 like TA-Lib.
+pub fn ht_dc_period_lookback() -> usize {
+    32
+}
+
+/// Calculates the Hilbert Transform - Dominant Cycle Period (HT_DCPERIOD) over a plain slice,
+/// the polars free core of [`ht_dc_period`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is not longer than [`ht_dc_period_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ht_dc_period_slice;
+///
+/// // a sine wave with a period of 20 bars
+/// let src: Vec<f64> = (0..200).map(|i| 100.0 + (i as f64 * std::f64::consts::TAU / 20.0).sin()).collect();
+/// let period = ht_dc_period_slice(&src).unwrap();
+/// assert!(period[31].is_nan());
+/// assert!((period[199] - 20.0).abs() < 1.0);
+/// ```
+pub fn ht_dc_period_slice(src: &[f64]) -> Result<Vec<f64>, TalibError> {
+    let lookback = ht_dc_period_lookback();
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = HtDcPeriod::new();
+    Ok(src.iter().map(|price| state.update(*price).unwrap_or(f64::NAN)).collect())
+}
+
+/// Calculates the Hilbert Transform - Dominant Cycle Period (HT_DCPERIOD).
+///
+/// ``` python
+///     #  This Python code produces the same result as the ht_dc_period function does
+///     import talib
+///     period = talib.HT_DCPERIOD(close)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing price data, usually the close.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ht_dc_period;
+///
+///  let close: Vec<f64> = (0..80).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+///  let res = ht_dc_period(&Series::new("close", close));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ht_dc_period(src: &Series) -> Result<Series, TalibError> {
+    let period = ht_dc_period_slice(&series_to_vec(src)?)?;
+    Ok(vec_to_series("data", period))
+}
+
+/// Streaming Hilbert Transform - Dominant Cycle Period (HT_DCPERIOD).
+///
+/// Returns `None` for the first [`ht_dc_period_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HtDcPeriod;
+///
+/// let mut period = HtDcPeriod::new();
+/// for price in (0..40).map(|i| 10.0 + (i as f64 * 0.3).sin()) {
+///     eprintln!("{:?}", period.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtDcPeriod {
+    hilbert: HilbertTransform,
+}
+
+impl Default for HtDcPeriod {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtDcPeriod {
+    /// Creates a new HT_DCPERIOD state.
+    pub fn new() -> Self {
+        HtDcPeriod {
+            hilbert: HilbertTransform::new(HT_START_32),
+        }
+    }
+
+    /// Feeds the next price and returns the current dominant cycle period.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let step = self.hilbert.update(price)?;
+        if self.hilbert.bar_index() <= ht_dc_period_lookback() {
+            return None;
+        }
+        Some(step.smooth_period)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ht_dc_period() -> Result<(), TalibError> {
+        let src: Vec<f64> = (0..300).map(|i| 100.0 + 5.0 * (i as f64 * std::f64::consts::TAU / 30.0).sin()).collect();
+        let res = ht_dc_period_slice(&src)?;
+        assert!(res[..32].iter().all(|value| value.is_nan()));
+        assert!(res[32..].iter().all(|value| *value > 0.0 && *value <= 50.0));
+        assert!((res[299] - 30.0).abs() < 1.5);
+        assert!(ht_dc_period_slice(&src[..32]).is_err());
+        Ok(())
+    }
+}
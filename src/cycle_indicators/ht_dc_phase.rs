@@ -0,0 +1,148 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 20:40
+ * @Email: uyplayer@qq.com
+ * @File: ht_dc_phase.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform - Dominant Cycle Phase
+//!
+//! The phase in degrees of the smoothed price over one dominant cycle, between -45 and 315.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::cycle_indicators::dominant_cycle_phase::DominantCyclePhase;
+use crate::TalibError;
+
+/// Number of leading bars without a dominant cycle phase, 63 like TA-Lib.
+pub fn ht_dc_phase_lookback() -> usize {
+    63
+}
+
+/// Calculates the Hilbert Transform - Dominant Cycle Phase (HT_DCPHASE) over a plain slice,
+/// the polars free core of [`ht_dc_phase`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is not longer than [`ht_dc_phase_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ht_dc_phase_slice;
+///
+/// let src: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+/// let phase = ht_dc_phase_slice(&src).unwrap();
+/// assert!(phase[62].is_nan());
+/// assert!(phase[63..].iter().all(|phase| (-45.0..=315.0).contains(phase)));
+/// ```
+pub fn ht_dc_phase_slice(src: &[f64]) -> Result<Vec<f64>, TalibError> {
+    let lookback = ht_dc_phase_lookback();
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = HtDcPhase::new();
+    Ok(src.iter().map(|price| state.update(*price).unwrap_or(f64::NAN)).collect())
+}
+
+/// Calculates the Hilbert Transform - Dominant Cycle Phase (HT_DCPHASE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the ht_dc_phase function does
+///     import talib
+///     phase = talib.HT_DCPHASE(close)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing price data, usually the close.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ht_dc_phase;
+///
+///  let close: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+///  let res = ht_dc_phase(&Series::new("close", close));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ht_dc_phase(src: &Series) -> Result<Series, TalibError> {
+    let phase = ht_dc_phase_slice(&series_to_vec(src)?)?;
+    Ok(vec_to_series("data", phase))
+}
+
+/// Streaming Hilbert Transform - Dominant Cycle Phase (HT_DCPHASE).
+///
+/// Returns `None` for the first [`ht_dc_phase_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HtDcPhase;
+///
+/// let mut phase = HtDcPhase::new();
+/// for price in (0..70).map(|i| 10.0 + (i as f64 * 0.3).sin()) {
+///     eprintln!("{:?}", phase.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtDcPhase {
+    phase: DominantCyclePhase,
+}
+
+impl Default for HtDcPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtDcPhase {
+    /// Creates a new HT_DCPHASE state.
+    pub fn new() -> Self {
+        HtDcPhase {
+            phase: DominantCyclePhase::new(),
+        }
+    }
+
+    /// Feeds the next price and returns the current dominant cycle phase in degrees.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let step = self.phase.update(price)?;
+        if self.phase.bar_index() <= ht_dc_phase_lookback() {
+            return None;
+        }
+        Some(step.phase)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ht_dc_phase() -> Result<(), TalibError> {
+        // a cycle of 20 bars moves the phase by about 18 degrees per bar
+        let src: Vec<f64> = (0..300).map(|i| 100.0 + 5.0 * (i as f64 * std::f64::consts::TAU / 20.0).sin()).collect();
+        let res = ht_dc_phase_slice(&src)?;
+        assert!(res[..63].iter().all(|value| value.is_nan()));
+        let steps: Vec<f64> = res[250..].windows(2).map(|pair| (pair[1] - pair[0]).rem_euclid(360.0)).collect();
+        assert!(steps.iter().all(|step| (step - 18.0).abs() < 3.0));
+        assert!(ht_dc_phase_slice(&src[..63]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 20:10
+ * @Email: uyplayer@qq.com
+ * @File: ht_phasor.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform - Phasor Components
+//!
+//! - in-phase = the detrended smoothed price 3 bars ago
+//! - quadrature = the Hilbert transform of the detrended smoothed price
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::{HilbertTransform, HT_START_32};
+use crate::TalibError;
+
+/// Number of leading bars without phasor components, 32 like TA-Lib.
+pub fn ht_phasor_lookback() -> usize {
+    32
+}
+
+/// Calculates the Hilbert Transform - Phasor Components (HT_PHASOR) over a plain slice,
+/// the polars free core of [`ht_phasor`].
+///
+/// Returns `(in_phase, quadrature)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is not longer than [`ht_phasor_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ht_phasor_slice;
+///
+/// let src: Vec<f64> = (0..80).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+/// let (in_phase, quadrature) = ht_phasor_slice(&src).unwrap();
+/// assert!(in_phase[31].is_nan() && quadrature[31].is_nan());
+/// assert!(in_phase[32].is_finite() && quadrature[32].is_finite());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn ht_phasor_slice(src: &[f64]) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    let lookback = ht_phasor_lookback();
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = HtPhasor::new();
+    let mut in_phase = Vec::with_capacity(src.len());
+    let mut quadrature = Vec::with_capacity(src.len());
+    for price in src {
+        let (i, q) = state.update(*price).unwrap_or((f64::NAN, f64::NAN));
+        in_phase.push(i);
+        quadrature.push(q);
+    }
+    Ok((in_phase, quadrature))
+}
+
+/// Calculates the Hilbert Transform - Phasor Components (HT_PHASOR).
+///
+/// Returns the `(in_phase, quadrature)` Series.
+///
+/// ``` python
+///     #  This Python code produces the same result as the ht_phasor function does
+///     import talib
+///     in_phase, quadrature = talib.HT_PHASOR(close)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing price data, usually the close.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ht_phasor;
+///
+///  let close: Vec<f64> = (0..80).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+///  let res = ht_phasor(&Series::new("close", close));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ht_phasor(src: &Series) -> Result<(Series, Series), TalibError> {
+    let (in_phase, quadrature) = ht_phasor_slice(&series_to_vec(src)?)?;
+    Ok((vec_to_series("INPHASE", in_phase), vec_to_series("QUADRATURE", quadrature)))
+}
+
+/// Streaming Hilbert Transform - Phasor Components (HT_PHASOR).
+///
+/// Returns `(in_phase, quadrature)`, `None` for the first [`ht_phasor_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HtPhasor;
+///
+/// let mut phasor = HtPhasor::new();
+/// for price in (0..40).map(|i| 10.0 + (i as f64 * 0.3).sin()) {
+///     eprintln!("{:?}", phasor.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtPhasor {
+    hilbert: HilbertTransform,
+}
+
+impl Default for HtPhasor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtPhasor {
+    /// Creates a new HT_PHASOR state.
+    pub fn new() -> Self {
+        HtPhasor {
+            hilbert: HilbertTransform::new(HT_START_32),
+        }
+    }
+
+    /// Feeds the next price and returns the current `(in_phase, quadrature)`.
+    pub fn update(&mut self, price: f64) -> Option<(f64, f64)> {
+        let step = self.hilbert.update(price)?;
+        if self.hilbert.bar_index() <= ht_phasor_lookback() {
+            return None;
+        }
+        Some((step.i1, step.q1))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ht_phasor() -> Result<(), TalibError> {
+        // a flat price has nothing to detrend
+        let (in_phase, quadrature) = ht_phasor_slice(&[10.0; 40])?;
+        assert!(in_phase[..32].iter().all(|value| value.is_nan()));
+        assert!(in_phase[32..].iter().all(|value| value.abs() < 1e-9));
+        assert!(quadrature[32..].iter().all(|value| value.abs() < 1e-9));
+        assert!(ht_phasor_slice(&[10.0; 32]).is_err());
+        Ok(())
+    }
+}
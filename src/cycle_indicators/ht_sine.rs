@@ -0,0 +1,163 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 21:10
+ * @Email: uyplayer@qq.com
+ * @File: ht_sine.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform - SineWave
+//!
+//! - sine = sin(dominant cycle phase)
+//! - lead sine = sin(dominant cycle phase + 45 degrees)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::cycle_indicators::dominant_cycle_phase::DominantCyclePhase;
+use crate::TalibError;
+
+/// Number of leading bars without a sine wave, 63 like TA-Lib.
+pub fn ht_sine_lookback() -> usize {
+    63
+}
+
+/// Calculates the Hilbert Transform - SineWave (HT_SINE) over a plain slice,
+/// the polars free core of [`ht_sine`].
+///
+/// Returns `(sine, lead_sine)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is not longer than [`ht_sine_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ht_sine_slice;
+///
+/// let src: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+/// let (sine, lead_sine) = ht_sine_slice(&src).unwrap();
+/// assert!(sine[62].is_nan() && lead_sine[62].is_nan());
+/// assert!(sine[63..].iter().all(|sine| sine.abs() <= 1.0));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn ht_sine_slice(src: &[f64]) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    let lookback = ht_sine_lookback();
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = HtSine::new();
+    let mut sine = Vec::with_capacity(src.len());
+    let mut lead_sine = Vec::with_capacity(src.len());
+    for price in src {
+        let (s, l) = state.update(*price).unwrap_or((f64::NAN, f64::NAN));
+        sine.push(s);
+        lead_sine.push(l);
+    }
+    Ok((sine, lead_sine))
+}
+
+/// Calculates the Hilbert Transform - SineWave (HT_SINE).
+///
+/// Returns the `(sine, lead_sine)` Series.
+///
+/// ``` python
+///     #  This Python code produces the same result as the ht_sine function does
+///     import talib
+///     sine, lead_sine = talib.HT_SINE(close)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing price data, usually the close.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ht_sine;
+///
+///  let close: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+///  let res = ht_sine(&Series::new("close", close));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ht_sine(src: &Series) -> Result<(Series, Series), TalibError> {
+    let (sine, lead_sine) = ht_sine_slice(&series_to_vec(src)?)?;
+    Ok((vec_to_series("SINE", sine), vec_to_series("LEADSINE", lead_sine)))
+}
+
+/// Streaming Hilbert Transform - SineWave (HT_SINE).
+///
+/// Returns `(sine, lead_sine)`, `None` for the first [`ht_sine_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HtSine;
+///
+/// let mut sine = HtSine::new();
+/// for price in (0..70).map(|i| 10.0 + (i as f64 * 0.3).sin()) {
+///     eprintln!("{:?}", sine.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtSine {
+    phase: DominantCyclePhase,
+}
+
+impl Default for HtSine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtSine {
+    /// Creates a new HT_SINE state.
+    pub fn new() -> Self {
+        HtSine {
+            phase: DominantCyclePhase::new(),
+        }
+    }
+
+    /// Feeds the next price and returns the current `(sine, lead_sine)`.
+    pub fn update(&mut self, price: f64) -> Option<(f64, f64)> {
+        let step = self.phase.update(price)?;
+        if self.phase.bar_index() <= ht_sine_lookback() {
+            return None;
+        }
+        Some((step.phase.to_radians().sin(), (step.phase + 45.0).to_radians().sin()))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ht_sine() -> Result<(), TalibError> {
+        let src: Vec<f64> = (0..150).map(|i| 100.0 + 5.0 * (i as f64 * 0.3).sin()).collect();
+        let (sine, lead_sine) = ht_sine_slice(&src)?;
+        let phase = crate::ht_dc_phase_slice(&src)?;
+        assert!(sine[..63].iter().all(|value| value.is_nan()));
+        for i in 63..src.len() {
+            assert_eq!(sine[i], phase[i].to_radians().sin());
+            assert_eq!(lead_sine[i], (phase[i] + 45.0).to_radians().sin());
+        }
+        assert!(ht_sine_slice(&src[..63]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/27 21:40
+ * @Email: uyplayer@qq.com
+ * @File: ht_trend_mode.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/cycle_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform - Trend vs Cycle Mode
+//!
+//! 1 when the market trends, 0 when it cycles:
+//! - cycle mode when the sine crosses the lead sine, for half a dominant cycle after that
+//!   and while the phase moves by about 360 / dominant cycle period per bar
+//! - trend mode anyway when the smoothed price is 1.5% or more away from the instantaneous trendline
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{int_vec_to_series, series_to_vec};
+use crate::cycle_indicators::dominant_cycle_phase::DominantCyclePhase;
+use crate::TalibError;
+use std::collections::VecDeque;
+
+/// Number of leading bars without a trend mode, 63 like TA-Lib.
+pub fn ht_trend_mode_lookback() -> usize {
+    63
+}
+
+/// Calculates the Hilbert Transform - Trend vs Cycle Mode (HT_TRENDMODE) over a plain slice,
+/// the polars free core of [`ht_trend_mode`].
+///
+/// Returns `Some(1)` for trend mode and `Some(0)` for cycle mode, `None` for the first
+/// [`ht_trend_mode_lookback`] bars.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is not longer than [`ht_trend_mode_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ht_trend_mode_slice;
+///
+/// let src: Vec<f64> = (0..100).map(|i| 10.0 + i as f64).collect();
+/// let mode = ht_trend_mode_slice(&src).unwrap();
+/// assert_eq!(mode[62], None);
+/// // a steady rise is a trend
+/// assert_eq!(mode[99], Some(1));
+/// ```
+pub fn ht_trend_mode_slice(src: &[f64]) -> Result<Vec<Option<i32>>, TalibError> {
+    let lookback = ht_trend_mode_lookback();
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = HtTrendMode::new();
+    Ok(src.iter().map(|price| state.update(*price)).collect())
+}
+
+/// Calculates the Hilbert Transform - Trend vs Cycle Mode (HT_TRENDMODE).
+///
+/// Returns an Int32 Series of 1 (trend) and 0 (cycle), null for the first [`ht_trend_mode_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the ht_trend_mode function does
+///     import talib
+///     mode = talib.HT_TRENDMODE(close)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing price data, usually the close.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ht_trend_mode;
+///
+///  let close: Vec<f64> = (0..100).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+///  let res = ht_trend_mode(&Series::new("close", close));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ht_trend_mode(src: &Series) -> Result<Series, TalibError> {
+    let mode = ht_trend_mode_slice(&series_to_vec(src)?)?;
+    Ok(int_vec_to_series("data", mode))
+}
+
+/// Streaming Hilbert Transform - Trend vs Cycle Mode (HT_TRENDMODE).
+///
+/// Returns `None` for the first [`ht_trend_mode_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HtTrendMode;
+///
+/// let mut mode = HtTrendMode::new();
+/// for price in (0..70).map(|i| 10.0 + (i as f64 * 0.3).sin()) {
+///     eprintln!("{:?}", mode.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtTrendMode {
+    phase: DominantCyclePhase,
+    prices: VecDeque<f64>,
+    i_trend1: f64,
+    i_trend2: f64,
+    i_trend3: f64,
+    days_in_trend: usize,
+    prev_phase: f64,
+    sine: f64,
+    lead_sine: f64,
+}
+
+impl Default for HtTrendMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtTrendMode {
+    /// Creates a new HT_TRENDMODE state.
+    pub fn new() -> Self {
+        HtTrendMode {
+            phase: DominantCyclePhase::new(),
+            prices: VecDeque::with_capacity(51),
+            i_trend1: 0.0,
+            i_trend2: 0.0,
+            i_trend3: 0.0,
+            days_in_trend: 0,
+            prev_phase: 0.0,
+            sine: 0.0,
+            lead_sine: 0.0,
+        }
+    }
+
+    /// Feeds the next price and returns 1 for trend mode and 0 for cycle mode.
+    pub fn update(&mut self, price: f64) -> Option<i32> {
+        // the dominant cycle period is limited to 50, so are the prices averaged over it
+        self.prices.push_front(price);
+        self.prices.truncate(50);
+        let step = self.phase.update(price)?;
+
+        let prev_sine = self.sine;
+        let prev_lead_sine = self.lead_sine;
+        self.sine = step.phase.to_radians().sin();
+        self.lead_sine = (step.phase + 45.0).to_radians().sin();
+
+        let dc_period = ((step.smooth_period + 0.5) as usize).min(self.prices.len());
+        let mut average = self.prices.iter().take(dc_period).sum::<f64>();
+        if dc_period > 0 {
+            average /= dc_period as f64;
+        }
+        let trend_line = (4.0 * average + 3.0 * self.i_trend1 + 2.0 * self.i_trend2 + self.i_trend3) / 10.0;
+        self.i_trend3 = self.i_trend2;
+        self.i_trend2 = self.i_trend1;
+        self.i_trend1 = average;
+
+        let mut trend = 1;
+        if (self.sine > self.lead_sine && prev_sine <= prev_lead_sine)
+            || (self.sine < self.lead_sine && prev_sine >= prev_lead_sine)
+        {
+            self.days_in_trend = 0;
+            trend = 0;
+        }
+        self.days_in_trend += 1;
+        if (self.days_in_trend as f64) < 0.5 * step.smooth_period {
+            trend = 0;
+        }
+        let phase_change = step.phase - self.prev_phase;
+        self.prev_phase = step.phase;
+        if step.smooth_period != 0.0
+            && phase_change > 0.67 * 360.0 / step.smooth_period
+            && phase_change < 1.5 * 360.0 / step.smooth_period
+        {
+            trend = 0;
+        }
+        if trend_line != 0.0 && ((step.smoothed - trend_line) / trend_line).abs() >= 0.015 {
+            trend = 1;
+        }
+
+        if self.phase.bar_index() <= ht_trend_mode_lookback() {
+            return None;
+        }
+        Some(trend)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ht_trend_mode() -> Result<(), TalibError> {
+        // a clean 20 bar cycle around a flat level spends most bars in cycle mode
+        let src: Vec<f64> = (0..300).map(|i| 100.0 + (i as f64 * std::f64::consts::TAU / 20.0).sin()).collect();
+        let res = ht_trend_mode_slice(&src)?;
+        assert!(res[..63].iter().all(|value| value.is_none()));
+        assert!(res[63..].iter().all(|value| matches!(value, Some(0) | Some(1))));
+        assert!(res[200..].iter().filter(|value| **value == Some(0)).count() > 50);
+        assert!(ht_trend_mode_slice(&src[..63]).is_err());
+        Ok(())
+    }
+}
@@ -7,4 +7,37 @@
  * @Dir: rusty-talib / src/cycle_indicators
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+// | Function     | Description                                 | Status  |
+// |--------------|---------------------------------------------|---------|
+// | HT_DCPERIOD  | Hilbert Transform - Dominant Cycle Period   | Done    |
+// | HT_DCPHASE   | Hilbert Transform - Dominant Cycle Phase    | Done    |
+// | HT_PHASOR    | Hilbert Transform - Phasor Components       | Done    |
+// | HT_SINE      | Hilbert Transform - SineWave                | Done    |
+// | HT_TRENDMODE | Hilbert Transform - Trend vs Cycle Mode     | Done    |
+
+/// This module contains the cycle indicators.
+mod dominant_cycle_phase;
+mod ht_dc_period;
+mod ht_dc_phase;
+mod ht_phasor;
+mod ht_sine;
+mod ht_trend_mode;
+
+
+pub use ht_dc_period::{ht_dc_period_lookback, ht_dc_period_slice, HtDcPeriod};
+pub use ht_dc_phase::{ht_dc_phase_lookback, ht_dc_phase_slice, HtDcPhase};
+pub use ht_phasor::{ht_phasor_lookback, ht_phasor_slice, HtPhasor};
+pub use ht_sine::{ht_sine_lookback, ht_sine_slice, HtSine};
+pub use ht_trend_mode::{ht_trend_mode_lookback, ht_trend_mode_slice, HtTrendMode};
+
+#[cfg(feature = "polars")]
+pub use ht_dc_period::ht_dc_period;
+#[cfg(feature = "polars")]
+pub use ht_dc_phase::ht_dc_phase;
+#[cfg(feature = "polars")]
+pub use ht_phasor::ht_phasor;
+#[cfg(feature = "polars")]
+pub use ht_sine::ht_sine;
+#[cfg(feature = "polars")]
+pub use ht_trend_mode::ht_trend_mode;
@@ -9,18 +9,77 @@
  * @Description:
  */
 
+#[cfg(feature = "polars")]
+use polars::prelude::PolarsError;
 use std::error::Error;
 use std::fmt;
 
-/// Custom error type for providing error messages.
+/// Error type returned by every indicator.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{simple_moving_average_slice, TalibError};
+///
+/// let close = [1.0, 2.0];
+/// match simple_moving_average_slice(&close, Some(3)) {
+///     Err(TalibError::InsufficientData { needed, got }) => eprintln!("need {} bars, got {}", needed, got),
+///     Err(e) => eprintln!("{}", e),
+///     Ok(sma) => eprintln!("{:?}", sma),
+/// }
+/// ```
 #[derive(Debug)]
-pub struct ErrorMsg(pub String);
+pub enum TalibError {
+    /// The input is shorter than the indicator needs.
+    InsufficientData { needed: usize, got: usize },
+    /// A parameter is outside of its valid range.
+    InvalidParameter {
+        name: &'static str,
+        value: f64,
+        reason: &'static str,
+    },
+    /// Two inputs that must be aligned bar for bar have different lengths.
+    LengthMismatch { expected: usize, got: usize },
+    /// The input Series has a data type the indicator can't work on.
+    UnsupportedDtype { dtype: String },
+    /// An error raised by polars.
+    #[cfg(feature = "polars")]
+    Polars(PolarsError),
+}
 
-impl fmt::Display for ErrorMsg {
+impl fmt::Display for TalibError {
     /// Formats the error message.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            TalibError::InsufficientData { needed, got } => {
+                write!(f, "insufficient data: needed {} values, got {}", needed, got)
+            }
+            TalibError::InvalidParameter { name, value, reason } => {
+                write!(f, "invalid parameter `{}` = {}: {}", name, value, reason)
+            }
+            TalibError::LengthMismatch { expected, got } => {
+                write!(f, "length mismatch: expected {} values, got {}", expected, got)
+            }
+            TalibError::UnsupportedDtype { dtype } => write!(f, "unsupported dtype: {}", dtype),
+            #[cfg(feature = "polars")]
+            TalibError::Polars(e) => write!(f, "polars error: {}", e),
+        }
+    }
+}
+
+impl Error for TalibError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "polars")]
+            TalibError::Polars(e) => Some(e),
+            _ => None,
+        }
     }
 }
 
-impl Error for ErrorMsg {}
+#[cfg(feature = "polars")]
+impl From<PolarsError> for TalibError {
+    fn from(e: PolarsError) -> Self {
+        TalibError::Polars(e)
+    }
+}
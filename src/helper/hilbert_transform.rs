@@ -0,0 +1,141 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/25 10:40
+ * @Email: uyplayer@qq.com
+ * @File: hilbert_transform.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/helper
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hilbert Transform engine shared by MAMA, HT_TRENDLINE and the cycle indicators.
+//!
+//! This is John Ehlers' homodyne discriminator as written in TA-Lib:
+//! - smoothed = WMA(price, 4)
+//! - detrender = hilbert(smoothed), q1 = hilbert(detrender), i1 = detrender 3 bars ago
+//! - ji = hilbert(i1), jq = hilbert(q1)
+//! - i2 = 0.2 * (i1 - jq) + 0.8 * i2, q2 = 0.2 * (q1 + ji) + 0.8 * q2
+//! - re = 0.2 * (i2 * i2' + q2 * q2') + 0.8 * re, im = 0.2 * (i2 * q2' - q2 * i2') + 0.8 * im
+//! - period = 360 / atan(im / re) in degrees, limited to 0.67..1.5 times the previous period and to 6..50
+//!
+//! where `hilbert(x) = (0.0962 * x + 0.5769 * x[2] - 0.5769 * x[4] - 0.0962 * x[6]) * (0.075 * period' + 0.54)`.
+
+use crate::helper::LagBuffer;
+
+/// Index of the first bar fed to the Hilbert filters for the indicators with a lookback of 32.
+pub(crate) const HT_START_32: usize = 12;
+/// Index of the first bar fed to the Hilbert filters for the indicators with a lookback of 63.
+pub(crate) const HT_START_63: usize = 37;
+
+/// Output of one Hilbert Transform step.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HilbertStep {
+    /// WMA of the price the filters were fed.
+    #[cfg(feature = "cycle_indicators")]
+    pub(crate) smoothed: f64,
+    /// In-phase component.
+    pub(crate) i1: f64,
+    /// Quadrature component.
+    pub(crate) q1: f64,
+    /// Dominant cycle period.
+    #[cfg(feature = "overlap_studies")]
+    pub(crate) period: f64,
+    /// Dominant cycle period smoothed once more, `0.33 * period + 0.67 * smooth_period`.
+    pub(crate) smooth_period: f64,
+}
+
+/// Streaming Hilbert Transform, one price per bar.
+#[derive(Debug, Clone)]
+pub(crate) struct HilbertTransform {
+    start: usize,
+    bar_index: usize,
+    price: LagBuffer<4>,
+    smoothed: LagBuffer<7>,
+    detrender: LagBuffer<7>,
+    i1: LagBuffer<7>,
+    q1: LagBuffer<7>,
+    i2: f64,
+    q2: f64,
+    re: f64,
+    im: f64,
+    period: f64,
+    smooth_period: f64,
+}
+
+impl HilbertTransform {
+    /// Creates a new engine, the bars before `start` only warm the price WMA up.
+    pub(crate) fn new(start: usize) -> Self {
+        HilbertTransform {
+            start,
+            bar_index: 0,
+            price: LagBuffer::new(),
+            smoothed: LagBuffer::new(),
+            detrender: LagBuffer::new(),
+            i1: LagBuffer::new(),
+            q1: LagBuffer::new(),
+            i2: 0.0,
+            q2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            period: 0.0,
+            smooth_period: 0.0,
+        }
+    }
+
+    /// Index of the next bar, the number of prices seen so far.
+    pub(crate) fn bar_index(&self) -> usize {
+        self.bar_index
+    }
+
+    /// Feeds the next price, returns `None` while the WMA is warming up.
+    pub(crate) fn update(&mut self, price: f64) -> Option<HilbertStep> {
+        let bar_index = self.bar_index;
+        self.bar_index += 1;
+        self.price.push(price);
+        if bar_index < self.start {
+            return None;
+        }
+
+        let p = &self.price;
+        let smoothed = (4.0 * p.get(0) + 3.0 * p.get(1) + 2.0 * p.get(2) + p.get(3)) * 0.1;
+        let adjust = 0.075 * self.period + 0.54;
+        let hilbert = |x: &LagBuffer<7>| (0.0962 * x.get(0) + 0.5769 * x.get(2) - 0.5769 * x.get(4) - 0.0962 * x.get(6)) * adjust;
+
+        self.smoothed.push(smoothed);
+        let detrender = hilbert(&self.smoothed);
+        self.detrender.push(detrender);
+        let q1 = hilbert(&self.detrender);
+        let i1 = self.detrender.get(3);
+        self.q1.push(q1);
+        self.i1.push(i1);
+        let ji = hilbert(&self.i1);
+        let jq = hilbert(&self.q1);
+
+        let q2 = 0.2 * (q1 + ji) + 0.8 * self.q2;
+        let i2 = 0.2 * (i1 - jq) + 0.8 * self.i2;
+        self.re = 0.2 * (i2 * self.i2 + q2 * self.q2) + 0.8 * self.re;
+        self.im = 0.2 * (i2 * self.q2 - q2 * self.i2) + 0.8 * self.im;
+        self.q2 = q2;
+        self.i2 = i2;
+
+        let prev_period = self.period;
+        let mut period = prev_period;
+        if self.im != 0.0 && self.re != 0.0 {
+            period = 360.0 / (self.im / self.re).atan().to_degrees();
+        }
+        period = period.min(1.5 * prev_period).max(0.67 * prev_period).clamp(6.0, 50.0);
+        self.period = 0.2 * period + 0.8 * prev_period;
+        self.smooth_period = 0.33 * self.period + 0.67 * self.smooth_period;
+
+        Some(HilbertStep {
+            #[cfg(feature = "cycle_indicators")]
+            smoothed,
+            i1,
+            q1,
+            #[cfg(feature = "overlap_studies")]
+            period: self.period,
+            smooth_period: self.smooth_period,
+        })
+    }
+}
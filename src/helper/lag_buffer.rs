@@ -0,0 +1,39 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2026/10/17 10:05
+ * @Email: uyplayer@qq.com
+ * @File: lag_buffer.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/helper
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Fixed size history used by the streaming indicators.
+
+/// Keeps the last `N` values pushed into it, newest first.
+///
+/// `get(0)` is the most recent value, `get(1)` the one before it and so on.
+/// Slots that were never written read as `0.0`, which is the same warm-up
+/// value the batch functions start their vectors with.
+#[derive(Debug, Clone)]
+pub(crate) struct LagBuffer<const N: usize> {
+    values: [f64; N],
+}
+
+impl<const N: usize> LagBuffer<N> {
+    pub(crate) fn new() -> Self {
+        LagBuffer { values: [0.0; N] }
+    }
+
+    /// Pushes a new value, dropping the oldest one.
+    pub(crate) fn push(&mut self, value: f64) {
+        self.values.rotate_right(1);
+        self.values[0] = value;
+    }
+
+    /// Returns the value pushed `lag` updates ago.
+    pub(crate) fn get(&self, lag: usize) -> f64 {
+        self.values[lag]
+    }
+}
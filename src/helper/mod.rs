@@ -10,4 +10,31 @@
  */
 
 mod error_handle;
-pub use error_handle::ErrorMsg;
+#[cfg(any(feature = "overlap_studies", feature = "cycle_indicators"))]
+mod hilbert_transform;
+#[cfg(any(feature = "overlap_studies", feature = "cycle_indicators"))]
+mod lag_buffer;
+#[cfg(feature = "overlap_studies")]
+mod rolling_extremes;
+#[cfg(feature = "polars")]
+mod series;
+pub use error_handle::TalibError;
+
+#[cfg(any(feature = "overlap_studies", feature = "cycle_indicators"))]
+pub(crate) use hilbert_transform::{HilbertTransform, HT_START_32, HT_START_63};
+#[cfg(any(feature = "overlap_studies", feature = "cycle_indicators"))]
+pub(crate) use lag_buffer::LagBuffer;
+#[cfg(feature = "overlap_studies")]
+pub(crate) use rolling_extremes::RollingExtremes;
+#[cfg(feature = "polars")]
+pub(crate) use series::series_to_vec;
+#[cfg(all(any(feature = "price_transform", feature = "cycle_indicators"), feature = "polars"))]
+pub(crate) use series::vec_to_series;
+#[cfg(all(feature = "overlap_studies", feature = "polars"))]
+pub(crate) use series::series_to_keys;
+#[cfg(all(any(feature = "cycle_indicators", feature = "pattern_recognition"), feature = "polars"))]
+pub(crate) use series::int_vec_to_series;
+
+/// Values whose absolute value is below this are treated as zero, like TA-Lib's `TA_IS_ZERO`.
+#[cfg(any(feature = "momentum_indicators", feature = "volatility_indicators"))]
+pub(crate) const ZERO: f64 = 1e-8;
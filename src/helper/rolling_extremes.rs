@@ -0,0 +1,78 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2026/10/17 11:40
+ * @Email: uyplayer@qq.com
+ * @File: rolling_extremes.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/helper
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Highest and lowest value over a sliding window in amortized O(1) per update.
+
+use std::collections::VecDeque;
+
+/// Tracks the highest high and the lowest low of the last `time_period` updates.
+///
+/// Each side is a monotonic deque of `(index, value)`: a new value drops every older one it
+/// dominates, so the front is always the extreme of the window and every value is pushed and
+/// popped at most once.
+#[derive(Debug, Clone)]
+pub(crate) struct RollingExtremes {
+    time_period: usize,
+    index: usize,
+    highs: VecDeque<(usize, f64)>,
+    lows: VecDeque<(usize, f64)>,
+}
+
+impl RollingExtremes {
+    pub(crate) fn new(time_period: usize) -> Self {
+        RollingExtremes {
+            time_period,
+            index: 0,
+            highs: VecDeque::with_capacity(time_period),
+            lows: VecDeque::with_capacity(time_period),
+        }
+    }
+
+    /// Feeds the next high/low pair, returns `(highest, lowest)` once the window is full.
+    pub(crate) fn update(&mut self, high: f64, low: f64) -> Option<(f64, f64)> {
+        while self.highs.back().is_some_and(|(_, value)| *value <= high) {
+            self.highs.pop_back();
+        }
+        self.highs.push_back((self.index, high));
+        while self.lows.back().is_some_and(|(_, value)| *value >= low) {
+            self.lows.pop_back();
+        }
+        self.lows.push_back((self.index, low));
+        self.index += 1;
+        // drop the values that slid out of the window
+        let oldest = self.index.saturating_sub(self.time_period);
+        while self.highs.front().is_some_and(|(index, _)| *index < oldest) {
+            self.highs.pop_front();
+        }
+        while self.lows.front().is_some_and(|(index, _)| *index < oldest) {
+            self.lows.pop_front();
+        }
+        if self.index < self.time_period {
+            return None;
+        }
+        Some((self.highs.front()?.1, self.lows.front()?.1))
+    }
+
+    /// Returns how many updates ago the current highest high and lowest low were fed,
+    /// the most recent one wins a tie.
+    #[cfg(feature = "momentum_indicators")]
+    pub(crate) fn ages(&self) -> (usize, usize) {
+        let age = |extremes: &VecDeque<(usize, f64)>| extremes.front().map_or(0, |(index, _)| self.index - 1 - index);
+        (age(&self.highs), age(&self.lows))
+    }
+
+    /// Clears all accumulated values.
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
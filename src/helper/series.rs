@@ -0,0 +1,88 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2026/10/17 14:20
+ * @Email: uyplayer@qq.com
+ * @File: series.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/helper
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Conversions between polars `Series` and the plain slices the indicators work on.
+
+use crate::TalibError;
+use polars::prelude::*;
+
+/// Casts a numeric Series to `Vec<f64>`, nulls become `NaN`.
+pub(crate) fn series_to_vec(src: &Series) -> Result<Vec<f64>, TalibError> {
+    if !src.dtype().is_numeric() {
+        return Err(TalibError::UnsupportedDtype {
+            dtype: src.dtype().to_string(),
+        });
+    }
+    let values = src.cast(&DataType::Float64)?;
+    Ok(values
+        .f64()?
+        .into_iter()
+        .map(|value| value.unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Builds a Float64 Series from indicator output, `NaN` (the unstable prefix) becomes null.
+#[cfg(any(feature = "price_transform", feature = "cycle_indicators"))]
+pub(crate) fn vec_to_series(name: &str, values: Vec<f64>) -> Series {
+    let values: Float64Chunked = values
+        .into_iter()
+        .map(|value| if value.is_nan() { None } else { Some(value) })
+        .collect();
+    values.with_name(name).into_series()
+}
+
+/// Builds an Int32 Series from integer indicator output, `None` (the unstable prefix) becomes null.
+#[cfg(any(feature = "cycle_indicators", feature = "pattern_recognition"))]
+pub(crate) fn int_vec_to_series(name: &str, values: Vec<Option<i32>>) -> Series {
+    let values: Int32Chunked = values.into_iter().collect();
+    values.with_name(name).into_series()
+}
+
+/// Casts an integer, date or datetime Series to session keys, a null stays in the session of the
+/// value before it (leading nulls in the first session). A datetime is truncated to its calendar
+/// day (the local day in the column's own time zone for a zoned datetime), so the intraday bars
+/// of one day share a session.
+#[cfg(feature = "overlap_studies")]
+pub(crate) fn series_to_keys(src: &Series) -> Result<Vec<i64>, TalibError> {
+    let local;
+    let src = match src.dtype() {
+        // shift the UTC instants to local wall clock time before truncating
+        DataType::Datetime(_, Some(_)) => {
+            let ambiguous = Utf8Chunked::from_slice("ambiguous", &["raise"]);
+            local = replace_time_zone(src.datetime()?, None, &ambiguous)?.into_series();
+            &local
+        }
+        _ => src,
+    };
+    let per_day: i64 = match src.dtype() {
+        DataType::Datetime(TimeUnit::Nanoseconds, _) => 86_400_000_000_000,
+        DataType::Datetime(TimeUnit::Microseconds, _) => 86_400_000_000,
+        DataType::Datetime(TimeUnit::Milliseconds, _) => 86_400_000,
+        _ => 1,
+    };
+    let physical = src.to_physical_repr();
+    if !physical.dtype().is_integer() {
+        return Err(TalibError::UnsupportedDtype {
+            dtype: src.dtype().to_string(),
+        });
+    }
+    let keys = physical.cast(&DataType::Int64)?;
+    let keys = keys.i64()?;
+    let day = |value: i64| value.div_euclid(per_day);
+    let mut key = keys.into_iter().flatten().next().map(day).unwrap_or_default();
+    Ok(keys
+        .into_iter()
+        .map(|value| {
+            key = value.map_or(key, day);
+            key
+        })
+        .collect())
+}
@@ -1,10 +1,85 @@
 #[cfg(feature = "overlap_studies")]
 mod overlap_studies;
+#[cfg(all(feature = "overlap_studies", feature = "polars"))]
 pub use overlap_studies::{
-    b_bands, exponential_moving_average, moving_average, simple_moving_average,double_exponential_moving_average,ht_trend_line,kama,mama,mavp
+    b_bands, b_bands_legacy, exponential_moving_average, exponential_moving_average_legacy, moving_average, moving_average_legacy, simple_moving_average, simple_moving_average_legacy,double_exponential_moving_average,double_exponential_moving_average_legacy,ht_trend_line,kama,kama_legacy,mama,mama_with_period,mavp,mavp_legacy,midpoint,midprice,rolling_vwap,sar,sarext,t3,triangular_moving_average,triple_exponential_moving_average,vwap,vwma,weighted_moving_average
 };
+#[cfg(feature = "overlap_studies")]
+pub use overlap_studies::{
+    b_bands_legacy_slice, b_bands_slice, exponential_moving_average_legacy_slice, exponential_moving_average_slice, moving_average_legacy_slice, moving_average_slice, simple_moving_average_legacy_slice, simple_moving_average_slice,double_exponential_moving_average_legacy_slice,double_exponential_moving_average_slice,ht_trend_line_slice,kama_legacy_slice,kama_slice,mama_slice,mama_with_period_slice,mavp_legacy_slice,mavp_slice,midpoint_slice,midprice_slice,rolling_vwap_slice,sar_slice,sarext_slice,t3_slice,triangular_moving_average_slice,triple_exponential_moving_average_slice,vwap_slice,vwma_slice,weighted_moving_average_slice
+};
+#[cfg(feature = "overlap_studies")]
+pub use overlap_studies::{ b_bands_lookback, exponential_moving_average_lookback, moving_average_lookback, simple_moving_average_lookback,double_exponential_moving_average_lookback,ht_trend_line_lookback,kama_lookback,mama_lookback,mavp_lookback,midpoint_lookback,midprice_lookback,rolling_vwap_lookback,sar_lookback,sarext_lookback,t3_lookback,triangular_moving_average_lookback,triple_exponential_moving_average_lookback,vwap_lookback,vwma_lookback,weighted_moving_average_lookback };
+#[cfg(feature = "overlap_studies")]
+pub use overlap_studies::{BBands, Dema, Ema, HtTrendLine, Kama, Ma, MaType, Mama, Mavp, MidPoint, MidPrice, RollingVwap, Sar, Sarext, Sma, Tema, Trima, Vwap, Vwma, Wma, T3};
+
+#[cfg(feature = "momentum_indicators")]
+mod momentum_indicators;
+#[cfg(all(feature = "momentum_indicators", feature = "polars"))]
+pub use momentum_indicators::{adx, adxr, apo, aroon, aroonosc, bop, cci, cmo, dx, macd, macdext, macdfix, mfi, minus_di, minus_dm, mom, plus_di, plus_dm, ppo, roc, rocp, rocr, rocr100, rsi, stoch, stochf, stochrsi, trix, ultosc, willr};
+#[cfg(feature = "momentum_indicators")]
+pub use momentum_indicators::{adx_slice, adxr_slice, apo_slice, aroon_slice, aroonosc_slice, bop_slice, cci_slice, cmo_slice, dx_slice, macd_slice, macdext_slice, macdfix_slice, mfi_slice, minus_di_slice, minus_dm_slice, mom_slice, plus_di_slice, plus_dm_slice, ppo_slice, roc_slice, rocp_slice, rocr_slice, rocr100_slice, rsi_slice, stoch_slice, stochf_slice, stochrsi_slice, trix_slice, ultosc_slice, willr_slice};
+#[cfg(feature = "momentum_indicators")]
+pub use momentum_indicators::{adx_lookback, adxr_lookback, apo_lookback, aroon_lookback, aroonosc_lookback, bop_lookback, cci_lookback, cmo_lookback, dx_lookback, macd_lookback, macdext_lookback, macdfix_lookback, mfi_lookback, minus_di_lookback, minus_dm_lookback, mom_lookback, plus_di_lookback, plus_dm_lookback, ppo_lookback, roc_lookback, rocp_lookback, rocr_lookback, rocr100_lookback, rsi_lookback, stoch_lookback, stochf_lookback, stochrsi_lookback, trix_lookback, ultosc_lookback, willr_lookback};
+#[cfg(feature = "momentum_indicators")]
+pub use momentum_indicators::{Adx, Adxr, Apo, Aroon, AroonOsc, Bop, Cci, Cmo, Dx, Macd, MacdExt, MacdFix, Mfi, MinusDi, MinusDm, Mom, PlusDi, PlusDm, Ppo, Roc, Rocp, Rocr, Rocr100, Rsi, Stoch, StochF, StochRsi, Trix, UltOsc, Willr};
+
+#[cfg(feature = "volume_indicators")]
+mod volume_indicators;
+#[cfg(all(feature = "volume_indicators", feature = "polars"))]
+pub use volume_indicators::{ad, adosc, force_index, nvi, obv, pvi, pvt};
+#[cfg(feature = "volume_indicators")]
+pub use volume_indicators::{ad_slice, adosc_slice, force_index_slice, nvi_slice, obv_slice, pvi_slice, pvt_slice};
+#[cfg(feature = "volume_indicators")]
+pub use volume_indicators::{ad_lookback, adosc_lookback, force_index_lookback, nvi_lookback, obv_lookback, pvi_lookback, pvt_lookback};
+#[cfg(feature = "volume_indicators")]
+pub use volume_indicators::{Ad, AdOsc, ForceIndex, Nvi, Obv, Pvi, Pvt};
+
+#[cfg(feature = "volatility_indicators")]
+mod volatility_indicators;
+#[cfg(all(feature = "volatility_indicators", feature = "polars"))]
+pub use volatility_indicators::{atr, chandelier_exit, close_to_close_volatility, donchian_channels, garman_klass_volatility, keltner_channels, natr, parkinson_volatility, rogers_satchell_volatility, trange, yang_zhang_volatility};
+#[cfg(feature = "volatility_indicators")]
+pub use volatility_indicators::{atr_slice, chandelier_exit_slice, close_to_close_volatility_slice, donchian_channels_slice, garman_klass_volatility_slice, keltner_channels_slice, natr_slice, parkinson_volatility_slice, rogers_satchell_volatility_slice, trange_slice, yang_zhang_volatility_slice};
+#[cfg(feature = "volatility_indicators")]
+pub use volatility_indicators::{atr_lookback, chandelier_exit_lookback, close_to_close_volatility_lookback, donchian_channels_lookback, garman_klass_volatility_lookback, keltner_channels_lookback, natr_lookback, parkinson_volatility_lookback, rogers_satchell_volatility_lookback, trange_lookback, yang_zhang_volatility_lookback};
+#[cfg(feature = "volatility_indicators")]
+pub use volatility_indicators::{Atr, ChandelierExit, CloseToCloseVolatility, DonchianChannels, GarmanKlassVolatility, KeltnerChannels, Natr, ParkinsonVolatility, RogersSatchellVolatility, TRange, YangZhangVolatility};
+
+#[cfg(feature = "price_transform")]
+mod price_transform;
+#[cfg(all(feature = "price_transform", feature = "polars"))]
+pub use price_transform::{avgprice, heikin_ashi, medprice, typprice, wclprice};
+#[cfg(feature = "price_transform")]
+pub use price_transform::{avgprice_slice, heikin_ashi_slice, medprice_slice, typprice_slice, wclprice_slice};
+#[cfg(feature = "price_transform")]
+pub use price_transform::{avgprice_lookback, heikin_ashi_lookback, medprice_lookback, typprice_lookback, wclprice_lookback};
+#[cfg(feature = "price_transform")]
+pub use price_transform::{AvgPrice, HeikinAshi, MedPrice, TypPrice, WclPrice};
+
+#[cfg(feature = "cycle_indicators")]
+mod cycle_indicators;
+#[cfg(all(feature = "cycle_indicators", feature = "polars"))]
+pub use cycle_indicators::{ht_dc_period, ht_dc_phase, ht_phasor, ht_sine, ht_trend_mode};
+#[cfg(feature = "cycle_indicators")]
+pub use cycle_indicators::{ht_dc_period_slice, ht_dc_phase_slice, ht_phasor_slice, ht_sine_slice, ht_trend_mode_slice};
+#[cfg(feature = "cycle_indicators")]
+pub use cycle_indicators::{ht_dc_period_lookback, ht_dc_phase_lookback, ht_phasor_lookback, ht_sine_lookback, ht_trend_mode_lookback};
+#[cfg(feature = "cycle_indicators")]
+pub use cycle_indicators::{HtDcPeriod, HtDcPhase, HtPhasor, HtSine, HtTrendMode};
+
+#[cfg(feature = "pattern_recognition")]
+mod pattern_recognition;
+#[cfg(all(feature = "pattern_recognition", feature = "polars"))]
+pub use pattern_recognition::{cdl_2_crows, cdl_3_black_crows, cdl_3_inside, cdl_3_line_strike, cdl_3_outside, cdl_3_stars_in_south, cdl_3_white_soldiers, cdl_abandoned_baby, cdl_advance_block, cdl_breakaway, cdl_conceal_baby_swallow, cdl_doji, cdl_dragonfly_doji, cdl_engulfing, cdl_evening_doji_star, cdl_evening_star, cdl_gap_side_side_white, cdl_gravestone_doji, cdl_hammer, cdl_hanging_man, cdl_harami, cdl_harami_cross, cdl_hikkake, cdl_hikkake_mod, cdl_identical_3_crows, cdl_inverted_hammer, cdl_ladder_bottom, cdl_long_legged_doji, cdl_long_line, cdl_marubozu, cdl_mat_hold, cdl_morning_doji_star, cdl_morning_star, cdl_rise_fall_3_methods, cdl_shooting_star, cdl_short_line, cdl_spinning_top, cdl_stalled_pattern, cdl_stick_sandwich, cdl_tasuki_gap, cdl_tristar, cdl_unique_3_river, cdl_upside_gap_2_crows, cdl_xside_gap_3_methods};
+#[cfg(feature = "pattern_recognition")]
+pub use pattern_recognition::{cdl_2_crows_slice, cdl_3_black_crows_slice, cdl_3_inside_slice, cdl_3_line_strike_slice, cdl_3_outside_slice, cdl_3_stars_in_south_slice, cdl_3_white_soldiers_slice, cdl_abandoned_baby_slice, cdl_advance_block_slice, cdl_breakaway_slice, cdl_conceal_baby_swallow_slice, cdl_doji_slice, cdl_dragonfly_doji_slice, cdl_engulfing_slice, cdl_evening_doji_star_slice, cdl_evening_star_slice, cdl_gap_side_side_white_slice, cdl_gravestone_doji_slice, cdl_hammer_slice, cdl_hanging_man_slice, cdl_harami_slice, cdl_harami_cross_slice, cdl_hikkake_slice, cdl_hikkake_mod_slice, cdl_identical_3_crows_slice, cdl_inverted_hammer_slice, cdl_ladder_bottom_slice, cdl_long_legged_doji_slice, cdl_long_line_slice, cdl_marubozu_slice, cdl_mat_hold_slice, cdl_morning_doji_star_slice, cdl_morning_star_slice, cdl_rise_fall_3_methods_slice, cdl_shooting_star_slice, cdl_short_line_slice, cdl_spinning_top_slice, cdl_stalled_pattern_slice, cdl_stick_sandwich_slice, cdl_tasuki_gap_slice, cdl_tristar_slice, cdl_unique_3_river_slice, cdl_upside_gap_2_crows_slice, cdl_xside_gap_3_methods_slice};
+#[cfg(feature = "pattern_recognition")]
+pub use pattern_recognition::{cdl_2_crows_lookback, cdl_3_black_crows_lookback, cdl_3_inside_lookback, cdl_3_line_strike_lookback, cdl_3_outside_lookback, cdl_3_stars_in_south_lookback, cdl_3_white_soldiers_lookback, cdl_abandoned_baby_lookback, cdl_advance_block_lookback, cdl_breakaway_lookback, cdl_conceal_baby_swallow_lookback, cdl_doji_lookback, cdl_dragonfly_doji_lookback, cdl_engulfing_lookback, cdl_evening_doji_star_lookback, cdl_evening_star_lookback, cdl_gap_side_side_white_lookback, cdl_gravestone_doji_lookback, cdl_hammer_lookback, cdl_hanging_man_lookback, cdl_harami_lookback, cdl_harami_cross_lookback, cdl_hikkake_lookback, cdl_hikkake_mod_lookback, cdl_identical_3_crows_lookback, cdl_inverted_hammer_lookback, cdl_ladder_bottom_lookback, cdl_long_legged_doji_lookback, cdl_long_line_lookback, cdl_marubozu_lookback, cdl_mat_hold_lookback, cdl_morning_doji_star_lookback, cdl_morning_star_lookback, cdl_rise_fall_3_methods_lookback, cdl_shooting_star_lookback, cdl_short_line_lookback, cdl_spinning_top_lookback, cdl_stalled_pattern_lookback, cdl_stick_sandwich_lookback, cdl_tasuki_gap_lookback, cdl_tristar_lookback, cdl_unique_3_river_lookback, cdl_upside_gap_2_crows_lookback, cdl_xside_gap_3_methods_lookback};
+#[cfg(feature = "pattern_recognition")]
+pub use pattern_recognition::{CandleSetting, CandleSettingType, CandleSettings, Cdl2Crows, Cdl3BlackCrows, Cdl3Inside, Cdl3LineStrike, Cdl3Outside, Cdl3StarsInSouth, Cdl3WhiteSoldiers, CdlAbandonedBaby, CdlAdvanceBlock, CdlBreakaway, CdlConcealBabySwallow, CdlDoji, CdlDragonflyDoji, CdlEngulfing, CdlEveningDojiStar, CdlEveningStar, CdlGapSideSideWhite, CdlGravestoneDoji, CdlHammer, CdlHangingMan, CdlHarami, CdlHaramiCross, CdlHikkake, CdlHikkakeMod, CdlIdentical3Crows, CdlInvertedHammer, CdlLadderBottom, CdlLongLeggedDoji, CdlLongLine, CdlMarubozu, CdlMatHold, CdlMorningDojiStar, CdlMorningStar, CdlRiseFall3Methods, CdlShootingStar, CdlShortLine, CdlSpinningTop, CdlStalledPattern, CdlStickSandwich, CdlTasukiGap, CdlTristar, CdlUnique3River, CdlUpsideGap2Crows, CdlXSideGap3Methods, RangeType};
 
 mod helper;
-pub use helper::ErrorMsg;
+pub use helper::TalibError;
 
 mod math;
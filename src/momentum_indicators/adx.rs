@@ -0,0 +1,197 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 17:45
+ * @Email: uyplayer@qq.com
+ * @File: adx.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Average Directional Movement Index
+//!
+//! - dx = 100 * |+DI - -DI| / (+DI + -DI)
+//! - the first adx is the average of the first `time_period` dx values, then
+//!   adx = (adx * (time_period - 1) + dx) / time_period
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::directional_movement::DirectionalIndex;
+use crate::TalibError;
+
+/// Number of leading bars without an ADX value, `2 * time_period - 1` like TA-Lib.
+pub fn adx_lookback(time_period: Option<usize>) -> usize {
+    (2 * time_period.unwrap_or(14)).saturating_sub(1)
+}
+
+/// Calculates the Average Directional Movement Index (ADX) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`adx`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::adx_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+/// let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+/// let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+/// let adx = adx_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(adx[2].is_nan());
+/// assert!((adx[3] - 37.5).abs() < 1e-9);
+/// ```
+pub fn adx_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Adx::new(time_period)?;
+    let lookback = adx_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Average Directional Movement Index (ADX).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::adx;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = adx(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn adx(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let adx = adx_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", adx))
+}
+
+/// Streaming Average Directional Movement Index (ADX).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Adx;
+///
+/// let mut adx = Adx::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", adx.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adx {
+    time_period: usize,
+    index: DirectionalIndex,
+    count: usize,
+    sum: f64,
+    adx: Option<f64>,
+}
+
+impl Adx {
+    /// Creates a new ADX state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Adx {
+            time_period,
+            index: DirectionalIndex::new(time_period),
+            count: 0,
+            sum: 0.0,
+            adx: None,
+        })
+    }
+
+    /// Feeds the next bar and returns the current ADX once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let dx = self.index.update(high, low, close)?.dx();
+        let time_period = self.time_period as f64;
+        match self.adx {
+            // a DX of 0 / 0 counts as 0 in the first average and is skipped afterwards
+            None => {
+                self.count += 1;
+                self.sum += dx.unwrap_or(0.0);
+                if self.count < self.time_period {
+                    return None;
+                }
+                self.adx = Some(self.sum / time_period);
+            }
+            Some(adx) => {
+                if let Some(dx) = dx {
+                    self.adx = Some((adx * (time_period - 1.0) + dx) / time_period);
+                }
+            }
+        }
+        self.adx
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Adx {
+            time_period: self.time_period,
+            index: DirectionalIndex::new(self.time_period),
+            count: 0,
+            sum: 0.0,
+            adx: None,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adx() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0, 12.5, 12.5, 12.5];
+        let low = [9.0, 9.5, 9.0, 10.0, 11.5, 11.5, 11.5];
+        let close = [9.5, 10.5, 10.0, 11.5, 12.0, 12.0, 12.0];
+        let adx = adx_slice(&high, &low, &close, Some(2))?;
+        let dx = crate::dx_slice(&high, &low, &close, Some(2))?;
+        assert!(adx[..3].iter().all(|value| value.is_nan()));
+        assert!((adx[3] - (dx[2] + dx[3]) / 2.0).abs() < 1e-9);
+        for i in 4..adx.len() {
+            assert!((adx[i] - (adx[i - 1] + dx[i]) / 2.0).abs() < 1e-9);
+        }
+        assert!(adx_slice(&high, &low, &close, Some(4)).is_err());
+        Ok(())
+    }
+}
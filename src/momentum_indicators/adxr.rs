@@ -0,0 +1,171 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 18:20
+ * @Email: uyplayer@qq.com
+ * @File: adxr.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Average Directional Movement Index Rating
+//!
+//! adxr = (adx + adx `time_period - 1` bars ago) / 2
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{Adx, TalibError};
+use std::collections::VecDeque;
+
+/// Number of leading bars without an ADX value, `2 * time_period - 1` like TA-Lib.
+pub fn adxr_lookback(time_period: Option<usize>) -> usize {
+    (2 * time_period.unwrap_or(14)).saturating_sub(1)
+}
+
+/// Calculates the Average Directional Movement Index Rating (ADXR) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`adxr`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::adxr_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+/// let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+/// let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+/// let adxr = adxr_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(adxr[3].is_nan());
+/// assert!(!adxr[4].is_nan());
+/// ```
+pub fn adxr_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Adxr::new(time_period)?;
+    let lookback = adxr_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Average Directional Movement Index Rating (ADXR).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::adxr;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = adxr(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn adxr(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let adxr = adxr_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", adxr))
+}
+
+/// Streaming Average Directional Movement Index Rating (ADXR).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Adxr;
+///
+/// let mut adxr = Adxr::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", adxr.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adxr {
+    time_period: usize,
+    adx: Adx,
+    window: VecDeque<f64>,
+}
+
+impl Adxr {
+    /// Creates a new ADXR state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(Adxr {
+            time_period,
+            adx: Adx::new(time_period)?,
+            window: VecDeque::with_capacity(time_period + 1),
+        })
+    }
+
+    /// Feeds the next bar and returns the current ADXR once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let adx = self.adx.update(high, low, close)?;
+        self.window.push_back(adx);
+        if self.window.len() > self.time_period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        Some((self.window[0] + adx) / 2.0)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.adx.reset();
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adxr() -> Result<(), TalibError> {
+        let high: Vec<f64> = (0..30).map(|i| 12.0 + 3.0 * (i as f64 * 0.4).sin()).collect();
+        let low: Vec<f64> = high.iter().map(|h| h - 1.5).collect();
+        let close: Vec<f64> = high.iter().map(|h| h - 0.5).collect();
+        let adxr = adxr_slice(&high, &low, &close, Some(4))?;
+        let adx = crate::adx_slice(&high, &low, &close, Some(4))?;
+        assert!(adxr[..10].iter().all(|value| value.is_nan()));
+        for i in 10..adxr.len() {
+            assert!((adxr[i] - (adx[i] + adx[i - 3]) / 2.0).abs() < 1e-9);
+        }
+        assert!(matches!(
+            adxr_slice(&high, &low, &close, Some(1)),
+            Err(TalibError::InvalidParameter { name: "time_period", .. })
+        ));
+        Ok(())
+    }
+}
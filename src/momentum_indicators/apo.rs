@@ -0,0 +1,149 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/04 19:55
+ * @Email: uyplayer@qq.com
+ * @File: apo.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Absolute Price Oscillator
+//!
+//! apo = fast MA - slow MA
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_oscillator::OscillatorLegs;
+use crate::{moving_average_lookback, MaType, TalibError};
+
+/// Number of leading bars without an APO value, the longest lookback of the fast and slow MAs like TA-Lib.
+pub fn apo_lookback(fast_period: Option<usize>, slow_period: Option<usize>, ma_type: Option<MaType>) -> usize {
+    let fast = moving_average_lookback(Some(fast_period.unwrap_or(12)), ma_type);
+    let slow = moving_average_lookback(Some(slow_period.unwrap_or(26)), ma_type);
+    fast.max(slow)
+}
+
+/// Calculates the Absolute Price Oscillator (APO) over a plain slice, the polars free core of [`apo`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `fast_period` or `slow_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{apo_slice, MaType};
+///
+/// let apo = apo_slice(&[10.0, 11.0, 12.0, 13.0, 14.0], Some(2), Some(4), Some(MaType::Sma)).unwrap();
+/// assert!(apo[2].is_nan());
+/// assert_eq!(apo[3], 1.0);
+/// ```
+pub fn apo_slice(
+    src: &[f64],
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    ma_type: Option<MaType>,
+) -> Result<Vec<f64>, TalibError> {
+    let mut state = Apo::new(fast_period.unwrap_or(12), slow_period.unwrap_or(26), ma_type.unwrap_or_default())?;
+    let lookback = apo_lookback(fast_period, slow_period, ma_type);
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Absolute Price Oscillator (APO).
+///
+/// ``` python
+///     #  This Python code produces the same result as the apo function does
+///     import talib
+///     apo = talib.APO(close, fastperiod=12, slowperiod=26, matype=0)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `fast_period` - An optional period of the fast MA. Defaults to 12.
+/// * `slow_period` - An optional period of the slow MA. Defaults to 26.
+/// * `ma_type` - An optional type of both MAs. Defaults to SMA like TA-Lib.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::{apo, MaType};
+///
+///  let close = Series::new("close", (0..40).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let res = apo(&close, Some(5), Some(10), Some(MaType::Ema));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn apo(src: &Series, fast_period: Option<usize>, slow_period: Option<usize>, ma_type: Option<MaType>) -> Result<Series, TalibError> {
+    let apo = apo_slice(&series_to_vec(src)?, fast_period, slow_period, ma_type)?;
+    Ok(vec_to_series("data", apo))
+}
+
+/// Streaming Absolute Price Oscillator (APO).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{Apo, MaType};
+///
+/// let mut apo = Apo::new(2, 3, MaType::Ema).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", apo.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Apo {
+    legs: OscillatorLegs,
+}
+
+impl Apo {
+    /// Creates a new APO state, returns an error if `fast_period` or `slow_period` is less than 2.
+    /// The periods are swapped when `slow_period` is less than `fast_period`.
+    pub fn new(fast_period: usize, slow_period: usize, ma_type: MaType) -> Result<Self, TalibError> {
+        Ok(Apo {
+            legs: OscillatorLegs::new(fast_period, ma_type, slow_period, ma_type)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current APO once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let (fast, slow) = self.legs.update(value)?;
+        Some(fast - slow)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.legs.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apo() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..10).map(|i| 10.0 + i as f64).collect();
+        let res = apo_slice(&close, Some(2), Some(4), Some(MaType::Sma))?;
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        // the SMA(2) of a straight line is 1.0 above the SMA(4)
+        assert!(res[3..].iter().all(|value| *value == 1.0));
+        assert!(apo_slice(&close, Some(1), None, None).is_err());
+        assert!(apo_slice(&close, None, None, None).is_err());
+        Ok(())
+    }
+}
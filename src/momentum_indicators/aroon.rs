@@ -0,0 +1,182 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/06 20:00
+ * @Email: uyplayer@qq.com
+ * @File: aroon.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Aroon
+//!
+//! - aroon down = 100 * (time_period - bars since the lowest low) / time_period
+//! - aroon up = 100 * (time_period - bars since the highest high) / time_period
+//!
+//! Both look back on `time_period + 1` bars, the most recent bar wins a tie like TA-Lib.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without an AROON value, `time_period` like TA-Lib.
+pub fn aroon_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14)
+}
+
+/// Calculates the Aroon (AROON) over plain `high` and `low` slices, the polars free core of [`aroon`].
+///
+/// Returns `(aroon_down, aroon_up)` in the order TA-Lib does.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::aroon_slice;
+///
+/// let high = [10.0, 12.0, 11.0];
+/// let low = [8.0, 9.0, 7.0];
+/// let (down, up) = aroon_slice(&high, &low, Some(2)).unwrap();
+/// assert!(down[1].is_nan());
+/// // the lowest low is today, the highest high one bar ago
+/// assert_eq!(down[2], 100.0);
+/// assert_eq!(up[2], 50.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn aroon_slice(high: &[f64], low: &[f64], time_period: Option<usize>) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    if low.len() != high.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Aroon::new(time_period)?;
+    let lookback = aroon_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or((f64::NAN, f64::NAN)))
+        .unzip())
+}
+
+/// Calculates the Aroon (AROON).
+///
+/// ``` python
+///     #  This Python code produces the same result as the aroon function does
+///     import talib
+///     aroon_down, aroon_up = talib.AROON(high, low, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Returns
+///
+/// The `(aroon_down, aroon_up)` Series, the first [`aroon_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::aroon;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let (down, up) = aroon(&high, &low, Some(3)).unwrap();
+///  eprintln!("{:?} {:?}", down, up);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn aroon(high: &Series, low: &Series, time_period: Option<usize>) -> Result<(Series, Series), TalibError> {
+    let (down, up) = aroon_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period)?;
+    Ok((vec_to_series("AROON_DOWN", down), vec_to_series("AROON_UP", up)))
+}
+
+/// Streaming Aroon (AROON).
+///
+/// Returns `(aroon_down, aroon_up)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Aroon;
+///
+/// let mut aroon = Aroon::new(2).unwrap();
+/// for (high, low) in [(10.0, 8.0), (12.0, 9.0), (11.0, 7.0)] {
+///     eprintln!("{:?}", aroon.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    time_period: usize,
+    extremes: RollingExtremes,
+}
+
+impl Aroon {
+    /// Creates a new AROON state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Aroon {
+            time_period,
+            extremes: RollingExtremes::new(time_period + 1),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current `(aroon_down, aroon_up)` once warmed up.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<(f64, f64)> {
+        self.extremes.update(high, low)?;
+        let (high_age, low_age) = self.extremes.ages();
+        let factor = 100.0 / self.time_period as f64;
+        Some((
+            factor * (self.time_period - low_age) as f64,
+            factor * (self.time_period - high_age) as f64,
+        ))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aroon() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 11.0, 11.0, 12.0];
+        let low = [8.0, 9.0, 7.0, 8.0, 8.0, 8.0];
+        let (down, up) = aroon_slice(&high, &low, Some(2))?;
+        assert!(down[..2].iter().chain(up[..2].iter()).all(|value| value.is_nan()));
+        // the most recent of equal highs and lows counts
+        assert_eq!(down[2..], [100.0, 50.0, 0.0, 100.0]);
+        assert_eq!(up[2..], [50.0, 0.0, 100.0, 100.0]);
+        assert!(aroon_slice(&high, &low, Some(1)).is_err());
+        assert!(aroon_slice(&high, &low[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/06 20:30
+ * @Email: uyplayer@qq.com
+ * @File: aroonosc.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Aroon Oscillator
+//!
+//! aroonosc = aroon up - aroon down = 100 * (bars since the lowest low - bars since the highest high) / time_period
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without an AROONOSC value, `time_period` like TA-Lib.
+pub fn aroonosc_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14)
+}
+
+/// Calculates the Aroon Oscillator (AROONOSC) over plain `high` and `low` slices,
+/// the polars free core of [`aroonosc`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::aroonosc_slice;
+///
+/// let aroonosc = aroonosc_slice(&[10.0, 12.0, 11.0], &[8.0, 9.0, 7.0], Some(2)).unwrap();
+/// assert!(aroonosc[1].is_nan());
+/// assert_eq!(aroonosc[2], -50.0);
+/// ```
+pub fn aroonosc_slice(high: &[f64], low: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    if low.len() != high.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = AroonOsc::new(time_period)?;
+    let lookback = aroonosc_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Aroon Oscillator (AROONOSC).
+///
+/// ``` python
+///     #  This Python code produces the same result as the aroonosc function does
+///     import talib
+///     aroonosc = talib.AROONOSC(high, low, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::aroonosc;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let res = aroonosc(&high, &low, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn aroonosc(high: &Series, low: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let aroonosc = aroonosc_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period)?;
+    Ok(vec_to_series("data", aroonosc))
+}
+
+/// Streaming Aroon Oscillator (AROONOSC).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::AroonOsc;
+///
+/// let mut aroonosc = AroonOsc::new(2).unwrap();
+/// for (high, low) in [(10.0, 8.0), (12.0, 9.0), (11.0, 7.0)] {
+///     eprintln!("{:?}", aroonosc.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AroonOsc {
+    time_period: usize,
+    extremes: RollingExtremes,
+}
+
+impl AroonOsc {
+    /// Creates a new AROONOSC state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(AroonOsc {
+            time_period,
+            extremes: RollingExtremes::new(time_period + 1),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current oscillator once warmed up.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        self.extremes.update(high, low)?;
+        let (high_age, low_age) = self.extremes.ages();
+        let factor = 100.0 / self.time_period as f64;
+        Some(factor * (low_age as f64 - high_age as f64))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aroonosc() -> Result<(), TalibError> {
+        let high: Vec<f64> = (0..40).map(|i| 12.0 + 3.0 * (i as f64 * 0.4).sin()).collect();
+        let low: Vec<f64> = high.iter().enumerate().map(|(i, h)| h - 1.0 - (i % 3) as f64).collect();
+        let res = aroonosc_slice(&high, &low, Some(5))?;
+        let (down, up) = crate::aroon_slice(&high, &low, Some(5))?;
+        assert!(res[..5].iter().all(|value| value.is_nan()));
+        for i in 5..high.len() {
+            assert!((res[i] - (up[i] - down[i])).abs() < 1e-9);
+        }
+        assert!(aroonosc_slice(&high, &low, Some(1)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/05 19:00
+ * @Email: uyplayer@qq.com
+ * @File: bop.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Balance Of Power
+//!
+//! bop = (close - open) / (high - low), 0 when the bar has no range
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::ZERO;
+use crate::TalibError;
+
+/// Number of leading bars without a BOP value, always 0.
+pub fn bop_lookback() -> usize {
+    0
+}
+
+/// Calculates the Balance Of Power (BOP) over plain `open`, `high`, `low` and `close` slices,
+/// the polars free core of [`bop`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::bop_slice;
+///
+/// let bop = bop_slice(&[10.0, 11.0], &[12.0, 11.0], &[9.0, 11.0], &[11.5, 11.0]).unwrap();
+/// assert_eq!(bop, [0.5, 0.0]);
+/// ```
+pub fn bop_slice(open: &[f64], high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    if open.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Bop::new();
+    Ok(open
+        .iter()
+        .zip(high.iter())
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|(((o, h), l), c)| state.update(*o, *h, *l, *c))
+        .collect())
+}
+
+/// Calculates the Balance Of Power (BOP).
+///
+/// ``` python
+///     #  This Python code produces the same result as the bop function does
+///     import talib
+///     bop = talib.BOP(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::bop;
+///
+///  let open = Series::new("open", [10.0, 11.0, 10.5]);
+///  let high = Series::new("high", [12.0, 11.5, 11.0]);
+///  let low = Series::new("low", [9.0, 10.0, 10.0]);
+///  let close = Series::new("close", [11.5, 10.5, 10.8]);
+///  let res = bop(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn bop(open: &Series, high: &Series, low: &Series, close: &Series) -> Result<Series, TalibError> {
+    let bop = bop_slice(
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok(vec_to_series("data", bop))
+}
+
+/// Streaming Balance Of Power (BOP).
+///
+/// BOP has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Bop;
+///
+/// let mut bop = Bop::new();
+/// assert_eq!(bop.update(10.0, 12.0, 9.0, 11.5), 0.5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Bop;
+
+impl Bop {
+    /// Creates a new BOP state.
+    pub fn new() -> Self {
+        Bop
+    }
+
+    /// Feeds the next bar and returns its BOP.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> f64 {
+        let range = high - low;
+        if range < ZERO {
+            0.0
+        } else {
+            (close - open) / range
+        }
+    }
+
+    /// Does nothing, BOP keeps no state between bars.
+    pub fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bop() -> Result<(), TalibError> {
+        let res = bop_slice(&[10.0, 11.0, 12.0], &[12.0, 11.0, 12.0], &[9.0, 11.0, 8.0], &[11.5, 11.0, 8.0])?;
+        assert_eq!(res, [0.5, 0.0, -1.0]);
+        assert!(bop_slice(&[1.0], &[1.0, 2.0], &[1.0], &[1.0]).is_err());
+        assert!(bop_slice(&[], &[], &[], &[]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_bop_series() -> Result<(), TalibError> {
+        let series = bop(
+            &Series::new("open", [10.0]),
+            &Series::new("high", [12.0]),
+            &Series::new("low", [9.0]),
+            &Series::new("close", [9.0]),
+        )?;
+        assert_eq!(series.f64()?.get(0), Some(-1.0 / 3.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/05 19:30
+ * @Email: uyplayer@qq.com
+ * @File: cci.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Commodity Channel Index
+//!
+//! - typical price = (high + low + close) / 3
+//! - cci = (typical price - SMA of it) / (0.015 * mean absolute deviation of it), over `time_period` bars,
+//!   0 when either of them is 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+use std::collections::VecDeque;
+
+/// Number of leading bars without a CCI value, `time_period - 1` like TA-Lib.
+pub fn cci_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the Commodity Channel Index (CCI) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`cci`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cci_slice;
+///
+/// let high = [11.0, 12.0, 13.0];
+/// let low = [9.0, 10.0, 11.0];
+/// let close = [10.0, 11.0, 12.0];
+/// let cci = cci_slice(&high, &low, &close, Some(3)).unwrap();
+/// assert!(cci[1].is_nan());
+/// // typical prices 10, 11, 12: the last one is 1.0 above the average, the mean deviation is 2 / 3
+/// assert!((cci[2] - 1.0 / (0.015 * 2.0 / 3.0)).abs() < 1e-9);
+/// ```
+pub fn cci_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Cci::new(time_period)?;
+    let lookback = cci_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Commodity Channel Index (CCI).
+///
+/// ``` python
+///     #  This Python code produces the same result as the cci function does
+///     import talib
+///     cci = talib.CCI(high, low, close, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cci;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = cci(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cci(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let cci = cci_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", cci))
+}
+
+/// Streaming Commodity Channel Index (CCI).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cci;
+///
+/// let mut cci = Cci::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", cci.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cci {
+    time_period: usize,
+    window: VecDeque<f64>,
+}
+
+impl Cci {
+    /// Creates a new CCI state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Cci {
+            time_period,
+            window: VecDeque::with_capacity(time_period + 1),
+        })
+    }
+
+    /// Feeds the next bar and returns the current CCI once the window is full.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let typical_price = (high + low + close) / 3.0;
+        self.window.push_back(typical_price);
+        if self.window.len() > self.time_period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        let period = self.time_period as f64;
+        // the average is summed afresh like TA-Lib, a running sum would leave a tiny distance
+        // on a flat window, which the tiny deviation would blow up
+        let average = self.window.iter().sum::<f64>() / period;
+        let deviation = self.window.iter().map(|value| (value - average).abs()).sum::<f64>() / period;
+        let distance = typical_price - average;
+        Some(if distance != 0.0 && deviation != 0.0 { distance / (0.015 * deviation) } else { 0.0 })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cci() -> Result<(), TalibError> {
+        let high = [11.0, 12.0, 13.0, 13.0, 13.0, 13.0];
+        let low = [9.0, 10.0, 11.0, 11.0, 11.0, 11.0];
+        let close = [10.0, 11.0, 12.0, 12.0, 12.0, 12.0];
+        let res = cci_slice(&high, &low, &close, Some(3))?;
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        assert!((res[2] - 1.0 / 0.01).abs() < 1e-9);
+        // typical prices 11, 12, 12: 1 / 3 above the average, the mean deviation is 4 / 9
+        assert!((res[3] - (1.0 / 3.0) / (0.015 * 4.0 / 9.0)).abs() < 1e-9);
+        // no deviation at all gives 0
+        assert_eq!(res[5], 0.0);
+        assert!(cci_slice(&high, &low, &close, Some(1)).is_err());
+        assert!(cci_slice(&high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
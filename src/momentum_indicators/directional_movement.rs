@@ -0,0 +1,163 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 15:10
+ * @Email: uyplayer@qq.com
+ * @File: directional_movement.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Wilder's directional movement, shared by PLUS_DM/MINUS_DM, PLUS_DI/MINUS_DI, DX, ADX and ADXR.
+//!
+//! - +DM = high - prev high, when it is positive and greater than -DM, otherwise 0
+//! - -DM = prev low - low, when it is positive and greater than +DM, otherwise 0
+//! - TR = max(high, prev close) - min(low, prev close)
+//! - each of them is summed over the first `time_period - 1` bars, then smoothed with
+//!   `sum = sum - sum / time_period + value`
+
+use crate::helper::ZERO;
+
+/// Wilder's running sum.
+#[derive(Debug, Clone)]
+pub(crate) struct WilderSum {
+    time_period: usize,
+    count: usize,
+    sum: f64,
+}
+
+impl WilderSum {
+    pub(crate) fn new(time_period: usize) -> Self {
+        WilderSum {
+            time_period,
+            count: 0,
+            sum: 0.0,
+        }
+    }
+
+    /// Adds the next value, returns the sum once `time_period - 1` values were added up.
+    pub(crate) fn update(&mut self, value: f64) -> Option<f64> {
+        self.count += 1;
+        if self.count < self.time_period {
+            self.sum += value;
+            return (self.count == self.time_period - 1).then_some(self.sum);
+        }
+        self.sum = self.sum - self.sum / self.time_period as f64 + value;
+        Some(self.sum)
+    }
+}
+
+/// Smoothed +DM and -DM of a high/low series, returned from bar `max(time_period - 1, 1)` on.
+#[derive(Debug, Clone)]
+pub(crate) struct DirectionalMovement {
+    prev: Option<(f64, f64)>,
+    plus_dm: WilderSum,
+    minus_dm: WilderSum,
+}
+
+impl DirectionalMovement {
+    pub(crate) fn new(time_period: usize) -> Self {
+        DirectionalMovement {
+            prev: None,
+            plus_dm: WilderSum::new(time_period),
+            minus_dm: WilderSum::new(time_period),
+        }
+    }
+
+    /// Feeds the next high/low pair and returns the smoothed `(+DM, -DM)`.
+    pub(crate) fn update(&mut self, high: f64, low: f64) -> Option<(f64, f64)> {
+        let (prev_high, prev_low) = self.prev.replace((high, low))?;
+        let diff_plus = high - prev_high;
+        let diff_minus = prev_low - low;
+        let plus_dm = if diff_plus > 0.0 && diff_plus > diff_minus { diff_plus } else { 0.0 };
+        let minus_dm = if diff_minus > 0.0 && diff_minus > diff_plus { diff_minus } else { 0.0 };
+        let plus_dm = self.plus_dm.update(plus_dm);
+        let minus_dm = self.minus_dm.update(minus_dm);
+        plus_dm.zip(minus_dm)
+    }
+}
+
+/// Smoothed +DM, -DM and true range of one bar.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DirectionalSums {
+    pub(crate) plus_dm: f64,
+    pub(crate) minus_dm: f64,
+    pub(crate) true_range: f64,
+}
+
+impl DirectionalSums {
+    /// +DI, 0 when the true range is 0.
+    pub(crate) fn plus_di(&self) -> f64 {
+        if self.true_range.abs() < ZERO {
+            return 0.0;
+        }
+        100.0 * (self.plus_dm / self.true_range)
+    }
+
+    /// -DI, 0 when the true range is 0.
+    pub(crate) fn minus_di(&self) -> f64 {
+        if self.true_range.abs() < ZERO {
+            return 0.0;
+        }
+        100.0 * (self.minus_dm / self.true_range)
+    }
+
+    /// DX, `None` when the true range or the sum of both DIs is 0.
+    pub(crate) fn dx(&self) -> Option<f64> {
+        if self.true_range.abs() < ZERO {
+            return None;
+        }
+        let plus_di = self.plus_di();
+        let minus_di = self.minus_di();
+        let sum = plus_di + minus_di;
+        if sum.abs() < ZERO {
+            return None;
+        }
+        Some(100.0 * ((minus_di - plus_di).abs() / sum))
+    }
+}
+
+/// Smoothed +DM, -DM and true range of a high/low/close series, returned from bar `time_period` on.
+#[derive(Debug, Clone)]
+pub(crate) struct DirectionalIndex {
+    time_period: usize,
+    bar_index: usize,
+    prev_close: f64,
+    movement: DirectionalMovement,
+    true_range: WilderSum,
+}
+
+impl DirectionalIndex {
+    pub(crate) fn new(time_period: usize) -> Self {
+        DirectionalIndex {
+            time_period,
+            bar_index: 0,
+            prev_close: f64::NAN,
+            movement: DirectionalMovement::new(time_period),
+            true_range: WilderSum::new(time_period),
+        }
+    }
+
+    /// Feeds the next bar and returns the smoothed sums once `time_period` bars were smoothed.
+    pub(crate) fn update(&mut self, high: f64, low: f64, close: f64) -> Option<DirectionalSums> {
+        let bar_index = self.bar_index;
+        self.bar_index += 1;
+        let prev_close = std::mem::replace(&mut self.prev_close, close);
+        let movement = self.movement.update(high, low);
+        if bar_index == 0 {
+            return None;
+        }
+        let true_range = high.max(prev_close) - low.min(prev_close);
+        let true_range = self.true_range.update(true_range);
+        if bar_index < self.time_period {
+            return None;
+        }
+        let ((plus_dm, minus_dm), true_range) = movement.zip(true_range)?;
+        Some(DirectionalSums {
+            plus_dm,
+            minus_dm,
+            true_range,
+        })
+    }
+}
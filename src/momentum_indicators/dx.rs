@@ -0,0 +1,182 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 17:15
+ * @Email: uyplayer@qq.com
+ * @File: dx.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Directional Movement Index
+//!
+//! dx = 100 * |+DI - -DI| / (+DI + -DI), the previous value is repeated when both DIs are 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::directional_movement::DirectionalIndex;
+use crate::TalibError;
+
+/// Number of leading bars without a DX value, `time_period` like TA-Lib.
+pub fn dx_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14)
+}
+
+/// Calculates the Directional Movement Index (DX) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`dx`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::dx_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+/// let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+/// let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+/// let dx = dx_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(dx[1].is_nan());
+/// assert_eq!(dx[2], 0.0);
+/// ```
+pub fn dx_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Dx::new(time_period)?;
+    let lookback = dx_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Directional Movement Index (DX).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::dx;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = dx(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn dx(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let dx = dx_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", dx))
+}
+
+/// Streaming Directional Movement Index (DX).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Dx;
+///
+/// let mut dx = Dx::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", dx.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dx {
+    time_period: usize,
+    index: DirectionalIndex,
+    prev: Option<f64>,
+}
+
+impl Dx {
+    /// Creates a new DX state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Dx {
+            time_period,
+            index: DirectionalIndex::new(time_period),
+            prev: None,
+        })
+    }
+
+    /// Feeds the next bar and returns the current DX once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let sums = self.index.update(high, low, close)?;
+        let dx = sums.dx().unwrap_or(self.prev.unwrap_or(0.0));
+        self.prev = Some(dx);
+        Some(dx)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.index = DirectionalIndex::new(self.time_period);
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dx() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0, 12.5, 12.5, 12.5];
+        let low = [9.0, 9.5, 9.0, 10.0, 11.5, 11.5, 11.5];
+        let close = [9.5, 10.5, 10.0, 11.5, 12.0, 12.0, 12.0];
+        let res = dx_slice(&high, &low, &close, Some(2))?;
+        // +DI and -DI are equal on the third bar
+        assert_eq!(res[2], 0.0);
+        // only +DM on the fourth bar, +DM grows to 1.75 and -DM decays to 0.25
+        assert!((res[3] - 100.0 * 1.5 / 2.0).abs() < 1e-9);
+        assert!(res.iter().skip(2).all(|value| (0.0..=100.0).contains(value)));
+        assert!(dx_slice(&high, &low, &close, Some(1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dx_extremes() -> Result<(), TalibError> {
+        // a steady trend in one direction is 100, no movement at all is 0
+        let high = [10.0, 11.0, 12.0, 13.0, 14.0, 15.0];
+        let low = [9.0, 10.0, 11.0, 12.0, 13.0, 14.0];
+        let res = dx_slice(&high, &low, &high, Some(2))?;
+        assert_eq!(res[2..], [100.0, 100.0, 100.0, 100.0]);
+        let flat = dx_slice(&[1.0; 5], &[1.0; 5], &[1.0; 5], Some(2))?;
+        assert_eq!(flat[2..], [0.0, 0.0, 0.0]);
+        Ok(())
+    }
+}
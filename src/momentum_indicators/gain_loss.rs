@@ -0,0 +1,60 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/30 20:10
+ * @Email: uyplayer@qq.com
+ * @File: gain_loss.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Wilder's average gain and loss, shared by RSI, CMO and STOCHRSI.
+//!
+//! - gain = value - prev value when it is positive, loss = prev value - value when it is positive
+//! - both are averaged over the first `time_period` changes, then smoothed with
+//!   `avg = (avg * (time_period - 1) + value) / time_period`
+
+/// Wilder's smoothed average gain and loss, returned from bar `time_period` on.
+#[derive(Debug, Clone)]
+pub(crate) struct AverageGainLoss {
+    time_period: usize,
+    count: usize,
+    prev: Option<f64>,
+    gain: f64,
+    loss: f64,
+}
+
+impl AverageGainLoss {
+    pub(crate) fn new(time_period: usize) -> Self {
+        AverageGainLoss {
+            time_period,
+            count: 0,
+            prev: None,
+            gain: 0.0,
+            loss: 0.0,
+        }
+    }
+
+    /// Feeds the next value and returns the current `(average gain, average loss)`.
+    pub(crate) fn update(&mut self, value: f64) -> Option<(f64, f64)> {
+        let prev = self.prev.replace(value)?;
+        let change = value - prev;
+        let (gain, loss) = if change < 0.0 { (0.0, -change) } else { (change, 0.0) };
+        let period = self.time_period as f64;
+        self.count += 1;
+        if self.count < self.time_period {
+            self.gain += gain;
+            self.loss += loss;
+            return None;
+        }
+        if self.count == self.time_period {
+            self.gain = (self.gain + gain) / period;
+            self.loss = (self.loss + loss) / period;
+        } else {
+            self.gain = (self.gain * (period - 1.0) + gain) / period;
+            self.loss = (self.loss * (period - 1.0) + loss) / period;
+        }
+        Some((self.gain, self.loss))
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/28 19:30
+ * @Email: uyplayer@qq.com
+ * @File: macd.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Moving Average Convergence/Divergence
+//!
+//! - macd = EMA(fast_period) - EMA(slow_period)
+//! - signal = EMA(signal_period) of macd
+//! - hist = macd - signal
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::macdext::run_macd;
+use crate::{exponential_moving_average_lookback, MaType, MacdExt, TalibError};
+
+/// Number of leading bars without a MACD value, `slow_period - 1 + signal_period - 1` like TA-Lib.
+pub fn macd_lookback(fast_period: Option<usize>, slow_period: Option<usize>, signal_period: Option<usize>) -> usize {
+    let slow_period = slow_period.unwrap_or(26).max(fast_period.unwrap_or(12));
+    exponential_moving_average_lookback(Some(slow_period))
+        + exponential_moving_average_lookback(Some(signal_period.unwrap_or(9)))
+}
+
+/// Calculates the Moving Average Convergence/Divergence (MACD) over a plain slice, the polars free core of [`macd`].
+///
+/// Returns `(macd, signal, hist)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `fast_period` or `slow_period` is less than 2
+/// or `signal_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::macd_slice;
+///
+/// let close: Vec<f64> = (0..40).map(|i| 10.0 + i as f64).collect();
+/// let (macd, signal, hist) = macd_slice(&close, Some(3), Some(5), Some(2)).unwrap();
+/// assert!(macd[4].is_nan());
+/// // the EMA of a straight line lags by (period - 1) / 2
+/// assert!((macd[5] - 1.0).abs() < 1e-9);
+/// assert!((signal[5] - 1.0).abs() < 1e-9);
+/// assert!(hist[5].abs() < 1e-9);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn macd_slice(
+    src: &[f64],
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    let state = Macd::new(fast_period.unwrap_or(12), slow_period.unwrap_or(26), signal_period.unwrap_or(9))?;
+    run_macd(src, state.macd, macd_lookback(fast_period, slow_period, signal_period))
+}
+
+/// Calculates the Moving Average Convergence/Divergence (MACD).
+///
+/// ``` python
+///     #  This Python code produces the same result as the macd function does
+///     import talib
+///     macd, signal, hist = talib.MACD(close, fastperiod=12, slowperiod=26, signalperiod=9)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `fast_period` - An optional period of the fast EMA. Defaults to 12.
+/// * `slow_period` - An optional period of the slow EMA. Defaults to 26.
+/// * `signal_period` - An optional period of the signal EMA. Defaults to 9.
+///
+/// # Returns
+///
+/// The `(macd, signal, hist)` Series, the first [`macd_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::macd;
+///
+///  let close = Series::new("close", (0..60).map(|i| (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let (macd, signal, hist) = macd(&close, Some(12), Some(26), Some(9)).unwrap();
+///  eprintln!("{:?} {:?} {:?}", macd, signal, hist);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn macd(
+    src: &Series,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (macd, signal, hist) = macd_slice(&series_to_vec(src)?, fast_period, slow_period, signal_period)?;
+    Ok((
+        vec_to_series("MACD", macd),
+        vec_to_series("MACD_SIGNAL", signal),
+        vec_to_series("MACD_HIST", hist),
+    ))
+}
+
+/// Streaming Moving Average Convergence/Divergence (MACD).
+///
+/// Returns `(macd, signal, hist)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Macd;
+///
+/// let mut macd = Macd::new(3, 5, 2).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", macd.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Macd {
+    macd: MacdExt,
+}
+
+impl Macd {
+    /// Creates a new MACD state, returns an error if `fast_period` or `slow_period` is less than 2
+    /// or `signal_period` is 0. The periods are swapped when `slow_period` is less than `fast_period`.
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self, TalibError> {
+        Ok(Macd {
+            macd: MacdExt::new(fast_period, MaType::Ema, slow_period, MaType::Ema, signal_period, MaType::Ema)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current `(macd, signal, hist)` once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        self.macd.update(value)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.macd.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macd() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..80).map(|i| 100.0 + 10.0 * (i as f64 * 0.2).sin()).collect();
+        let (macd, signal, hist) = macd_slice(&close, None, None, None)?;
+        assert_eq!(macd_lookback(None, None, None), 33);
+        assert!(macd[..33].iter().all(|value| value.is_nan()));
+        // like TA-Lib the fast EMA is seeded on the same bar as the slow one
+        let fast = crate::exponential_moving_average_slice(&close[14..], Some(12))?;
+        let slow = crate::exponential_moving_average_slice(&close, Some(26))?;
+        let line: Vec<f64> = (25..close.len()).map(|i| fast[i - 14] - slow[i]).collect();
+        let expected_signal = crate::exponential_moving_average_slice(&line, Some(9))?;
+        for i in 33..close.len() {
+            assert!((macd[i] - line[i - 25]).abs() < 1e-9);
+            assert!((signal[i] - expected_signal[i - 25]).abs() < 1e-9);
+            assert!((hist[i] - (macd[i] - signal[i])).abs() < 1e-9);
+        }
+        assert!(macd_slice(&close[..33], None, None, None).is_err());
+        Ok(())
+    }
+}
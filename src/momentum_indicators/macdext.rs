@@ -0,0 +1,243 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/28 20:10
+ * @Email: uyplayer@qq.com
+ * @File: macdext.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! MACD with controllable MA type
+//!
+//! - macd = fast MA - slow MA
+//! - signal = signal MA of macd
+//! - hist = macd - signal
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_oscillator::OscillatorLegs;
+use crate::{moving_average_lookback, Ma, MaType, TalibError};
+
+/// Number of leading bars without a MACDEXT value, the longest lookback of the fast and slow MAs
+/// plus the lookback of the signal MA, like TA-Lib.
+pub fn macdext_lookback(
+    fast_period: Option<usize>,
+    fast_ma_type: Option<MaType>,
+    slow_period: Option<usize>,
+    slow_ma_type: Option<MaType>,
+    signal_period: Option<usize>,
+    signal_ma_type: Option<MaType>,
+) -> usize {
+    let fast = moving_average_lookback(Some(fast_period.unwrap_or(12)), fast_ma_type);
+    let slow = moving_average_lookback(Some(slow_period.unwrap_or(26)), slow_ma_type);
+    fast.max(slow) + moving_average_lookback(Some(signal_period.unwrap_or(9)), signal_ma_type)
+}
+
+/// Calculates the MACD with controllable MA type (MACDEXT) over a plain slice, the polars free core of [`macdext`].
+///
+/// Returns `(macd, signal, hist)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `fast_period` or `slow_period` is less than 2
+/// or `signal_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{macdext_slice, MaType};
+///
+/// let close: Vec<f64> = (0..40).map(|i| 10.0 + i as f64).collect();
+/// let (macd, signal, hist) = macdext_slice(&close, Some(3), Some(MaType::Sma), Some(5), Some(MaType::Sma), Some(2), Some(MaType::Sma)).unwrap();
+/// assert!(macd[4].is_nan());
+/// // the SMA of a straight line lags by half of its period
+/// assert_eq!(macd[5], 1.0);
+/// assert_eq!(signal[5], 1.0);
+/// assert_eq!(hist[5], 0.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn macdext_slice(
+    src: &[f64],
+    fast_period: Option<usize>,
+    fast_ma_type: Option<MaType>,
+    slow_period: Option<usize>,
+    slow_ma_type: Option<MaType>,
+    signal_period: Option<usize>,
+    signal_ma_type: Option<MaType>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    let state = MacdExt::new(
+        fast_period.unwrap_or(12),
+        fast_ma_type.unwrap_or_default(),
+        slow_period.unwrap_or(26),
+        slow_ma_type.unwrap_or_default(),
+        signal_period.unwrap_or(9),
+        signal_ma_type.unwrap_or_default(),
+    )?;
+    let lookback = macdext_lookback(fast_period, fast_ma_type, slow_period, slow_ma_type, signal_period, signal_ma_type);
+    run_macd(src, state, lookback)
+}
+
+/// Feeds `src` to a MACD state, shared by MACD, MACDEXT and MACDFIX.
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_macd(
+    src: &[f64],
+    mut state: MacdExt,
+    lookback: usize,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut macd = Vec::with_capacity(src.len());
+    let mut signal = Vec::with_capacity(src.len());
+    let mut hist = Vec::with_capacity(src.len());
+    for value in src {
+        let (m, s, h) = state.update(*value).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+        macd.push(m);
+        signal.push(s);
+        hist.push(h);
+    }
+    Ok((macd, signal, hist))
+}
+
+/// Calculates the MACD with controllable MA type (MACDEXT).
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `fast_period` - An optional period of the fast MA. Defaults to 12.
+/// * `fast_ma_type` - An optional type of the fast MA. Defaults to SMA like TA-Lib.
+/// * `slow_period` - An optional period of the slow MA. Defaults to 26.
+/// * `slow_ma_type` - An optional type of the slow MA. Defaults to SMA like TA-Lib.
+/// * `signal_period` - An optional period of the signal MA. Defaults to 9.
+/// * `signal_ma_type` - An optional type of the signal MA. Defaults to SMA like TA-Lib.
+///
+/// # Returns
+///
+/// The `(macd, signal, hist)` Series, the first [`macdext_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::{macdext, MaType};
+///
+///  let close = Series::new("close", (0..60).map(|i| (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let res = macdext(&close, None, Some(MaType::Ema), None, Some(MaType::Ema), None, Some(MaType::Sma));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn macdext(
+    src: &Series,
+    fast_period: Option<usize>,
+    fast_ma_type: Option<MaType>,
+    slow_period: Option<usize>,
+    slow_ma_type: Option<MaType>,
+    signal_period: Option<usize>,
+    signal_ma_type: Option<MaType>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (macd, signal, hist) = macdext_slice(
+        &series_to_vec(src)?,
+        fast_period,
+        fast_ma_type,
+        slow_period,
+        slow_ma_type,
+        signal_period,
+        signal_ma_type,
+    )?;
+    Ok((
+        vec_to_series("MACD", macd),
+        vec_to_series("MACD_SIGNAL", signal),
+        vec_to_series("MACD_HIST", hist),
+    ))
+}
+
+/// Streaming MACD with controllable MA type (MACDEXT).
+///
+/// Returns `(macd, signal, hist)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{MacdExt, MaType};
+///
+/// let mut macd = MacdExt::new(3, MaType::Ema, 5, MaType::Sma, 2, MaType::Wma).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", macd.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MacdExt {
+    legs: OscillatorLegs,
+    signal: Ma,
+}
+
+impl MacdExt {
+    /// Creates a new MACDEXT state, returns an error if `fast_period` or `slow_period` is less than 2
+    /// or `signal_period` is 0. The legs are swapped when `slow_period` is less than `fast_period`.
+    pub fn new(
+        fast_period: usize,
+        fast_ma_type: MaType,
+        slow_period: usize,
+        slow_ma_type: MaType,
+        signal_period: usize,
+        signal_ma_type: MaType,
+    ) -> Result<Self, TalibError> {
+        Ok(Self::from_legs(
+            OscillatorLegs::new(fast_period, fast_ma_type, slow_period, slow_ma_type)?,
+            Ma::new(signal_period, signal_ma_type)?,
+        ))
+    }
+
+    /// Builds a state from already configured fast and slow legs and a signal MA.
+    pub(crate) fn from_legs(legs: OscillatorLegs, signal: Ma) -> Self {
+        MacdExt { legs, signal }
+    }
+
+    /// Feeds the next value and returns the current `(macd, signal, hist)` once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        let (fast, slow) = self.legs.update(value)?;
+        let macd = fast - slow;
+        let signal = self.signal.update(macd)?;
+        Some((macd, signal, macd - signal))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.legs.reset();
+        self.signal.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macdext() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..60).map(|i| 100.0 + 10.0 * (i as f64 * 0.2).sin()).collect();
+        let (macd, signal, hist) = macdext_slice(&close, Some(4), Some(MaType::Sma), Some(8), Some(MaType::Wma), Some(3), Some(MaType::Sma))?;
+        let fast = crate::simple_moving_average_slice(&close, Some(4))?;
+        let slow = crate::weighted_moving_average_slice(&close, Some(8))?;
+        assert_eq!(macdext_lookback(Some(4), Some(MaType::Sma), Some(8), Some(MaType::Wma), Some(3), Some(MaType::Sma)), 9);
+        assert!(macd[..9].iter().all(|value| value.is_nan()));
+        for i in 9..close.len() {
+            // windowed MAs don't depend on where they start
+            assert!((macd[i] - (fast[i] - slow[i])).abs() < 1e-9);
+            let expected_signal = (0..3).map(|lag| fast[i - lag] - slow[i - lag]).sum::<f64>() / 3.0;
+            assert!((signal[i] - expected_signal).abs() < 1e-9);
+            assert!((hist[i] - (macd[i] - signal[i])).abs() < 1e-9);
+        }
+        // the legs are swapped when the slow period is the shorter one
+        let (swapped, _, _) = macdext_slice(&close, Some(8), Some(MaType::Wma), Some(4), Some(MaType::Sma), Some(3), Some(MaType::Sma))?;
+        assert!(swapped[9..].iter().zip(macd[9..].iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!(macdext_slice(&close, Some(1), None, None, None, None, None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/28 20:45
+ * @Email: uyplayer@qq.com
+ * @File: macdfix.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Moving Average Convergence/Divergence Fix 12/26
+//!
+//! MACD with a fast EMA of 12 and a slow EMA of 26 whose smoothing factors are the
+//! rounded 0.15 and 0.075 instead of `2 / (period + 1)`, like TA-Lib's MACDFIX.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::macdext::run_macd;
+use crate::momentum_indicators::price_oscillator::OscillatorLegs;
+use crate::{exponential_moving_average_lookback, macd_lookback, Ema, Ma, MaType, MacdExt, TalibError};
+
+/// Number of leading bars without a MACDFIX value, `25 + signal_period - 1` like TA-Lib.
+pub fn macdfix_lookback(signal_period: Option<usize>) -> usize {
+    macd_lookback(Some(12), Some(26), signal_period)
+}
+
+/// Calculates the Moving Average Convergence/Divergence Fix 12/26 (MACDFIX) over a plain slice,
+/// the polars free core of [`macdfix`].
+///
+/// Returns `(macd, signal, hist)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `signal_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::macdfix_slice;
+///
+/// let close: Vec<f64> = (0..60).map(|i| (i as f64 * 0.3).sin()).collect();
+/// let (macd, signal, hist) = macdfix_slice(&close, Some(9)).unwrap();
+/// assert!(macd[32].is_nan());
+/// assert!((hist[33] - (macd[33] - signal[33])).abs() < 1e-12);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn macdfix_slice(src: &[f64], signal_period: Option<usize>) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    let state = MacdFix::new(signal_period.unwrap_or(9))?;
+    run_macd(src, state.macd, macdfix_lookback(signal_period))
+}
+
+/// Calculates the Moving Average Convergence/Divergence Fix 12/26 (MACDFIX).
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `signal_period` - An optional period of the signal EMA. Defaults to 9.
+///
+/// # Returns
+///
+/// The `(macd, signal, hist)` Series, the first [`macdfix_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::macdfix;
+///
+///  let close = Series::new("close", (0..60).map(|i| (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let (macd, signal, hist) = macdfix(&close, None).unwrap();
+///  eprintln!("{:?} {:?} {:?}", macd, signal, hist);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn macdfix(src: &Series, signal_period: Option<usize>) -> Result<(Series, Series, Series), TalibError> {
+    let (macd, signal, hist) = macdfix_slice(&series_to_vec(src)?, signal_period)?;
+    Ok((
+        vec_to_series("MACD", macd),
+        vec_to_series("MACD_SIGNAL", signal),
+        vec_to_series("MACD_HIST", hist),
+    ))
+}
+
+/// Streaming Moving Average Convergence/Divergence Fix 12/26 (MACDFIX).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::MacdFix;
+///
+/// let mut macd = MacdFix::new(9).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", macd.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MacdFix {
+    macd: MacdExt,
+}
+
+impl MacdFix {
+    /// Creates a new MACDFIX state, returns an error if `signal_period` is 0.
+    pub fn new(signal_period: usize) -> Result<Self, TalibError> {
+        Ok(MacdFix {
+            macd: MacdExt::from_legs(
+                OscillatorLegs::from_mas(
+                    Ma::from_ema(Ema::with_alpha(12, 0.15)?),
+                    exponential_moving_average_lookback(Some(12)),
+                    Ma::from_ema(Ema::with_alpha(26, 0.075)?),
+                    exponential_moving_average_lookback(Some(26)),
+                ),
+                Ma::new(signal_period, MaType::Ema)?,
+            ),
+        })
+    }
+
+    /// Feeds the next value and returns the current `(macd, signal, hist)` once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        self.macd.update(value)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.macd.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macdfix() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..80).map(|i| 100.0 + 10.0 * (i as f64 * 0.2).sin()).collect();
+        let (fixed, _, _) = macdfix_slice(&close, None)?;
+        let (macd, _, _) = crate::macd_slice(&close, Some(12), Some(26), Some(9))?;
+        assert_eq!(macdfix_lookback(None), 33);
+        assert!(fixed[..33].iter().all(|value| value.is_nan()));
+        // the rounded smoothing factors make it close to, but not the same as, MACD 12/26
+        for i in 33..close.len() {
+            assert!((fixed[i] - macd[i]).abs() < 0.5);
+        }
+        assert!(fixed[33..].iter().zip(macd[33..].iter()).any(|(a, b)| (a - b).abs() > 1e-6));
+        assert!(macdfix_slice(&close, Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/10 19:40
+ * @Email: uyplayer@qq.com
+ * @File: mfi.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Money Flow Index
+//!
+//! - typical price = (high + low + close) / 3, money flow = typical price * volume
+//! - the money flow is positive when the typical price rose and negative when it fell
+//! - mfi = 100 * positive flow / (positive flow + negative flow) over `time_period` bars
+
+use std::collections::VecDeque;
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without an MFI value, `time_period` like TA-Lib.
+pub fn mfi_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14)
+}
+
+/// Calculates the Money Flow Index (MFI) over plain `high`, `low`, `close` and `volume` slices,
+/// the polars free core of [`mfi`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::mfi_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0];
+/// let low = [9.0, 10.0, 9.0, 11.0];
+/// let close = [9.5, 10.5, 9.6, 11.5];
+/// let volume = [100.0, 200.0, 100.0, 300.0];
+/// let mfi = mfi_slice(&high, &low, &close, &volume, Some(2)).unwrap();
+/// assert!(mfi[1].is_nan());
+/// // typical prices 9.5, 10.5, 9.7, 11.5: the flow of 10.5 * 200 is positive, 9.7 * 100 negative
+/// assert!((mfi[2] - 100.0 * 2100.0 / 3070.0).abs() < 1e-9);
+/// ```
+pub fn mfi_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len(), volume.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Mfi::new(time_period)?;
+    let lookback = mfi_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok((0..high.len())
+        .map(|i| state.update(high[i], low[i], close[i], volume[i]).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Money Flow Index (MFI).
+///
+/// ``` python
+///     #  This Python code produces the same result as the mfi function does
+///     import talib
+///     mfi = talib.MFI(high, low, close, volume, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::mfi;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = mfi(&high, &low, &close, &volume, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn mfi(high: &Series, low: &Series, close: &Series, volume: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let mfi = mfi_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        &series_to_vec(volume)?,
+        time_period,
+    )?;
+    Ok(vec_to_series("data", mfi))
+}
+
+/// Streaming Money Flow Index (MFI).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Mfi;
+///
+/// let mut mfi = Mfi::new(2).unwrap();
+/// for (high, low, close, volume) in [(10.0, 9.0, 9.5, 100.0), (11.0, 10.0, 10.5, 200.0), (10.5, 9.0, 9.6, 100.0)] {
+///     eprintln!("{:?}", mfi.update(high, low, close, volume));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mfi {
+    time_period: usize,
+    prev_typical: Option<f64>,
+    flows: VecDeque<(f64, f64)>,
+    positive: f64,
+    negative: f64,
+}
+
+impl Mfi {
+    /// Creates a new MFI state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Mfi {
+            time_period,
+            prev_typical: None,
+            flows: VecDeque::with_capacity(time_period),
+            positive: 0.0,
+            negative: 0.0,
+        })
+    }
+
+    /// Feeds the next bar and returns the current MFI once `time_period` money flows are known,
+    /// 0 when the total money flow is below 1 like TA-Lib.
+    pub fn update(&mut self, high: f64, low: f64, close: f64, volume: f64) -> Option<f64> {
+        let typical = (high + low + close) / 3.0;
+        let prev = self.prev_typical.replace(typical)?;
+        // like TA-Lib the oldest flow leaves the sums before the new one enters
+        if self.flows.len() == self.time_period {
+            if let Some((positive, negative)) = self.flows.pop_front() {
+                self.positive -= positive;
+                self.negative -= negative;
+            }
+        }
+        let flow = typical * volume;
+        let (positive, negative) = if typical > prev {
+            (flow, 0.0)
+        } else if typical < prev {
+            (0.0, flow)
+        } else {
+            (0.0, 0.0)
+        };
+        self.positive += positive;
+        self.negative += negative;
+        self.flows.push_back((positive, negative));
+        if self.flows.len() < self.time_period {
+            return None;
+        }
+        let total = self.positive + self.negative;
+        Some(if total < 1.0 { 0.0 } else { 100.0 * (self.positive / total) })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_typical = None;
+        self.flows.clear();
+        self.positive = 0.0;
+        self.negative = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mfi() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0, 12.0, 12.0];
+        let low = [9.0, 10.0, 9.0, 11.0, 11.0, 11.0];
+        let close = [9.5, 10.5, 9.6, 11.5, 11.5, 11.5];
+        let volume = [100.0, 200.0, 100.0, 300.0, 400.0, 500.0];
+        let res = mfi_slice(&high, &low, &close, &volume, Some(2))?;
+        assert_eq!(mfi_lookback(Some(2)), 2);
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        assert!((res[2] - 100.0 * 2100.0 / 3070.0).abs() < 1e-9);
+        assert!((res[3] - 100.0 * 3450.0 / 4420.0).abs() < 1e-9);
+        // an unchanged typical price adds no flow
+        assert_eq!(res[4], 100.0);
+        assert_eq!(res[5], 0.0);
+        assert!(mfi_slice(&high, &low, &close, &volume[1..], None).is_err());
+        assert!(mfi_slice(&high, &low, &close, &volume, Some(0)).is_err());
+        Ok(())
+    }
+}
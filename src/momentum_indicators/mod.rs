@@ -4,7 +4,170 @@
  * @Email: uyplayer@qq.com
  * @File: mod.rs
  * @Software: RustRover
- * @Dir: rusty-talib / src/cycle_indicators
+ * @Dir: rusty-talib / src/momentum_indicators
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+
+// | Function | Description                                            | Status  |
+// |----------|--------------------------------------------------------|---------|
+// | ADX      | Average Directional Movement Index                     | Done    |
+// | ADXR     | Average Directional Movement Index Rating              | Done    |
+// | APO      | Absolute Price Oscillator                              | Done    |
+// | AROON    | Aroon                                                  | Done    |
+// | AROONOSC | Aroon Oscillator                                       | Done    |
+// | BOP      | Balance Of Power                                       | Done    |
+// | CCI      | Commodity Channel Index                                | Done    |
+// | CMO      | Chande Momentum Oscillator                             | Done    |
+// | DX       | Directional Movement Index                             | Done    |
+// | MACD     | Moving Average Convergence/Divergence                  | Done    |
+// | MACDEXT  | MACD with controllable MA type                         | Done    |
+// | MACDFIX  | Moving Average Convergence/Divergence Fix 12/26        | Done    |
+// | MFI      | Money Flow Index                                       | Done    |
+// | MINUS_DI | Minus Directional Indicator                            | Done    |
+// | MINUS_DM | Minus Directional Movement                             | Done    |
+// | MOM      | Momentum                                               | Done    |
+// | PLUS_DI  | Plus Directional Indicator                             | Done    |
+// | PLUS_DM  | Plus Directional Movement                              | Done    |
+// | PPO      | Percentage Price Oscillator                            | Done    |
+// | ROC      | Rate of change : ((price/prevPrice)-1)*100             | Done    |
+// | ROCP     | Rate of change Percentage: (price-prevPrice)/prevPrice | Done    |
+// | ROCR     | Rate of change ratio: (price/prevPrice)                | Done    |
+// | ROCR100  | Rate of change ratio 100 scale: (price/prevPrice)*100  | Done    |
+// | RSI      | Relative Strength Index                                | Done    |
+// | STOCH    | Stochastic                                             | Done    |
+// | STOCHF   | Stochastic Fast                                        | Done    |
+// | STOCHRSI | Stochastic Relative Strength Index                     | Done    |
+// | TRIX     | 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA      | Done    |
+// | ULTOSC   | Ultimate Oscillator                                    | Done    |
+// | WILLR    | Williams' %R                                           | Done    |
+
+/// This module contains the momentum indicators.
+mod directional_movement;
+mod gain_loss;
+mod price_change;
+mod price_oscillator;
+mod stochastic;
+mod adx;
+mod adxr;
+mod apo;
+mod aroon;
+mod aroonosc;
+mod bop;
+mod cci;
+mod cmo;
+mod dx;
+mod macd;
+mod macdext;
+mod macdfix;
+mod mfi;
+mod minus_di;
+mod minus_dm;
+mod mom;
+mod plus_di;
+mod plus_dm;
+mod ppo;
+mod roc;
+mod rocp;
+mod rocr;
+mod rocr100;
+mod rsi;
+mod stoch;
+mod stochf;
+mod stochrsi;
+mod trix;
+mod ultosc;
+mod willr;
+
+
+pub use adx::{adx_lookback, adx_slice, Adx};
+pub use adxr::{adxr_lookback, adxr_slice, Adxr};
+pub use apo::{apo_lookback, apo_slice, Apo};
+pub use aroon::{aroon_lookback, aroon_slice, Aroon};
+pub use aroonosc::{aroonosc_lookback, aroonosc_slice, AroonOsc};
+pub use bop::{bop_lookback, bop_slice, Bop};
+pub use cci::{cci_lookback, cci_slice, Cci};
+pub use cmo::{cmo_lookback, cmo_slice, Cmo};
+pub use dx::{dx_lookback, dx_slice, Dx};
+pub use macd::{macd_lookback, macd_slice, Macd};
+pub use macdext::{macdext_lookback, macdext_slice, MacdExt};
+pub use macdfix::{macdfix_lookback, macdfix_slice, MacdFix};
+pub use mfi::{mfi_lookback, mfi_slice, Mfi};
+pub use minus_di::{minus_di_lookback, minus_di_slice, MinusDi};
+pub use minus_dm::{minus_dm_lookback, minus_dm_slice, MinusDm};
+pub use mom::{mom_lookback, mom_slice, Mom};
+pub use plus_di::{plus_di_lookback, plus_di_slice, PlusDi};
+pub use plus_dm::{plus_dm_lookback, plus_dm_slice, PlusDm};
+pub use ppo::{ppo_lookback, ppo_slice, Ppo};
+pub use roc::{roc_lookback, roc_slice, Roc};
+pub use rocp::{rocp_lookback, rocp_slice, Rocp};
+pub use rocr::{rocr_lookback, rocr_slice, Rocr};
+pub use rocr100::{rocr100_lookback, rocr100_slice, Rocr100};
+pub use rsi::{rsi_lookback, rsi_slice, Rsi};
+pub use stoch::{stoch_lookback, stoch_slice, Stoch};
+pub use stochf::{stochf_lookback, stochf_slice, StochF};
+pub use stochrsi::{stochrsi_lookback, stochrsi_slice, StochRsi};
+pub use trix::{trix_lookback, trix_slice, Trix};
+pub use ultosc::{ultosc_lookback, ultosc_slice, UltOsc};
+pub use willr::{willr_lookback, willr_slice, Willr};
+
+#[cfg(feature = "polars")]
+pub use adx::adx;
+#[cfg(feature = "polars")]
+pub use adxr::adxr;
+#[cfg(feature = "polars")]
+pub use apo::apo;
+#[cfg(feature = "polars")]
+pub use aroon::aroon;
+#[cfg(feature = "polars")]
+pub use aroonosc::aroonosc;
+#[cfg(feature = "polars")]
+pub use bop::bop;
+#[cfg(feature = "polars")]
+pub use cci::cci;
+#[cfg(feature = "polars")]
+pub use cmo::cmo;
+#[cfg(feature = "polars")]
+pub use dx::dx;
+#[cfg(feature = "polars")]
+pub use macd::macd;
+#[cfg(feature = "polars")]
+pub use macdext::macdext;
+#[cfg(feature = "polars")]
+pub use macdfix::macdfix;
+#[cfg(feature = "polars")]
+pub use mfi::mfi;
+#[cfg(feature = "polars")]
+pub use minus_di::minus_di;
+#[cfg(feature = "polars")]
+pub use minus_dm::minus_dm;
+#[cfg(feature = "polars")]
+pub use mom::mom;
+#[cfg(feature = "polars")]
+pub use plus_di::plus_di;
+#[cfg(feature = "polars")]
+pub use plus_dm::plus_dm;
+#[cfg(feature = "polars")]
+pub use ppo::ppo;
+#[cfg(feature = "polars")]
+pub use roc::roc;
+#[cfg(feature = "polars")]
+pub use rocp::rocp;
+#[cfg(feature = "polars")]
+pub use rocr::rocr;
+#[cfg(feature = "polars")]
+pub use rocr100::rocr100;
+#[cfg(feature = "polars")]
+pub use rsi::rsi;
+#[cfg(feature = "polars")]
+pub use stoch::stoch;
+#[cfg(feature = "polars")]
+pub use stochf::stochf;
+#[cfg(feature = "polars")]
+pub use stochrsi::stochrsi;
+#[cfg(feature = "polars")]
+pub use trix::trix;
+#[cfg(feature = "polars")]
+pub use ultosc::ultosc;
+#[cfg(feature = "polars")]
+pub use willr::willr;
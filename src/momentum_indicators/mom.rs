@@ -0,0 +1,152 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/03 10:30
+ * @Email: uyplayer@qq.com
+ * @File: mom.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Momentum
+//!
+//! mom = value - value `time_period` bars ago
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_change::PeriodAgo;
+use crate::TalibError;
+
+/// Number of leading bars without a MOM value, `time_period` like TA-Lib.
+pub fn mom_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(10)
+}
+
+/// Calculates the Momentum (MOM) over a plain slice, the polars free core of [`mom`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::mom_slice;
+///
+/// let mom = mom_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1)).unwrap();
+/// assert!(mom[0].is_nan());
+/// assert!((mom[1] - 2.0).abs() < 1e-9);
+/// assert!((mom[2] + 12.0).abs() < 1e-9);
+/// assert!((mom[3] - 6.0).abs() < 1e-9);
+/// assert!((mom[4] - 3.0).abs() < 1e-9);
+/// ```
+pub fn mom_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(10);
+    let mut state = Mom::new(time_period)?;
+    let lookback = mom_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Momentum (MOM).
+///
+/// ``` python
+///     #  This Python code produces the same result as the mom function does
+///     import talib
+///     mom = talib.MOM(close, timeperiod=10)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 10 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::mom;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = mom(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn mom(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let mom = mom_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", mom))
+}
+
+/// Streaming Momentum (MOM).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Mom;
+///
+/// let mut mom = Mom::new(2).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", mom.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mom {
+    prev: PeriodAgo,
+}
+
+impl Mom {
+    /// Creates a new MOM state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Mom {
+            prev: PeriodAgo::new(time_period),
+        })
+    }
+
+    /// Feeds the next value and returns the current MOM once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let prev = self.prev.update(value)?;
+        Some(value - prev)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mom() -> Result<(), TalibError> {
+        let res = mom_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1))?;
+        assert!(res[0].is_nan());
+        assert!((res[1] - 2.0).abs() < 1e-9);
+        assert!((res[2] + 12.0).abs() < 1e-9);
+        assert!((res[3] - 6.0).abs() < 1e-9);
+        assert!((res[4] - 3.0).abs() < 1e-9);
+        assert!(mom_slice(&[1.0; 5], Some(0)).is_err());
+        assert!(mom_slice(&[1.0; 5], Some(5)).is_err());
+        Ok(())
+    }
+}
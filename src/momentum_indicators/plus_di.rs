@@ -0,0 +1,165 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 16:30
+ * @Email: uyplayer@qq.com
+ * @File: plus_di.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Plus Directional Indicator
+//!
+//! +DI = 100 * smoothed +DM / smoothed true range, both smoothed with Wilder's running sum
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::directional_movement::DirectionalIndex;
+use crate::TalibError;
+
+/// Number of leading bars without a PLUS_DI value, `time_period` like TA-Lib.
+pub fn plus_di_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).max(1)
+}
+
+/// Calculates the Plus Directional Indicator (PLUS_DI) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`plus_di`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::plus_di_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+/// let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+/// let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+/// let plus_di = plus_di_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(plus_di[1].is_nan());
+/// assert!(plus_di[4] > 0.0);
+/// ```
+pub fn plus_di_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = PlusDi::new(time_period)?;
+    let lookback = plus_di_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Plus Directional Indicator (PLUS_DI).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::plus_di;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = plus_di(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn plus_di(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let plus_di = plus_di_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", plus_di))
+}
+
+/// Streaming Plus Directional Indicator (PLUS_DI).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::PlusDi;
+///
+/// let mut plus_di = PlusDi::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", plus_di.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlusDi {
+    time_period: usize,
+    index: DirectionalIndex,
+}
+
+impl PlusDi {
+    /// Creates a new PLUS_DI state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(PlusDi {
+            time_period,
+            index: DirectionalIndex::new(time_period),
+        })
+    }
+
+    /// Feeds the next bar and returns the current +DI once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        self.index.update(high, low, close).map(|sums| sums.plus_di())
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.index = DirectionalIndex::new(self.time_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plus_di() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+        let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+        let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+        let res = plus_di_slice(&high, &low, &close, Some(2))?;
+        // +DM 1.0 and TR 1.5 are the seeds, then +DM 0.5 + 0.0 over TR 0.75 + 1.5
+        assert!((res[2] - 100.0 * 0.5 / 2.25).abs() < 1e-9);
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        // a flat market has no true range and no directional movement
+        let flat = plus_di_slice(&[1.0; 5], &[1.0; 5], &[1.0; 5], Some(2))?;
+        assert_eq!(flat[2..], [0.0, 0.0, 0.0]);
+        assert!(plus_di_slice(&high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
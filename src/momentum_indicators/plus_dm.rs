@@ -0,0 +1,161 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 15:40
+ * @Email: uyplayer@qq.com
+ * @File: plus_dm.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Plus Directional Movement
+//!
+//! - +DM = high - prev high, when it is positive and greater than prev low - low, otherwise 0
+//! - the first `time_period - 1` values are summed up, then `+DM = +DM - +DM / time_period + value`
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::directional_movement::DirectionalMovement;
+use crate::TalibError;
+
+/// Number of leading bars without a PLUS_DM value, `time_period - 1` (at least 1) like TA-Lib.
+pub fn plus_dm_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1).max(1)
+}
+
+/// Calculates the Plus Directional Movement (PLUS_DM) over plain `high` and `low` slices,
+/// the polars free core of [`plus_dm`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::plus_dm_slice;
+///
+/// let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+/// let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+/// let plus_dm = plus_dm_slice(&high, &low, Some(3)).unwrap();
+/// assert!(plus_dm[1].is_nan());
+/// assert_eq!(plus_dm[2], 1.0);
+/// ```
+pub fn plus_dm_slice(high: &[f64], low: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = PlusDm::new(time_period)?;
+    let lookback = plus_dm_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Plus Directional Movement (PLUS_DM).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::plus_dm;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let res = plus_dm(&high, &low, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn plus_dm(high: &Series, low: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let plus_dm = plus_dm_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period)?;
+    Ok(vec_to_series("data", plus_dm))
+}
+
+/// Streaming Plus Directional Movement (PLUS_DM).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::PlusDm;
+///
+/// let mut plus_dm = PlusDm::new(2).unwrap();
+/// for (high, low) in [(10.0, 9.0), (11.0, 9.5), (10.5, 9.0)] {
+///     eprintln!("{:?}", plus_dm.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlusDm {
+    time_period: usize,
+    movement: DirectionalMovement,
+}
+
+impl PlusDm {
+    /// Creates a new PLUS_DM state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(PlusDm {
+            time_period,
+            movement: DirectionalMovement::new(time_period),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current +DM once warmed up.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        self.movement.update(high, low).map(|(plus_dm, _)| plus_dm)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.movement = DirectionalMovement::new(self.time_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plus_dm() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+        let low = [9.0, 9.5, 9.0, 10.0, 11.5];
+        let res = plus_dm_slice(&high, &low, Some(3))?;
+        // 1.0 + 0.0, then 1.0 - 1.0 / 3 + 1.5 and 2.1666.. - 0.7222.. + 0.5
+        let expected = [1.0, 1.0 - 1.0 / 3.0 + 1.5, (1.0 - 1.0 / 3.0 + 1.5) * 2.0 / 3.0 + 0.5];
+        for (i, value) in expected.iter().enumerate() {
+            assert!((res[i + 2] - value).abs() < 1e-9);
+        }
+        // a period of 1 is the raw +DM
+        assert_eq!(plus_dm_slice(&high, &low, Some(1))?[1..], [1.0, 0.0, 1.5, 0.5]);
+        assert!(plus_dm_slice(&high, &low[1..], None).is_err());
+        assert!(plus_dm_slice(&high, &low, Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/04 20:10
+ * @Email: uyplayer@qq.com
+ * @File: ppo.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Percentage Price Oscillator
+//!
+//! ppo = 100 * (fast MA - slow MA) / slow MA, 0 when the slow MA is 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_oscillator::OscillatorLegs;
+use crate::helper::ZERO;
+use crate::{moving_average_lookback, MaType, TalibError};
+
+/// Number of leading bars without a PPO value, the longest lookback of the fast and slow MAs like TA-Lib.
+pub fn ppo_lookback(fast_period: Option<usize>, slow_period: Option<usize>, ma_type: Option<MaType>) -> usize {
+    let fast = moving_average_lookback(Some(fast_period.unwrap_or(12)), ma_type);
+    let slow = moving_average_lookback(Some(slow_period.unwrap_or(26)), ma_type);
+    fast.max(slow)
+}
+
+/// Calculates the Percentage Price Oscillator (PPO) over a plain slice, the polars free core of [`ppo`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `fast_period` or `slow_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{ppo_slice, MaType};
+///
+/// let ppo = ppo_slice(&[10.0, 11.0, 12.0, 13.0, 14.0], Some(2), Some(4), Some(MaType::Sma)).unwrap();
+/// assert!(ppo[2].is_nan());
+/// assert_eq!(ppo[3], 100.0 / 11.5);
+/// ```
+pub fn ppo_slice(
+    src: &[f64],
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    ma_type: Option<MaType>,
+) -> Result<Vec<f64>, TalibError> {
+    let mut state = Ppo::new(fast_period.unwrap_or(12), slow_period.unwrap_or(26), ma_type.unwrap_or_default())?;
+    let lookback = ppo_lookback(fast_period, slow_period, ma_type);
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Percentage Price Oscillator (PPO).
+///
+/// ``` python
+///     #  This Python code produces the same result as the ppo function does
+///     import talib
+///     ppo = talib.PPO(close, fastperiod=12, slowperiod=26, matype=0)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `fast_period` - An optional period of the fast MA. Defaults to 12.
+/// * `slow_period` - An optional period of the slow MA. Defaults to 26.
+/// * `ma_type` - An optional type of both MAs. Defaults to SMA like TA-Lib.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::{ppo, MaType};
+///
+///  let close = Series::new("close", (0..40).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let res = ppo(&close, Some(5), Some(10), Some(MaType::Ema));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ppo(src: &Series, fast_period: Option<usize>, slow_period: Option<usize>, ma_type: Option<MaType>) -> Result<Series, TalibError> {
+    let ppo = ppo_slice(&series_to_vec(src)?, fast_period, slow_period, ma_type)?;
+    Ok(vec_to_series("data", ppo))
+}
+
+/// Streaming Percentage Price Oscillator (PPO).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{Ppo, MaType};
+///
+/// let mut ppo = Ppo::new(2, 3, MaType::Ema).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", ppo.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ppo {
+    legs: OscillatorLegs,
+}
+
+impl Ppo {
+    /// Creates a new PPO state, returns an error if `fast_period` or `slow_period` is less than 2.
+    /// The periods are swapped when `slow_period` is less than `fast_period`.
+    pub fn new(fast_period: usize, slow_period: usize, ma_type: MaType) -> Result<Self, TalibError> {
+        Ok(Ppo {
+            legs: OscillatorLegs::new(fast_period, ma_type, slow_period, ma_type)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current PPO once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let (fast, slow) = self.legs.update(value)?;
+        Some(if slow.abs() < ZERO { 0.0 } else { 100.0 * (fast - slow) / slow })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.legs.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppo() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..10).map(|i| 10.0 + i as f64).collect();
+        let res = ppo_slice(&close, Some(2), Some(4), Some(MaType::Sma))?;
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        // the SMA(2) of a straight line is 1.0 above the SMA(4)
+        assert!(res[3..].iter().zip(close[3..].iter()).all(|(value, close)| (value - 100.0 / (close - 1.5)).abs() < 1e-9));
+        // a slow MA of 0 gives 0
+        assert_eq!(ppo_slice(&[0.0; 5], Some(2), Some(4), None)?[3..], [0.0, 0.0]);
+        assert!(ppo_slice(&close, Some(1), None, None).is_err());
+        assert!(ppo_slice(&close, None, None, None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/03 10:20
+ * @Email: uyplayer@qq.com
+ * @File: price_change.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The value `time_period` bars ago, shared by MOM, ROC, ROCP, ROCR and ROCR100.
+
+use std::collections::VecDeque;
+
+/// Keeps the last `time_period + 1` values and hands out the oldest one.
+#[derive(Debug, Clone)]
+pub(crate) struct PeriodAgo {
+    time_period: usize,
+    window: VecDeque<f64>,
+}
+
+impl PeriodAgo {
+    pub(crate) fn new(time_period: usize) -> Self {
+        PeriodAgo {
+            time_period,
+            window: VecDeque::with_capacity(time_period + 1),
+        }
+    }
+
+    /// Feeds the next value and returns the value `time_period` bars before it.
+    pub(crate) fn update(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        if self.window.len() <= self.time_period {
+            return None;
+        }
+        self.window.pop_front()
+    }
+
+    /// Clears all accumulated values.
+    pub(crate) fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// `value / prev`, `None` when `prev` is 0 so that neither inf nor NaN leaks into the output.
+pub(crate) fn ratio(value: f64, prev: f64) -> Option<f64> {
+    (prev != 0.0).then(|| value / prev)
+}
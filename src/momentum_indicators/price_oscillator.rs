@@ -0,0 +1,81 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/04 19:40
+ * @Email: uyplayer@qq.com
+ * @File: price_oscillator.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The fast and slow MAs of a price oscillator, shared by MACD, MACDEXT, MACDFIX, APO and PPO.
+//!
+//! Like TA-Lib the leg with the shorter lookback starts later, so both legs are seeded on the same bar.
+
+use crate::{moving_average_lookback, Ma, MaType, TalibError};
+
+/// A fast and a slow MA over the same input, returned from the longest lookback on.
+#[derive(Debug, Clone)]
+pub(crate) struct OscillatorLegs {
+    fast: Ma,
+    slow: Ma,
+    fast_offset: usize,
+    slow_offset: usize,
+    bar_index: usize,
+}
+
+impl OscillatorLegs {
+    /// Returns an error if `fast_period` or `slow_period` is less than 2,
+    /// the legs are swapped when `slow_period` is less than `fast_period`.
+    pub(crate) fn new(fast_period: usize, fast_ma_type: MaType, slow_period: usize, slow_ma_type: MaType) -> Result<Self, TalibError> {
+        for (name, value) in [("fast_period", fast_period), ("slow_period", slow_period)] {
+            if value < 2 {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value: value as f64,
+                    reason: "must be greater than 1",
+                });
+            }
+        }
+        let ((fast_period, fast_ma_type), (slow_period, slow_ma_type)) = if slow_period < fast_period {
+            ((slow_period, slow_ma_type), (fast_period, fast_ma_type))
+        } else {
+            ((fast_period, fast_ma_type), (slow_period, slow_ma_type))
+        };
+        Ok(Self::from_mas(
+            Ma::new(fast_period, fast_ma_type)?,
+            moving_average_lookback(Some(fast_period), Some(fast_ma_type)),
+            Ma::new(slow_period, slow_ma_type)?,
+            moving_average_lookback(Some(slow_period), Some(slow_ma_type)),
+        ))
+    }
+
+    /// Builds the legs from already configured MAs and their lookbacks.
+    pub(crate) fn from_mas(fast: Ma, fast_lookback: usize, slow: Ma, slow_lookback: usize) -> Self {
+        let lookback = fast_lookback.max(slow_lookback);
+        OscillatorLegs {
+            fast,
+            slow,
+            fast_offset: lookback - fast_lookback,
+            slow_offset: lookback - slow_lookback,
+            bar_index: 0,
+        }
+    }
+
+    /// Feeds the next value and returns the current `(fast, slow)` once both legs are warmed up.
+    pub(crate) fn update(&mut self, value: f64) -> Option<(f64, f64)> {
+        let bar_index = self.bar_index;
+        self.bar_index += 1;
+        let fast = if bar_index >= self.fast_offset { self.fast.update(value) } else { None };
+        let slow = if bar_index >= self.slow_offset { self.slow.update(value) } else { None };
+        fast.zip(slow)
+    }
+
+    /// Clears all accumulated values.
+    pub(crate) fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.bar_index = 0;
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/03 10:45
+ * @Email: uyplayer@qq.com
+ * @File: roc.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Rate of change
+//!
+//! roc = (value / prev value - 1) * 100, null when the previous value is 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_change::{ratio, PeriodAgo};
+use crate::TalibError;
+
+/// Number of leading bars without a ROC value, `time_period` like TA-Lib.
+pub fn roc_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(10)
+}
+
+/// Calculates the Rate of change (ROC) over a plain slice, the polars free core of [`roc`].
+///
+/// A previous value of 0 gives NaN instead of inf, which the Series wrapper turns into null.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::roc_slice;
+///
+/// let roc = roc_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1)).unwrap();
+/// assert!(roc[0].is_nan());
+/// assert!((roc[1] - 20.0).abs() < 1e-9);
+/// assert!((roc[2] + 100.0).abs() < 1e-9);
+/// assert!(roc[3].is_nan());
+/// assert!((roc[4] - 50.0).abs() < 1e-9);
+/// ```
+pub fn roc_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(10);
+    let mut state = Roc::new(time_period)?;
+    let lookback = roc_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Rate of change (ROC).
+///
+/// ``` python
+///     #  This Python code produces the same result as the roc function does
+///     import talib
+///     roc = talib.ROC(close, timeperiod=10)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 10 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::roc;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = roc(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn roc(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let roc = roc_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", roc))
+}
+
+/// Streaming Rate of change (ROC).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Roc;
+///
+/// let mut roc = Roc::new(2).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", roc.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Roc {
+    prev: PeriodAgo,
+}
+
+impl Roc {
+    /// Creates a new ROC state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Roc {
+            prev: PeriodAgo::new(time_period),
+        })
+    }
+
+    /// Feeds the next value and returns the current ROC once warmed up, `None` while the previous value is 0.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let prev = self.prev.update(value)?;
+        ratio(value, prev).map(|ratio| (ratio - 1.0) * 100.0)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roc() -> Result<(), TalibError> {
+        let res = roc_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1))?;
+        assert!(res[0].is_nan());
+        assert!((res[1] - 20.0).abs() < 1e-9);
+        assert!((res[2] + 100.0).abs() < 1e-9);
+        assert!(res[3].is_nan());
+        assert!((res[4] - 50.0).abs() < 1e-9);
+        assert!(roc_slice(&[1.0; 5], Some(0)).is_err());
+        assert!(roc_slice(&[1.0; 5], Some(5)).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_roc_series() -> Result<(), TalibError> {
+        // the Series output has a null where the previous value is 0
+        let series = roc(&Series::new("data", [10.0, 12.0, 0.0, 6.0, 9.0]), Some(1))?;
+        assert_eq!(series.null_count(), 2);
+        assert!(series.f64()?.get(3).is_none());
+        Ok(())
+    }
+}
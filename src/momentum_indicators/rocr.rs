@@ -0,0 +1,164 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/03 11:10
+ * @Email: uyplayer@qq.com
+ * @File: rocr.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Rate of change ratio
+//!
+//! rocr = value / prev value, null when the previous value is 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_change::{ratio, PeriodAgo};
+use crate::TalibError;
+
+/// Number of leading bars without a ROCR value, `time_period` like TA-Lib.
+pub fn rocr_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(10)
+}
+
+/// Calculates the Rate of change ratio (ROCR) over a plain slice, the polars free core of [`rocr`].
+///
+/// A previous value of 0 gives NaN instead of inf, which the Series wrapper turns into null.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::rocr_slice;
+///
+/// let rocr = rocr_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1)).unwrap();
+/// assert!(rocr[0].is_nan());
+/// assert!((rocr[1] - 1.2).abs() < 1e-9);
+/// assert!((rocr[2] - 0.0).abs() < 1e-9);
+/// assert!(rocr[3].is_nan());
+/// assert!((rocr[4] - 1.5).abs() < 1e-9);
+/// ```
+pub fn rocr_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(10);
+    let mut state = Rocr::new(time_period)?;
+    let lookback = rocr_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Rate of change ratio (ROCR).
+///
+/// ``` python
+///     #  This Python code produces the same result as the rocr function does
+///     import talib
+///     rocr = talib.ROCR(close, timeperiod=10)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 10 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::rocr;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = rocr(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn rocr(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let rocr = rocr_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", rocr))
+}
+
+/// Streaming Rate of change ratio (ROCR).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Rocr;
+///
+/// let mut rocr = Rocr::new(2).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", rocr.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rocr {
+    prev: PeriodAgo,
+}
+
+impl Rocr {
+    /// Creates a new ROCR state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Rocr {
+            prev: PeriodAgo::new(time_period),
+        })
+    }
+
+    /// Feeds the next value and returns the current ROCR once warmed up, `None` while the previous value is 0.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let prev = self.prev.update(value)?;
+        ratio(value, prev)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rocr() -> Result<(), TalibError> {
+        let res = rocr_slice(&[10.0, 12.0, 0.0, 6.0, 9.0], Some(1))?;
+        assert!(res[0].is_nan());
+        assert!((res[1] - 1.2).abs() < 1e-9);
+        assert!((res[2] - 0.0).abs() < 1e-9);
+        assert!(res[3].is_nan());
+        assert!((res[4] - 1.5).abs() < 1e-9);
+        assert!(rocr_slice(&[1.0; 5], Some(0)).is_err());
+        assert!(rocr_slice(&[1.0; 5], Some(5)).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_rocr_series() -> Result<(), TalibError> {
+        // the Series output has a null where the previous value is 0
+        let series = rocr(&Series::new("data", [10.0, 12.0, 0.0, 6.0, 9.0]), Some(1))?;
+        assert_eq!(series.null_count(), 2);
+        assert!(series.f64()?.get(3).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/30 20:30
+ * @Email: uyplayer@qq.com
+ * @File: rsi.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Relative Strength Index
+//!
+//! rsi = 100 * average gain / (average gain + average loss), 0 when the price did not move at all
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::gain_loss::AverageGainLoss;
+use crate::helper::ZERO;
+use crate::TalibError;
+
+/// Number of leading bars without an RSI value, `time_period` like TA-Lib.
+pub fn rsi_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14)
+}
+
+/// Calculates the Relative Strength Index (RSI) over a plain slice, the polars free core of [`rsi`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::rsi_slice;
+///
+/// let rsi = rsi_slice(&[10.0, 11.0, 10.0, 12.0, 12.0], Some(2)).unwrap();
+/// assert!(rsi[1].is_nan());
+/// assert_eq!(rsi[2], 50.0);
+/// assert!((rsi[3] - 250.0 / 3.0).abs() < 1e-9);
+/// ```
+pub fn rsi_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Rsi::new(time_period)?;
+    let lookback = rsi_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Relative Strength Index (RSI).
+///
+/// ``` python
+///     #  This Python code produces the same result as the rsi function does
+///     import talib
+///     rsi = talib.RSI(close, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::rsi;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = rsi(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn rsi(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let rsi = rsi_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", rsi))
+}
+
+/// Streaming Relative Strength Index (RSI).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Rsi;
+///
+/// let mut rsi = Rsi::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", rsi.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rsi {
+    time_period: usize,
+    average: AverageGainLoss,
+}
+
+impl Rsi {
+    /// Creates a new RSI state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Rsi {
+            time_period,
+            average: AverageGainLoss::new(time_period),
+        })
+    }
+
+    /// Feeds the next value and returns the current RSI once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let (gain, loss) = self.average.update(value)?;
+        let total = gain + loss;
+        Some(if total.abs() < ZERO { 0.0 } else { 100.0 * gain / total })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.average = AverageGainLoss::new(self.time_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsi() -> Result<(), TalibError> {
+        let res = rsi_slice(&[10.0, 11.0, 10.0, 12.0, 12.0, 11.0], Some(2))?;
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        // seeded with the plain averages, gain 0.5 and loss 0.5
+        assert_eq!(res[2], 50.0);
+        // then Wilder's smoothing, gain (0.5 + 2) / 2 and loss 0.5 / 2
+        assert_eq!(res[3], 100.0 * 1.25 / 1.5);
+        assert_eq!(res[4], 100.0 * 0.625 / 0.75);
+        assert_eq!(res[5], 100.0 * 0.3125 / (0.3125 + 0.5625));
+        assert_eq!(rsi_slice(&[1.0; 5], Some(2))?[2..], [0.0, 0.0, 0.0]);
+        assert!(rsi_slice(&[1.0; 5], Some(1)).is_err());
+        assert!(rsi_slice(&[1.0; 5], Some(5)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/02 15:30
+ * @Email: uyplayer@qq.com
+ * @File: stoch.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Stochastic
+//!
+//! - fastk = 100 * (close - lowest low) / (highest high - lowest low) over `fastk_period` bars
+//! - slowk = MA(slowk_period) of fastk
+//! - slowd = MA(slowd_period) of slowk
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::stochastic::FastK;
+use crate::{moving_average_lookback, Ma, MaType, TalibError};
+
+/// Number of leading bars without a STOCH value, `fastk_period - 1` plus the lookbacks of the
+/// slowk and slowd MAs, like TA-Lib.
+pub fn stoch_lookback(
+    fastk_period: Option<usize>,
+    slowk_period: Option<usize>,
+    slowk_ma_type: Option<MaType>,
+    slowd_period: Option<usize>,
+    slowd_ma_type: Option<MaType>,
+) -> usize {
+    fastk_period.unwrap_or(5).saturating_sub(1)
+        + moving_average_lookback(Some(slowk_period.unwrap_or(3)), slowk_ma_type)
+        + moving_average_lookback(Some(slowd_period.unwrap_or(3)), slowd_ma_type)
+}
+
+/// Calculates the Stochastic (STOCH) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`stoch`].
+///
+/// Returns `(slowk, slowd)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if any of the periods is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{stoch_slice, MaType};
+///
+/// let high = [10.0, 12.0, 11.0, 13.0, 13.0];
+/// let low = [8.0, 9.0, 9.0, 10.0, 13.0];
+/// let close = [9.0, 11.0, 10.0, 12.0, 13.0];
+/// let (slowk, slowd) = stoch_slice(&high, &low, &close, Some(2), Some(2), Some(MaType::Sma), Some(2), Some(MaType::Sma)).unwrap();
+/// assert!(slowk[2].is_nan());
+/// // fastk is 33.3, 75 and 100 from the third bar on
+/// assert_eq!(slowk[4], 87.5);
+/// assert!((slowd[4] - (87.5 + (100.0 / 3.0 + 75.0) / 2.0) / 2.0).abs() < 1e-9);
+/// ```
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn stoch_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    fastk_period: Option<usize>,
+    slowk_period: Option<usize>,
+    slowk_ma_type: Option<MaType>,
+    slowd_period: Option<usize>,
+    slowd_ma_type: Option<MaType>,
+) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let mut state = Stoch::new(
+        fastk_period.unwrap_or(5),
+        slowk_period.unwrap_or(3),
+        slowk_ma_type.unwrap_or_default(),
+        slowd_period.unwrap_or(3),
+        slowd_ma_type.unwrap_or_default(),
+    )?;
+    let lookback = stoch_lookback(fastk_period, slowk_period, slowk_ma_type, slowd_period, slowd_ma_type);
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or((f64::NAN, f64::NAN)))
+        .unzip())
+}
+
+/// Calculates the Stochastic (STOCH).
+///
+/// ``` python
+///     #  This Python code produces the same result as the stoch function does
+///     import talib
+///     slowk, slowd = talib.STOCH(high, low, close, fastk_period=5, slowk_period=3, slowk_matype=0, slowd_period=3, slowd_matype=0)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `fastk_period` - An optional number of bars the fastk looks back on. Defaults to 5.
+/// * `slowk_period` - An optional period of the slowk MA. Defaults to 3.
+/// * `slowk_ma_type` - An optional type of the slowk MA. Defaults to SMA like TA-Lib.
+/// * `slowd_period` - An optional period of the slowd MA. Defaults to 3.
+/// * `slowd_ma_type` - An optional type of the slowd MA. Defaults to SMA like TA-Lib.
+///
+/// # Returns
+///
+/// The `(slowk, slowd)` Series, the first [`stoch_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::stoch;
+///
+///  let high = Series::new("high", [10.0, 12.0, 11.0, 13.0, 12.5, 14.0, 13.0, 12.0, 12.5, 13.5]);
+///  let low = Series::new("low", [8.0, 9.0, 9.0, 10.0, 11.0, 12.0, 11.5, 10.0, 10.5, 11.0]);
+///  let close = Series::new("close", [9.0, 11.0, 10.0, 12.0, 12.0, 13.0, 12.0, 11.0, 12.0, 13.0]);
+///  let (slowk, slowd) = stoch(&high, &low, &close, None, None, None, None, None).unwrap();
+///  eprintln!("{:?} {:?}", slowk, slowd);
+/// ```
+///
+#[cfg(feature = "polars")]
+#[allow(clippy::too_many_arguments)]
+pub fn stoch(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    fastk_period: Option<usize>,
+    slowk_period: Option<usize>,
+    slowk_ma_type: Option<MaType>,
+    slowd_period: Option<usize>,
+    slowd_ma_type: Option<MaType>,
+) -> Result<(Series, Series), TalibError> {
+    let (slowk, slowd) = stoch_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        fastk_period,
+        slowk_period,
+        slowk_ma_type,
+        slowd_period,
+        slowd_ma_type,
+    )?;
+    Ok((vec_to_series("SLOWK", slowk), vec_to_series("SLOWD", slowd)))
+}
+
+/// Streaming Stochastic (STOCH).
+///
+/// Returns `(slowk, slowd)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{MaType, Stoch};
+///
+/// let mut stoch = Stoch::new(2, 2, MaType::Sma, 2, MaType::Sma).unwrap();
+/// for (high, low, close) in [(10.0, 8.0, 9.0), (12.0, 9.0, 11.0), (11.0, 9.0, 10.0), (13.0, 10.0, 12.0)] {
+///     eprintln!("{:?}", stoch.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stoch {
+    fastk: FastK,
+    slowk: Ma,
+    slowd: Ma,
+}
+
+impl Stoch {
+    /// Creates a new STOCH state, returns an error if any of the periods is 0.
+    pub fn new(
+        fastk_period: usize,
+        slowk_period: usize,
+        slowk_ma_type: MaType,
+        slowd_period: usize,
+        slowd_ma_type: MaType,
+    ) -> Result<Self, TalibError> {
+        if fastk_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "fastk_period",
+                value: fastk_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Stoch {
+            fastk: FastK::new(fastk_period),
+            slowk: Ma::new(slowk_period, slowk_ma_type)?,
+            slowd: Ma::new(slowd_period, slowd_ma_type)?,
+        })
+    }
+
+    /// Feeds the next bar and returns the current `(slowk, slowd)` once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<(f64, f64)> {
+        let fastk = self.fastk.update(high, low, close)?;
+        let slowk = self.slowk.update(fastk)?;
+        let slowd = self.slowd.update(slowk)?;
+        Some((slowk, slowd))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.fastk.reset();
+        self.slowk.reset();
+        self.slowd.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stoch() -> Result<(), TalibError> {
+        let high: Vec<f64> = (0..40).map(|i| 12.0 + 3.0 * (i as f64 * 0.4).sin()).collect();
+        let low: Vec<f64> = high.iter().enumerate().map(|(i, h)| h - 1.0 - (i % 3) as f64).collect();
+        let close: Vec<f64> = high.iter().zip(low.iter()).map(|(h, l)| (h + 2.0 * l) / 3.0).collect();
+        let (slowk, slowd) = stoch_slice(&high, &low, &close, None, None, None, None, None)?;
+        assert_eq!(stoch_lookback(None, None, None, None, None), 8);
+        assert!(slowk[..8].iter().chain(slowd[..8].iter()).all(|value| value.is_nan()));
+        // slowk is the SMA of STOCHF's fastk, slowd the SMA of slowk
+        let (fastk, _) = crate::stochf_slice(&high, &low, &close, Some(5), Some(1), None)?;
+        for i in 8..high.len() {
+            let slowk_at = |j: usize| fastk[j - 2..=j].iter().sum::<f64>() / 3.0;
+            assert!((slowk[i] - slowk_at(i)).abs() < 1e-9);
+            assert!((slowd[i] - (slowk_at(i - 2) + slowk_at(i - 1) + slowk_at(i)) / 3.0).abs() < 1e-9);
+        }
+        assert!(stoch_slice(&high, &low, &close, None, Some(0), None, None, None).is_err());
+        assert!(stoch_slice(&high[..8], &low[..8], &close[..8], None, None, None, None, None).is_err());
+        Ok(())
+    }
+}
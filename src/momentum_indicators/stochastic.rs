@@ -0,0 +1,43 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/02 14:20
+ * @Email: uyplayer@qq.com
+ * @File: stochastic.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The raw stochastic %K, shared by STOCH, STOCHF and STOCHRSI.
+//!
+//! fastk = 100 * (close - lowest low) / (highest high - lowest low) over `fastk_period` bars,
+//! 0 when the highest high and the lowest low are equal
+
+use crate::helper::RollingExtremes;
+
+/// Raw stochastic %K, returned from bar `fastk_period - 1` on.
+#[derive(Debug, Clone)]
+pub(crate) struct FastK {
+    extremes: RollingExtremes,
+}
+
+impl FastK {
+    pub(crate) fn new(fastk_period: usize) -> Self {
+        FastK {
+            extremes: RollingExtremes::new(fastk_period),
+        }
+    }
+
+    /// Feeds the next bar and returns the current %K once `fastk_period` bars were seen.
+    pub(crate) fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let (highest, lowest) = self.extremes.update(high, low)?;
+        let range = highest - lowest;
+        Some(if range != 0.0 { 100.0 * (close - lowest) / range } else { 0.0 })
+    }
+
+    /// Clears all accumulated values.
+    pub(crate) fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
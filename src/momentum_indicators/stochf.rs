@@ -0,0 +1,210 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/02 14:45
+ * @Email: uyplayer@qq.com
+ * @File: stochf.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Stochastic Fast
+//!
+//! - fastk = 100 * (close - lowest low) / (highest high - lowest low) over `fastk_period` bars
+//! - fastd = MA(fastd_period) of fastk
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::stochastic::FastK;
+use crate::{moving_average_lookback, Ma, MaType, TalibError};
+
+/// Number of leading bars without a STOCHF value, `fastk_period - 1` plus the lookback of the
+/// fastd MA, like TA-Lib.
+pub fn stochf_lookback(fastk_period: Option<usize>, fastd_period: Option<usize>, fastd_ma_type: Option<MaType>) -> usize {
+    fastk_period.unwrap_or(5).saturating_sub(1) + moving_average_lookback(Some(fastd_period.unwrap_or(3)), fastd_ma_type)
+}
+
+/// Calculates the Stochastic Fast (STOCHF) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`stochf`].
+///
+/// Returns `(fastk, fastd)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `fastk_period` or `fastd_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{stochf_slice, MaType};
+///
+/// let high = [10.0, 12.0, 11.0, 13.0];
+/// let low = [8.0, 9.0, 9.0, 10.0];
+/// let close = [9.0, 11.0, 10.0, 12.0];
+/// let (fastk, fastd) = stochf_slice(&high, &low, &close, Some(2), Some(2), Some(MaType::Sma)).unwrap();
+/// assert!(fastk[1].is_nan());
+/// // close 10 between the lowest low 9 and the highest high 12, then close 12 between 9 and 13
+/// assert!((fastk[2] - 100.0 / 3.0).abs() < 1e-9);
+/// assert_eq!(fastk[3], 75.0);
+/// assert!((fastd[3] - (100.0 / 3.0 + 75.0) / 2.0).abs() < 1e-9);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn stochf_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    fastk_period: Option<usize>,
+    fastd_period: Option<usize>,
+    fastd_ma_type: Option<MaType>,
+) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let mut state = StochF::new(fastk_period.unwrap_or(5), fastd_period.unwrap_or(3), fastd_ma_type.unwrap_or_default())?;
+    let lookback = stochf_lookback(fastk_period, fastd_period, fastd_ma_type);
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or((f64::NAN, f64::NAN)))
+        .unzip())
+}
+
+/// Calculates the Stochastic Fast (STOCHF).
+///
+/// ``` python
+///     #  This Python code produces the same result as the stochf function does
+///     import talib
+///     fastk, fastd = talib.STOCHF(high, low, close, fastk_period=5, fastd_period=3, fastd_matype=0)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `fastk_period` - An optional number of bars the fastk looks back on. Defaults to 5.
+/// * `fastd_period` - An optional period of the fastd MA. Defaults to 3.
+/// * `fastd_ma_type` - An optional type of the fastd MA. Defaults to SMA like TA-Lib.
+///
+/// # Returns
+///
+/// The `(fastk, fastd)` Series, the first [`stochf_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::stochf;
+///
+///  let high = Series::new("high", [10.0, 12.0, 11.0, 13.0, 12.5, 14.0, 13.0, 12.0]);
+///  let low = Series::new("low", [8.0, 9.0, 9.0, 10.0, 11.0, 12.0, 11.5, 10.0]);
+///  let close = Series::new("close", [9.0, 11.0, 10.0, 12.0, 12.0, 13.0, 12.0, 11.0]);
+///  let (fastk, fastd) = stochf(&high, &low, &close, None, None, None).unwrap();
+///  eprintln!("{:?} {:?}", fastk, fastd);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn stochf(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    fastk_period: Option<usize>,
+    fastd_period: Option<usize>,
+    fastd_ma_type: Option<MaType>,
+) -> Result<(Series, Series), TalibError> {
+    let (fastk, fastd) = stochf_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        fastk_period,
+        fastd_period,
+        fastd_ma_type,
+    )?;
+    Ok((vec_to_series("FASTK", fastk), vec_to_series("FASTD", fastd)))
+}
+
+/// Streaming Stochastic Fast (STOCHF).
+///
+/// Returns `(fastk, fastd)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{MaType, StochF};
+///
+/// let mut stochf = StochF::new(2, 2, MaType::Sma).unwrap();
+/// for (high, low, close) in [(10.0, 8.0, 9.0), (12.0, 9.0, 11.0), (11.0, 9.0, 10.0)] {
+///     eprintln!("{:?}", stochf.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StochF {
+    fastk: FastK,
+    fastd: Ma,
+}
+
+impl StochF {
+    /// Creates a new STOCHF state, returns an error if `fastk_period` or `fastd_period` is 0.
+    pub fn new(fastk_period: usize, fastd_period: usize, fastd_ma_type: MaType) -> Result<Self, TalibError> {
+        if fastk_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "fastk_period",
+                value: fastk_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(StochF {
+            fastk: FastK::new(fastk_period),
+            fastd: Ma::new(fastd_period, fastd_ma_type)?,
+        })
+    }
+
+    /// Feeds the next bar and returns the current `(fastk, fastd)` once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<(f64, f64)> {
+        let fastk = self.fastk.update(high, low, close)?;
+        let fastd = self.fastd.update(fastk)?;
+        Some((fastk, fastd))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.fastk.reset();
+        self.fastd.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stochf() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 13.0, 13.0, 13.0];
+        let low = [8.0, 9.0, 9.0, 10.0, 13.0, 13.0];
+        let close = [9.0, 11.0, 10.0, 12.0, 13.0, 13.0];
+        let (fastk, fastd) = stochf_slice(&high, &low, &close, Some(2), Some(2), Some(MaType::Sma))?;
+        assert!(fastk[..2].iter().chain(fastd[..2].iter()).all(|value| value.is_nan()));
+        assert_eq!(fastk[3], 75.0);
+        // a close at the highest high is 100, a window without any range is 0
+        assert_eq!(fastk[4..], [100.0, 0.0]);
+        assert_eq!(fastd[5], 50.0);
+        assert!(stochf_slice(&high, &low, &close[1..], None, None, None).is_err());
+        assert!(stochf_slice(&high, &low, &close, Some(0), None, None).is_err());
+        Ok(())
+    }
+}
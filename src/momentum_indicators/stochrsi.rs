@@ -0,0 +1,210 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/30 21:15
+ * @Email: uyplayer@qq.com
+ * @File: stochrsi.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Stochastic Relative Strength Index
+//!
+//! - fastk = 100 * (rsi - lowest rsi) / (highest rsi - lowest rsi) over `fastk_period` RSI values,
+//!   0 when the RSI did not move
+//! - fastd = MA(fastd_period) of fastk
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::stochastic::FastK;
+use crate::{moving_average_lookback, rsi_lookback, Ma, MaType, Rsi, TalibError};
+
+/// Number of leading bars without a STOCHRSI value, the RSI lookback plus `fastk_period - 1`
+/// plus the lookback of the fastd MA, like TA-Lib.
+pub fn stochrsi_lookback(
+    time_period: Option<usize>,
+    fastk_period: Option<usize>,
+    fastd_period: Option<usize>,
+    fastd_ma_type: Option<MaType>,
+) -> usize {
+    rsi_lookback(time_period)
+        + fastk_period.unwrap_or(5).saturating_sub(1)
+        + moving_average_lookback(Some(fastd_period.unwrap_or(3)), fastd_ma_type)
+}
+
+/// Calculates the Stochastic Relative Strength Index (STOCHRSI) over a plain slice, the polars free core of [`stochrsi`].
+///
+/// Returns `(fastk, fastd)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is less than 2, or `fastk_period`
+/// or `fastd_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{stochrsi_slice, MaType};
+///
+/// let close = [10.0, 11.0, 10.0, 12.0, 12.0, 11.0, 13.0];
+/// let (fastk, fastd) = stochrsi_slice(&close, Some(2), Some(2), Some(2), Some(MaType::Sma)).unwrap();
+/// assert!(fastk[3].is_nan());
+/// // the RSI was 50.0, 83.3 and 83.3, the highest and the lowest of the last two are equal
+/// assert_eq!(fastk[4], 0.0);
+/// assert_eq!(fastd[4], 50.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn stochrsi_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+    fastk_period: Option<usize>,
+    fastd_period: Option<usize>,
+    fastd_ma_type: Option<MaType>,
+) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    let mut state = StochRsi::new(
+        time_period.unwrap_or(14),
+        fastk_period.unwrap_or(5),
+        fastd_period.unwrap_or(3),
+        fastd_ma_type.unwrap_or_default(),
+    )?;
+    let lookback = stochrsi_lookback(time_period, fastk_period, fastd_period, fastd_ma_type);
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or((f64::NAN, f64::NAN)))
+        .unzip())
+}
+
+/// Calculates the Stochastic Relative Strength Index (STOCHRSI).
+///
+/// ``` python
+///     #  This Python code produces the same result as the stochrsi function does
+///     import talib
+///     fastk, fastd = talib.STOCHRSI(close, timeperiod=14, fastk_period=5, fastd_period=3, fastd_matype=0)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional period of the RSI. Defaults to 14.
+/// * `fastk_period` - An optional number of RSI values the fastk looks back on. Defaults to 5.
+/// * `fastd_period` - An optional period of the fastd MA. Defaults to 3.
+/// * `fastd_ma_type` - An optional type of the fastd MA. Defaults to SMA like TA-Lib.
+///
+/// # Returns
+///
+/// The `(fastk, fastd)` Series, the first [`stochrsi_lookback`] values are null.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::stochrsi;
+///
+///  let close = Series::new("close", (0..40).map(|i| (i as f64 * 0.3).sin()).collect::<Vec<f64>>());
+///  let (fastk, fastd) = stochrsi(&close, None, None, None, None).unwrap();
+///  eprintln!("{:?} {:?}", fastk, fastd);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn stochrsi(
+    src: &Series,
+    time_period: Option<usize>,
+    fastk_period: Option<usize>,
+    fastd_period: Option<usize>,
+    fastd_ma_type: Option<MaType>,
+) -> Result<(Series, Series), TalibError> {
+    let (fastk, fastd) = stochrsi_slice(&series_to_vec(src)?, time_period, fastk_period, fastd_period, fastd_ma_type)?;
+    Ok((vec_to_series("FASTK", fastk), vec_to_series("FASTD", fastd)))
+}
+
+/// Streaming Stochastic Relative Strength Index (STOCHRSI).
+///
+/// Returns `(fastk, fastd)` once warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{MaType, StochRsi};
+///
+/// let mut stochrsi = StochRsi::new(3, 2, 2, MaType::Sma).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", stochrsi.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StochRsi {
+    rsi: Rsi,
+    fastk: FastK,
+    fastd: Ma,
+}
+
+impl StochRsi {
+    /// Creates a new STOCHRSI state, returns an error if `time_period` is less than 2,
+    /// or `fastk_period` or `fastd_period` is 0.
+    pub fn new(time_period: usize, fastk_period: usize, fastd_period: usize, fastd_ma_type: MaType) -> Result<Self, TalibError> {
+        if fastk_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "fastk_period",
+                value: fastk_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(StochRsi {
+            rsi: Rsi::new(time_period)?,
+            fastk: FastK::new(fastk_period),
+            fastd: Ma::new(fastd_period, fastd_ma_type)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current `(fastk, fastd)` once warmed up.
+    pub fn update(&mut self, value: f64) -> Option<(f64, f64)> {
+        let rsi = self.rsi.update(value)?;
+        let fastk = self.fastk.update(rsi, rsi, rsi)?;
+        let fastd = self.fastd.update(fastk)?;
+        Some((fastk, fastd))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.rsi.reset();
+        self.fastk.reset();
+        self.fastd.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stochrsi() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..60).map(|i| 100.0 + 10.0 * (i as f64 * 0.3).sin() + (i % 4) as f64).collect();
+        let (fastk, fastd) = stochrsi_slice(&close, None, None, None, None)?;
+        assert_eq!(stochrsi_lookback(None, None, None, None), 20);
+        assert!(fastk[..20].iter().all(|value| value.is_nan()));
+        let rsi = crate::rsi_slice(&close, Some(14))?;
+        let fastk_at = |i: usize| {
+            let window = &rsi[i - 4..=i];
+            let highest = window.iter().copied().fold(f64::MIN, f64::max);
+            let lowest = window.iter().copied().fold(f64::MAX, f64::min);
+            100.0 * (rsi[i] - lowest) / (highest - lowest)
+        };
+        for i in 20..close.len() {
+            assert!((fastk[i] - fastk_at(i)).abs() < 1e-9);
+            // fastd is the SMA of fastk, including the two fastk values before the first output
+            let expected = (fastk_at(i - 2) + fastk_at(i - 1) + fastk_at(i)) / 3.0;
+            assert!((fastd[i] - expected).abs() < 1e-9);
+        }
+        assert!(stochrsi_slice(&close, Some(14), Some(0), None, None).is_err());
+        assert!(stochrsi_slice(&close[..20], None, None, None, None).is_err());
+        Ok(())
+    }
+}
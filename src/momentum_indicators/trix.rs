@@ -0,0 +1,155 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/06 19:20
+ * @Email: uyplayer@qq.com
+ * @File: trix.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA
+//!
+//! trix = 1-day ROC of EMA(EMA(EMA(value))), null like ROC when the previous triple EMA is 0
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::momentum_indicators::price_change::{ratio, PeriodAgo};
+use crate::{exponential_moving_average_lookback, Ema, TalibError};
+
+/// Number of leading bars without a TRIX value, three EMA lookbacks plus one, like TA-Lib.
+pub fn trix_lookback(time_period: Option<usize>) -> usize {
+    3 * exponential_moving_average_lookback(Some(time_period.unwrap_or(30))) + 1
+}
+
+/// Calculates the 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA (TRIX) over a plain slice,
+/// the polars free core of [`trix`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::trix_slice;
+///
+/// // an EMA of period 1 is the value itself
+/// let trix = trix_slice(&[10.0, 12.0, 9.0], Some(1)).unwrap();
+/// assert!(trix[0].is_nan());
+/// assert!((trix[1] - 20.0).abs() < 1e-9);
+/// assert!((trix[2] + 25.0).abs() < 1e-9);
+/// ```
+pub fn trix_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(30);
+    let mut state = Trix::new(time_period)?;
+    let lookback = trix_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA (TRIX).
+///
+/// ``` python
+///     #  This Python code produces the same result as the trix function does
+///     import talib
+///     trix = talib.TRIX(close, timeperiod=30)
+/// ```
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional period of the EMAs. Defaults to 30 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::trix;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = trix(&close, Some(2));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn trix(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let trix = trix_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", trix))
+}
+
+/// Streaming 1-day Rate-Of-Change (ROC) of a Triple Smooth EMA (TRIX).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Trix;
+///
+/// let mut trix = Trix::new(2).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0] {
+///     eprintln!("{:?}", trix.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trix {
+    emas: [Ema; 3],
+    prev: PeriodAgo,
+}
+
+impl Trix {
+    /// Creates a new TRIX state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(Trix {
+            emas: [Ema::new(time_period)?, Ema::new(time_period)?, Ema::new(time_period)?],
+            prev: PeriodAgo::new(1),
+        })
+    }
+
+    /// Feeds the next value and returns the current TRIX once warmed up,
+    /// `None` while the previous triple EMA is 0.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let [ema1, ema2, ema3] = &mut self.emas;
+        let triple = ema3.update(ema2.update(ema1.update(value)?)?)?;
+        let prev = self.prev.update(triple)?;
+        ratio(triple, prev).map(|ratio| (ratio - 1.0) * 100.0)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.emas.iter_mut().for_each(Ema::reset);
+        self.prev.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trix() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..40).map(|i| 100.0 + 10.0 * (i as f64 * 0.3).sin()).collect();
+        let res = trix_slice(&close, Some(4))?;
+        assert_eq!(trix_lookback(Some(4)), 10);
+        assert!(res[..10].iter().all(|value| value.is_nan()));
+        let ema = crate::exponential_moving_average_slice(&close, Some(4))?;
+        let ema = crate::exponential_moving_average_slice(&ema[3..], Some(4))?;
+        let ema = crate::exponential_moving_average_slice(&ema[3..], Some(4))?;
+        for i in 10..close.len() {
+            let expected = (ema[i - 6] / ema[i - 7] - 1.0) * 100.0;
+            assert!((res[i] - expected).abs() < 1e-9);
+        }
+        assert!(trix_slice(&close, Some(0)).is_err());
+        Ok(())
+    }
+}
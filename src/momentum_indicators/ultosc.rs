@@ -0,0 +1,242 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/05 20:40
+ * @Email: uyplayer@qq.com
+ * @File: ultosc.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Ultimate Oscillator
+//!
+//! - buying pressure = close - min(low, prev close)
+//! - true range = max(high, prev close) - min(low, prev close)
+//! - average = sum of buying pressure / sum of true range over a period, 0 when there is no true range
+//! - ultosc = 100 * (4 * shortest average + 2 * middle average + longest average) / 7
+//!
+//! Like TA-Lib the three periods are sorted first, so the weights follow the period lengths
+//! rather than the argument order.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::ZERO;
+use crate::TalibError;
+use std::collections::VecDeque;
+
+/// Number of leading bars without an ULTOSC value, the longest period like TA-Lib.
+pub fn ultosc_lookback(time_period1: Option<usize>, time_period2: Option<usize>, time_period3: Option<usize>) -> usize {
+    time_period1
+        .unwrap_or(7)
+        .max(time_period2.unwrap_or(14))
+        .max(time_period3.unwrap_or(28))
+}
+
+/// Calculates the Ultimate Oscillator (ULTOSC) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`ultosc`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if any of the periods is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ultosc_slice;
+///
+/// // every bar closes at its high, so the buying pressure is the whole true range
+/// let high = [10.0, 11.0, 12.0, 13.0];
+/// let low = [9.0, 10.0, 11.0, 12.0];
+/// let ultosc = ultosc_slice(&high, &low, &high, Some(1), Some(2), Some(3)).unwrap();
+/// assert!(ultosc[2].is_nan());
+/// assert_eq!(ultosc[3], 100.0);
+/// ```
+pub fn ultosc_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period1: Option<usize>,
+    time_period2: Option<usize>,
+    time_period3: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let mut state = UltOsc::new(time_period1.unwrap_or(7), time_period2.unwrap_or(14), time_period3.unwrap_or(28))?;
+    let lookback = ultosc_lookback(time_period1, time_period2, time_period3);
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Ultimate Oscillator (ULTOSC).
+///
+/// ``` python
+///     #  This Python code produces the same result as the ultosc function does
+///     import talib
+///     ultosc = talib.ULTOSC(high, low, close, timeperiod1=7, timeperiod2=14, timeperiod3=28)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period1` - An optional first period. Defaults to 7.
+/// * `time_period2` - An optional second period. Defaults to 14.
+/// * `time_period3` - An optional third period. Defaults to 28.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ultosc;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = ultosc(&high, &low, &close, Some(2), Some(3), Some(4));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ultosc(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    time_period1: Option<usize>,
+    time_period2: Option<usize>,
+    time_period3: Option<usize>,
+) -> Result<Series, TalibError> {
+    let ultosc = ultosc_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        time_period1,
+        time_period2,
+        time_period3,
+    )?;
+    Ok(vec_to_series("data", ultosc))
+}
+
+/// Streaming Ultimate Oscillator (ULTOSC).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::UltOsc;
+///
+/// let mut ultosc = UltOsc::new(1, 2, 3).unwrap();
+/// for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (10.5, 9.0, 10.0), (12.0, 10.0, 11.5)] {
+///     eprintln!("{:?}", ultosc.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UltOsc {
+    /// The periods from the shortest to the longest.
+    periods: [usize; 3],
+    prev_close: Option<f64>,
+    /// `(buying pressure, true range)` of the last `periods[2]` bars.
+    window: VecDeque<(f64, f64)>,
+    sums: [(f64, f64); 3],
+}
+
+impl UltOsc {
+    /// Creates a new ULTOSC state, returns an error if any of the periods is 0.
+    pub fn new(time_period1: usize, time_period2: usize, time_period3: usize) -> Result<Self, TalibError> {
+        let mut periods = [time_period1, time_period2, time_period3];
+        for (name, value) in ["time_period1", "time_period2", "time_period3"].into_iter().zip(periods) {
+            if value == 0 {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value: value as f64,
+                    reason: "must be greater than 0",
+                });
+            }
+        }
+        periods.sort_unstable();
+        Ok(UltOsc {
+            periods,
+            prev_close: None,
+            window: VecDeque::with_capacity(periods[2] + 1),
+            sums: [(0.0, 0.0); 3],
+        })
+    }
+
+    /// Feeds the next bar and returns the current ULTOSC once the longest window is full.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let prev_close = self.prev_close.replace(close)?;
+        let true_low = low.min(prev_close);
+        let buying_pressure = close - true_low;
+        let true_range = high.max(prev_close) - true_low;
+        self.window.push_back((buying_pressure, true_range));
+        for (period, sum) in self.periods.iter().zip(self.sums.iter_mut()) {
+            sum.0 += buying_pressure;
+            sum.1 += true_range;
+            if self.window.len() > *period {
+                let (old_pressure, old_range) = self.window[self.window.len() - 1 - period];
+                sum.0 -= old_pressure;
+                sum.1 -= old_range;
+            }
+        }
+        if self.window.len() > self.periods[2] {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.periods[2] {
+            return None;
+        }
+        let total: f64 = self
+            .sums
+            .iter()
+            .zip([4.0, 2.0, 1.0])
+            .filter(|((_, range), _)| range.abs() >= ZERO)
+            .map(|((pressure, range), weight)| weight * (pressure / range))
+            .sum();
+        Some(100.0 * (total / 7.0))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+        self.window.clear();
+        self.sums = [(0.0, 0.0); 3];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ultosc() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 12.0, 13.0, 13.0, 13.0];
+        let low = [9.0, 10.0, 11.0, 12.0, 12.0, 12.0];
+        let close = [9.5, 11.0, 11.5, 12.0, 12.0, 12.0];
+        let res = ultosc_slice(&high, &low, &close, Some(3), Some(1), Some(2))?;
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        // buying pressure / true range of the bars: 1.5 / 1.5, 0.5 / 1.0 and 0.5 / 1.5
+        let expected = 100.0 * (4.0 * (0.5 / 1.5) + 2.0 * (1.0 / 2.5) + (2.5 / 4.0)) / 7.0;
+        assert!((res[3] - expected).abs() < 1e-9);
+        // the periods are sorted, so the argument order doesn't matter
+        assert_eq!(res[3..], ultosc_slice(&high, &low, &close, Some(1), Some(2), Some(3))?[3..]);
+        assert!(ultosc_slice(&high, &low, &close, Some(0), None, None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/05 20:10
+ * @Email: uyplayer@qq.com
+ * @File: willr.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/momentum_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Williams' %R
+//!
+//! willr = -100 * (highest high - close) / (highest high - lowest low) over `time_period` bars,
+//! 0 when the highest high and the lowest low are equal
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without a WILLR value, `time_period - 1` like TA-Lib.
+pub fn willr_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the Williams' %R (WILLR) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`willr`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::willr_slice;
+///
+/// let high = [10.0, 12.0, 11.0];
+/// let low = [8.0, 9.0, 9.0];
+/// let close = [9.0, 11.0, 10.0];
+/// let willr = willr_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(willr[0].is_nan());
+/// assert_eq!(willr[1], -25.0);
+/// ```
+pub fn willr_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Willr::new(time_period)?;
+    let lookback = willr_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Williams' %R (WILLR).
+///
+/// ``` python
+///     #  This Python code produces the same result as the willr function does
+///     import talib
+///     willr = talib.WILLR(high, low, close, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::willr;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = willr(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn willr(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let willr = willr_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", willr))
+}
+
+/// Streaming Williams' %R (WILLR).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Willr;
+///
+/// let mut willr = Willr::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 8.0, 9.0), (12.0, 9.0, 11.0), (11.0, 9.0, 10.0)] {
+///     eprintln!("{:?}", willr.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Willr {
+    extremes: RollingExtremes,
+}
+
+impl Willr {
+    /// Creates a new WILLR state, returns an error if `time_period` is less than 2.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(Willr {
+            extremes: RollingExtremes::new(time_period),
+        })
+    }
+
+    /// Feeds the next bar and returns the current %R once the window is full.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let (highest, lowest) = self.extremes.update(high, low)?;
+        let range = highest - lowest;
+        Some(if range != 0.0 { -100.0 * (highest - close) / range } else { 0.0 })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_willr() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 13.0, 13.0, 13.0];
+        let low = [8.0, 9.0, 9.0, 10.0, 13.0, 13.0];
+        let close = [9.0, 11.0, 10.0, 9.0, 13.0, 13.0];
+        let res = willr_slice(&high, &low, &close, Some(2))?;
+        assert!(res[0].is_nan());
+        // a close at the lowest low is -100, at the highest high 0 and a window without range 0
+        assert_eq!(res[1..], [-25.0, -200.0 / 3.0, -100.0, 0.0, 0.0]);
+        assert!(willr_slice(&high, &low, &close, Some(1)).is_err());
+        assert!(willr_slice(&high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -9,10 +9,62 @@
  * @Description:
  */
 
-use crate::simple_moving_average;
-use crate::ErrorMsg;
+use crate::{moving_average_lookback, simple_moving_average_legacy_slice, Ma, MaType, TalibError};
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
-use std::ops::Mul;
+use std::collections::VecDeque;
+
+/// Number of leading bars without a band value, the lookback of the middle band MA like TA-Lib.
+pub fn b_bands_lookback(time_period: Option<usize>, ma_type: Option<MaType>) -> usize {
+    let time_period = time_period.unwrap_or(14);
+    moving_average_lookback(Some(time_period), ma_type).max(time_period.saturating_sub(1))
+}
+
+/// Calculates the Bollinger Bands (BBands) over a plain slice, the polars free core of [`b_bands`].
+///
+/// Returns `(middle_band, upper_band, lower_band)`.
+///
+/// # Examples
+/// ```
+/// use rusty_talib::b_bands_slice;
+///
+/// let close = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0];
+/// let (middle_band, upper_band, lower_band) = b_bands_slice(&close, Some(5), Some(2), None).unwrap();
+/// assert!(middle_band[3].is_nan());
+/// assert_eq!(middle_band[4], 3.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn b_bands_slice(
+    close: &[f64],
+    time_period: Option<usize>,
+    multi: Option<usize>,
+    ma_type: Option<MaType>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    let multi = multi.unwrap_or(5);
+    let lookback = b_bands_lookback(Some(time_period), ma_type);
+
+    if close.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: close.len(),
+        });
+    }
+
+    let mut state = BBands::new(time_period, multi, ma_type.unwrap_or_default())?;
+    let mut middle_band = Vec::with_capacity(close.len());
+    let mut upper_band = Vec::with_capacity(close.len());
+    let mut lower_band = Vec::with_capacity(close.len());
+    for value in close {
+        let (middle, upper, lower) = state.update(*value).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+        middle_band.push(middle);
+        upper_band.push(upper);
+        lower_band.push(lower);
+    }
+    Ok((middle_band, upper_band, lower_band))
+}
 
 ///  Calculates the Bollinger Bands (BBands).
 ///``` python
@@ -49,6 +101,7 @@ use std::ops::Mul;
 /// * `close` - A reference to a vector of closing prices for the financial instrument.
 /// * `time_period` - An optional time period used in the calculations. Default is 14.
 /// * `multi` - An optional multiplier value used in the calculations. Default is 5.
+/// * `ma_type` - An optional averaging method of the middle band. Default is [`MaType::Sma`].
 ///
 /// # Returns
 ///
@@ -62,53 +115,187 @@ use std::ops::Mul;
 /// ```
 /// use polars::prelude::*;
 /// use rusty_talib::b_bands;
-/// use rusty_talib::ErrorMsg;
+/// use rusty_talib::TalibError;
 /// let close = Vec::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0]);
-/// let res = b_bands(&Series::new("data",close), Some(5), Some(2));
+/// let res = b_bands(&Series::new("data",close), Some(5), Some(2), None);
 ///     match res {
 ///             Ok((middle_band, upper_band, lower_band)) => {
 ///                 eprintln!("{:?}", middle_band);
 ///                 eprintln!("{:?}", upper_band);
 ///                 eprintln!("{:?}", lower_band);
 ///             }
+///             Err(TalibError::InsufficientData { needed, got }) => {
+///                 eprintln!("needed {} values, got {}", needed, got);
+///             }
 ///             Err(e) => {
-///                 if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-///                     eprintln!("{}", my_error.0);
-///                 } else {
-///                     eprintln!("An error occurred");
-///                 }
+///                 eprintln!("{}", e);
 ///             }
 ///         }
 /// ```
 ///
-pub fn b_bands<'a>(
+#[cfg(feature = "polars")]
+pub fn b_bands(
     close: &Series,
     time_period: Option<usize>,
     multi: Option<usize>,
-) -> Result<(Series, Series, Series), Box<dyn std::error::Error>> {
+    ma_type: Option<MaType>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (middle_band, upper_band, lower_band) = b_bands_slice(&series_to_vec(close)?, time_period, multi, ma_type)?;
+    Ok((
+        vec_to_series(close.name(), middle_band),
+        vec_to_series(close.name(), upper_band),
+        vec_to_series(close.name(), lower_band),
+    ))
+}
+
+/// Streaming Bollinger Bands (BBands).
+///
+/// Uses the same population standard deviation as [`b_bands`] and returns
+/// `(middle_band, upper_band, lower_band)` once [`b_bands_lookback`] values have been seen.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{BBands, MaType};
+///
+/// let mut bands = BBands::new(5, 2, MaType::Sma).unwrap();
+/// for price in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] {
+///     if let Some((middle, upper, lower)) = bands.update(price) {
+///         eprintln!("{} {} {}", middle, upper, lower);
+///     }
+/// }
+/// ```
+/// Calculates the Bollinger Bands (BBands) over a plain slice the way [`b_bands`] did before it followed
+/// TA-Lib.
+///
+/// The middle band is [`simple_moving_average_legacy_slice`] and the bands are `multi` sample standard
+/// deviations away from it. Both average the values seen so far over the first `time_period - 1` bars
+/// instead of being `NaN`, the deviation of the first bar is 0.
+///
+/// Returns `(middle_band, upper_band, lower_band)`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0 and [`TalibError::InsufficientData`] if
+/// `close` is shorter than `time_period`.
+///
+/// # Examples
+/// ```
+/// use rusty_talib::b_bands_legacy_slice;
+///
+/// let (middle_band, upper_band, _) = b_bands_legacy_slice(&[1.0, 2.0, 3.0, 4.0], Some(3), Some(2)).unwrap();
+/// assert_eq!(middle_band, [1.0, 1.5, 2.0, 3.0]);
+/// assert_eq!(upper_band[0], 1.0);
+/// assert_eq!(upper_band[3], 5.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn b_bands_legacy_slice(
+    close: &[f64],
+    time_period: Option<usize>,
+    multi: Option<usize>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
     let time_period = time_period.unwrap_or(14);
-    let multi = multi.unwrap_or(5);
+    let multi = multi.unwrap_or(5) as f64;
+    let middle_band = simple_moving_average_legacy_slice(close, Some(time_period))?;
+    let mut upper_band = Vec::with_capacity(close.len());
+    let mut lower_band = Vec::with_capacity(close.len());
+    for (i, middle) in middle_band.iter().enumerate() {
+        let window = &close[(i + 1).saturating_sub(time_period)..=i];
+        let dev = match window.len() {
+            1 => 0.0,
+            n => {
+                let mean = window.iter().sum::<f64>() / n as f64;
+                let sq: f64 = window.iter().map(|v| (v - mean) * (v - mean)).sum();
+                (sq / (n - 1) as f64).sqrt() * multi
+            }
+        };
+        upper_band.push(middle + dev);
+        lower_band.push(middle - dev);
+    }
+    Ok((middle_band, upper_band, lower_band))
+}
+
+/// Calculates the Bollinger Bands (BBands) the way [`b_bands`] did before it followed TA-Lib, see
+/// [`b_bands_legacy_slice`].
+///
+/// # Examples
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::b_bands_legacy;
+///
+/// let close = Series::new("data", [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+/// let (middle_band, _, _) = b_bands_legacy(&close, Some(5), Some(2)).unwrap();
+/// assert_eq!(middle_band.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn b_bands_legacy(
+    close: &Series,
+    time_period: Option<usize>,
+    multi: Option<usize>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (middle_band, upper_band, lower_band) = b_bands_legacy_slice(&series_to_vec(close)?, time_period, multi)?;
+    Ok((
+        vec_to_series(close.name(), middle_band),
+        vec_to_series(close.name(), upper_band),
+        vec_to_series(close.name(), lower_band),
+    ))
+}
 
-    if close.len() < time_period {
-        return Err(Box::new(ErrorMsg(
-            "src Length must be greater than time_period".into(),
-        )));
+#[derive(Debug, Clone)]
+pub struct BBands {
+    time_period: usize,
+    multi: f64,
+    window: VecDeque<f64>,
+    sum: f64,
+    middle: Ma,
+}
+
+impl BBands {
+    /// Creates a new BBands state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize, multi: usize, ma_type: MaType) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(BBands {
+            time_period,
+            multi: multi as f64,
+            window: VecDeque::with_capacity(time_period + 1),
+            sum: 0.0,
+            middle: Ma::new(time_period, ma_type)?,
+        })
     }
 
-    let basis = simple_moving_average(&close, Some(time_period as usize))?;
-
-    let duration = Duration::new(time_period as i64);
-    let options = RollingOptionsImpl {
-        window_size: duration,
-        min_periods: 1,
-        ..Default::default()
-    };
-    let dev = close.rolling_std(options)?;
-    let dev = dev.mul(multi);
-    let middle_band = &basis;
-    let upper_band = basis.add_to(&dev)?;
-    let lower_band = basis.subtract(&dev)?;
-    Ok((middle_band.clone(), upper_band.clone(), lower_band.clone()))
+    /// Feeds the next value and returns `(middle_band, upper_band, lower_band)` once the lookback is over.
+    pub fn update(&mut self, value: f64) -> Option<(f64, f64, f64)> {
+        let middle = self.middle.update(value);
+        self.window.push_back(value);
+        self.sum += value;
+        if self.window.len() > self.time_period {
+            if let Some(old) = self.window.pop_front() {
+                self.sum -= old;
+            }
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        let middle = middle?;
+        let n = self.time_period as f64;
+        let mean = self.sum / n;
+        let sq: f64 = self.window.iter().map(|v| (v - mean) * (v - mean)).sum();
+        let dev = (sq / n).sqrt() * self.multi;
+        Some((middle, middle + dev, middle - dev))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.middle.reset();
+    }
 }
 
 // unit test
@@ -116,6 +303,7 @@ pub fn b_bands<'a>(
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
     fn test_b_bands() {
         // let mut rng = rand::thread_rng();
@@ -123,20 +311,63 @@ mod tests {
         let close = Vec::from([
             1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0,
         ]);
-        let res = b_bands(&Series::new("data",close), Some(5), Some(2));
+        let res = b_bands(&Series::new("data",close), Some(5), Some(2), None);
         match res {
             Ok((middle_band, upper_band, lower_band)) => {
                 eprintln!("{:?}", middle_band);
                 eprintln!("{:?}", upper_band);
                 eprintln!("{:?}", lower_band);
             }
+            Err(TalibError::InsufficientData { needed, got }) => {
+                eprintln!("needed {} values, got {}", needed, got);
+            }
             Err(e) => {
-                if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-                    eprintln!("{}", my_error.0);
-                } else {
-                    eprintln!("An error occurred");
-                }
+                eprintln!("{}", e);
             }
         }
     }
+
+    #[test]
+    fn test_b_bands_talib_values() -> Result<(), TalibError> {
+        // values from the ta.BBANDS(random_data, 5, 2.0) output in the b_bands docs
+        let close = [
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0,
+        ];
+        let upper_expected = [5.828427, 6.828427, 7.828427, 8.828427, 9.828427, 11.771355, 12.483315, 14.690444, 16.766612, 18.756056];
+        let lower_expected = [0.171573, 1.171573, 2.171573, 3.171573, 4.171573, 0.628645, -2.483315, -3.090444, -3.566612, -3.956056];
+        let (_, upper, lower) = b_bands_slice(&close, Some(5), Some(2), None)?;
+        for i in 0..upper_expected.len() {
+            assert!((upper[i + 4] - upper_expected[i]).abs() < 1e-6);
+            assert!((lower[i + 4] - lower_expected[i]).abs() < 1e-6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_b_bands_legacy() -> Result<(), TalibError> {
+        let (middle, upper, lower) = b_bands_legacy_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(3), Some(2))?;
+        let middle_expected = [1.0, 1.5, 2.0, 3.0, 4.0];
+        // sample standard deviations of the partial windows, 0 for the first bar
+        let dev_expected = [0.0, 2.0 * 0.5f64.sqrt(), 2.0, 2.0, 2.0];
+        for i in 0..middle_expected.len() {
+            assert!((middle[i] - middle_expected[i]).abs() < 1e-9);
+            assert!((upper[i] - middle_expected[i] - dev_expected[i]).abs() < 1e-9);
+            assert!((lower[i] - middle_expected[i] + dev_expected[i]).abs() < 1e-9);
+        }
+        let err = b_bands_legacy_slice(&[1.0, 2.0], Some(3), None);
+        assert!(matches!(err, Err(TalibError::InsufficientData { needed: 3, got: 2 })));
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_b_bands_legacy_series() -> Result<(), TalibError> {
+        let close = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let (middle, upper, lower) = b_bands_legacy(&Series::new("data", close), Some(3), Some(2))?;
+        let expected = b_bands_legacy_slice(&close, Some(3), Some(2))?;
+        assert_eq!(middle.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected.0);
+        assert_eq!(upper.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected.1);
+        assert_eq!(lower.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected.2);
+        Ok(())
+    }
 }
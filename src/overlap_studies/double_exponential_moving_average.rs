@@ -12,9 +12,44 @@
 
 //! double exponential moving average
 
+#[cfg(feature = "polars")]
 use polars::prelude::*;
-use crate::exponential_moving_average;
-use std::ops::Mul;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{exponential_moving_average_legacy_slice, exponential_moving_average_lookback, Ema, TalibError};
+
+/// Number of leading bars without a DEMA value, twice the EMA lookback like TA-Lib.
+pub fn double_exponential_moving_average_lookback(time_period: Option<usize>) -> usize {
+    2 * exponential_moving_average_lookback(Some(time_period.unwrap_or(5)))
+}
+
+/// Calculates the Double Exponential Moving Average (DEMA) over a plain slice,
+/// the polars free core of [`double_exponential_moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::double_exponential_moving_average_slice;
+///
+/// let dema = double_exponential_moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(3)).unwrap();
+/// assert!(dema[3].is_nan());
+/// assert_eq!(dema[4], 5.0);
+/// ```
+pub fn double_exponential_moving_average_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(5);
+    let lookback = double_exponential_moving_average_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Dema::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
 
 /// Calculates the Double Exponential Moving Average (DEMA)
 ///
@@ -29,6 +64,9 @@ use std::ops::Mul;
 /// Calculates the Double Exponential Moving Average (DEMA) using the formula in python and ta_lib:
 /// `2 * ta.ema(Source, Length) - ta.ema(ta.ema(Source, Length), Length)`
 ///
+/// The second EMA only starts once the first one has a value, so the first
+/// [`double_exponential_moving_average_lookback`] values are null.
+///
 /// # Arguments
 ///
 /// * `src` - A Series containing the source data.
@@ -62,26 +100,137 @@ use std::ops::Mul;
 ///  eprintln!("{:?}", res);
 /// ```
 ///
-pub fn double_exponential_moving_average(src:&Series,time_period: Option<usize>)-> Result<Series, Box<dyn std::error::Error>> {
+#[cfg(feature = "polars")]
+pub fn double_exponential_moving_average(src:&Series,time_period: Option<usize>)-> Result<Series, TalibError> {
+    let dema = double_exponential_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", dema))
+}
 
+/// Calculates the Double Exponential Moving Average (DEMA) over a plain slice the way
+/// [`double_exponential_moving_average`] did before it followed TA-Lib.
+///
+/// Both EMAs are [`exponential_moving_average_legacy_slice`], seeded with their first value, so there is no
+/// `NaN` warm-up.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0 and [`TalibError::InsufficientData`] if
+/// `src` is shorter than `time_period`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::double_exponential_moving_average_legacy_slice;
+///
+/// let dema = double_exponential_moving_average_legacy_slice(&[1.0, 2.0, 3.0, 4.0], Some(3)).unwrap();
+/// assert_eq!(dema, [1.0, 1.75, 2.75, 3.8125]);
+/// ```
+pub fn double_exponential_moving_average_legacy_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
     let time_period = time_period.unwrap_or(5);
-    let ema =  exponential_moving_average(src,Some(time_period))?;
-    let dema = ema.clone().mul(2) - exponential_moving_average(&ema,Some(time_period))?;
-    Ok(Series::new("data", dema))
+    let ema = exponential_moving_average_legacy_slice(src, Some(time_period))?;
+    let ema_of_ema = exponential_moving_average_legacy_slice(&ema, Some(time_period))?;
+    Ok(ema.iter().zip(ema_of_ema).map(|(ema, ema_of_ema)| 2.0 * ema - ema_of_ema).collect())
+}
 
+/// Calculates the Double Exponential Moving Average (DEMA) the way [`double_exponential_moving_average`] did
+/// before it followed TA-Lib, see [`double_exponential_moving_average_legacy_slice`].
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::double_exponential_moving_average_legacy;
+///
+/// let dema = double_exponential_moving_average_legacy(&Series::new("data", [1.0, 2.0, 3.0, 4.0]), Some(3)).unwrap();
+/// assert_eq!(dema.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn double_exponential_moving_average_legacy(
+    src: &Series,
+    time_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let dema = double_exponential_moving_average_legacy_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", dema))
+}
+
+/// Streaming double exponential moving average (DEMA).
+///
+/// Holds the EMA and the EMA of the EMA so each update is O(1), matching
+/// [`double_exponential_moving_average`] bar for bar. Returns `None` during the warm-up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Dema;
+///
+/// let mut dema = Dema::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0] {
+///     eprintln!("{:?}", dema.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dema {
+    ema: Ema,
+    ema_of_ema: Ema,
 }
 
+impl Dema {
+    /// Creates a new DEMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(Dema {
+            ema: Ema::new(time_period)?,
+            ema_of_ema: Ema::new(time_period)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current DEMA.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let ema = self.ema.update(value)?;
+        let ema_of_ema = self.ema_of_ema.update(ema)?;
+        Some(2.0 * ema - ema_of_ema)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.ema.reset();
+        self.ema_of_ema.reset();
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_double_exponential_moving_average() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_double_exponential_moving_average() -> Result<(), TalibError> {
         let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
         let close = Series::new("data", random_data);
         let res = double_exponential_moving_average(&close, Some(3))?;
         eprintln!("{:?}", res);
         Ok(())
     }
+
+    #[test]
+    fn test_double_exponential_moving_average_legacy() -> Result<(), TalibError> {
+        // alpha = 0.5, both EMAs seeded with their first value
+        let res = double_exponential_moving_average_legacy_slice(&[1.0, 2.0, 3.0, 4.0], Some(3))?;
+        assert_eq!(res, [1.0, 1.75, 2.75, 3.8125]);
+        let err = double_exponential_moving_average_legacy_slice(&[1.0, 2.0], Some(3));
+        assert!(matches!(err, Err(TalibError::InsufficientData { needed: 3, got: 2 })));
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_double_exponential_moving_average_legacy_series() -> Result<(), TalibError> {
+        let close = Series::new("data", [1.0, 2.0, 3.0, 4.0]);
+        let res = double_exponential_moving_average_legacy(&close, Some(3))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [1.0, 1.75, 2.75, 3.8125].map(Some));
+        Ok(())
+    }
 }
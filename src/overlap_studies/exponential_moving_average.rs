@@ -11,9 +11,49 @@
 
 //! exponential_moving_average
 
-use crate::ErrorMsg;
+use crate::TalibError;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
 
+/// Number of leading bars without an EMA value, `time_period - 1` like TA-Lib.
+pub fn exponential_moving_average_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the exponential moving average(EMA) over a plain slice,
+/// the polars free core of [`exponential_moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::exponential_moving_average_slice;
+///
+/// let ema = exponential_moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(3)).unwrap();
+/// assert!(ema[1].is_nan());
+/// assert_eq!(ema[2], 2.0);
+/// ```
+pub fn exponential_moving_average_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    let lookback = exponential_moving_average_lookback(Some(time_period));
+
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Ema::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
 /// Calculates the exponential moving average of a Series(EMA).
 ///
 /// ``` python
@@ -25,9 +65,11 @@ use polars::prelude::*;
 ///     random_data = DataFrame(data=d)
 ///     res = ta.EMA(random_data, 3)
 ///     print(res)
-///     print(random_data.ewm(span=3,adjust=False).mean())
 /// ```
 ///
+/// Like TA-Lib the EMA is seeded with the simple average of the first `time_period` values,
+/// the first [`exponential_moving_average_lookback`] values are null.
+///
 /// # Arguments
 ///
 /// * `src` - A reference to the Series for which the exponential moving average needs to be calculated.
@@ -41,8 +83,8 @@ use polars::prelude::*;
 ///
 /// ```
 /// use polars::prelude::*;
-/// use rusty_talib::exponential_moving_average;
-/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use rusty_talib::{exponential_moving_average, TalibError};
+/// fn main() -> Result<(), TalibError> {
 ///
 /// let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
 /// let series = Series::new("data", values);
@@ -62,29 +104,162 @@ use polars::prelude::*;
 ///
 /// This function calculates the exponential moving average for the specified `time_period`
 /// using the formula for calculating EMA. The `src` Series must have a length greater than or equal to the `time_period`.
-pub fn exponential_moving_average<'a>(
+#[cfg(feature = "polars")]
+pub fn exponential_moving_average(
     src: &Series,
     time_period: Option<usize>,
-) -> Result<Series, Box<dyn std::error::Error>> {
-    let time_period = time_period.unwrap_or(14);
+) -> Result<Series, TalibError> {
+    let ema_values = exponential_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", ema_values))
+}
 
+/// Calculates the exponential moving average (EMA) over a plain slice the way
+/// [`exponential_moving_average`] did before it followed TA-Lib.
+///
+/// The EMA is seeded with the first value instead of the average of the first `time_period` values,
+/// so there is no `NaN` warm-up.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0 and [`TalibError::InsufficientData`] if
+/// `src` is shorter than `time_period`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::exponential_moving_average_legacy_slice;
+///
+/// let ema = exponential_moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0], Some(3)).unwrap();
+/// assert_eq!(ema, [23.0, 24.0, 18.0, 23.0]);
+/// ```
+pub fn exponential_moving_average_legacy_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    if time_period == 0 {
+        return Err(TalibError::InvalidParameter {
+            name: "time_period",
+            value: time_period as f64,
+            reason: "must be greater than 0",
+        });
+    }
     if src.len() < time_period {
-        return Err(Box::new(ErrorMsg(
-            "src Length must be greater than time_period".into(),
-        )));
+        return Err(TalibError::InsufficientData {
+            needed: time_period,
+            got: src.len(),
+        });
     }
     let alpha = 2.0 / (time_period as f64 + 1.0);
-    let mut ema_values: Vec<f64> = vec![];
-    for i in 0..src.len() {
-        let elem = src.get(i)?.try_extract::<f64>()?;
-        if i == 0 {
-            ema_values.push(elem);
-            continue;
-        }
-        let ema = alpha * elem + (1.0 - alpha) * ema_values[i - 1];
+    let mut ema_values: Vec<f64> = Vec::with_capacity(src.len());
+    for (i, value) in src.iter().enumerate() {
+        let ema = match i {
+            0 => *value,
+            _ => alpha * value + (1.0 - alpha) * ema_values[i - 1],
+        };
         ema_values.push(ema);
     }
-    Ok(Series::new("data", ema_values))
+    Ok(ema_values)
+}
+
+/// Calculates the exponential moving average (EMA) the way [`exponential_moving_average`] did before it
+/// followed TA-Lib, seeded with the first value instead of a simple average.
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::exponential_moving_average_legacy;
+///
+/// let series = Series::new("data", [1.0, 2.0, 3.0, 4.0, 5.0]);
+/// let ema = exponential_moving_average_legacy(&series, Some(3)).unwrap();
+/// assert_eq!(ema.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn exponential_moving_average_legacy(
+    src: &Series,
+    time_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let ema_values = exponential_moving_average_legacy_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series("data", ema_values))
+}
+
+/// Streaming exponential moving average (EMA).
+///
+/// Seeded with the simple average of the first `time_period` values just like
+/// [`exponential_moving_average`], so both produce the same values bar for bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Ema;
+///
+/// let mut ema = Ema::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0] {
+///     eprintln!("{:?}", ema.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ema {
+    time_period: usize,
+    alpha: f64,
+    count: usize,
+    sum: f64,
+    value: Option<f64>,
+}
+
+impl Ema {
+    /// Creates a new EMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Ema {
+            time_period,
+            alpha: 2.0 / (time_period as f64 + 1.0),
+            count: 0,
+            sum: 0.0,
+            value: None,
+        })
+    }
+
+    /// Creates a new EMA state with a smoothing factor other than `2 / (time_period + 1)`,
+    /// like the fixed 0.15 and 0.075 of MACDFIX.
+    #[cfg(feature = "momentum_indicators")]
+    pub(crate) fn with_alpha(time_period: usize, alpha: f64) -> Result<Self, TalibError> {
+        Ok(Ema {
+            alpha,
+            ..Ema::new(time_period)?
+        })
+    }
+
+    /// Feeds the next value and returns the current EMA once the seed window is full.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        match self.value {
+            Some(prev) => {
+                self.value = Some((value - prev) * self.alpha + prev);
+            }
+            None => {
+                self.count += 1;
+                self.sum += value;
+                if self.count == self.time_period {
+                    self.value = Some(self.sum / self.time_period as f64);
+                }
+            }
+        }
+        self.value
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.sum = 0.0;
+        self.value = None;
+    }
 }
 
 // unit test
@@ -92,12 +267,33 @@ pub fn exponential_moving_average<'a>(
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_exponential_moving_average() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_exponential_moving_average() -> Result<(), TalibError> {
         let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
         let close = Series::new("data", random_data);
         let res = exponential_moving_average(&close, Some(3))?;
         eprintln!("{:?}", res);
         Ok(())
     }
+
+    #[test]
+    fn test_ema_legacy() -> Result<(), TalibError> {
+        let res = exponential_moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0], Some(3))?;
+        // alpha = 0.5, seeded with the first value
+        assert_eq!(res, [23.0, 24.0, 18.0, 23.0, 28.0, 29.5, 32.25]);
+        let err = exponential_moving_average_legacy_slice(&[1.0, 2.0], Some(3));
+        assert!(matches!(err, Err(TalibError::InsufficientData { needed: 3, got: 2 })));
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_ema_legacy_series() -> Result<(), TalibError> {
+        let close = Series::new("data", [23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0]);
+        let res = exponential_moving_average_legacy(&close, Some(3))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [23.0, 24.0, 18.0, 23.0, 28.0, 29.5, 32.25].map(Some));
+        Ok(())
+    }
 }
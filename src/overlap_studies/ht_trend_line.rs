@@ -24,95 +24,193 @@
 //! - [TradingView Script - Blackcat L2 Ehlers Hilbert Transform](https://tw.tradingview.com/script/dFWImthM-blackcat-L2-Ehlers-Hilbert-Transform/)
 //!
 
-use std::ops::{Div};
-use polars::export::arrow::array::Float64Array;
+#[cfg(feature = "polars")]
 use polars::prelude::*;
-use crate::ErrorMsg;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{MedPrice, TalibError};
+use crate::helper::{HilbertTransform, HT_START_63};
+use std::collections::VecDeque;
 
+/// Number of leading bars without a trendline value, 63 like TA-Lib.
+pub fn ht_trend_line_lookback() -> usize {
+    63
+}
+
+/// Calculate the Hilbert Transform Instantaneous Trendline(HT) of the median price over plain slices,
+/// the polars free core of [`ht_trend_line`].
+///
+/// # Errors
+///
+/// Returns an error if the inputs are not longer than [`ht_trend_line_lookback`] or have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::ht_trend_line_slice;
+///
+/// let high: Vec<f64> = (0..80).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..80).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+/// let trend_line = ht_trend_line_slice(&high, &low).unwrap();
+/// assert!(trend_line[62].is_nan());
+/// assert!((trend_line[79] - 10.0).abs() < 1.0);
+/// ```
+pub fn ht_trend_line_slice(high: &[f64], low: &[f64]) -> Result<Vec<f64>, TalibError> {
+    let lookback = ht_trend_line_lookback();
+    if low.len() <= lookback || high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: low.len().min(high.len()),
+        });
+    }
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let mut state = HtTrendLine::new();
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
 
 /// Calculate the Hilbert Transform Instantaneous Trendline(HT).
 ///
+/// The trendline is the average of the median price over the smoothed dominant cycle period,
+/// smoothed once more by a 4 bar WMA, like TA-Lib's HT_TRENDLINE.
+///
 /// # Arguments
 ///
 /// * `high` - A series representing high price data.
 /// * `low` - A series representing low price data.
 ///
-/// # Returns
-///
-/// A tuple containing two Series: the Quadrature phase output signal (Q1) and the In-phase output signal (I1).
-///
 /// # Errors
 ///
-/// Returns an error if the length of the input data is less than 6.
+/// Returns an error if the length of the input data is not greater than [`ht_trend_line_lookback`],
+/// the first [`ht_trend_line_lookback`] values are null.
 ///
 /// # Example
 ///
 /// ```
-/// use polars::prelude::LiteralValue::Series;
+/// use polars::prelude::*;
 /// use rusty_talib::ht_trend_line;
 ///
 /// // Provide high and low price data
-/// let high = Vec::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0]);
-/// let low = Vec::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 11.0, 12.0, 13.0]);
+/// let high: Vec<f64> = (0..80).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..80).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
 ///
 /// // Calculate the Hilbert Transform Instantaneous Trendline
-/// let (q1, i1) = ht_trend_line(&Series::new("data", high), &Series::new("data", low)).unwrap();
+/// let trend_line = ht_trend_line(&Series::new("data", high), &Series::new("data", low)).unwrap();
 ///
 /// // Print the results
-/// eprintln!("{:?}", q1);
-/// eprintln!("{:?}", i1);
+/// eprintln!("{:?}", trend_line);
 /// ```
+#[cfg(feature = "polars")]
+pub fn ht_trend_line(high: &Series, low: &Series) -> Result<Series, TalibError> {
+    let trend_line = ht_trend_line_slice(&series_to_vec(high)?, &series_to_vec(low)?)?;
+    Ok(vec_to_series("data", trend_line))
+}
 
-pub fn ht_trend_line<'a>(high: &'a Series, low: &'a Series) -> Result<(Series,Series), Box<dyn std::error::Error>> {
-    const BAR_INDEX_6: usize = 6;
-    if low.len() < BAR_INDEX_6 || high.len() < BAR_INDEX_6 {
-        return Err(Box::new(ErrorMsg(
-            "source Length must be greater than 5".into(),
-        )));
+/// Streaming Hilbert Transform Instantaneous Trendline(HT).
+///
+/// Returns `None` for the first [`ht_trend_line_lookback`] bars.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::HtTrendLine;
+///
+/// let mut ht = HtTrendLine::new();
+/// for (high, low) in [(2.0, 1.0), (3.0, 2.0), (4.0, 3.0)] {
+///     eprintln!("{:?}", ht.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtTrendLine {
+    medprice: MedPrice,
+    hilbert: HilbertTransform,
+    prices: VecDeque<f64>,
+    i_trend1: f64,
+    i_trend2: f64,
+    i_trend3: f64,
+}
+
+impl Default for HtTrendLine {
+    fn default() -> Self {
+        Self::new()
     }
-    let price = high.add_to(low)?.div(2);
-    let array = price.to_arrow(0);
-    let price = match array.as_any().downcast_ref::<Float64Array>() {
-        Some(float_array) => {
-            let values: &[f64] = float_array.values();
-            let vec_values: Vec<f64> = values.to_vec();
-            vec_values
+}
+
+impl HtTrendLine {
+    /// Creates a new HT state.
+    pub fn new() -> Self {
+        HtTrendLine {
+            medprice: MedPrice::new(),
+            hilbert: HilbertTransform::new(HT_START_63),
+            prices: VecDeque::with_capacity(51),
+            i_trend1: 0.0,
+            i_trend2: 0.0,
+            i_trend3: 0.0,
         }
-        None => return Err("Failed to downcast to Float64Array".into()),
-    };
-    let mut smooth = vec![0.0; high.len()];
-    let mut detrender = vec![0.0; high.len()];
-    // In-phase output signal
-    let mut i1 = vec![0.0; high.len()];
-    // Quadrature phase output signal
-    let mut q1 = vec![0.0; high.len()];
+    }
 
-    for bar_index in 0..price.len() {
-        if bar_index > 5 {
-            smooth[bar_index] = (4.0 * price[bar_index] + 3.0 * price[bar_index - 1] + 2.0 * price[bar_index - 2] + price[bar_index] - 3.0) / 10.0;
-            detrender[bar_index] = (0.0962 * smooth[bar_index] + 0.5769 * smooth[bar_index - 2] - 0.5769 * smooth[bar_index - 4] - 0.0962 * smooth[bar_index - 6]) * (0.075 * price[bar_index - 1] + 0.54);
-            q1[bar_index] = (0.0962 * detrender[bar_index] + 0.5769 * detrender[bar_index - 2] - 0.5769 * detrender[bar_index - 4] - 0.0962 * detrender[bar_index - 6]) * (0.075 * price[bar_index - 1] + 0.54);
-            i1[bar_index] = detrender[bar_index - 3];
+    /// Feeds the next high/low pair and returns the current trendline.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        let price = self.medprice.update(high, low);
+        // the dominant cycle period is limited to 50, so are the prices averaged over it
+        self.prices.push_front(price);
+        self.prices.truncate(50);
+        let step = self.hilbert.update(price)?;
+
+        let dc_period = ((step.smooth_period + 0.5) as usize).min(self.prices.len());
+        let mut average = self.prices.iter().take(dc_period).sum::<f64>();
+        if dc_period > 0 {
+            average /= dc_period as f64;
+        }
+        let trend_line = (4.0 * average + 3.0 * self.i_trend1 + 2.0 * self.i_trend2 + self.i_trend3) / 10.0;
+        self.i_trend3 = self.i_trend2;
+        self.i_trend2 = self.i_trend1;
+        self.i_trend1 = average;
+        if self.hilbert.bar_index() <= ht_trend_line_lookback() {
+            return None;
         }
+        Some(trend_line)
     }
-    Ok((Series::new("data", q1),Series::new("data", i1)))
-}
 
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
 
 // unit test
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "polars")]
     use rand::Rng;
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_b_bands()  -> Result<(), Box<dyn std::error::Error>> {
+    fn test_ht_trend_line()  -> Result<(), TalibError> {
         let mut rng = rand::thread_rng();
-        let high: Vec<f64> = (0..1000).map(|_| rng.gen_range(1.0..2000.0)).collect();
-        let low : Vec<f64>= (0..1000).map(|_| rng.gen_range(1.0..2000.0)).collect();
-        let (q1, i1) = ht_trend_line(&Series::new("data", high), &Series::new("data", low))?;
-        eprintln!("{:?}", q1);
-        eprintln!("{:?}", i1);
+        let high: Vec<f64> = (0..1000).map(|_| rng.gen_range(1000.0..2000.0)).collect();
+        let low : Vec<f64>= high.iter().map(|h| h - rng.gen_range(0.0..100.0)).collect();
+        let trend_line = ht_trend_line(&Series::new("data", high), &Series::new("data", low))?;
+        assert_eq!(trend_line.null_count(), ht_trend_line_lookback());
+        // an average of prices stays inside the price range
+        assert!(trend_line.f64()?.into_iter().flatten().all(|value| (900.0..=2000.0).contains(&value)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ht_trend_line_constant() -> Result<(), TalibError> {
+        let trend_line = ht_trend_line_slice(&[11.0; 100], &[9.0; 100])?;
+        assert!(trend_line[64..].iter().all(|value| (value - 10.0).abs() < 1e-9));
+        assert!(ht_trend_line_slice(&[1.0; 70], &[1.0; 69]).is_err());
         Ok(())
     }
 }
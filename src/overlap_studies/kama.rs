@@ -20,7 +20,7 @@
 //! - smfactor = squared(efficienty_ratio * (fast - slow) + slow)
 //! - smfactor1 = 1.0  - smfactor
 //!
-//! - The initial seed value is a Simple Moving Average.
+//! - The initial seed value is the price just before the first full window, as in TA-Lib.
 //!
 //! See also:
 //! - [Kaufman's Adaptive Moving Average (KAMA)](http://fxcodebase.com/wiki/index.php/Kaufman's_Adaptive_Moving_Average_(KAMA))
@@ -29,12 +29,57 @@
 //! - [Python Pandas KAMA Implementation](https://copyprogramming.com/howto/python-pandas-kaufman-adaptive-moving-average-kama)
 //! - [StockCharts.com - KAMA](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
 //!
-use std::ops::{Add, Mul};
-use polars::export::arrow::array::Float64Array;
+use crate::TalibError;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
+use std::collections::VecDeque;
+
+/// Number of leading bars without a KAMA value, `time_period` like TA-Lib.
+pub fn kama_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(10)
+}
+
+/// Calculate Kaufman's Adaptive Moving Average (KAMA) over a plain slice, the polars free core of [`kama`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::kama_slice;
+///
+/// let kama_v = kama_slice(&[35.0, 10.0, 20.0, 56.0, 10.0, 20.0], Some(3), Some(2), Some(30)).unwrap();
+/// assert!(kama_v[2].is_nan());
+/// assert!(!kama_v[3].is_nan());
+/// ```
+pub fn kama_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+    fast: Option<usize>,
+    slow: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(10);
+    let fast = fast.unwrap_or(2);
+    let slow = slow.unwrap_or(30);
+    let mut state = Kama::new(time_period, fast, slow)?;
+    let lookback = kama_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+
+    Ok(src
+        .iter()
+        .map(|price| state.update(*price).unwrap_or(f64::NAN))
+        .collect())
+}
 
 /// Calculate Kaufman's Adaptive Moving Average (KAMA).
 ///
+/// The first [`kama_lookback`] values are null, the first KAMA value is seeded with the price before it.
+///
 /// # Arguments
 ///
 /// * `src` - A Polars Series containing price data.
@@ -56,91 +101,249 @@ use polars::prelude::*;
 ///     35.0, 10.0, 20.0, 56.0, 10.0, 20.0, 56.0, 89.0, 89.0, 76.0, 76.0, 30.0, 10.0, 20.0, 56.0, 89.0, 46.0,
 ///     10.0, 653.0, 10.0, 20.0, 56.0, 89.0, 30.0, 46.0, 10.0, 653.0, 76.0, 30.0, 46.0, 10.0, 653.0,
 /// ];
-/// let kama_series = kama(&Series::new("Close", close.iter().copied().collect()), Some(10), Some(2), Some(30)).unwrap();
+/// let kama_series = kama(&Series::new("Close", close), Some(10), Some(2), Some(30)).unwrap();
 /// println!("{:?}", kama_series);
 /// ```
-pub fn kama<'a>(
-    src: &'a Series,
+#[cfg(feature = "polars")]
+pub fn kama(
+    src: &Series,
     time_period: Option<usize>,
     fast: Option<usize>,
     slow: Option<usize>,
-) -> Result<Series, Box<dyn std::error::Error>> {
+) -> Result<Series, TalibError> {
+    let kama_v = kama_slice(&series_to_vec(src)?, time_period, fast, slow)?;
+    Ok(vec_to_series("KAMA", kama_v))
+}
+
+/// Calculate Kaufman's Adaptive Moving Average (KAMA) over a plain slice the way [`kama`] did before it
+/// followed TA-Lib.
+///
+/// The direction and volatility of the first `time_period` bars are filled backward from the first full
+/// window, so those bars read prices ahead of them and no streaming state can reproduce them. The average
+/// starts from `0.0` on the first bar instead of being seeded with a price, so there is no `NaN` warm-up.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period`, `fast` or `slow` is 0 and
+/// [`TalibError::InsufficientData`] if `src` has no full window.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::kama_legacy_slice;
+///
+/// let kama_v = kama_legacy_slice(&[35.0, 10.0, 20.0, 56.0, 10.0, 20.0], Some(3), Some(2), Some(30)).unwrap();
+/// assert_eq!(kama_v[0], 0.0);
+/// assert!(kama_v.iter().all(|value| !value.is_nan()));
+/// ```
+pub fn kama_legacy_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+    fast: Option<usize>,
+    slow: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
     let time_period = time_period.unwrap_or(10);
     let fast = fast.unwrap_or(2);
     let slow = slow.unwrap_or(30);
-
-    // Calculate direction and volatility
-    let duration = Duration::new(time_period as i64);
-    let options = RollingOptionsImpl {
-        window_size: duration,
-        min_periods: 1,
-        ..Default::default()
-    };
-    let direction = src
-        .subtract(&src.shift(time_period as i64))?
-        .abs()?
-        .fill_null(FillNullStrategy::Backward(None))?;
-    let volatility = src
-        .subtract(&src.shift(1i64))?
-        .abs()?
-        .rolling_sum(options)?
-        .fill_null(FillNullStrategy::Backward(None))?;
-
-    // Calculate efficiency ratio (ER)
-    let er = direction.divide(&volatility)?;
-
-    // Calculate smoothing constants
+    if time_period == 0 {
+        return Err(TalibError::InvalidParameter {
+            name: "time_period",
+            value: time_period as f64,
+            reason: "must be greater than 0",
+        });
+    }
+    if fast == 0 {
+        return Err(TalibError::InvalidParameter {
+            name: "fast",
+            value: fast as f64,
+            reason: "must be greater than 0",
+        });
+    }
+    if slow == 0 {
+        return Err(TalibError::InvalidParameter {
+            name: "slow",
+            value: slow as f64,
+            reason: "must be greater than 0",
+        });
+    }
+    if src.len() <= time_period {
+        return Err(TalibError::InsufficientData {
+            needed: time_period + 1,
+            got: src.len(),
+        });
+    }
     let sc_fastest = 2.0 / (fast as f64 + 1.0);
     let sc_slowest = 2.0 / (slow as f64 + 1.0);
-    let sc = er.mul(sc_fastest - sc_slowest).add(sc_slowest);
-    let sc = sc.multiply(&sc)?;
-
-    // Convert to Vec for calculation
-    let sc = sc.to_arrow(0);
-    let sc = match sc.as_any().downcast_ref::<Float64Array>() {
-        Some(float_array) => {
-            let values: &[f64] = float_array.values();
-            let vec_values: Vec<f64> = values.to_vec();
-            vec_values
-        }
-        None => return Err("Failed to downcast to Float64Array".into()),
-    };
-
-    let src = src.to_arrow(0);
-    let src = match src.as_any().downcast_ref::<Float64Array>() {
-        Some(float_array) => {
-            let values: &[f64] = float_array.values();
-            let vec_values: Vec<f64> = values.to_vec();
-            vec_values
+
+    // volatility = sumN(abs(close - close_n), period) over the changes seen so far
+    let mut volatility = vec![0.0; src.len()];
+    for i in 1..src.len() {
+        volatility[i] = volatility[i - 1] + (src[i] - src[i - 1]).abs();
+        if i > time_period {
+            volatility[i] -= (src[i - time_period] - src[i - time_period - 1]).abs();
         }
-        None => return Err("Failed to downcast to Float64Array".into()),
-    };
+    }
+    volatility[0] = volatility[1];
 
-    // Calculate KAMA values
     let mut kama_v = vec![0.0; src.len()];
     for i in 1..src.len() {
-        let p = src[i];
-        let s = sc[i];
-        kama_v[i] = kama_v[i - 1] + s * (p - kama_v[i - 1]);
+        // direction = close - close_period, the first bars take the first full window
+        let today = i.max(time_period);
+        let direction = (src[today] - src[today - time_period]).abs();
+        let er = direction / volatility[i];
+        let sc = er * (sc_fastest - sc_slowest) + sc_slowest;
+        let sc = sc * sc;
+        kama_v[i] = kama_v[i - 1] + sc * (src[i] - kama_v[i - 1]);
     }
+    Ok(kama_v)
+}
 
+/// Calculate Kaufman's Adaptive Moving Average (KAMA) the way [`kama`] did before it followed TA-Lib.
+///
+/// See [`kama_legacy_slice`] for how the first bars differ from [`kama`].
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::kama_legacy;
+///
+/// let close = Series::new("Close", [35.0, 10.0, 20.0, 56.0, 10.0, 20.0]);
+/// let kama_series = kama_legacy(&close, Some(3), Some(2), Some(30)).unwrap();
+/// println!("{:?}", kama_series);
+/// ```
+#[cfg(feature = "polars")]
+pub fn kama_legacy(
+    src: &Series,
+    time_period: Option<usize>,
+    fast: Option<usize>,
+    slow: Option<usize>,
+) -> Result<Series, TalibError> {
+    let kama_v = kama_legacy_slice(&series_to_vec(src)?, time_period, fast, slow)?;
     Ok(Series::new("KAMA", kama_v))
 }
 
+/// Streaming Kaufman's Adaptive Moving Average (KAMA).
+///
+/// Returns `None` until `time_period + 1` prices have been seen, then the same values as [`kama`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Kama;
+///
+/// let mut kama = Kama::new(3, 2, 30).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 10.0] {
+///     eprintln!("{:?}", kama.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kama {
+    time_period: usize,
+    sc_fastest: f64,
+    sc_slowest: f64,
+    prices: VecDeque<f64>,
+    changes: VecDeque<f64>,
+    volatility: f64,
+    value: Option<f64>,
+}
+
+impl Kama {
+    /// Creates a new KAMA state, returns an error if `time_period`, `fast` or `slow` is 0.
+    pub fn new(time_period: usize, fast: usize, slow: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        if fast == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "fast",
+                value: fast as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        if slow == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "slow",
+                value: slow as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Kama {
+            time_period,
+            sc_fastest: 2.0 / (fast as f64 + 1.0),
+            sc_slowest: 2.0 / (slow as f64 + 1.0),
+            prices: VecDeque::with_capacity(time_period + 2),
+            changes: VecDeque::with_capacity(time_period + 1),
+            volatility: 0.0,
+            value: None,
+        })
+    }
+
+    /// Feeds the next price and returns the current KAMA once the window is full.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        // volatility = sumN(abs(close - close_n), period)
+        if let Some(last) = self.prices.back() {
+            let change = (price - last).abs();
+            self.changes.push_back(change);
+            self.volatility += change;
+            if self.changes.len() > self.time_period {
+                if let Some(old) = self.changes.pop_front() {
+                    self.volatility -= old;
+                }
+            }
+        }
+
+        self.prices.push_back(price);
+        if self.prices.len() > self.time_period + 1 {
+            self.prices.pop_front();
+        }
+        if self.prices.len() <= self.time_period {
+            return None;
+        }
+
+        // direction = close - close_period
+        let direction = price - self.prices[0];
+        let er = if self.volatility <= direction || self.volatility.abs() < 1e-8 {
+            1.0
+        } else {
+            (direction / self.volatility).abs()
+        };
+        let sc = er * (self.sc_fastest - self.sc_slowest) + self.sc_slowest;
+        let sc = sc * sc;
+
+        let prev = self.value.unwrap_or(self.prices[self.time_period - 1]);
+        self.value = Some((price - prev) * sc + prev);
+        self.value
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.changes.clear();
+        self.volatility = 0.0;
+        self.value = None;
+    }
+}
+
 // Unit test
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_kama() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_kama() -> Result<(), TalibError> {
         let close: [f64; 32] = [
             35.0, 10.0, 20.0, 56.0, 10.0, 20.0, 56.0, 89.0, 89.0, 76.0, 76.0, 30.0, 10.0, 20.0,
             56.0, 89.0, 46.0, 10.0, 653.0, 10.0, 20.0, 56.0, 89.0, 30.0, 46.0, 10.0, 653.0, 76.0,
             30.0, 46.0, 10.0, 653.0,
         ];
         let kama_series = kama(
-            &Series::new("Close", close.iter().copied().collect::<Vec<_>>()),
+            &Series::new("Close", close.to_vec()),
             Some(10),
             Some(2),
             Some(30),
@@ -148,4 +351,46 @@ mod tests {
         println!("{:?}", kama_series);
         Ok(())
     }
+
+    #[test]
+    fn test_kama_legacy() -> Result<(), TalibError> {
+        let close = [35.0, 10.0, 20.0, 56.0, 10.0, 20.0, 56.0, 89.0];
+        // bar 1 reads the first full window: er = |56 - 35| / |10 - 35| = 0.84
+        let expected = [
+            0.0, 3.25267846, 6.289154324, 9.215284078, 9.218550326, 9.263426287, 9.457959372, 44.809977429,
+        ];
+        let res = kama_legacy_slice(&close, Some(3), Some(2), Some(30))?;
+        for (value, expected) in res.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+        let too_short = kama_legacy_slice(&close[..3], Some(3), None, None);
+        assert!(matches!(too_short, Err(TalibError::InsufficientData { needed: 4, got: 3 })));
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_kama_legacy_series() -> Result<(), TalibError> {
+        let close = [35.0, 10.0, 20.0, 56.0, 10.0, 20.0, 56.0, 89.0];
+        let res = kama_legacy(&Series::new("Close", close), Some(3), Some(2), Some(30))?;
+        assert_eq!(res.name(), "KAMA");
+        let expected = kama_legacy_slice(&close, Some(3), Some(2), Some(30))?;
+        assert_eq!(res.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_kama_invalid_periods() {
+        let close = [35.0, 10.0, 20.0, 56.0, 10.0, 20.0, 56.0, 89.0];
+        assert!(matches!(Kama::new(3, 0, 30), Err(TalibError::InvalidParameter { name: "fast", .. })));
+        assert!(matches!(Kama::new(3, 2, 0), Err(TalibError::InvalidParameter { name: "slow", .. })));
+        let fast = kama_slice(&close, Some(3), Some(0), None);
+        assert!(matches!(fast, Err(TalibError::InvalidParameter { name: "fast", .. })));
+        let slow = kama_slice(&close[..2], Some(3), None, Some(0));
+        assert!(matches!(slow, Err(TalibError::InvalidParameter { name: "slow", .. })));
+        let fast = kama_legacy_slice(&close, Some(3), Some(0), None);
+        assert!(matches!(fast, Err(TalibError::InvalidParameter { name: "fast", .. })));
+        let slow = kama_legacy_slice(&close, Some(3), None, Some(0));
+        assert!(matches!(slow, Err(TalibError::InvalidParameter { name: "slow", .. })));
+    }
 }
@@ -18,186 +18,322 @@
 //! # Example
 //!
 //! ```
+//! # #[cfg(feature = "polars")] {
 //! use polars::prelude::*;
 //! use rusty_talib::mama;
 //! // Create sample data
-//! let high = Series::new("High", [1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13]);
-//! let close = Series::new("Close", [1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13]);
+//! let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+//! let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+//! let high = Series::new("High", high);
+//! let close = Series::new("Low", low);
 //!
-//! // Calculate MAMA and FAMA
-//! let mama = mama(&high, &close).expect("Failed to calculate MAMA");
+//! // Calculate MAMA and FAMA with the default fast and slow limits
+//! let (mama, fama) = mama(&high, &close, None, None).expect("Failed to calculate MAMA");
 //!
-//! // Print the MAMA values
-//! println!("{:?}", mama);
+//! // Print the MAMA and FAMA values
+//! println!("{:?} {:?}", mama, fama);
+//! # }
 //! ```
 //!
 //! Note: You need to have the `polars` crate in your `Cargo.toml` for this to work.
 //!
 //! # Details
 //!
-//! This implementation calculates the MAMA and FAMA values using the provided algorithm.
+//! The phase of the median price comes from the shared Hilbert Transform engine, the alpha is
+//! `fast_limit / delta_phase` limited to `slow_limit`, and FAMA follows MAMA with half of that alpha.
 
-use polars::export::arrow::array::Float64Array;
-use std::ops::Div;
+#[cfg(feature = "polars")]
 use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::{HilbertTransform, HT_START_32};
+use crate::{MedPrice, TalibError};
+
+/// Number of leading bars without a MAMA value, 32 like TA-Lib.
+pub fn mama_lookback() -> usize {
+    32
+}
+
+/// Calculate the MAMA and FAMA values over plain `high` and `low` slices, the polars free core of [`mama`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths,
+/// [`TalibError::InsufficientData`] if they are not longer than [`mama_lookback`] and
+/// [`TalibError::InvalidParameter`] if a limit is outside of `[0.01, 0.99]`.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::mama_slice;
+/// let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+/// let (mama, fama) = mama_slice(&high, &low, Some(0.5), Some(0.05)).unwrap();
+/// assert!(mama[31].is_nan());
+/// assert!(!mama[32].is_nan());
+/// assert!(!fama[32].is_nan());
+/// ```
+pub fn mama_slice(
+    high: &[f64],
+    low: &[f64],
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    let (mama, fama, _) = mama_with_period_slice(high, low, fast_limit, slow_limit)?;
+    Ok((mama, fama))
+}
+
+/// Same as [`mama_slice`], and also returns the smoothed dominant cycle period the alpha is derived from.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::mama_with_period_slice;
+/// let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+/// let (_, _, period) = mama_with_period_slice(&high, &low, None, None).unwrap();
+/// assert!(period[32] > 0.0);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn mama_with_period_slice(
+    high: &[f64],
+    low: &[f64],
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let mut state = Mama::new(fast_limit.unwrap_or(0.5), slow_limit.unwrap_or(0.05))?;
+    let lookback = mama_lookback();
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut mama = Vec::with_capacity(high.len());
+    let mut fama = Vec::with_capacity(high.len());
+    let mut period = Vec::with_capacity(high.len());
+    for (h, l) in high.iter().zip(low.iter()) {
+        match state.update(*h, *l) {
+            Some((m, f)) => {
+                mama.push(m);
+                fama.push(f);
+                period.push(state.period());
+            }
+            None => {
+                mama.push(f64::NAN);
+                fama.push(f64::NAN);
+                period.push(f64::NAN);
+            }
+        }
+    }
+    Ok((mama, fama, period))
+}
 
 /// Calculate the MAMA and FAMA values based on the provided algorithm.
-/// This function takes two Series, 'high' and 'low', as input and returns a Series with the MAMA values.
+/// This function takes two Series, 'high' and 'low', as input and returns the MAMA and FAMA Series
+/// like TA-Lib's MAMA. The first [`mama_lookback`] values are null.
 ///
 /// # Arguments
 ///
 /// * `high` - A Series containing high price data.
 /// * `low` - A Series containing low price data.
+/// * `fast_limit` - An optional upper limit of the alpha, between 0.01 and 0.99. Defaults to 0.5.
+/// * `slow_limit` - An optional lower limit of the alpha, between 0.01 and 0.99. Defaults to 0.05.
 ///
 /// # Example
 ///
 /// ```
 /// use polars::prelude::*;
 /// use rusty_talib::mama;
-/// let high = Series::new("High", [1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13]);
-/// let close = Series::new("Close", [1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13]);
-/// let mama_series = mama(&high, &close).expect("Failed to calculate MAMA");
+/// let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+/// let (mama, fama) = mama(&Series::new("High", high), &Series::new("Low", low), None, None).expect("Failed to calculate MAMA");
+/// assert_eq!(mama.null_count(), 32);
+/// assert_eq!(fama.null_count(), 32);
 /// ```
-pub fn mama<'a>(high: &'a Series, low:&'a Series) ->Result<Series,Box<dyn std::error::Error>> {
-    let high = high.cast(&DataType::Float64)?.clone().into_series();
-    let low = low.cast(&DataType::Float64)?.clone().into_series();
-    let prices =  (high+low).div(2);
-    let array = prices.to_arrow(0);
-    let prices = match array.as_any().downcast_ref::<Float64Array>() {
-        Some(float_array) => {
-            let values: &[f64] = float_array.values();
-            let vec_values: Vec<f64> = values.to_vec();
-            vec_values
-        }
-        None => return Err("Failed to downcast to Float64Array".into()),
-    };
-
-    let fast_limit = 0.5;
-    let slow_limit = 0.05;
-
-    let  length :usize= prices.len();
-    let mut mama =  vec![0.0;length];
-    let mut fama =  vec![0.0;length];
-    let mut smooth = vec![0.0;length];
-    let mut detrender = vec![0.0;length];
-    let mut period = vec![0.0;length];
-    let mut q1 = vec![0.0;length];
-    let mut i1 = vec![0.0;length];
-    let mut ji = vec![0.0;length];
-    let mut jq = vec![0.0;length];
-    let mut i2 = vec![0.0;length];
-    let mut q2 = vec![0.0;length];
-    let mut re = vec![0.0;length];
-    let mut im = vec![0.0;length];
-    let mut smooth_period = vec![0.0;length];
-    let mut phase = vec![0.0;length];
-    for i in 6..prices.len(){
-
-        smooth[i] = (4.0 * prices[i]
-            + 3.0 * smooth[i - 1]
-            + 2.0 * smooth[i - 2]
-            + smooth[i - 3])
-            / 10.0;
-
-        detrender[i] = (0.0962 * smooth[i] + 0.5769 * smooth[i - 2]
-            - 0.5769 * smooth[i - 4]
-            - 0.0962 * smooth[i - 6])
-            * (0.075 * period[i - 1] + 0.54);
-        q1[i] = (0.0962 * detrender[i] + 0.5769 * detrender[i - 2]
-            - 0.5769 * detrender[i - 4]
-            - 0.0962 * detrender[i - 6])
-            * (0.075 * period[i - 1] + 0.54);
-        i1[i] = detrender[i - 3];
-        ji[i] = (0.0962 * i1[i] + 0.5769 * i1[i - 2]
-            - 0.5769 * i1[i - 4]
-            - 0.0962 * i1[i - 6])
-            * (0.075 *  period[i - 1] + 0.54);
-
-        jq[i] = (0.0962 * q1[i] + 0.5769 * q1[i - 2]
-            - 0.5769 * q1[i - 4]
-            - 0.0962 * q1[i - 6])
-            * (0.075 * period[i - 1] + 0.54);
-
-        i2[i] = i1[i] - jq[i];
-        q2[i] = q1[i] + ji[i];
-
-        i2[i] = 0.2 * i2[i] + 0.8 * i2[i - 1];
-        q2[i] = 0.2 * q2[i] + 0.8 * q2[i - 1];
-
-        re[i] = i2[i] * i2[i - 1] + q2[i] * q2[i - 1];
-        im[i] = i2[i] * q2[i - 1] - q2[i] * i2[i - 1];
-
-        re[i] = 0.2 * re[i] + 0.8 * re[i - 1];
-        im[i] = 0.2 * im[i] + 0.8 * im[i - 1];
-
-
-        if im[i] != 0.0 && re[i] != 0.0 {
-            period[i] = 360.0 / (im[i] / re[i]).atan();
-        }
-
-        if period[i] > 1.5 * period[i - 1] {
-            period[i] = 1.5 * period[i - 1];
-        }
+#[cfg(feature = "polars")]
+pub fn mama(
+    high: &Series,
+    low: &Series,
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+) -> Result<(Series, Series), TalibError> {
+    let (mama, fama) = mama_slice(&series_to_vec(high)?, &series_to_vec(low)?, fast_limit, slow_limit)?;
+    Ok((vec_to_series("MAMA", mama), vec_to_series("FAMA", fama)))
+}
 
-        if period[i] < 0.67 * period[i - 1] {
-            period[i] = 0.67 * period[i - 1];
-        }
-        if period[i] < 6.0 {
-            period[i] = 6.0;
-        }
+/// Same as [`mama`], and also returns the smoothed dominant cycle period as a third Series.
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::mama_with_period;
+/// let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+/// let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+/// let (_, _, period) = mama_with_period(&Series::new("High", high), &Series::new("Low", low), Some(0.5), Some(0.05)).unwrap();
+/// eprintln!("{:?}", period);
+/// ```
+#[cfg(feature = "polars")]
+pub fn mama_with_period(
+    high: &Series,
+    low: &Series,
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (mama, fama, period) = mama_with_period_slice(&series_to_vec(high)?, &series_to_vec(low)?, fast_limit, slow_limit)?;
+    Ok((
+        vec_to_series("MAMA", mama),
+        vec_to_series("FAMA", fama),
+        vec_to_series("PERIOD", period),
+    ))
+}
 
-        if period[i] > 50.0 {
-            period[i] = 50.0;
-        }
+/// Streaming MESA Adaptive Moving Average (MAMA).
+///
+/// Returns `None` for the first [`mama_lookback`] bars and `(mama, fama)` afterwards.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::Mama;
+///
+/// let mut mama = Mama::new(0.5, 0.05).unwrap();
+/// for (high, low) in [(2.0, 1.0), (3.0, 2.0), (4.0, 3.0)] {
+///     eprintln!("{:?} {}", mama.update(high, low), mama.period());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mama {
+    medprice: MedPrice,
+    hilbert: HilbertTransform,
+    period: f64,
+    phase: f64,
+    mama: f64,
+    fama: f64,
+    fast_limit: f64,
+    slow_limit: f64,
+}
 
+impl Default for Mama {
+    /// A MAMA state with the TA-Lib limits of 0.5 and 0.05.
+    fn default() -> Self {
+        Self::with_limits(0.5, 0.05)
+    }
+}
 
-        period[i] = 0.2 * period[i] + 0.8 * period[i - 1];
-        smooth_period[i] = 0.33 * period[i] + 0.67 * smooth_period[i - 1];
-        if i1[i] != 0.0 {
-            phase[i] = q1[i] / i1[i];
+impl Mama {
+    /// Creates a new MAMA state, returns an error if a limit is outside of `[0.01, 0.99]`.
+    pub fn new(fast_limit: f64, slow_limit: f64) -> Result<Self, TalibError> {
+        for (name, value) in [("fast_limit", fast_limit), ("slow_limit", slow_limit)] {
+            if !(0.01..=0.99).contains(&value) {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value,
+                    reason: "must be between 0.01 and 0.99",
+                });
+            }
         }
+        Ok(Self::with_limits(fast_limit, slow_limit))
+    }
 
-        let mut delta_phase = phase[i - 1] - phase[i];
-
-        if delta_phase < 1.0 {
-            delta_phase = 1.0;
+    fn with_limits(fast_limit: f64, slow_limit: f64) -> Self {
+        Mama {
+            medprice: MedPrice::new(),
+            hilbert: HilbertTransform::new(HT_START_32),
+            period: 0.0,
+            phase: 0.0,
+            mama: 0.0,
+            fama: 0.0,
+            fast_limit,
+            slow_limit,
         }
+    }
 
-        let mut alpha = fast_limit / delta_phase;
+    /// The smoothed dominant cycle period of the last bar.
+    pub fn period(&self) -> f64 {
+        self.period
+    }
 
-        if alpha < slow_limit {
-            alpha = slow_limit;
+    /// Feeds the next high/low pair and returns the current `(mama, fama)`.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<(f64, f64)> {
+        let price = self.medprice.update(high, low);
+        let step = self.hilbert.update(price)?;
+        self.period = step.period;
+
+        let phase = if step.i1 != 0.0 {
+            (step.q1 / step.i1).atan().to_degrees()
+        } else {
+            0.0
+        };
+        let delta_phase = (self.phase - phase).max(1.0);
+        self.phase = phase;
+        let alpha = if delta_phase > 1.0 {
+            (self.fast_limit / delta_phase).max(self.slow_limit)
+        } else {
+            self.fast_limit
+        };
+
+        self.mama = alpha * price + (1.0 - alpha) * self.mama;
+        self.fama = 0.5 * alpha * self.mama + (1.0 - 0.5 * alpha) * self.fama;
+        if self.hilbert.bar_index() <= mama_lookback() {
+            return None;
         }
+        Some((self.mama, self.fama))
+    }
 
-        mama[i] = alpha * prices[i] + (1.0 - alpha) * mama[i - 1];
-        fama[i] = 0.5 * alpha * mama[i] + (1.0 - 0.5 * alpha) * fama[i - 1];
-
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        *self = Self::with_limits(self.fast_limit, self.slow_limit);
     }
-    Ok(Series::new("data",mama))
 }
 
-
-
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_mama() ->Result<(), Box<dyn std::error::Error>>{
-
-        let high = vec![
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13,
-        ];
-        let close = vec![
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 0, 11, 12, 13,
-        ];
-        // let mut prices = Series::new("data", close);
-        // let prices = prices.f64().unwrap().set_at_idx(vec![0, 1], Some(10.10)).unwrap().into_series();
-        // eprintln!("{}",prices);
-        let ma = mama(&Series::new("data",high), &Series::new("data", close))?;
-        eprintln!("{}",ma);
+    fn test_mama() ->Result<(), TalibError> {
+        let high: Vec<f64> = (0..40).map(|i| 11.0 + (i as f64 * 0.3).sin()).collect();
+        let low: Vec<f64> = (0..40).map(|i| 9.0 + (i as f64 * 0.3).sin()).collect();
+        let (mama, fama) = mama(&Series::new("data",high), &Series::new("data", low), None, None)?;
+        eprintln!("{} {}", mama, fama);
         Ok(())
+    }
+
+    #[test]
+    fn test_mama_errors() {
+        let too_short = mama_slice(&[1.0; 14], &[1.0; 14], None, None);
+        assert!(matches!(too_short, Err(TalibError::InsufficientData { needed: 33, got: 14 })));
+        let bad_limit = mama_slice(&[1.0; 40], &[1.0; 40], Some(1.5), None);
+        assert!(matches!(bad_limit, Err(TalibError::InvalidParameter { name: "fast_limit", .. })));
+    }
 
+    #[test]
+    fn test_mama_limits() -> Result<(), TalibError> {
+        let high: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.4).sin() * 10.0 + 1.0).collect();
+        let low: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.4).sin() * 10.0 - 1.0).collect();
+        // with equal limits the alpha is constant and both lines are plain EMAs of the median price
+        let (mama, fama, period) = mama_with_period_slice(&high, &low, Some(0.3), Some(0.3))?;
+        let (mut expected_mama, mut expected_fama) = (0.0, 0.0);
+        for i in 0..high.len() {
+            if i >= 12 {
+                expected_mama = 0.3 * (high[i] + low[i]) / 2.0 + 0.7 * expected_mama;
+                expected_fama = 0.15 * expected_mama + 0.85 * expected_fama;
+            }
+            if i >= mama_lookback() {
+                assert!((mama[i] - expected_mama).abs() < 1e-9);
+                assert!((fama[i] - expected_fama).abs() < 1e-9);
+                assert!(period[i] >= 6.0 && period[i] <= 50.0);
+            }
+        }
+        Ok(())
     }
 }
@@ -12,18 +12,21 @@
 //! Calculate Moving Average with Variable Period (MAVP).
 //!
 //! This function computes the Moving Average with Variable Period (MAVP) for a given input Series of price data.
-//! MAVP is calculated for different variable periods, and the result is returned as a Polars Series.
+//! Every bar is averaged over its own period, taken from `periods` and clamped to `[min_period, max_period]`,
+//! with the averaging method picked by a [`MaType`].
 //!
 //! # Arguments
 //!
 //! * `src` - A reference to the input Series containing price data.
-//! * `periods` - An optional list of variable periods for MAVP. If not provided, default periods [2, 5, 8] will be used.
+//! * `periods` - The period of every bar, truncated to an integer like TA-Lib. Must be as long as `src`.
 //! * `min_period` - An optional parameter specifying the minimum allowed period for MAVP. If not provided, a default value of 2 will be used.
 //! * `max_period` - An optional parameter specifying the maximum allowed period for MAVP. If not provided, a default value of 30 will be used.
+//! * `ma_type` - An optional averaging method. If not provided, a simple moving average is used.
 //!
 //! # Example
 //!
 //! ```rust
+//! # #[cfg(feature = "polars")] {
 //! use polars::prelude::*;
 //! use rusty_talib::mavp;
 //!
@@ -31,10 +34,11 @@
 //! let prices = Series::new("price", &[50.0, 52.0, 55.0, 57.0, 60.0]);
 //!
 //! // Calculate MAVP with custom periods and parameters
-//! let mavp_result = mavp(&prices, Some(vec![2, 3, 4]), Some(2), Some(10));
+//! let mavp_result = mavp(&prices, &[2.0, 3.0, 4.0, 2.0, 3.0], Some(2), Some(4), None);
 //!
 //! // Print the MAVP values
 //! println!("{:?}", mavp_result);
+//! # }
 //! ```
 //!
 //! # Returns
@@ -45,30 +49,84 @@
 //!
 //! Returns an error if there is an issue with data conversion or other errors during execution.
 //!
-//! # Note
-//!
-//! This implementation uses the Polars library for data manipulation and computation.
-//!
 //! # See Also
 //!
-//! - [simple_moving_average](crate::simple_moving_average) - A related function for simple moving average.
+//! - [moving_average](crate::moving_average) - The moving average computed for every period.
 //!
 //! # References
 //!
 //! - [MAVP Documentation](https://btalib.backtrader.com/indgroups/)
 //!
 
-use polars::export::arrow::array::Float64Array;
+use crate::{moving_average_lookback, Ma, MaType, TalibError};
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
 
+/// Number of leading bars without a MAVP value, the lookback of the `max_period` moving average like TA-Lib.
+pub fn mavp_lookback(max_period: Option<usize>, ma_type: Option<MaType>) -> usize {
+    moving_average_lookback(Some(max_period.unwrap_or(30)), ma_type)
+}
+
+/// Calculate Moving Average with Variable Period (MAVP) over a plain slice, the polars free core of [`mavp`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `periods` and `src` have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::mavp_slice;
+///
+/// let mavp_values = mavp_slice(&[50.0, 52.0, 55.0, 57.0], &[2.0, 3.0, 4.0, 9.0], Some(2), Some(3), None).unwrap();
+/// assert!(mavp_values[1].is_nan());
+/// assert_eq!(mavp_values[2], 157.0 / 3.0);
+/// // periods are clamped to `max_period`
+/// assert_eq!(mavp_values[3], 164.0 / 3.0);
+/// ```
+pub fn mavp_slice(
+    src: &[f64],
+    periods: &[f64],
+    min_period: Option<usize>,
+    max_period: Option<usize>,
+    ma_type: Option<MaType>,
+) -> Result<Vec<f64>, TalibError> {
+    let min_period = min_period.unwrap_or(2);
+    let max_period = max_period.unwrap_or(30);
+
+    if src.len() != periods.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: src.len(),
+            got: periods.len(),
+        });
+    }
+    let lookback = mavp_lookback(Some(max_period), ma_type);
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+
+    let mut state = Mavp::new(min_period, max_period, ma_type.unwrap_or_default())?;
+    Ok(src
+        .iter()
+        .zip(periods.iter())
+        .map(|(value, period)| state.update(*value, *period).unwrap_or(f64::NAN))
+        .collect())
+}
+
 /// Calculate Moving Average with Variable Period (MAVP).
 ///
 /// # Arguments
 ///
 /// * `src` - A reference to the input Series containing price data.
-/// * `periods` - An optional list of variable periods for MAVP. If not provided, default periods [2, 5, 8] will be used.
+/// * `periods` - The period of every bar, truncated to an integer like TA-Lib. Must be as long as `src`.
 /// * `min_period` - An optional parameter specifying the minimum allowed period for MAVP. If not provided, a default value of 2 will be used.
 /// * `max_period` - An optional parameter specifying the maximum allowed period for MAVP. If not provided, a default value of 30 will be used.
+/// * `ma_type` - An optional averaging method. If not provided, a simple moving average is used.
 ///
 /// # Returns
 ///
@@ -76,58 +134,185 @@ use polars::prelude::*;
 ///
 /// # Errors
 ///
-/// Returns an error if there is an issue with data conversion or other errors during execution.
-///
-/// # Note
-///
-/// This implementation uses the Polars library for data manipulation and computation.
+/// Returns [`TalibError::LengthMismatch`] if `periods` and `src` have different lengths,
+/// or an error if there is an issue with data conversion.
 ///
 /// # See Also
 ///
-/// - [simple_moving_average](crate::simple_moving_average) - A related function for simple moving average.
+/// - [moving_average](crate::moving_average) - The moving average computed for every period.
 ///
 /// # References
 ///
 /// - [MAVP Documentation](https://btalib.backtrader.com/indgroups/)
 ///
-
-pub fn mavp<'a>(
-    src: &'a Series,
-    periods: Option<Vec<i32>>,
+#[cfg(feature = "polars")]
+pub fn mavp(
+    src: &Series,
+    periods: &[f64],
     min_period: Option<usize>,
     max_period: Option<usize>,
-) -> Result<Series, Box<dyn std::error::Error>> {
+    ma_type: Option<MaType>,
+) -> Result<Series, TalibError> {
+    let mavp_values = mavp_slice(&series_to_vec(src)?, periods, min_period, max_period, ma_type)?;
+    Ok(vec_to_series("data", mavp_values))
+}
 
-    let src = src.cast(&DataType::Float64)?.clone().into_series();
-    let periods = periods.unwrap_or(vec![2, 5, 8]);
+/// Calculate Moving Average with Variable Period (MAVP) over a plain slice the way [`mavp`] did before it
+/// followed TA-Lib.
+///
+/// Bars whose period is outside `[min_period, max_period]` are `0.0` instead of using the clamped period, and
+/// the others divide the sum of the last `period + 1` values (fewer at the start) by `period`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `periods` and `src` have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::mavp_legacy_slice;
+///
+/// let mavp_values = mavp_legacy_slice(&[50.0, 52.0, 55.0, 57.0], &[2.0, 3.0, 1.0, 2.0], Some(2), Some(3)).unwrap();
+/// assert_eq!(mavp_values, [25.0, 34.0, 0.0, 82.0]);
+/// ```
+pub fn mavp_legacy_slice(
+    src: &[f64],
+    periods: &[f64],
+    min_period: Option<usize>,
+    max_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
     let min_period = min_period.unwrap_or(2);
     let max_period = max_period.unwrap_or(30);
 
-    assert_eq!(src.len(),periods.iter().len(),"Lengths are not same");
+    if src.len() != periods.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: src.len(),
+            got: periods.len(),
+        });
+    }
+    Ok(periods
+        .iter()
+        .enumerate()
+        .map(|(i, period)| {
+            let period = *period as i32;
+            if period < min_period as i32 || period > max_period as i32 {
+                return 0.0;
+            }
+            let sum: f64 = src[i.saturating_sub(period as usize)..=i].iter().sum();
+            sum / period as f64
+        })
+        .collect())
+}
+
+/// Calculate Moving Average with Variable Period (MAVP) the way [`mavp`] did before it followed TA-Lib, see
+/// [`mavp_legacy_slice`].
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::mavp_legacy;
+///
+/// let prices = Series::new("price", &[50.0, 52.0, 55.0, 57.0, 60.0]);
+/// let mavp_values = mavp_legacy(&prices, &[2.0, 3.0, 4.0, 2.0, 3.0], Some(2), Some(4)).unwrap();
+/// assert_eq!(mavp_values.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn mavp_legacy(
+    src: &Series,
+    periods: &[f64],
+    min_period: Option<usize>,
+    max_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let mavp_values = mavp_legacy_slice(&series_to_vec(src)?, periods, min_period, max_period)?;
+    Ok(vec_to_series("data", mavp_values))
+}
+
+/// Streaming Moving Average with Variable Period (MAVP).
+///
+/// The period is passed with every value and clamped to `[min_period, max_period]`. One [`Ma`] is kept per
+/// period, each one starting its own warm-up so that it ends on the bar [`mavp_lookback`] like the MA TA-Lib
+/// computes for that period. Returns `None` during the lookback, like [`mavp`].
+///
+/// # Example
+///
+/// ```
+/// use rusty_talib::{MaType, Mavp};
+///
+/// let mut mavp = Mavp::new(2, 3, MaType::Sma).unwrap();
+/// for (price, period) in [(35.0, 2.0), (10.0, 5.0), (20.0, 8.0)] {
+///     eprintln!("{:?}", mavp.update(price, period));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mavp {
+    min_period: usize,
+    max_period: usize,
+    lookback: usize,
+    seen: usize,
+    averages: Vec<(usize, Ma)>,
+}
 
-    let mut mavp_values: Vec<f64> = Vec::new();
-    let array = src.to_arrow(0);
-    let prices = match array.as_any().downcast_ref::<Float64Array>() {
-        Some(float_array) => {
-            let values: &[f64] = float_array.values();
-            let vec_values: Vec<f64> = values.to_vec();
-            vec_values
+impl Mavp {
+    /// Creates a new MAVP state, returns an error if `min_period` is 0 or greater than `max_period`.
+    pub fn new(min_period: usize, max_period: usize, ma_type: MaType) -> Result<Self, TalibError> {
+        if min_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "min_period",
+                value: min_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        if min_period > max_period {
+            return Err(TalibError::InvalidParameter {
+                name: "min_period",
+                value: min_period as f64,
+                reason: "must not be greater than max_period",
+            });
         }
-        None => return Err("Failed to downcast to Float64Array".into()),
-    };
-    for (i, &period) in periods.iter().enumerate() {
-        if period < min_period as i32 || period > max_period as i32 {
-            mavp_values.push(0.0);
-        } else {
-            let mut sum = 0.0;
-            for j in i.saturating_sub(period as usize)..=i {
-                sum += prices[j];
+        let lookback = mavp_lookback(Some(max_period), Some(ma_type));
+        let averages = (min_period..=max_period)
+            .map(|period| {
+                let start = lookback - moving_average_lookback(Some(period), Some(ma_type));
+                Ok((start, Ma::new(period, ma_type)?))
+            })
+            .collect::<Result<_, TalibError>>()?;
+        Ok(Mavp {
+            min_period,
+            max_period,
+            lookback,
+            seen: 0,
+            averages,
+        })
+    }
+
+    /// Feeds the next value with its period and returns the average over that period once the lookback is over.
+    pub fn update(&mut self, value: f64, period: f64) -> Option<f64> {
+        let period = (period.max(0.0) as usize).clamp(self.min_period, self.max_period);
+        let mut res = None;
+        for (offset, (start, ma)) in self.averages.iter_mut().enumerate() {
+            if self.seen < *start {
+                continue;
             }
-            let mavp = sum / period as f64;
-            mavp_values.push(mavp);
+            let average = ma.update(value);
+            if offset + self.min_period == period {
+                res = average;
+            }
+        }
+        self.seen += 1;
+        if self.seen <= self.lookback {
+            return None;
+        }
+        res
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.seen = 0;
+        for (_, ma) in self.averages.iter_mut() {
+            ma.reset();
         }
     }
-    Ok(Series::new("data", &mavp_values))
 }
 
 // unit test
@@ -135,12 +320,52 @@ pub fn mavp<'a>(
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
-    fn test_exponential_moving_average() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_exponential_moving_average() -> Result<(), TalibError> {
         let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
         let close = Series::new("data", random_data);
-        let res = mavp(&close, Option::from(vec![2, 5, 8,1,6,9,4,2,3,1]), Some(2), Some(8))?;
+        let res = mavp(&close, &[2.0, 5.0, 8.0, 1.0, 6.0, 9.0, 4.0, 2.0, 3.0, 1.0], Some(2), Some(8), None)?;
         eprintln!("{:?}", res);
         Ok(())
     }
+
+    #[test]
+    fn test_mavp_ma_type() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..40).map(|i| 10.0 + (i as f64 * 0.4).sin()).collect();
+        let periods: Vec<f64> = (0..40).map(|i| [3.0, 5.0, 4.0][i % 3]).collect();
+        let res = mavp_slice(&close, &periods, Some(3), Some(5), Some(MaType::Ema))?;
+        assert!(res[..4].iter().all(|value| value.is_nan()));
+        // every EMA is seeded so that its first value falls on the first MAVP bar
+        for period in [3, 4, 5] {
+            let ema = crate::exponential_moving_average_slice(&close[5 - period..], Some(period))?;
+            for i in (4..40).filter(|i| periods[*i] as usize == period) {
+                assert!((res[i] - ema[i + period - 5]).abs() < 1e-12);
+            }
+        }
+        let err = mavp_slice(&close, &[2.0, 5.0, 8.0], None, None, None);
+        assert!(matches!(err, Err(TalibError::LengthMismatch { expected: 40, got: 3 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mavp_legacy() -> Result<(), TalibError> {
+        let res = mavp_legacy_slice(&[35.0, 10.0, 20.0, 56.0, 89.0], &[2.0, 5.0, 2.0, 1.0, 3.0], Some(2), Some(4))?;
+        // out of range periods give 0, the others divide `period + 1` values by `period`
+        let expected = [17.5, 0.0, 32.5, 0.0, 175.0 / 3.0];
+        for (value, expected) in res.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_mavp_legacy_series() -> Result<(), TalibError> {
+        let periods = [2.0, 5.0, 2.0, 1.0, 3.0];
+        let res = mavp_legacy(&Series::new("data", [35, 10, 20, 56, 89]), &periods, Some(2), Some(4))?;
+        let expected = mavp_legacy_slice(&[35.0, 10.0, 20.0, 56.0, 89.0], &periods, Some(2), Some(4))?;
+        assert_eq!(res.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
 }
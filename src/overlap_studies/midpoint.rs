@@ -0,0 +1,135 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 18:02
+ * @Email: uyplayer@qq.com
+ * @File: midpoint.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! MidPoint over period
+//!
+//! midpoint = (highest value + lowest value) / 2 over `time_period` values
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without a MIDPOINT value, `time_period - 1` like TA-Lib.
+pub fn midpoint_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the MidPoint over period (MIDPOINT) over a plain slice, the polars free core of [`midpoint`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::midpoint_slice;
+///
+/// let midpoint = midpoint_slice(&[1.0, 5.0, 3.0, 2.0, 4.0], Some(3)).unwrap();
+/// assert!(midpoint[1].is_nan());
+/// assert_eq!(midpoint[2], 3.0);
+/// assert_eq!(midpoint[3], 3.5);
+/// ```
+pub fn midpoint_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    let lookback = midpoint_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = MidPoint::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the MidPoint over period (MIDPOINT).
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::midpoint;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = midpoint(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn midpoint(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let midpoint = midpoint_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), midpoint))
+}
+
+/// Streaming MidPoint over period (MIDPOINT).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::MidPoint;
+///
+/// let mut midpoint = MidPoint::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0] {
+///     eprintln!("{:?}", midpoint.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MidPoint {
+    extremes: RollingExtremes,
+}
+
+impl MidPoint {
+    /// Creates a new MIDPOINT state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(MidPoint {
+            extremes: RollingExtremes::new(time_period),
+        })
+    }
+
+    /// Feeds the next value and returns the current midpoint once the window is full.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let (highest, lowest) = self.extremes.update(value, value)?;
+        Some((highest + lowest) / 2.0)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_midpoint() -> Result<(), TalibError> {
+        let res = midpoint_slice(&[1.0, 5.0, 3.0, 2.0, 4.0], Some(3))?;
+        assert!(res[1].is_nan());
+        assert_eq!(res[2..], [3.0, 3.5, 3.0]);
+        Ok(())
+    }
+}
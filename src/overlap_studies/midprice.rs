@@ -0,0 +1,149 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 18:25
+ * @Email: uyplayer@qq.com
+ * @File: midprice.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Midpoint Price over period
+//!
+//! midprice = (highest high + lowest low) / 2 over `time_period` bars
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without a MIDPRICE value, `time_period - 1` like TA-Lib.
+pub fn midprice_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the Midpoint Price over period (MIDPRICE) over plain `high` and `low` slices,
+/// the polars free core of [`midprice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::midprice_slice;
+///
+/// let midprice = midprice_slice(&[2.0, 6.0, 4.0, 3.0, 5.0], &[1.0, 2.0, 3.0, 1.0, 4.0], Some(3)).unwrap();
+/// assert!(midprice[1].is_nan());
+/// assert_eq!(midprice[2], 3.5);
+/// assert_eq!(midprice[4], 3.0);
+/// ```
+pub fn midprice_slice(high: &[f64], low: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(14);
+    let lookback = midprice_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut state = MidPrice::new(time_period)?;
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Midpoint Price over period (MIDPRICE).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::midprice;
+///
+///  let high = Series::new("high", [36.0, 12.0, 22.0, 58.0, 90.0, 77.0]);
+///  let low = Series::new("low", [34.0, 9.0, 19.0, 55.0, 88.0, 74.0]);
+///  let res = midprice(&high, &low, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn midprice(high: &Series, low: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let midprice = midprice_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period)?;
+    Ok(vec_to_series("data", midprice))
+}
+
+/// Streaming Midpoint Price over period (MIDPRICE).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::MidPrice;
+///
+/// let mut midprice = MidPrice::new(2).unwrap();
+/// for (high, low) in [(36.0, 34.0), (12.0, 9.0), (22.0, 19.0)] {
+///     eprintln!("{:?}", midprice.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MidPrice {
+    extremes: RollingExtremes,
+}
+
+impl MidPrice {
+    /// Creates a new MIDPRICE state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(MidPrice {
+            extremes: RollingExtremes::new(time_period),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current midprice once the window is full.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        let (highest, lowest) = self.extremes.update(high, low)?;
+        Some((highest + lowest) / 2.0)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_midprice() -> Result<(), TalibError> {
+        let res = midprice_slice(&[2.0, 6.0, 4.0, 3.0, 5.0], &[1.0, 2.0, 3.0, 1.0, 4.0], Some(3))?;
+        assert!(res[1].is_nan());
+        assert_eq!(res[2..], [3.5, 3.5, 3.0]);
+        assert!(midprice_slice(&[2.0, 6.0], &[1.0], Some(1)).is_err());
+        Ok(())
+    }
+}
@@ -18,14 +18,18 @@
 // | KAMA                 | Kaufman Adaptive Moving Average             | Done    |
 // | MA                   | Moving Average                              | Done    |
 // | MAMA                 | MESA Adaptive Moving Average                | Done    |
-// | MAVP                 | Moving average with variable period         | Pending |
-// | MIDPOINT             | MidPoint over period                        | Pending |
-// | MIDPRICE             | Midpoint Price over period                  | Pending |
-// | SAR                  | Parabolic SAR                               | Pending |
-// | SAREXT               | Parabolic SAR - Extended                    | Pending |
-// | SMA                  | Simple Moving Average                       | Pending |
-// | T3                   | Triple Exponential Moving Average (T3)      | Pending |
-// | TEMA                 | Triple Exponential Moving                   | Pending |
+// | MAVP                 | Moving average with variable period         | Done    |
+// | MIDPOINT             | MidPoint over period                        | Done    |
+// | MIDPRICE             | Midpoint Price over period                  | Done    |
+// | SAR                  | Parabolic SAR                               | Done    |
+// | SAREXT               | Parabolic SAR - Extended                    | Done    |
+// | SMA                  | Simple Moving Average                       | Done    |
+// | T3                   | Triple Exponential Moving Average (T3)      | Done    |
+// | TEMA                 | Triple Exponential Moving                   | Done    |
+// | TRIMA                | Triangular Moving Average                   | Done    |
+// | VWAP                 | Volume Weighted Average Price               | Done    |
+// | VWMA                 | Volume Weighted Moving Average              | Done    |
+// | WMA                  | Weighted Moving Average                     | Done    |
 
 /// This module contains various moving average functions and indicators.
 mod bbands;
@@ -37,40 +41,77 @@ mod ht_trend_line;
 mod kama;
 mod mama;
 mod mavp;
-
-
-pub use bbands::b_bands;
-
-
-pub use exponential_moving_average::exponential_moving_average;
-
-
-pub use moving_average::moving_average;
-
-
-pub use simple_moving_average::simple_moving_average;
-
-
-pub use double_exponential_moving_average::double_exponential_moving_average;
-
-
+mod midpoint;
+mod midprice;
+mod rolling_vwap;
+mod sar;
+mod sarext;
+mod t3;
+mod triangular_moving_average;
+mod triple_exponential_moving_average;
+mod vwap;
+mod vwma;
+mod weighted_moving_average;
+
+
+pub use bbands::{b_bands_legacy_slice, b_bands_lookback, b_bands_slice, BBands};
+pub use exponential_moving_average::{exponential_moving_average_legacy_slice, exponential_moving_average_lookback, exponential_moving_average_slice, Ema};
+pub use moving_average::{moving_average_legacy_slice, moving_average_lookback, moving_average_slice, Ma, MaType};
+pub use simple_moving_average::{simple_moving_average_legacy_slice, simple_moving_average_lookback, simple_moving_average_slice, Sma};
+pub use double_exponential_moving_average::{double_exponential_moving_average_legacy_slice, double_exponential_moving_average_lookback, double_exponential_moving_average_slice, Dema};
+pub use ht_trend_line::{ht_trend_line_lookback, ht_trend_line_slice, HtTrendLine};
+pub use kama::{kama_legacy_slice, kama_lookback, kama_slice, Kama};
+pub use mama::{mama_lookback, mama_slice, mama_with_period_slice, Mama};
+pub use mavp::{mavp_legacy_slice, mavp_lookback, mavp_slice, Mavp};
+pub use midpoint::{midpoint_lookback, midpoint_slice, MidPoint};
+pub use midprice::{midprice_lookback, midprice_slice, MidPrice};
+pub use rolling_vwap::{rolling_vwap_lookback, rolling_vwap_slice, RollingVwap};
+pub use sar::{sar_lookback, sar_slice, Sar};
+pub use sarext::{sarext_lookback, sarext_slice, Sarext};
+pub use t3::{t3_lookback, t3_slice, T3};
+pub use triangular_moving_average::{triangular_moving_average_lookback, triangular_moving_average_slice, Trima};
+pub use triple_exponential_moving_average::{triple_exponential_moving_average_lookback, triple_exponential_moving_average_slice, Tema};
+pub use vwap::{vwap_lookback, vwap_slice, Vwap};
+pub use vwma::{vwma_lookback, vwma_slice, Vwma};
+pub use weighted_moving_average::{weighted_moving_average_lookback, weighted_moving_average_slice, Wma};
+
+#[cfg(feature = "polars")]
+pub use bbands::{b_bands, b_bands_legacy};
+#[cfg(feature = "polars")]
+pub use exponential_moving_average::{exponential_moving_average, exponential_moving_average_legacy};
+#[cfg(feature = "polars")]
+pub use moving_average::{moving_average, moving_average_legacy};
+#[cfg(feature = "polars")]
+pub use simple_moving_average::{simple_moving_average, simple_moving_average_legacy};
+#[cfg(feature = "polars")]
+pub use double_exponential_moving_average::{double_exponential_moving_average, double_exponential_moving_average_legacy};
+#[cfg(feature = "polars")]
 pub use ht_trend_line::ht_trend_line;
-
-
-pub use kama::kama;
-pub use mama::mama;
-pub use mavp::mavp;
-
-
-
-
-
-
-
-
-
-
-
-
-
-
+#[cfg(feature = "polars")]
+pub use kama::{kama, kama_legacy};
+#[cfg(feature = "polars")]
+pub use mama::{mama, mama_with_period};
+#[cfg(feature = "polars")]
+pub use mavp::{mavp, mavp_legacy};
+#[cfg(feature = "polars")]
+pub use midpoint::midpoint;
+#[cfg(feature = "polars")]
+pub use midprice::midprice;
+#[cfg(feature = "polars")]
+pub use rolling_vwap::rolling_vwap;
+#[cfg(feature = "polars")]
+pub use sar::sar;
+#[cfg(feature = "polars")]
+pub use sarext::sarext;
+#[cfg(feature = "polars")]
+pub use t3::t3;
+#[cfg(feature = "polars")]
+pub use triangular_moving_average::triangular_moving_average;
+#[cfg(feature = "polars")]
+pub use triple_exponential_moving_average::triple_exponential_moving_average;
+#[cfg(feature = "polars")]
+pub use vwap::vwap;
+#[cfg(feature = "polars")]
+pub use vwma::vwma;
+#[cfg(feature = "polars")]
+pub use weighted_moving_average::weighted_moving_average;
@@ -10,63 +10,270 @@
  */
 
 //! moving average
+//!
+//! [`moving_average`] is the generic MA of TA-Lib, the averaging method is picked with a [`MaType`].
 
-use crate::ErrorMsg;
+use crate::{
+    double_exponential_moving_average_lookback, exponential_moving_average_lookback, kama_lookback, mama_lookback,
+    simple_moving_average_legacy_slice, simple_moving_average_lookback, t3_lookback, triangular_moving_average_lookback,
+    triple_exponential_moving_average_lookback, weighted_moving_average_lookback, Dema, Ema, Kama, Mama, Sma,
+    TalibError, Tema, Trima, Wma, T3,
+};
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
 
+/// Averaging method of [`moving_average`], the discriminants are the TA-Lib `MAType` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MaType {
+    /// Simple Moving Average
+    #[default]
+    Sma = 0,
+    /// Exponential Moving Average
+    Ema = 1,
+    /// Weighted Moving Average
+    Wma = 2,
+    /// Double Exponential Moving Average
+    Dema = 3,
+    /// Triple Exponential Moving Average
+    Tema = 4,
+    /// Triangular Moving Average
+    Trima = 5,
+    /// Kaufman Adaptive Moving Average, with the default fast and slow periods of 2 and 30
+    Kama = 6,
+    /// MESA Adaptive Moving Average, `time_period` is ignored
+    Mama = 7,
+    /// Triple Exponential Moving Average (T3), with the default volume factor of 0.7
+    T3 = 8,
+}
+
+/// Number of leading bars without an MA value.
+pub fn moving_average_lookback(time_period: Option<usize>, ma_type: Option<MaType>) -> usize {
+    let time_period = time_period.unwrap_or(14);
+    if time_period <= 1 {
+        return 0;
+    }
+    match ma_type.unwrap_or_default() {
+        MaType::Sma => simple_moving_average_lookback(Some(time_period)),
+        MaType::Ema => exponential_moving_average_lookback(Some(time_period)),
+        MaType::Wma => weighted_moving_average_lookback(Some(time_period)),
+        MaType::Dema => double_exponential_moving_average_lookback(Some(time_period)),
+        MaType::Tema => triple_exponential_moving_average_lookback(Some(time_period)),
+        MaType::Trima => triangular_moving_average_lookback(Some(time_period)),
+        MaType::Kama => kama_lookback(Some(time_period)),
+        MaType::Mama => mama_lookback(),
+        MaType::T3 => t3_lookback(Some(time_period)),
+    }
+}
+
+/// Calculates the moving average(MA) over a plain slice, the polars free core of [`moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{moving_average_slice, MaType};
+///
+/// let ma = moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(2), None).unwrap();
+/// assert!(ma[0].is_nan());
+/// assert_eq!(ma[4], 4.5);
+///
+/// let ema = moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(2), Some(MaType::Ema)).unwrap();
+/// assert_eq!(ema[1], 1.5);
+/// ```
+pub fn moving_average_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+    ma_type: Option<MaType>,
+) -> Result<Vec<f64>, TalibError> {
+    let mut state = Ma::new(time_period.unwrap_or(14), ma_type.unwrap_or_default())?;
+    let lookback = moving_average_lookback(time_period, ma_type);
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src.iter().map(|value| state.update(*value).unwrap_or(f64::NAN)).collect())
+}
+
 /// Calculates the moving average of a given series(MA).
 ///
 /// # Arguments
 ///
 /// * `src` - A reference to the Series on which the moving average is to be calculated.
 /// * `time_period` - An optional parameter representing the time period for the moving average calculation. If not provided, the default value is 14.
+/// * `ma_type` - An optional averaging method. If not provided, a simple moving average is used.
 ///
 /// # Examples
 ///
 /// ```
 /// use polars::prelude::*;
-/// use rusty_talib::moving_average;
-/// use rusty_talib::ErrorMsg;
+/// use rusty_talib::{moving_average, MaType};
+/// use rusty_talib::TalibError;
 ///
 /// fn main() {
 ///     let data = Series::new("data", &[1, 2, 3, 4, 5]);
-///     let result = moving_average(&data, Some(2));
+///     let result = moving_average(&data, Some(2), Some(MaType::Ema));
 ///     match result {
 ///         Ok(ma) => {
 ///             println!("{:?}", ma);
 ///         },
+///         Err(TalibError::InsufficientData { needed, got }) => {
+///             eprintln!("needed {} values, got {}", needed, got);
+///         }
 ///         Err(e) => {
-///             if let Some(error) = e.downcast_ref::<ErrorMsg>() {
-///                 println!("{}", error.0);
-///             } else {
-///                 println!("An error occurred");
-///             }
+///             eprintln!("{}", e);
 ///         }
 ///     }
 /// }
 /// ```
 ///
-pub fn moving_average<'a>(
-    src: &'a Series,
+#[cfg(feature = "polars")]
+pub fn moving_average(
+    src: &Series,
     time_period: Option<usize>,
-) -> Result<Series, Box<dyn std::error::Error>> {
-    let time_period = time_period.unwrap_or(14);
+    ma_type: Option<MaType>,
+) -> Result<Series, TalibError> {
+    let res = moving_average_slice(&series_to_vec(src)?, time_period, ma_type)?;
+    Ok(vec_to_series(src.name(), res))
+}
+
+/// Calculates the moving average(MA) over a plain slice the way [`moving_average`] did before it followed
+/// TA-Lib and took an [`MaType`]: a simple moving average whose first `time_period - 1` values average the
+/// values seen so far, the same as [`simple_moving_average_legacy_slice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0 and [`TalibError::InsufficientData`] if
+/// `src` is shorter than `time_period`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::moving_average_legacy_slice;
+///
+/// let ma = moving_average_legacy_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(2)).unwrap();
+/// assert_eq!(ma, [1.0, 1.5, 2.5, 3.5, 4.5]);
+/// ```
+pub fn moving_average_legacy_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    simple_moving_average_legacy_slice(src, time_period)
+}
+
+/// Calculates the moving average of a given series(MA) the way [`moving_average`] did before it followed
+/// TA-Lib, see [`moving_average_legacy_slice`].
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::moving_average_legacy;
+///
+/// let ma = moving_average_legacy(&Series::new("data", &[1, 2, 3, 4, 5]), Some(2)).unwrap();
+/// assert_eq!(ma.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn moving_average_legacy(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let res = moving_average_legacy_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), res))
+}
+
+#[derive(Debug, Clone)]
+enum MaState {
+    Identity,
+    Sma(Sma),
+    Ema(Ema),
+    Wma(Wma),
+    Dema(Dema),
+    Tema(Tema),
+    Trima(Trima),
+    Kama(Kama),
+    Mama(Box<Mama>),
+    T3(Box<T3>),
+}
+
+/// Streaming moving average (MA), the incremental counterpart of [`moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{Ma, MaType};
+///
+/// let mut ma = Ma::new(2, MaType::Sma).unwrap();
+/// for price in [1.0, 2.0, 3.0, 4.0, 5.0] {
+///     eprintln!("{:?}", ma.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ma {
+    state: MaState,
+}
 
-    if src.len() < time_period {
-        return Err(Box::new(ErrorMsg(
-            "src Length must be greater than time_period".into(),
-        )));
+impl Ma {
+    /// Creates a new MA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize, ma_type: MaType) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        let state = match ma_type {
+            // TA-Lib copies the input for a period of 1, whatever the type
+            _ if time_period == 1 => MaState::Identity,
+            MaType::Sma => MaState::Sma(Sma::new(time_period)?),
+            MaType::Ema => MaState::Ema(Ema::new(time_period)?),
+            MaType::Wma => MaState::Wma(Wma::new(time_period)?),
+            MaType::Dema => MaState::Dema(Dema::new(time_period)?),
+            MaType::Tema => MaState::Tema(Tema::new(time_period)?),
+            MaType::Trima => MaState::Trima(Trima::new(time_period)?),
+            MaType::Kama => MaState::Kama(Kama::new(time_period, 2, 30)?),
+            MaType::Mama => MaState::Mama(Box::default()),
+            MaType::T3 => MaState::T3(Box::new(T3::new(time_period, 0.7)?)),
+        };
+        Ok(Ma { state })
     }
 
-    let duration = Duration::new(time_period as i64);
-    let options = RollingOptionsImpl {
-        window_size: duration,
-        min_periods: 1,
-        ..Default::default()
-    };
+    /// Wraps an already configured EMA, for the indicators that need a custom smoothing factor.
+    #[cfg(feature = "momentum_indicators")]
+    pub(crate) fn from_ema(ema: Ema) -> Self {
+        Ma {
+            state: MaState::Ema(ema),
+        }
+    }
 
-    let res = src.rolling_mean(options)?;
-    Ok(res)
+    /// Feeds the next value and returns the current average once the lookback is over.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        match &mut self.state {
+            MaState::Identity => Some(value),
+            MaState::Sma(state) => state.update(value),
+            MaState::Ema(state) => state.update(value),
+            MaState::Wma(state) => state.update(value),
+            MaState::Dema(state) => state.update(value),
+            MaState::Tema(state) => state.update(value),
+            MaState::Trima(state) => state.update(value),
+            MaState::Kama(state) => state.update(value),
+            MaState::Mama(state) => state.update(value, value).map(|(mama, _)| mama),
+            MaState::T3(state) => state.update(value),
+        }
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        match &mut self.state {
+            MaState::Identity => {}
+            MaState::Sma(state) => state.reset(),
+            MaState::Ema(state) => state.reset(),
+            MaState::Wma(state) => state.reset(),
+            MaState::Dema(state) => state.reset(),
+            MaState::Tema(state) => state.reset(),
+            MaState::Trima(state) => state.reset(),
+            MaState::Kama(state) => state.reset(),
+            MaState::Mama(state) => state.reset(),
+            MaState::T3(state) => state.reset(),
+        }
+    }
 }
 
 // unit test
@@ -74,23 +281,60 @@ pub fn moving_average<'a>(
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
     fn test_b_bands() {
         let random_data: [i32; 7] = [23, 25, 12, 28, 33, 31, 35];
         let close = Series::new("data", random_data);
-        let res = moving_average(&close, Some(3));
+        let res = moving_average(&close, Some(3), None);
         match res {
             Ok(ma) => {
                 assert_eq!(ma.len(), close.len());
                 eprintln!("{:?}", ma);
             }
+            Err(TalibError::InsufficientData { needed, got }) => {
+                eprintln!("needed {} values, got {}", needed, got);
+            }
             Err(e) => {
-                if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-                    eprintln!("{}", my_error.0);
-                } else {
-                    eprintln!("An error occurred");
-                }
+                eprintln!("{}", e);
             }
         }
     }
+
+    #[test]
+    fn test_ma_dispatch() -> Result<(), TalibError> {
+        let close: Vec<f64> = (0..60).map(|i| 10.0 + (i as f64 * 0.4).sin()).collect();
+        assert_eq!(
+            moving_average_slice(&close, Some(5), Some(MaType::Ema))?[10..],
+            crate::exponential_moving_average_slice(&close, Some(5))?[10..]
+        );
+        assert_eq!(
+            moving_average_slice(&close, Some(5), Some(MaType::Kama))?[10..],
+            crate::kama_slice(&close, Some(5), Some(2), Some(30))?[10..]
+        );
+        let mama = moving_average_slice(&close, Some(5), Some(MaType::Mama))?;
+        assert_eq!(mama[mama_lookback()..], crate::mama_slice(&close, &close, None, None)?.0[mama_lookback()..]);
+        // a period of 1 copies the input
+        assert_eq!(moving_average_slice(&close, Some(1), Some(MaType::Dema))?, close);
+        Ok(())
+    }
+
+    #[test]
+    fn test_moving_average_legacy() -> Result<(), TalibError> {
+        let res = moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0], Some(3))?;
+        let expected = [23.0, 24.0, 20.0, 65.0 / 3.0, 73.0 / 3.0, 92.0 / 3.0, 33.0];
+        for (value, expected) in res.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_moving_average_legacy_series() -> Result<(), TalibError> {
+        let res = moving_average_legacy(&Series::new("data", [23, 25, 12, 28, 33, 31, 35]), Some(3))?;
+        let expected = moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0], Some(3))?;
+        assert_eq!(res.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
 }
@@ -0,0 +1,183 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/10 21:15
+ * @Email: uyplayer@qq.com
+ * @File: rolling_vwap.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Rolling Volume Weighted Average Price
+//!
+//! - typical price = (high + low + close) / 3
+//! - rolling vwap = sum(typical price * volume) / sum(volume) over the last `time_period` bars
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{vwma_lookback, TalibError, Vwma};
+
+/// Number of leading bars without a rolling VWAP value, `time_period - 1`.
+pub fn rolling_vwap_lookback(time_period: Option<usize>) -> usize {
+    vwma_lookback(Some(time_period.unwrap_or(20)))
+}
+
+/// Calculates the rolling Volume Weighted Average Price over plain `high`, `low`, `close` and
+/// `volume` slices, the polars free core of [`rolling_vwap`].
+///
+/// Windows without any volume have no average and are `NaN`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::rolling_vwap_slice;
+///
+/// let high = [11.0, 13.0, 12.0];
+/// let low = [9.0, 11.0, 10.0];
+/// let close = [10.0, 12.0, 11.0];
+/// let vwap = rolling_vwap_slice(&high, &low, &close, &[100.0, 300.0, 100.0], Some(2)).unwrap();
+/// assert!(vwap[0].is_nan());
+/// assert_eq!(vwap[1], 11.5);
+/// assert_eq!(vwap[2], 11.75);
+/// ```
+pub fn rolling_vwap_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len(), volume.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = RollingVwap::new(time_period)?;
+    let lookback = rolling_vwap_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok((0..high.len())
+        .map(|i| state.update(high[i], low[i], close[i], volume[i]).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the rolling Volume Weighted Average Price.
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 20 if not provided.
+///
+/// # Returns
+///
+/// The rolling VWAP Series, null for the first [`rolling_vwap_lookback`] values and for windows without volume.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::rolling_vwap;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = rolling_vwap(&high, &low, &close, &volume, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn rolling_vwap(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    volume: &Series,
+    time_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let vwap = rolling_vwap_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        &series_to_vec(volume)?,
+        time_period,
+    )?;
+    Ok(vec_to_series("data", vwap))
+}
+
+/// Streaming rolling Volume Weighted Average Price.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::RollingVwap;
+///
+/// let mut vwap = RollingVwap::new(2).unwrap();
+/// for (high, low, close, volume) in [(11.0, 9.0, 10.0, 100.0), (13.0, 11.0, 12.0, 300.0), (12.0, 10.0, 11.0, 100.0)] {
+///     eprintln!("{:?}", vwap.update(high, low, close, volume));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingVwap {
+    vwma: Vwma,
+}
+
+impl RollingVwap {
+    /// Creates a new rolling VWAP state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(RollingVwap {
+            vwma: Vwma::new(time_period)?,
+        })
+    }
+
+    /// Feeds the next bar and returns the current VWAP once the window is full,
+    /// `None` while the window has no volume.
+    pub fn update(&mut self, high: f64, low: f64, close: f64, volume: f64) -> Option<f64> {
+        self.vwma.update((high + low + close) / 3.0, volume)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.vwma.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_vwap() -> Result<(), TalibError> {
+        let high = [11.0, 13.0, 12.0, 12.0];
+        let low = [9.0, 11.0, 10.0, 10.0];
+        let close = [10.0, 12.0, 11.0, 11.0];
+        let res = rolling_vwap_slice(&high, &low, &close, &[100.0, 300.0, 100.0, 0.0], Some(2))?;
+        assert_eq!(rolling_vwap_lookback(Some(2)), 1);
+        assert!(res[0].is_nan());
+        assert_eq!(res[1..], [11.5, 11.75, 11.0]);
+        // a window as long as the input is the session VWAP of its last bar
+        let rolling = rolling_vwap_slice(&high, &low, &close, &[100.0, 300.0, 100.0, 50.0], Some(4))?;
+        let session = crate::vwap_slice(&high, &low, &close, &[100.0, 300.0, 100.0, 50.0], None)?;
+        assert_eq!(rolling[3], session[3]);
+        assert!(rolling_vwap_slice(&high, &low, &close, &[1.0], Some(2)).is_err());
+        assert!(rolling_vwap_slice(&high, &low, &close, &[1.0; 4], Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/18 13:20
+ * @Email: uyplayer@qq.com
+ * @File: sar.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Parabolic SAR
+//!
+//! - the first position is long unless the low falls more than the high rises on the second bar
+//! - sar = sar + af * (ep - sar), where ep is the extreme price of the position
+//! - af starts at `acceleration` and grows by `acceleration` on every new extreme, up to `maximum`
+//! - the position is reversed when the price crosses the SAR
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{Sarext, TalibError};
+
+/// Number of leading bars without a SAR value, 1 like TA-Lib.
+pub fn sar_lookback() -> usize {
+    1
+}
+
+/// Calculates the Parabolic SAR (SAR) over plain `high` and `low` slices, the polars free core of [`sar`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InvalidParameter`] if `acceleration` or `maximum` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::sar_slice;
+///
+/// let high = [10.0, 11.0, 12.0, 13.0, 12.5, 11.0];
+/// let low = [9.0, 10.0, 11.0, 12.0, 10.0, 9.0];
+/// let sar = sar_slice(&high, &low, Some(0.02), Some(0.2)).unwrap();
+/// assert!(sar[0].is_nan());
+/// assert_eq!(sar[1], 9.0);
+/// assert_eq!(sar[5], 13.0);
+/// ```
+pub fn sar_slice(
+    high: &[f64],
+    low: &[f64],
+    acceleration: Option<f64>,
+    maximum: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let mut state = Sar::new(acceleration.unwrap_or(0.02), maximum.unwrap_or(0.2))?;
+    let lookback = sar_lookback();
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Parabolic SAR (SAR).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `acceleration` - An optional acceleration factor and step. Defaults to 0.02.
+/// * `maximum` - An optional maximum acceleration factor. Defaults to 0.2.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::sar;
+///
+///  let high = Series::new("high", [10.0, 11.0, 12.0, 13.0, 12.5, 11.0]);
+///  let low = Series::new("low", [9.0, 10.0, 11.0, 12.0, 10.0, 9.0]);
+///  let res = sar(&high, &low, Some(0.02), Some(0.2));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn sar(high: &Series, low: &Series, acceleration: Option<f64>, maximum: Option<f64>) -> Result<Series, TalibError> {
+    let sar = sar_slice(&series_to_vec(high)?, &series_to_vec(low)?, acceleration, maximum)?;
+    Ok(vec_to_series("data", sar))
+}
+
+/// Streaming Parabolic SAR (SAR), for trailing stops updated one bar at a time.
+///
+/// Returns `None` on the first bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Sar;
+///
+/// let mut sar = Sar::new(0.02, 0.2).unwrap();
+/// for (high, low) in [(10.0, 9.0), (11.0, 10.0), (12.0, 11.0)] {
+///     eprintln!("{:?}", sar.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sar {
+    sarext: Sarext,
+}
+
+impl Sar {
+    /// Creates a new SAR state, returns an error if `acceleration` or `maximum` is negative.
+    pub fn new(acceleration: f64, maximum: f64) -> Result<Self, TalibError> {
+        for (name, value) in [("acceleration", acceleration), ("maximum", maximum)] {
+            if value.is_nan() || value < 0.0 {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value,
+                    reason: "must be greater than or equal to 0",
+                });
+            }
+        }
+        Ok(Sar {
+            sarext: Sarext::new(0.0, 0.0, acceleration, acceleration, maximum, acceleration, acceleration, maximum)?,
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current SAR.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        self.sarext.update(high, low).map(f64::abs)
+    }
+
+    /// Returns `true` while the position is long.
+    pub fn is_long(&self) -> bool {
+        self.sarext.is_long()
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.sarext.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sar() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 12.0, 13.0, 12.5, 11.0];
+        let low = [9.0, 10.0, 11.0, 12.0, 10.0, 9.0];
+        let res = sar_slice(&high, &low, None, None)?;
+        let expected = [9.0, 9.04, 9.1584, 9.388896, 13.0];
+        for (i, value) in expected.iter().enumerate() {
+            assert!((res[i + 1] - value).abs() < 1e-9);
+        }
+        assert!(sar_slice(&high, &low[1..], None, None).is_err());
+        assert!(matches!(Sar::new(-0.02, 0.2), Err(TalibError::InvalidParameter { name: "acceleration", .. })));
+        assert!(matches!(Sar::new(0.02, f64::NAN), Err(TalibError::InvalidParameter { name: "maximum", .. })));
+        Ok(())
+    }
+}
@@ -0,0 +1,379 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/18 14:05
+ * @Email: uyplayer@qq.com
+ * @File: sarext.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Parabolic SAR - Extended
+//!
+//! Same stop and reverse logic as [`crate::sar`], with separate acceleration factors
+//! for long and short positions, a custom start value and an offset applied on reversal.
+//! Short positions are returned as negative values, like TA-Lib.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a SAREXT value, 1 like TA-Lib.
+pub fn sarext_lookback() -> usize {
+    1
+}
+
+/// Calculates the Parabolic SAR - Extended (SAREXT) over plain `high` and `low` slices,
+/// the polars free core of [`sarext`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InvalidParameter`] if an acceleration factor or the offset is negative.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::sarext_slice;
+///
+/// let high = [10.0, 11.0, 12.0, 13.0, 12.5, 11.0];
+/// let low = [9.0, 10.0, 11.0, 12.0, 10.0, 9.0];
+/// let sar = sarext_slice(&high, &low, None, None, None, None, None, None, None, None).unwrap();
+/// assert!(sar[0].is_nan());
+/// assert_eq!(sar[1], 9.0);
+/// // the position is reversed to short on the last bar
+/// assert_eq!(sar[5], -13.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn sarext_slice(
+    high: &[f64],
+    low: &[f64],
+    start_value: Option<f64>,
+    offset_on_reverse: Option<f64>,
+    accel_init_long: Option<f64>,
+    accel_long: Option<f64>,
+    accel_max_long: Option<f64>,
+    accel_init_short: Option<f64>,
+    accel_short: Option<f64>,
+    accel_max_short: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let mut state = Sarext::new(
+        start_value.unwrap_or(0.0),
+        offset_on_reverse.unwrap_or(0.0),
+        accel_init_long.unwrap_or(0.02),
+        accel_long.unwrap_or(0.02),
+        accel_max_long.unwrap_or(0.2),
+        accel_init_short.unwrap_or(0.02),
+        accel_short.unwrap_or(0.02),
+        accel_max_short.unwrap_or(0.2),
+    )?;
+    let lookback = sarext_lookback();
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Parabolic SAR - Extended (SAREXT).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `start_value` - An optional start value, 0 picks the first direction from the data,
+///   a positive value starts long and a negative one starts short at its absolute value. Defaults to 0.
+/// * `offset_on_reverse` - An optional percentage added to the SAR on reversal. Defaults to 0.
+/// * `accel_init_long` - An optional initial acceleration factor of long positions. Defaults to 0.02.
+/// * `accel_long` - An optional acceleration factor step of long positions. Defaults to 0.02.
+/// * `accel_max_long` - An optional maximum acceleration factor of long positions. Defaults to 0.2.
+/// * `accel_init_short` - An optional initial acceleration factor of short positions. Defaults to 0.02.
+/// * `accel_short` - An optional acceleration factor step of short positions. Defaults to 0.02.
+/// * `accel_max_short` - An optional maximum acceleration factor of short positions. Defaults to 0.2.
+///
+/// # Returns
+///
+/// A Series with the SAR, negative while the position is short.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::sarext;
+///
+///  let high = Series::new("high", [10.0, 11.0, 12.0, 13.0, 12.5, 11.0]);
+///  let low = Series::new("low", [9.0, 10.0, 11.0, 12.0, 10.0, 9.0]);
+///  let res = sarext(&high, &low, None, Some(0.01), None, None, None, None, None, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+#[allow(clippy::too_many_arguments)]
+pub fn sarext(
+    high: &Series,
+    low: &Series,
+    start_value: Option<f64>,
+    offset_on_reverse: Option<f64>,
+    accel_init_long: Option<f64>,
+    accel_long: Option<f64>,
+    accel_max_long: Option<f64>,
+    accel_init_short: Option<f64>,
+    accel_short: Option<f64>,
+    accel_max_short: Option<f64>,
+) -> Result<Series, TalibError> {
+    let sar = sarext_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        start_value,
+        offset_on_reverse,
+        accel_init_long,
+        accel_long,
+        accel_max_long,
+        accel_init_short,
+        accel_short,
+        accel_max_short,
+    )?;
+    Ok(vec_to_series("data", sar))
+}
+
+/// Streaming Parabolic SAR - Extended (SAREXT).
+///
+/// Returns `None` on the first bar, then the SAR of every bar, negative while short.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Sarext;
+///
+/// let mut sar = Sarext::new(0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2).unwrap();
+/// for (high, low) in [(10.0, 9.0), (11.0, 10.0), (12.0, 11.0)] {
+///     eprintln!("{:?}", sar.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sarext {
+    start_value: f64,
+    offset_on_reverse: f64,
+    accel_init_long: f64,
+    accel_long: f64,
+    accel_max_long: f64,
+    accel_init_short: f64,
+    accel_short: f64,
+    accel_max_short: f64,
+    af_long: f64,
+    af_short: f64,
+    is_long: bool,
+    sar: f64,
+    ep: f64,
+    // previous bar, `None` until the first bar has been seen
+    prev: Option<(f64, f64)>,
+    started: bool,
+}
+
+impl Sarext {
+    /// Creates a new SAREXT state, returns an error if the offset or an acceleration factor is negative.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        start_value: f64,
+        offset_on_reverse: f64,
+        accel_init_long: f64,
+        accel_long: f64,
+        accel_max_long: f64,
+        accel_init_short: f64,
+        accel_short: f64,
+        accel_max_short: f64,
+    ) -> Result<Self, TalibError> {
+        let params = [
+            ("offset_on_reverse", offset_on_reverse),
+            ("accel_init_long", accel_init_long),
+            ("accel_long", accel_long),
+            ("accel_max_long", accel_max_long),
+            ("accel_init_short", accel_init_short),
+            ("accel_short", accel_short),
+            ("accel_max_short", accel_max_short),
+        ];
+        for (name, value) in params {
+            if value.is_nan() || value < 0.0 {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value,
+                    reason: "must be greater than or equal to 0",
+                });
+            }
+        }
+        if start_value.is_nan() {
+            return Err(TalibError::InvalidParameter {
+                name: "start_value",
+                value: start_value,
+                reason: "must be a number",
+            });
+        }
+        // the initial factor and the step can't exceed the maximum
+        let accel_init_long = accel_init_long.min(accel_max_long);
+        let accel_init_short = accel_init_short.min(accel_max_short);
+        Ok(Sarext {
+            start_value,
+            offset_on_reverse,
+            accel_init_long,
+            accel_long: accel_long.min(accel_max_long),
+            accel_max_long,
+            accel_init_short,
+            accel_short: accel_short.min(accel_max_short),
+            accel_max_short,
+            af_long: accel_init_long,
+            af_short: accel_init_short,
+            is_long: true,
+            sar: 0.0,
+            ep: 0.0,
+            prev: None,
+            started: false,
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current SAR, negative while short.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        let (prev_high, prev_low) = match self.prev {
+            None => {
+                self.prev = Some((high, low));
+                return None;
+            }
+            Some(prev) => prev,
+        };
+        if !self.started {
+            self.start(prev_high, prev_low, high, low);
+            // the first bar is compared with itself
+            self.prev = Some((high, low));
+            return self.step(high, low, high, low);
+        }
+        self.prev = Some((high, low));
+        self.step(prev_high, prev_low, high, low)
+    }
+
+    /// Picks the first position from the first two bars.
+    fn start(&mut self, prev_high: f64, prev_low: f64, high: f64, low: f64) {
+        self.started = true;
+        if self.start_value == 0.0 {
+            // short when the one bar -DM is positive
+            let minus_dm = prev_low - low;
+            let plus_dm = high - prev_high;
+            self.is_long = !(minus_dm > 0.0 && plus_dm < minus_dm);
+            if self.is_long {
+                self.ep = high;
+                self.sar = prev_low;
+            } else {
+                self.ep = low;
+                self.sar = prev_high;
+            }
+        } else if self.start_value > 0.0 {
+            self.is_long = true;
+            self.ep = high;
+            self.sar = self.start_value;
+        } else {
+            self.is_long = false;
+            self.ep = low;
+            self.sar = self.start_value.abs();
+        }
+    }
+
+    fn step(&mut self, prev_high: f64, prev_low: f64, high: f64, low: f64) -> Option<f64> {
+        let output;
+        if self.is_long {
+            if low <= self.sar {
+                // reverse to short
+                self.is_long = false;
+                self.sar = self.ep.max(prev_high).max(high);
+                if self.offset_on_reverse != 0.0 {
+                    self.sar += self.sar * self.offset_on_reverse;
+                }
+                output = -self.sar;
+                self.af_short = self.accel_init_short;
+                self.ep = low;
+                self.sar += self.af_short * (self.ep - self.sar);
+                self.sar = self.sar.max(prev_high).max(high);
+            } else {
+                output = self.sar;
+                if high > self.ep {
+                    self.ep = high;
+                    self.af_long = (self.af_long + self.accel_long).min(self.accel_max_long);
+                }
+                self.sar += self.af_long * (self.ep - self.sar);
+                self.sar = self.sar.min(prev_low).min(low);
+            }
+        } else if high >= self.sar {
+            // reverse to long
+            self.is_long = true;
+            self.sar = self.ep.min(prev_low).min(low);
+            if self.offset_on_reverse != 0.0 {
+                self.sar -= self.sar * self.offset_on_reverse;
+            }
+            output = self.sar;
+            self.af_long = self.accel_init_long;
+            self.ep = high;
+            self.sar += self.af_long * (self.ep - self.sar);
+            self.sar = self.sar.min(prev_low).min(low);
+        } else {
+            output = -self.sar;
+            if low < self.ep {
+                self.ep = low;
+                self.af_short = (self.af_short + self.accel_short).min(self.accel_max_short);
+            }
+            self.sar += self.af_short * (self.ep - self.sar);
+            self.sar = self.sar.max(prev_high).max(high);
+        }
+        Some(output)
+    }
+
+    /// Returns `true` while the position is long.
+    pub fn is_long(&self) -> bool {
+        self.is_long
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.af_long = self.accel_init_long;
+        self.af_short = self.accel_init_short;
+        self.is_long = true;
+        self.sar = 0.0;
+        self.ep = 0.0;
+        self.prev = None;
+        self.started = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sarext() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 12.0, 13.0, 12.5, 11.0];
+        let low = [9.0, 10.0, 11.0, 12.0, 10.0, 9.0];
+        let res = sarext_slice(&high, &low, None, None, None, None, None, None, None, None)?;
+        let expected = [9.0, 9.04, 9.1584, 9.388896, -13.0];
+        for (i, value) in expected.iter().enumerate() {
+            assert!((res[i + 1] - value).abs() < 1e-9);
+        }
+        // the offset moves the SAR away from the price on reversal
+        let res = sarext_slice(&high, &low, None, Some(0.01), None, None, None, None, None, None)?;
+        assert!((res[5] + 13.13).abs() < 1e-9);
+        // a negative start value starts short
+        let res = sarext_slice(&high, &low, Some(-14.0), None, None, None, None, None, None, None)?;
+        assert!((res[1] + 14.0).abs() < 1e-9);
+        assert!(sarext_slice(&high, &low, None, Some(-0.1), None, None, None, None, None, None).is_err());
+        Ok(())
+    }
+}
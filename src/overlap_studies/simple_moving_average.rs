@@ -9,8 +9,54 @@
  * @Description:
  */
 
-use crate::ErrorMsg;
+use crate::TalibError;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+#[cfg(feature = "polars")]
 use polars::prelude::*;
+use std::collections::VecDeque;
+
+/// Number of leading bars without an SMA value, `time_period - 1` like TA-Lib.
+pub fn simple_moving_average_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the simple moving average within the given time period(SMA) over a plain slice.
+///
+/// This is the polars free core of [`simple_moving_average`]. The first
+/// [`simple_moving_average_lookback`] values are `NaN`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InsufficientData`] if `src` is shorter than `time_period`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::simple_moving_average_slice;
+///
+/// let sma = simple_moving_average_slice(&[23.0, 25.0, 12.0, 28.0], Some(3)).unwrap();
+/// assert!(sma[1].is_nan());
+/// assert_eq!(sma[2], 20.0);
+/// ```
+pub fn simple_moving_average_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(14);
+    let lookback = simple_moving_average_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Sma::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
 
 /// Calculates the simple moving average within the given time period(SMA).
 ///
@@ -22,7 +68,8 @@ use polars::prelude::*;
 ///
 /// # Returns
 ///
-/// Returns a new Series object containing the calculated simple moving average.
+/// Returns a new Series object containing the calculated simple moving average,
+/// the first [`simple_moving_average_lookback`] values are null.
 ///
 /// # Errors
 ///
@@ -33,7 +80,7 @@ use polars::prelude::*;
 /// ```
 /// use polars::prelude::*;
 /// use rusty_talib::simple_moving_average;
-/// use rusty_talib::ErrorMsg;
+/// use rusty_talib::TalibError;
 ///
 /// let random_data: [i32; 7] = [23, 25, 12, 28, 33, 31, 35];
 /// let close = Series::new("data", random_data);
@@ -43,36 +90,154 @@ use polars::prelude::*;
 ///         assert_eq!(res.len(), close.len());
 ///         eprintln!("{:?}", res);
 ///     }
+///     Err(TalibError::InsufficientData { needed, got }) => {
+///         eprintln!("needed {} values, got {}", needed, got);
+///     }
 ///     Err(e) => {
-///         if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-///            eprintln!("{}", my_error.0);
-///          } else {
-///             eprintln!("An error occurred");
-///          }
+///         eprintln!("{}", e);
 ///     }
 /// }
 /// ```
 ///
-pub fn simple_moving_average<'a>(
-    src: &'a Series,
+#[cfg(feature = "polars")]
+pub fn simple_moving_average(
+    src: &Series,
     time_period: Option<usize>,
-) -> Result<Series, Box<dyn std::error::Error>> {
+) -> Result<Series, TalibError> {
+    let res = simple_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), res))
+}
+
+/// Calculates the simple moving average (SMA) over a plain slice the way [`simple_moving_average`] did
+/// before it followed TA-Lib.
+///
+/// The first `time_period - 1` values are the average of the values seen so far instead of `NaN`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is 0 and [`TalibError::InsufficientData`] if
+/// `src` is shorter than `time_period`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::simple_moving_average_legacy_slice;
+///
+/// let sma = simple_moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0], Some(3)).unwrap();
+/// assert_eq!(sma, [23.0, 24.0, 20.0, 65.0 / 3.0]);
+/// ```
+pub fn simple_moving_average_legacy_slice(
+    src: &[f64],
+    time_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
     let time_period = time_period.unwrap_or(14);
+    if time_period == 0 {
+        return Err(TalibError::InvalidParameter {
+            name: "time_period",
+            value: time_period as f64,
+            reason: "must be greater than 0",
+        });
+    }
     if src.len() < time_period {
-        return Err(Box::new(ErrorMsg(
-            "src Length must be greater than time_period".into(),
-        )));
+        return Err(TalibError::InsufficientData {
+            needed: time_period,
+            got: src.len(),
+        });
     }
+    let mut sum = 0.0;
+    Ok((0..src.len())
+        .map(|i| {
+            sum += src[i];
+            if i >= time_period {
+                sum -= src[i - time_period];
+            }
+            sum / (i + 1).min(time_period) as f64
+        })
+        .collect())
+}
 
-    let duration = Duration::new(time_period as i64);
-    let options = RollingOptionsImpl {
-        window_size: duration,
-        min_periods: 1,
-        ..Default::default()
-    };
+/// Calculates the simple moving average (SMA) the way [`simple_moving_average`] did before it followed
+/// TA-Lib, the first bars average the values seen so far instead of being null.
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use rusty_talib::simple_moving_average_legacy;
+///
+/// let close = Series::new("data", [23, 25, 12, 28, 33, 31, 35]);
+/// let res = simple_moving_average_legacy(&close, Some(3)).unwrap();
+/// assert_eq!(res.null_count(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn simple_moving_average_legacy(
+    src: &Series,
+    time_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let res = simple_moving_average_legacy_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), res))
+}
+
+/// Streaming simple moving average (SMA).
+///
+/// Keeps a running sum over the last `time_period` values so every update is O(1).
+/// Returns `None` until `time_period` values have been seen, so feeding a series through
+/// [`Sma::update`] yields the same values as [`simple_moving_average`] bar for bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Sma;
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// for price in [23.0, 25.0, 12.0, 28.0] {
+///     eprintln!("{:?}", sma.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sma {
+    time_period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Sma {
+    /// Creates a new SMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Sma {
+            time_period,
+            window: VecDeque::with_capacity(time_period + 1),
+            sum: 0.0,
+        })
+    }
 
-    let res = src.rolling_mean(options)?;
-    Ok(res)
+    /// Feeds the next value and returns the current average once the window is full.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        self.sum += value;
+        if self.window.len() > self.time_period {
+            if let Some(old) = self.window.pop_front() {
+                self.sum -= old;
+            }
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        Some(self.sum / self.time_period as f64)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
 }
 
 // unit test
@@ -80,6 +245,7 @@ pub fn simple_moving_average<'a>(
 mod tests {
     use super::*;
 
+    #[cfg(feature = "polars")]
     #[test]
     fn test_b_bands() {
         let random_data: [i32; 7] = [23, 25, 12, 28, 33, 31, 35];
@@ -90,13 +256,46 @@ mod tests {
                 assert_eq!(res.len(), close.len());
                 eprintln!("{:?}", res);
             }
+            Err(TalibError::InsufficientData { needed, got }) => {
+                eprintln!("needed {} values, got {}", needed, got);
+            }
             Err(e) => {
-                if let Some(my_error) = e.downcast_ref::<ErrorMsg>() {
-                    eprintln!("{}", my_error.0);
-                } else {
-                    eprintln!("An error occurred");
-                }
+                eprintln!("{}", e);
             }
         }
     }
+
+    #[test]
+    fn test_sma_legacy() -> Result<(), TalibError> {
+        let res = simple_moving_average_legacy_slice(&[23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0], Some(3))?;
+        let expected = [23.0, 24.0, 20.0, 65.0 / 3.0, 73.0 / 3.0, 92.0 / 3.0, 33.0];
+        for (value, expected) in res.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+        let err = simple_moving_average_legacy_slice(&[1.0, 2.0], Some(3));
+        assert!(matches!(err, Err(TalibError::InsufficientData { needed: 3, got: 2 })));
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_sma_legacy_series() -> Result<(), TalibError> {
+        let close = [23.0, 25.0, 12.0, 28.0, 33.0, 31.0, 35.0];
+        let res = simple_moving_average_legacy(&Series::new("data", close), Some(3))?;
+        assert_eq!(res.null_count(), 0);
+        let expected = simple_moving_average_legacy_slice(&close, Some(3))?;
+        assert_eq!(res.f64()?.into_no_null_iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sma_slice() -> Result<(), TalibError> {
+        let res = simple_moving_average_slice(&[23.0, 25.0, 12.0, 28.0], Some(3))?;
+        assert_eq!(simple_moving_average_lookback(Some(3)), 2);
+        assert!(res[0].is_nan() && res[1].is_nan());
+        assert_eq!(res[2..], [20.0, 65.0 / 3.0]);
+        let err = simple_moving_average_slice(&[1.0], Some(3));
+        assert!(matches!(err, Err(TalibError::InsufficientData { needed: 3, got: 1 })));
+        Ok(())
+    }
 }
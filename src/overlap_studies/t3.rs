@@ -0,0 +1,162 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 17:36
+ * @Email: uyplayer@qq.com
+ * @File: t3.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Triple Exponential Moving Average (T3) by Tim Tillson
+//!
+//! T3 runs six EMAs in a row and combines the last four of them:
+//! - a = v_factor
+//! - c1 = -a^3
+//! - c2 = 3a^2 + 3a^3
+//! - c3 = -6a^2 - 3a - 3a^3
+//! - c4 = 1 + 3a + a^3 + 3a^2
+//! - T3 = c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{exponential_moving_average_lookback, Ema, TalibError};
+
+/// Number of leading bars without a T3 value, six times the EMA lookback like TA-Lib.
+pub fn t3_lookback(time_period: Option<usize>) -> usize {
+    6 * exponential_moving_average_lookback(Some(time_period.unwrap_or(5)))
+}
+
+/// Calculates the T3 over a plain slice, the polars free core of [`t3`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::t3_slice;
+///
+/// let close: Vec<f64> = (0..20).map(|i| i as f64).collect();
+/// let t3 = t3_slice(&close, Some(3), Some(0.7)).unwrap();
+/// assert!(t3[11].is_nan());
+/// assert!(!t3[12].is_nan());
+/// ```
+pub fn t3_slice(src: &[f64], time_period: Option<usize>, v_factor: Option<f64>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(5);
+    let lookback = t3_lookback(Some(time_period));
+    let mut state = T3::new(time_period, v_factor.unwrap_or(0.7))?;
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Triple Exponential Moving Average (T3).
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 5 if not provided.
+/// * `v_factor` - An optional volume factor between 0 and 1. Defaults to 0.7 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::t3;
+///
+///  let close = Series::new("data", (0..30).map(|i| (i as f64 * 0.5).sin()).collect::<Vec<f64>>());
+///  let res = t3(&close, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn t3(src: &Series, time_period: Option<usize>, v_factor: Option<f64>) -> Result<Series, TalibError> {
+    let t3 = t3_slice(&series_to_vec(src)?, time_period, v_factor)?;
+    Ok(vec_to_series(src.name(), t3))
+}
+
+/// Streaming Triple Exponential Moving Average (T3).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::T3;
+///
+/// let mut t3 = T3::new(2, 0.7).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", t3.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct T3 {
+    emas: [Ema; 6],
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    c4: f64,
+}
+
+impl T3 {
+    /// Creates a new T3 state, returns an error if `time_period` is 0 or `v_factor` is outside of `[0, 1]`.
+    pub fn new(time_period: usize, v_factor: f64) -> Result<Self, TalibError> {
+        if !(0.0..=1.0).contains(&v_factor) {
+            return Err(TalibError::InvalidParameter {
+                name: "v_factor",
+                value: v_factor,
+                reason: "must be between 0 and 1",
+            });
+        }
+        let ema = Ema::new(time_period)?;
+        let a = v_factor;
+        let a2 = a * a;
+        let a3 = a2 * a;
+        Ok(T3 {
+            emas: [ema.clone(), ema.clone(), ema.clone(), ema.clone(), ema.clone(), ema],
+            c1: -a3,
+            c2: 3.0 * (a2 + a3),
+            c3: -6.0 * a2 - 3.0 * (a + a3),
+            c4: 1.0 + 3.0 * a + a3 + 3.0 * a2,
+        })
+    }
+
+    /// Feeds the next value and returns the current T3.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let mut e = [0.0; 6];
+        let mut input = value;
+        for (ema, out) in self.emas.iter_mut().zip(e.iter_mut()) {
+            input = ema.update(input)?;
+            *out = input;
+        }
+        Some(self.c1 * e[5] + self.c2 * e[4] + self.c3 * e[3] + self.c4 * e[2])
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.emas.iter_mut().for_each(Ema::reset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t3() -> Result<(), TalibError> {
+        // with a volume factor of 1 T3 has no lag on a straight line
+        let close: Vec<f64> = (0..30).map(|i| 3.0 + 0.5 * i as f64).collect();
+        let res = t3_slice(&close, Some(3), Some(1.0))?;
+        assert!(res[11].is_nan());
+        for i in 12..close.len() {
+            assert!((res[i] - close[i]).abs() < 1e-9);
+        }
+        assert!(t3_slice(&close, Some(3), Some(1.5)).is_err());
+        Ok(())
+    }
+}
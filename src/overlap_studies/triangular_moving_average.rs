@@ -0,0 +1,146 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 17:10
+ * @Email: uyplayer@qq.com
+ * @File: triangular_moving_average.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! triangular moving average
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{Sma, TalibError};
+
+/// Number of leading bars without a TRIMA value, `time_period - 1` like TA-Lib.
+pub fn triangular_moving_average_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(30).saturating_sub(1)
+}
+
+/// Calculates the Triangular Moving Average (TRIMA) over a plain slice,
+/// the polars free core of [`triangular_moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::triangular_moving_average_slice;
+///
+/// let trima = triangular_moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(3)).unwrap();
+/// assert!(trima[1].is_nan());
+/// assert_eq!(trima[2], 2.0);
+/// ```
+pub fn triangular_moving_average_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(30);
+    let lookback = triangular_moving_average_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Trima::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Triangular Moving Average (TRIMA)
+///
+/// The weights rise linearly to the middle of the window and fall back, which is
+/// an SMA of an SMA: for an odd `time_period` both have `(time_period + 1) / 2` values,
+/// for an even one `time_period / 2 + 1` and `time_period / 2`.
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 30 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::triangular_moving_average;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = triangular_moving_average(&close, Some(4));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn triangular_moving_average(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let trima = triangular_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), trima))
+}
+
+/// Streaming triangular moving average (TRIMA).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Trima;
+///
+/// let mut trima = Trima::new(4).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0] {
+///     eprintln!("{:?}", trima.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trima {
+    sma: Sma,
+    sma_of_sma: Sma,
+}
+
+impl Trima {
+    /// Creates a new TRIMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        let first = time_period / 2 + 1;
+        let second = time_period + 1 - first;
+        Ok(Trima {
+            sma: Sma::new(first)?,
+            sma_of_sma: Sma::new(second)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current TRIMA.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let sma = self.sma.update(value)?;
+        self.sma_of_sma.update(sma)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.sma.reset();
+        self.sma_of_sma.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangular_moving_average() -> Result<(), TalibError> {
+        let close = [35.0, 10.0, 20.0, 56.0, 89.0, 76.0];
+        // weights 1, 2, 2, 1 for an even period
+        let res = triangular_moving_average_slice(&close, Some(4))?;
+        assert!(res[2].is_nan());
+        assert!((res[3] - (35.0 + 20.0 + 40.0 + 56.0) / 6.0).abs() < 1e-12);
+        // weights 1, 2, 3, 2, 1 for an odd period
+        let res = triangular_moving_average_slice(&close, Some(5))?;
+        assert!((res[4] - (35.0 + 20.0 + 60.0 + 112.0 + 89.0) / 9.0).abs() < 1e-12);
+        Ok(())
+    }
+}
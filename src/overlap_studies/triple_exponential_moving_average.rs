@@ -0,0 +1,141 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 16:48
+ * @Email: uyplayer@qq.com
+ * @File: triple_exponential_moving_average.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! triple exponential moving average
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{exponential_moving_average_lookback, Ema, TalibError};
+
+/// Number of leading bars without a TEMA value, three times the EMA lookback like TA-Lib.
+pub fn triple_exponential_moving_average_lookback(time_period: Option<usize>) -> usize {
+    3 * exponential_moving_average_lookback(Some(time_period.unwrap_or(30)))
+}
+
+/// Calculates the Triple Exponential Moving Average (TEMA) over a plain slice,
+/// the polars free core of [`triple_exponential_moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::triple_exponential_moving_average_slice;
+///
+/// let tema = triple_exponential_moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], Some(3)).unwrap();
+/// assert!(tema[5].is_nan());
+/// assert_eq!(tema[6], 7.0);
+/// ```
+pub fn triple_exponential_moving_average_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(30);
+    let lookback = triple_exponential_moving_average_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Tema::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Triple Exponential Moving Average (TEMA) using the formula of ta_lib:
+/// `3 * ema1 - 3 * ema2 + ema3` where `ema2` is the EMA of `ema1` and `ema3` the EMA of `ema2`.
+///
+/// Each EMA only starts once the previous one has a value, so the first
+/// [`triple_exponential_moving_average_lookback`] values are null.
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 30 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::triple_exponential_moving_average;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = triple_exponential_moving_average(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn triple_exponential_moving_average(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let tema = triple_exponential_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), tema))
+}
+
+/// Streaming triple exponential moving average (TEMA).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Tema;
+///
+/// let mut tema = Tema::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0, 89.0, 76.0, 30.0] {
+///     eprintln!("{:?}", tema.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tema {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+}
+
+impl Tema {
+    /// Creates a new TEMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(Tema {
+            ema1: Ema::new(time_period)?,
+            ema2: Ema::new(time_period)?,
+            ema3: Ema::new(time_period)?,
+        })
+    }
+
+    /// Feeds the next value and returns the current TEMA.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        let ema1 = self.ema1.update(value)?;
+        let ema2 = self.ema2.update(ema1)?;
+        let ema3 = self.ema3.update(ema2)?;
+        Some(3.0 * ema1 - 3.0 * ema2 + ema3)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triple_exponential_moving_average() -> Result<(), TalibError> {
+        // TEMA has no lag on a straight line
+        let close: Vec<f64> = (0..20).map(|i| 3.0 + 0.5 * i as f64).collect();
+        let res = triple_exponential_moving_average_slice(&close, Some(4))?;
+        assert!(res[8].is_nan());
+        for i in 9..close.len() {
+            assert!((res[i] - close[i]).abs() < 1e-9);
+        }
+        Ok(())
+    }
+}
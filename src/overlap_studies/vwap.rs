@@ -0,0 +1,256 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/10 20:40
+ * @Email: uyplayer@qq.com
+ * @File: vwap.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Volume Weighted Average Price
+//!
+//! - typical price = (high + low + close) / 3
+//! - vwap = sum(typical price * volume) / sum(volume) since the start of the session
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_keys, series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a VWAP value, VWAP has no warm-up so it is always 0.
+pub fn vwap_lookback() -> usize {
+    0
+}
+
+/// Calculates the session anchored Volume Weighted Average Price (VWAP) over plain `high`, `low`,
+/// `close` and `volume` slices, the polars free core of [`vwap`].
+///
+/// A new session starts whenever the `session` key changes from one bar to the next, e.g. on a new
+/// trading day. Without `session` the whole input is one session. Bars before any volume in their
+/// session have no average and are `NaN`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::vwap_slice;
+///
+/// let high = [11.0, 13.0, 12.0];
+/// let low = [9.0, 11.0, 10.0];
+/// let close = [10.0, 12.0, 11.0];
+/// let volume = [100.0, 300.0, 200.0];
+/// let vwap = vwap_slice(&high, &low, &close, &volume, Some(&[1, 1, 2])).unwrap();
+/// assert_eq!(vwap, [10.0, 11.5, 11.0]);
+/// ```
+pub fn vwap_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    session: Option<&[i64]>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len(), volume.len(), session.map_or(high.len(), <[i64]>::len)] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    if high.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Vwap::new();
+    Ok((0..high.len())
+        .map(|i| {
+            if let Some(session) = session {
+                if i > 0 && session[i] != session[i - 1] {
+                    state.reset();
+                }
+            }
+            state.update(high[i], low[i], close[i], volume[i]).unwrap_or(f64::NAN)
+        })
+        .collect())
+}
+
+/// Calculates the session anchored Volume Weighted Average Price (VWAP).
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `session` - An optional integer, date or datetime Series, a new session starts whenever its
+///   value changes. A datetime is truncated to its calendar day (the local day in its own time zone
+///   for a zoned datetime), so the bar timestamps themselves can be passed for daily sessions. A null stays in the session of
+///   the bar before it. Without it the whole input is one session.
+///
+/// # Returns
+///
+/// The VWAP Series, null until the session has traded some volume.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::vwap;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let day = Series::new("day", [1i32, 1, 1, 2, 2, 2]);
+///  let res = vwap(&high, &low, &close, &volume, Some(&day));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn vwap(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    volume: &Series,
+    session: Option<&Series>,
+) -> Result<Series, TalibError> {
+    let session = session.map(series_to_keys).transpose()?;
+    let vwap = vwap_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        &series_to_vec(volume)?,
+        session.as_deref(),
+    )?;
+    Ok(vec_to_series("data", vwap))
+}
+
+/// Streaming session anchored Volume Weighted Average Price (VWAP).
+///
+/// Call [`Vwap::reset`] at the start of every session.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Vwap;
+///
+/// let mut vwap = Vwap::new();
+/// assert_eq!(vwap.update(11.0, 9.0, 10.0, 100.0), Some(10.0));
+/// assert_eq!(vwap.update(13.0, 11.0, 12.0, 300.0), Some(11.5));
+/// // a new session
+/// vwap.reset();
+/// assert_eq!(vwap.update(12.0, 10.0, 11.0, 200.0), Some(11.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Vwap {
+    weighted_sum: f64,
+    volume_sum: f64,
+}
+
+impl Vwap {
+    /// Creates a new VWAP state.
+    pub fn new() -> Self {
+        Vwap::default()
+    }
+
+    /// Feeds the next bar and returns the VWAP of the session so far,
+    /// `None` while the session has no volume.
+    pub fn update(&mut self, high: f64, low: f64, close: f64, volume: f64) -> Option<f64> {
+        self.weighted_sum += (high + low + close) / 3.0 * volume;
+        self.volume_sum += volume;
+        if self.volume_sum == 0.0 {
+            return None;
+        }
+        Some(self.weighted_sum / self.volume_sum)
+    }
+
+    /// Clears all accumulated values, which starts a new session.
+    pub fn reset(&mut self) {
+        self.weighted_sum = 0.0;
+        self.volume_sum = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwap() -> Result<(), TalibError> {
+        let high = [11.0, 13.0, 12.0, 12.0, 14.0];
+        let low = [9.0, 11.0, 10.0, 10.0, 12.0];
+        let close = [10.0, 12.0, 11.0, 11.0, 13.0];
+        let volume = [100.0, 300.0, 0.0, 200.0, 200.0];
+        let res = vwap_slice(&high, &low, &close, &volume, None)?;
+        assert_eq!(res, [10.0, 11.5, 11.5, 6800.0 / 600.0, 11.75]);
+        // the session without volume yet has no average
+        let res = vwap_slice(&high, &low, &close, &volume, Some(&[7, 7, 8, 8, 8]))?;
+        assert_eq!(res[..2], [10.0, 11.5]);
+        assert!(res[2].is_nan());
+        assert_eq!(res[3..], [11.0, 12.0]);
+        assert_eq!(vwap_lookback(), 0);
+        assert!(vwap_slice(&high, &low, &close, &volume, Some(&[1, 2])).is_err());
+        assert!(vwap_slice(&[], &[], &[], &[], None).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_vwap_session_series() -> Result<(), TalibError> {
+        let high = Series::new("high", [11.0, 13.0, 12.0, 14.0]);
+        let low = Series::new("low", [9.0, 11.0, 10.0, 12.0]);
+        let close = Series::new("close", [10.0, 12.0, 11.0, 13.0]);
+        let volume = Series::new("volume", [100i64, 300, 200, 200]);
+        let day = Series::new("day", [Some(1i64), None, Some(2), None]);
+        let res = vwap(&high, &low, &close, &volume, Some(&day))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [Some(10.0), Some(11.5), Some(11.0), Some(12.0)]);
+        let day = Series::new("day", ["a", "a", "b", "b"]);
+        assert!(vwap(&high, &low, &close, &volume, Some(&day)).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_vwap_intraday_timestamps() -> Result<(), TalibError> {
+        let high = Series::new("high", [11.0, 13.0, 12.0, 14.0]);
+        let low = Series::new("low", [9.0, 11.0, 10.0, 12.0]);
+        let close = Series::new("close", [10.0, 12.0, 11.0, 13.0]);
+        let volume = Series::new("volume", [100i64, 300, 200, 200]);
+        // 2023-10-23 09:30 and 15:55, then 2023-10-24 09:30 and 15:55 UTC, in milliseconds
+        let time = Series::new("time", [1_698_053_400_000i64, 1_698_076_500_000, 1_698_139_800_000, 1_698_162_900_000])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let res = vwap(&high, &low, &close, &volume, Some(&time))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [Some(10.0), Some(11.5), Some(11.0), Some(12.0)]);
+        let time = time.cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?;
+        let res = vwap(&high, &low, &close, &volume, Some(&time))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [Some(10.0), Some(11.5), Some(11.0), Some(12.0)]);
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_vwap_zoned_timestamps() -> Result<(), TalibError> {
+        let price = Series::new("price", [10.0, 12.0, 11.0]);
+        let volume = Series::new("volume", [100i64, 100, 100]);
+        // 2023-10-23 14:00, 2023-10-24 00:30 and 14:00 UTC: the first two bars are both on
+        // 2023-10-23 in New York, the last two share a UTC day
+        let time = Series::new("time", [1_698_069_600_000i64, 1_698_107_400_000, 1_698_156_000_000])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, Some("America/New_York".into())))?;
+        let res = vwap(&price, &price, &price, &volume, Some(&time))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [Some(10.0), Some(11.0), Some(11.0)]);
+        let time = time.cast(&DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".into())))?;
+        let res = vwap(&price, &price, &price, &volume, Some(&time))?;
+        let res: Vec<Option<f64>> = res.f64()?.into_iter().collect();
+        assert_eq!(res, [Some(10.0), Some(12.0), Some(11.5)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/10 20:05
+ * @Email: uyplayer@qq.com
+ * @File: vwma.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Volume Weighted Moving Average
+//!
+//! vwma = sum(price * volume) / sum(volume) over `time_period` bars
+
+use std::collections::VecDeque;
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a VWMA value, `time_period - 1`.
+pub fn vwma_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20).saturating_sub(1)
+}
+
+/// Calculates the Volume Weighted Moving Average (VWMA) over plain `src` and `volume` slices,
+/// the polars free core of [`vwma`].
+///
+/// Windows without any volume have no average and are `NaN`.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `src` and `volume` have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::vwma_slice;
+///
+/// let vwma = vwma_slice(&[10.0, 12.0, 11.0], &[100.0, 300.0, 0.0], Some(2)).unwrap();
+/// assert!(vwma[0].is_nan());
+/// assert_eq!(vwma[1], 11.5);
+/// assert_eq!(vwma[2], 12.0);
+/// ```
+pub fn vwma_slice(src: &[f64], volume: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    if volume.len() != src.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: src.len(),
+            got: volume.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = Vwma::new(time_period)?;
+    let lookback = vwma_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    Ok(src
+        .iter()
+        .zip(volume.iter())
+        .map(|(price, volume)| state.update(*price, *volume).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Volume Weighted Moving Average (VWMA).
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 20 if not provided.
+///
+/// # Returns
+///
+/// The VWMA Series, null for the first [`vwma_lookback`] values and for windows without volume.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::vwma;
+///
+///  let close = Series::new("close", [35.0, 10.0, 20.0, 56.0, 89.0, 76.0]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = vwma(&close, &volume, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn vwma(src: &Series, volume: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let vwma = vwma_slice(&series_to_vec(src)?, &series_to_vec(volume)?, time_period)?;
+    Ok(vec_to_series("data", vwma))
+}
+
+/// Streaming Volume Weighted Moving Average (VWMA).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Vwma;
+///
+/// let mut vwma = Vwma::new(2).unwrap();
+/// for (price, volume) in [(35.0, 1200.0), (10.0, 900.0), (20.0, 1500.0)] {
+///     eprintln!("{:?}", vwma.update(price, volume));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vwma {
+    time_period: usize,
+    window: VecDeque<(f64, f64)>,
+    weighted_sum: f64,
+    volume_sum: f64,
+}
+
+impl Vwma {
+    /// Creates a new VWMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Vwma {
+            time_period,
+            window: VecDeque::with_capacity(time_period + 1),
+            weighted_sum: 0.0,
+            volume_sum: 0.0,
+        })
+    }
+
+    /// Feeds the next price/volume pair and returns the current average once the window is full,
+    /// `None` while the window has no volume.
+    pub fn update(&mut self, price: f64, volume: f64) -> Option<f64> {
+        self.window.push_back((price, volume));
+        self.weighted_sum += price * volume;
+        self.volume_sum += volume;
+        if self.window.len() > self.time_period {
+            if let Some((old_price, old_volume)) = self.window.pop_front() {
+                self.weighted_sum -= old_price * old_volume;
+                self.volume_sum -= old_volume;
+            }
+        }
+        if self.window.len() < self.time_period || self.volume_sum == 0.0 {
+            return None;
+        }
+        Some(self.weighted_sum / self.volume_sum)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.weighted_sum = 0.0;
+        self.volume_sum = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwma() -> Result<(), TalibError> {
+        let res = vwma_slice(&[10.0, 12.0, 11.0, 13.0, 14.0], &[100.0, 300.0, 0.0, 0.0, 100.0], Some(2))?;
+        assert!(res[0].is_nan());
+        assert_eq!(res[1..3], [11.5, 12.0]);
+        // no volume in the window
+        assert!(res[3].is_nan());
+        assert_eq!(res[4], 14.0);
+        // equal volumes make it a simple moving average
+        let close = [35.0, 10.0, 20.0, 56.0, 89.0, 76.0];
+        let equal = vwma_slice(&close, &[5.0; 6], Some(3))?;
+        let sma = crate::simple_moving_average_slice(&close, Some(3))?;
+        assert!(equal[2..].iter().zip(sma[2..].iter()).all(|(a, b)| (a - b).abs() < 1e-9));
+        assert!(vwma_slice(&close, &[5.0; 5], Some(3)).is_err());
+        assert!(vwma_slice(&close, &[5.0; 6], Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/12 16:20
+ * @Email: uyplayer@qq.com
+ * @File: weighted_moving_average.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/overlap_studies
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! weighted moving average
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+use std::collections::VecDeque;
+
+/// Number of leading bars without a WMA value, `time_period - 1` like TA-Lib.
+pub fn weighted_moving_average_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(30).saturating_sub(1)
+}
+
+/// Calculates the Weighted Moving Average (WMA) over a plain slice,
+/// the polars free core of [`weighted_moving_average`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::weighted_moving_average_slice;
+///
+/// let wma = weighted_moving_average_slice(&[1.0, 2.0, 3.0, 4.0, 5.0], Some(3)).unwrap();
+/// assert!(wma[1].is_nan());
+/// assert_eq!(wma[2], 14.0 / 6.0);
+/// ```
+pub fn weighted_moving_average_slice(src: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(30);
+    let lookback = weighted_moving_average_lookback(Some(time_period));
+    if src.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: src.len(),
+        });
+    }
+    let mut state = Wma::new(time_period)?;
+    Ok(src
+        .iter()
+        .map(|value| state.update(*value).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Weighted Moving Average (WMA)
+///
+/// The latest value has the weight `time_period`, the oldest one the weight 1:
+/// `sum(i * price[i]) / (time_period * (time_period + 1) / 2)`
+///
+/// # Arguments
+///
+/// * `src` - A Series containing the source data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 30 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::weighted_moving_average;
+///
+///  let random_data: [i32; 10] = [35, 10, 20, 56, 89, 76, 30, 46, 10, 653];
+///  let close = Series::new("data", random_data);
+///  let res = weighted_moving_average(&close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn weighted_moving_average(src: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let wma = weighted_moving_average_slice(&series_to_vec(src)?, time_period)?;
+    Ok(vec_to_series(src.name(), wma))
+}
+
+/// Streaming weighted moving average (WMA).
+///
+/// Keeps the plain and the weighted sum of the window so each update is O(1).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Wma;
+///
+/// let mut wma = Wma::new(3).unwrap();
+/// for price in [35.0, 10.0, 20.0, 56.0] {
+///     eprintln!("{:?}", wma.update(price));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wma {
+    time_period: usize,
+    divider: f64,
+    window: VecDeque<f64>,
+    sum: f64,
+    weighted_sum: f64,
+}
+
+impl Wma {
+    /// Creates a new WMA state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Wma {
+            time_period,
+            divider: (time_period * (time_period + 1)) as f64 / 2.0,
+            window: VecDeque::with_capacity(time_period + 1),
+            sum: 0.0,
+            weighted_sum: 0.0,
+        })
+    }
+
+    /// Feeds the next value and returns the current average once the window is full.
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        if self.window.len() < self.time_period {
+            self.window.push_back(value);
+            self.sum += value;
+            self.weighted_sum += value * self.window.len() as f64;
+        } else {
+            // every value loses one weight, the new one gets the full weight
+            self.weighted_sum += value * self.time_period as f64 - self.sum;
+            self.window.push_back(value);
+            self.sum += value;
+            if let Some(old) = self.window.pop_front() {
+                self.sum -= old;
+            }
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        Some(self.weighted_sum / self.divider)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.weighted_sum = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_moving_average() -> Result<(), TalibError> {
+        let close = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let res = weighted_moving_average_slice(&close, Some(3))?;
+        let expected = [14.0 / 6.0, 20.0 / 6.0, 26.0 / 6.0];
+        for (i, value) in expected.iter().enumerate() {
+            assert!((res[i + 2] - value).abs() < 1e-12);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/29 10:20
+ * @Email: uyplayer@qq.com
+ * @File: candle_settings.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Candle settings
+//!
+//! What a pattern calls a long body, a doji or a near price is relative to the recent candles, like TA-Lib:
+//! - average = factor * (sum of the ranges of the `avg_period` candles before) / avg_period
+//! - with an `avg_period` of 0 the range of the candle itself is used
+//! - a [`RangeType::Shadows`] average is halved, it measures one shadow
+
+use crate::TalibError;
+
+/// The part of a candle a [`CandleSetting`] measures, the discriminants are the TA-Lib `RangeType` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeType {
+    /// `|close - open|`
+    RealBody = 0,
+    /// `high - low`
+    HighLow = 1,
+    /// upper shadow + lower shadow
+    Shadows = 2,
+}
+
+/// The configurable candle settings, the discriminants are the TA-Lib `CandleSettingType` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandleSettingType {
+    /// Real body is long when it's longer than the average of the 10 previous candles' real body
+    BodyLong = 0,
+    /// Real body is very long when it's longer than 3 times the average of the 10 previous candles' real body
+    BodyVeryLong = 1,
+    /// Real body is short when it's shorter than the average of the 10 previous candles' real bodies
+    BodyShort = 2,
+    /// Real body is like doji's body when it's shorter than 10% the average of the 10 previous candles' high-low range
+    BodyDoji = 3,
+    /// Shadow is long when it's longer than the real body
+    ShadowLong = 4,
+    /// Shadow is very long when it's longer than 2 times the real body
+    ShadowVeryLong = 5,
+    /// Shadow is short when it's shorter than half the average of the 10 previous candles' sum of shadows
+    ShadowShort = 6,
+    /// Shadow is very short when it's shorter than 10% the average of the 10 previous candles' high-low range
+    ShadowVeryShort = 7,
+    /// When measuring distance between parts of candles or width of gaps
+    /// "near" means "<= 20% of the average of the 5 previous candles' high-low range"
+    Near = 8,
+    /// When measuring distance between parts of candles or width of gaps
+    /// "far" means ">= 60% of the average of the 5 previous candles' high-low range"
+    Far = 9,
+    /// When measuring distance between parts of candles or width of gaps
+    /// "equal" means "<= 5% of the average of the 5 previous candles' high-low range"
+    Equal = 10,
+}
+
+/// How one [`CandleSettingType`] is measured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleSetting {
+    /// The part of the candles averaged.
+    pub range_type: RangeType,
+    /// Number of previous candles averaged, 0 for the candle itself.
+    pub avg_period: usize,
+    /// Multiplier of the average.
+    pub factor: f64,
+}
+
+impl CandleSetting {
+    /// Creates a candle setting.
+    pub fn new(range_type: RangeType, avg_period: usize, factor: f64) -> Self {
+        CandleSetting {
+            range_type,
+            avg_period,
+            factor,
+        }
+    }
+}
+
+/// The candle settings used by the pattern recognition functions, TA-Lib's defaults unless changed.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::{CandleSetting, CandleSettingType, CandleSettings, RangeType};
+///
+/// let mut settings = CandleSettings::new();
+/// assert_eq!(settings.get(CandleSettingType::BodyDoji), CandleSetting::new(RangeType::HighLow, 10, 0.1));
+/// // a doji body is up to 5% of the average range of the last 20 candles
+/// settings.set(CandleSettingType::BodyDoji, CandleSetting::new(RangeType::HighLow, 20, 0.05)).unwrap();
+/// assert!(settings.set(CandleSettingType::Near, CandleSetting::new(RangeType::HighLow, 5, -1.0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleSettings {
+    settings: [CandleSetting; 11],
+}
+
+impl Default for CandleSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CandleSettings {
+    /// Creates the TA-Lib default candle settings.
+    pub fn new() -> Self {
+        use RangeType::*;
+        CandleSettings {
+            settings: [
+                CandleSetting::new(RealBody, 10, 1.0),
+                CandleSetting::new(RealBody, 10, 3.0),
+                CandleSetting::new(RealBody, 10, 1.0),
+                CandleSetting::new(HighLow, 10, 0.1),
+                CandleSetting::new(RealBody, 0, 1.0),
+                CandleSetting::new(RealBody, 0, 2.0),
+                CandleSetting::new(Shadows, 10, 1.0),
+                CandleSetting::new(HighLow, 10, 0.1),
+                CandleSetting::new(HighLow, 5, 0.2),
+                CandleSetting::new(HighLow, 5, 0.6),
+                CandleSetting::new(HighLow, 5, 0.05),
+            ],
+        }
+    }
+
+    /// Returns the setting of `kind`.
+    pub fn get(&self, kind: CandleSettingType) -> CandleSetting {
+        self.settings[kind as usize]
+    }
+
+    /// Replaces the setting of `kind`, returns an error if the factor is negative or not finite.
+    pub fn set(&mut self, kind: CandleSettingType, setting: CandleSetting) -> Result<(), TalibError> {
+        if !setting.factor.is_finite() || setting.factor < 0.0 {
+            return Err(TalibError::InvalidParameter {
+                name: "factor",
+                value: setting.factor,
+                reason: "must be greater than or equal to 0",
+            });
+        }
+        self.settings[kind as usize] = setting;
+        Ok(())
+    }
+
+    /// The longest average period of `kinds`.
+    pub(crate) fn avg_period(&self, kinds: &[CandleSettingType]) -> usize {
+        kinds.iter().map(|kind| self.get(*kind).avg_period).max().unwrap_or(0)
+    }
+}
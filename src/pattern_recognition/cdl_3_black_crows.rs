@@ -0,0 +1,215 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 13:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_black_crows.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three Black Crows
+//!
+//! - a white candle
+//! - three black candles with no, or very short, lower shadows (ShadowVeryShort), the first closing under the high
+//!   of the white candle
+//! - each black candle opening within the real body of the previous one and closing lower
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeBlackCrows;
+
+impl CandlePattern for ThreeBlackCrows {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::ShadowVeryShort];
+    const CANDLES: usize = 4;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let white = window.candle(3);
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if white.color() == 1
+            && (0..3).all(|ago| {
+                window.candle(ago).color() == -1
+                    && window.candle(ago).lower_shadow() < window.average(CandleSettingType::ShadowVeryShort, ago)
+            })
+            && second.open < first.open
+            && second.open > first.close
+            && third.open < second.open
+            && third.open > second.close
+            && white.high > first.close
+            && first.close > second.close
+            && second.close > third.close
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the ShadowVeryShort average period plus 3.
+pub fn cdl_3_black_crows_lookback(settings: Option<&CandleSettings>) -> usize {
+    ThreeBlackCrows::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Three Black Crows (CDL3BLACKCROWS) over plain OHLC slices, the polars free core of [`cdl_3_black_crows`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_black_crows_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_black_crows_slice;
+///
+/// let mut open = vec![10.0; 14];
+/// let mut high = vec![10.6; 14];
+/// let mut low = vec![9.6; 14];
+/// let mut close = vec![10.2; 14];
+/// // three black candles, each opening in the previous body and closing at its low
+/// (open[11], high[11], low[11], close[11]) = (10.5, 10.5, 9.9, 9.9);
+/// (open[12], high[12], low[12], close[12]) = (10.2, 10.2, 9.6, 9.6);
+/// (open[13], high[13], low[13], close[13]) = (9.9, 9.9, 9.3, 9.3);
+/// let res = cdl_3_black_crows_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[12], None);
+/// assert_eq!(res[13], Some(-100));
+/// ```
+pub fn cdl_3_black_crows_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeBlackCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three Black Crows (CDL3BLACKCROWS).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_3_black_crows_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_black_crows function does
+///     import talib
+///     res = talib.CDL3BLACKCROWS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_black_crows;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_black_crows(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_black_crows(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeBlackCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three Black Crows (CDL3BLACKCROWS).
+///
+/// Returns `None` for the first [`cdl_3_black_crows_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3BlackCrows;
+///
+/// let mut crows = Cdl3BlackCrows::new();
+/// for _ in 0..11 {
+///     assert_eq!(crows.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(crows.update(10.5, 10.5, 9.9, 9.9), None);
+/// assert_eq!(crows.update(10.2, 10.2, 9.6, 9.6), None);
+/// assert_eq!(crows.update(9.9, 9.9, 9.3, 9.3), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3BlackCrows {
+    engine: PatternEngine<ThreeBlackCrows>,
+}
+
+impl Default for Cdl3BlackCrows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3BlackCrows {
+    /// Creates a new CDL3BLACKCROWS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3BLACKCROWS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3BlackCrows {
+            engine: PatternEngine::new(ThreeBlackCrows, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_black_crows() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 15];
+        let mut high = vec![10.6; 15];
+        let mut low = vec![9.6; 15];
+        let mut close = vec![10.2; 15];
+        (open[11], high[11], low[11], close[11]) = (10.5, 10.5, 9.9, 9.9);
+        (open[12], high[12], low[12], close[12]) = (10.2, 10.2, 9.6, 9.6);
+        (open[13], high[13], low[13], close[13]) = (9.9, 9.9, 9.3, 9.3);
+        // a fourth crow does not follow a white candle
+        (open[14], high[14], low[14], close[14]) = (9.6, 9.6, 9.0, 9.0);
+        let res = cdl_3_black_crows_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_3_black_crows_lookback(None), 13);
+        assert!(res[..13].iter().all(|value| value.is_none()));
+        assert_eq!(res[13..], [Some(-100), Some(0)]);
+        assert!(cdl_3_black_crows_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 14:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_inside.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three Inside Up/Down
+//!
+//! - a long candle (BodyLong)
+//! - a short candle (BodyShort) whose real body is engulfed by the first one, a harami
+//! - a candle of the opposite color to the first one, closing beyond its open
+//!
+//! 100 for three inside up after a black candle, -100 for three inside down after a white candle.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeInside;
+
+impl CandlePattern for ThreeInside {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong, CandleSettingType::BodyShort];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && second.real_body() <= window.average(CandleSettingType::BodyShort, 1)
+            && second.body_top() < first.body_top()
+            && second.body_bottom() > first.body_bottom()
+            && ((first.color() == 1 && third.color() == -1 && third.close < first.open)
+                || (first.color() == -1 && third.color() == 1 && third.close > first.open))
+        {
+            -first.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_3_inside_lookback(settings: Option<&CandleSettings>) -> usize {
+    ThreeInside::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Three Inside Up/Down (CDL3INSIDE) over plain OHLC slices, the polars free core of [`cdl_3_inside`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_inside_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_inside_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // a long white candle, a small body inside it, then a black candle closing under its open
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.3, 10.4, 10.1, 10.2);
+/// (open[12], high[12], low[12], close[12]) = (10.2, 10.25, 9.4, 9.5);
+/// let res = cdl_3_inside_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_3_inside_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeInside, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three Inside Up/Down (CDL3INSIDE).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_3_inside_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_inside function does
+///     import talib
+///     res = talib.CDL3INSIDE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_inside;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_inside(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_inside(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeInside, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three Inside Up/Down (CDL3INSIDE).
+///
+/// Returns `None` for the first [`cdl_3_inside_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3Inside;
+///
+/// let mut inside = Cdl3Inside::new();
+/// for _ in 0..10 {
+///     assert_eq!(inside.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(inside.update(9.6, 10.7, 9.5, 10.6), None);
+/// assert_eq!(inside.update(10.3, 10.4, 10.1, 10.2), None);
+/// assert_eq!(inside.update(10.2, 10.25, 9.4, 9.5), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3Inside {
+    engine: PatternEngine<ThreeInside>,
+}
+
+impl Default for Cdl3Inside {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3Inside {
+    /// Creates a new CDL3INSIDE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3INSIDE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3Inside {
+            engine: PatternEngine::new(ThreeInside, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_inside() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 14];
+        let mut high = vec![10.6; 14];
+        let mut low = vec![9.6; 14];
+        let mut close = vec![10.2; 14];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.3, 10.4, 10.1, 10.2);
+        (open[12], high[12], low[12], close[12]) = (10.2, 10.25, 9.4, 9.5);
+        let res = cdl_3_inside_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_3_inside_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12..], [Some(-100), Some(0)]);
+        // the third candle closes inside the first real body
+        close[12] = 9.7;
+        let res = cdl_3_inside_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_3_inside_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 10:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_line_strike.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three-Line Strike
+//!
+//! - three candles of the same color with consecutively higher closes when white, lower closes when black
+//! - the second and third candles opening within or near the real body of the previous one (Near)
+//! - a candle of the opposite color opening beyond the third close and closing beyond the first open
+//!
+//! 100 when it strikes three white candles, -100 when it strikes three black ones.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeLineStrike;
+
+impl CandlePattern for ThreeLineStrike {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::Near];
+    const CANDLES: usize = 4;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third, fourth) = (window.candle(3), window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == second.color()
+            && second.color() == third.color()
+            && fourth.color() == -third.color()
+            && second.open >= first.body_bottom() - window.average(CandleSettingType::Near, 3)
+            && second.open <= first.body_top() + window.average(CandleSettingType::Near, 3)
+            && third.open >= second.body_bottom() - window.average(CandleSettingType::Near, 2)
+            && third.open <= second.body_top() + window.average(CandleSettingType::Near, 2)
+            && ((third.color() == 1
+                && third.close > second.close
+                && second.close > first.close
+                && fourth.open > third.close
+                && fourth.close < first.open)
+                || (third.color() == -1
+                    && third.close < second.close
+                    && second.close < first.close
+                    && fourth.open < third.close
+                    && fourth.close > first.open))
+        {
+            third.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the Near average period plus 3.
+pub fn cdl_3_line_strike_lookback(settings: Option<&CandleSettings>) -> usize {
+    ThreeLineStrike::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Three-Line Strike (CDL3LINESTRIKE) over plain OHLC slices, the polars free core of [`cdl_3_line_strike`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_line_strike_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_line_strike_slice;
+///
+/// let mut open = vec![10.0; 9];
+/// let mut high = vec![10.6; 9];
+/// let mut low = vec![9.6; 9];
+/// let mut close = vec![10.2; 9];
+/// // three white candles, then a black one opening above them and closing under the first open
+/// (open[5], high[5], low[5], close[5]) = (10.0, 10.45, 9.95, 10.4);
+/// (open[6], high[6], low[6], close[6]) = (10.3, 10.75, 10.25, 10.7);
+/// (open[7], high[7], low[7], close[7]) = (10.6, 11.05, 10.55, 11.0);
+/// (open[8], high[8], low[8], close[8]) = (11.1, 11.2, 9.8, 9.9);
+/// let res = cdl_3_line_strike_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[7], None);
+/// assert_eq!(res[8], Some(100));
+/// ```
+pub fn cdl_3_line_strike_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeLineStrike, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three-Line Strike (CDL3LINESTRIKE).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_3_line_strike_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_line_strike function does
+///     import talib
+///     res = talib.CDL3LINESTRIKE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_line_strike;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_line_strike(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_line_strike(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeLineStrike, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three-Line Strike (CDL3LINESTRIKE).
+///
+/// Returns `None` for the first [`cdl_3_line_strike_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3LineStrike;
+///
+/// let mut strike = Cdl3LineStrike::new();
+/// for _ in 0..5 {
+///     assert_eq!(strike.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(strike.update(10.0, 10.45, 9.95, 10.4), None);
+/// assert_eq!(strike.update(10.3, 10.75, 10.25, 10.7), None);
+/// assert_eq!(strike.update(10.6, 11.05, 10.55, 11.0), None);
+/// assert_eq!(strike.update(11.1, 11.2, 9.8, 9.9), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3LineStrike {
+    engine: PatternEngine<ThreeLineStrike>,
+}
+
+impl Default for Cdl3LineStrike {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3LineStrike {
+    /// Creates a new CDL3LINESTRIKE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3LINESTRIKE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3LineStrike {
+            engine: PatternEngine::new(ThreeLineStrike, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_line_strike() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[5], high[5], low[5], close[5]) = (10.0, 10.45, 9.95, 10.4);
+        (open[6], high[6], low[6], close[6]) = (10.3, 10.75, 10.25, 10.7);
+        (open[7], high[7], low[7], close[7]) = (10.6, 11.05, 10.55, 11.0);
+        (open[8], high[8], low[8], close[8]) = (11.1, 11.2, 9.8, 9.9);
+        (open[9], high[9], low[9], close[9]) = (10.2, 10.25, 9.75, 9.8);
+        (open[10], high[10], low[10], close[10]) = (9.9, 9.95, 9.45, 9.5);
+        (open[11], high[11], low[11], close[11]) = (9.6, 9.65, 9.15, 9.2);
+        (open[12], high[12], low[12], close[12]) = (9.1, 10.4, 9.0, 10.3);
+        let res = cdl_3_line_strike_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_3_line_strike_lookback(None), 8);
+        assert!(res[..8].iter().all(|value| value.is_none()));
+        assert_eq!(res[8..], [Some(100), Some(0), Some(0), Some(0), Some(-100)]);
+        // the last strike does not close above the first open
+        close[12] = 10.1;
+        let res = cdl_3_line_strike_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_3_line_strike_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 14:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_outside.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three Outside Up/Down
+//!
+//! - an engulfing pattern: a real body engulfing the real body of the previous candle, of the opposite color
+//! - a third candle closing beyond the close of the engulfing one
+//!
+//! 100 for three outside up, -100 for three outside down.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeOutside;
+
+impl CandlePattern for ThreeOutside {
+    const SETTINGS: &'static [CandleSettingType] = &[];
+    // TA-Lib's lookback is 3 although the pattern is made of 3 candles
+    const CANDLES: usize = 4;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if (second.color() == 1
+            && first.color() == -1
+            && second.close > first.open
+            && second.open < first.close
+            && third.close > second.close)
+            || (second.color() == -1
+                && first.color() == 1
+                && second.open > first.close
+                && second.close < first.open
+                && third.close < second.close)
+        {
+            second.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, 3 like TA-Lib.
+pub fn cdl_3_outside_lookback() -> usize {
+    ThreeOutside::lookback(&CandleSettings::new())
+}
+
+/// Recognizes the Three Outside Up/Down (CDL3OUTSIDE) over plain OHLC slices, the polars free core of [`cdl_3_outside`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_outside_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_outside_slice;
+///
+/// let open = [10.0, 10.2, 9.8, 10.4];
+/// let high = [10.6, 10.3, 10.5, 10.7];
+/// let low = [9.6, 9.8, 9.7, 10.3];
+/// let close = [10.2, 9.9, 10.4, 10.6];
+/// // a bullish engulfing pattern confirmed by a higher close
+/// let res = cdl_3_outside_slice(&open, &high, &low, &close).unwrap();
+/// assert_eq!(res, [None, None, None, Some(100)]);
+/// ```
+pub fn cdl_3_outside_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeOutside, &CandleSettings::new());
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three Outside Up/Down (CDL3OUTSIDE).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_3_outside_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_outside function does
+///     import talib
+///     res = talib.CDL3OUTSIDE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_outside;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_outside(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_outside(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeOutside, &CandleSettings::new());
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three Outside Up/Down (CDL3OUTSIDE).
+///
+/// Returns `None` for the first [`cdl_3_outside_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3Outside;
+///
+/// let mut outside = Cdl3Outside::new();
+/// assert_eq!(outside.update(10.0, 10.6, 9.6, 10.2), None);
+/// assert_eq!(outside.update(10.2, 10.3, 9.8, 9.9), None);
+/// assert_eq!(outside.update(9.8, 10.5, 9.7, 10.4), None);
+/// assert_eq!(outside.update(10.4, 10.7, 10.3, 10.6), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3Outside {
+    engine: PatternEngine<ThreeOutside>,
+}
+
+impl Default for Cdl3Outside {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3Outside {
+    /// Creates a new CDL3OUTSIDE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3OUTSIDE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3Outside {
+            engine: PatternEngine::new(ThreeOutside, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_outside() -> Result<(), TalibError> {
+        let open = [10.0, 10.2, 9.8, 10.4, 10.7, 9.7, 9.5];
+        let high = [10.6, 10.3, 10.5, 10.7, 10.8, 9.8, 9.8];
+        let low = [9.6, 9.8, 9.7, 10.3, 9.6, 9.4, 9.4];
+        let close = [10.2, 9.9, 10.4, 10.6, 9.7, 9.5, 9.6];
+        let res = cdl_3_outside_slice(&open, &high, &low, &close)?;
+        assert_eq!(cdl_3_outside_lookback(), 3);
+        // a bearish engulfing at 4 confirmed by a lower close at 5
+        assert_eq!(res, [None, None, None, Some(100), Some(0), Some(-100), Some(0)]);
+        assert!(cdl_3_outside_slice(&open, &high, &low, &close[1..]).is_err());
+        assert!(cdl_3_outside_slice(&open[..3], &high[..3], &low[..3], &close[..3]).is_err());
+        Ok(())
+    }
+}
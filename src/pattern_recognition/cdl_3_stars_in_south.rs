@@ -0,0 +1,225 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 11:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_stars_in_south.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three Stars In The South
+//!
+//! - a long black candle (BodyLong) with a long lower shadow (ShadowLong)
+//! - a smaller black candle opening above the first close within its range, trading under the first close but not
+//!   under its low, with a lower shadow (ShadowVeryShort)
+//! - a short black marubozu (BodyShort, ShadowVeryShort) within the range of the second candle
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeStarsInSouth;
+
+impl CandlePattern for ThreeStarsInSouth {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::BodyLong,
+        CandleSettingType::ShadowLong,
+        CandleSettingType::ShadowVeryShort,
+        CandleSettingType::BodyShort,
+    ];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == -1
+            && second.color() == -1
+            && third.color() == -1
+            && first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && first.lower_shadow() > window.average(CandleSettingType::ShadowLong, 2)
+            && second.real_body() < first.real_body()
+            && second.open > first.close
+            && second.open <= first.high
+            && second.low < first.close
+            && second.low >= first.low
+            && second.lower_shadow() > window.average(CandleSettingType::ShadowVeryShort, 1)
+            && third.real_body() < window.average(CandleSettingType::BodyShort, 0)
+            && third.lower_shadow() < window.average(CandleSettingType::ShadowVeryShort, 0)
+            && third.upper_shadow() < window.average(CandleSettingType::ShadowVeryShort, 0)
+            && third.low > second.low
+            && third.high < second.high
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_3_stars_in_south_lookback(settings: Option<&CandleSettings>) -> usize {
+    ThreeStarsInSouth::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Three Stars In The South (CDL3STARSINSOUTH) over plain OHLC slices, the polars free core of [`cdl_3_stars_in_south`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_stars_in_south_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_stars_in_south_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // a long black candle with a long lower shadow, a smaller one above its low, then a small black marubozu
+/// (open[10], high[10], low[10], close[10]) = (10.5, 10.55, 9.3, 10.0);
+/// (open[11], high[11], low[11], close[11]) = (10.3, 10.35, 9.6, 10.1);
+/// (open[12], high[12], low[12], close[12]) = (10.0, 10.02, 9.9, 9.92);
+/// let res = cdl_3_stars_in_south_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(100));
+/// ```
+pub fn cdl_3_stars_in_south_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeStarsInSouth, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three Stars In The South (CDL3STARSINSOUTH).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_3_stars_in_south_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_stars_in_south function does
+///     import talib
+///     res = talib.CDL3STARSINSOUTH(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_stars_in_south;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_stars_in_south(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_stars_in_south(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeStarsInSouth, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three Stars In The South (CDL3STARSINSOUTH).
+///
+/// Returns `None` for the first [`cdl_3_stars_in_south_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3StarsInSouth;
+///
+/// let mut stars = Cdl3StarsInSouth::new();
+/// for _ in 0..10 {
+///     assert_eq!(stars.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(stars.update(10.5, 10.55, 9.3, 10.0), None);
+/// assert_eq!(stars.update(10.3, 10.35, 9.6, 10.1), None);
+/// assert_eq!(stars.update(10.0, 10.02, 9.9, 9.92), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3StarsInSouth {
+    engine: PatternEngine<ThreeStarsInSouth>,
+}
+
+impl Default for Cdl3StarsInSouth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3StarsInSouth {
+    /// Creates a new CDL3STARSINSOUTH state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3STARSINSOUTH state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3StarsInSouth {
+            engine: PatternEngine::new(ThreeStarsInSouth, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_stars_in_south() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.5, 10.55, 9.3, 10.0);
+        (open[11], high[11], low[11], close[11]) = (10.3, 10.35, 9.6, 10.1);
+        (open[12], high[12], low[12], close[12]) = (10.0, 10.02, 9.9, 9.92);
+        let res = cdl_3_stars_in_south_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_3_stars_in_south_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(100));
+        // the second candle trades under the first low
+        low[11] = 9.2;
+        let res = cdl_3_stars_in_south_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_3_stars_in_south_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 13:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_3_white_soldiers.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Three Advancing White Soldiers
+//!
+//! - three white candles with consecutively higher closes and very short upper shadows (ShadowVeryShort)
+//! - each candle opening within or near the real body of the previous one (Near)
+//! - no real body far shorter than the previous one (Far), the last one not short (BodyShort)
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ThreeWhiteSoldiers;
+
+impl CandlePattern for ThreeWhiteSoldiers {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::ShadowVeryShort,
+        CandleSettingType::BodyShort,
+        CandleSettingType::Far,
+        CandleSettingType::Near,
+    ];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if (0..3).all(|ago| {
+            window.candle(ago).color() == 1
+                && window.candle(ago).upper_shadow() < window.average(CandleSettingType::ShadowVeryShort, ago)
+        }) && third.close > second.close
+            && second.close > first.close
+            && second.open > first.open
+            && second.open <= first.close + window.average(CandleSettingType::Near, 2)
+            && third.open > second.open
+            && third.open <= second.close + window.average(CandleSettingType::Near, 1)
+            && second.real_body() > first.real_body() - window.average(CandleSettingType::Far, 2)
+            && third.real_body() > second.real_body() - window.average(CandleSettingType::Far, 1)
+            && third.real_body() > window.average(CandleSettingType::BodyShort, 0)
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_3_white_soldiers_lookback(settings: Option<&CandleSettings>) -> usize {
+    ThreeWhiteSoldiers::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Three Advancing White Soldiers (CDL3WHITESOLDIERS) over plain OHLC slices, the polars free core of [`cdl_3_white_soldiers`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_3_white_soldiers_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_3_white_soldiers_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // three white candles, each opening in the previous body and closing near its high
+/// (open[10], high[10], low[10], close[10]) = (10.0, 10.55, 9.95, 10.5);
+/// (open[11], high[11], low[11], close[11]) = (10.4, 10.95, 10.35, 10.9);
+/// (open[12], high[12], low[12], close[12]) = (10.8, 11.35, 10.75, 11.3);
+/// let res = cdl_3_white_soldiers_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(100));
+/// ```
+pub fn cdl_3_white_soldiers_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ThreeWhiteSoldiers, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Three Advancing White Soldiers (CDL3WHITESOLDIERS).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_3_white_soldiers_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_3_white_soldiers function does
+///     import talib
+///     res = talib.CDL3WHITESOLDIERS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_3_white_soldiers;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_3_white_soldiers(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_3_white_soldiers(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ThreeWhiteSoldiers, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Three Advancing White Soldiers (CDL3WHITESOLDIERS).
+///
+/// Returns `None` for the first [`cdl_3_white_soldiers_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Cdl3WhiteSoldiers;
+///
+/// let mut soldiers = Cdl3WhiteSoldiers::new();
+/// for _ in 0..10 {
+///     assert_eq!(soldiers.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(soldiers.update(10.0, 10.55, 9.95, 10.5), None);
+/// assert_eq!(soldiers.update(10.4, 10.95, 10.35, 10.9), None);
+/// assert_eq!(soldiers.update(10.8, 11.35, 10.75, 11.3), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cdl3WhiteSoldiers {
+    engine: PatternEngine<ThreeWhiteSoldiers>,
+}
+
+impl Default for Cdl3WhiteSoldiers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cdl3WhiteSoldiers {
+    /// Creates a new CDL3WHITESOLDIERS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDL3WHITESOLDIERS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        Cdl3WhiteSoldiers {
+            engine: PatternEngine::new(ThreeWhiteSoldiers, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_3_white_soldiers() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.0, 10.55, 9.95, 10.5);
+        (open[11], high[11], low[11], close[11]) = (10.4, 10.95, 10.35, 10.9);
+        (open[12], high[12], low[12], close[12]) = (10.8, 11.35, 10.75, 11.3);
+        let res = cdl_3_white_soldiers_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_3_white_soldiers_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(100));
+        // the third soldier has a long upper shadow
+        high[12] = 11.6;
+        let res = cdl_3_white_soldiers_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_3_white_soldiers_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
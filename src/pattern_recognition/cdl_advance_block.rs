@@ -0,0 +1,232 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 11:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_advance_block.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Advance Block
+//!
+//! - three white candles with consecutively higher closes, each opening within or near the real body of the
+//!   previous one (Near)
+//! - the first one long (BodyLong) with a short upper shadow (ShadowShort)
+//! - the advance weakening: the second real body far shorter than the first (Far), or the third far shorter than
+//!   the second, or shrinking real bodies with an upper shadow that is not short (ShadowShort), or a shorter third
+//!   real body with a long upper shadow (ShadowLong)
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct AdvanceBlock;
+
+impl CandlePattern for AdvanceBlock {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::ShadowLong,
+        CandleSettingType::ShadowShort,
+        CandleSettingType::Far,
+        CandleSettingType::Near,
+        CandleSettingType::BodyLong,
+    ];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == 1
+            && second.color() == 1
+            && third.color() == 1
+            && third.close > second.close
+            && second.close > first.close
+            && second.open > first.open
+            && second.open <= first.close + window.average(CandleSettingType::Near, 2)
+            && third.open > second.open
+            && third.open <= second.close + window.average(CandleSettingType::Near, 1)
+            && first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && first.upper_shadow() < window.average(CandleSettingType::ShadowShort, 2)
+            && ((second.real_body() < first.real_body() - window.average(CandleSettingType::Far, 2)
+                && third.real_body() < second.real_body() + window.average(CandleSettingType::Near, 1))
+                || third.real_body() < second.real_body() - window.average(CandleSettingType::Far, 1)
+                || (third.real_body() < second.real_body()
+                    && second.real_body() < first.real_body()
+                    && (third.upper_shadow() > window.average(CandleSettingType::ShadowShort, 0)
+                        || second.upper_shadow() > window.average(CandleSettingType::ShadowShort, 1)))
+                || (third.real_body() < second.real_body()
+                    && third.upper_shadow() > window.average(CandleSettingType::ShadowLong, 0)))
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_advance_block_lookback(settings: Option<&CandleSettings>) -> usize {
+    AdvanceBlock::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Advance Block (CDLADVANCEBLOCK) over plain OHLC slices, the polars free core of [`cdl_advance_block`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_advance_block_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_advance_block_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // three white candles with shrinking real bodies, the last one with a long upper shadow
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.65, 9.55, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.3, 11.1, 10.25, 10.9);
+/// (open[12], high[12], low[12], close[12]) = (10.7, 11.4, 10.65, 11.0);
+/// let res = cdl_advance_block_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_advance_block_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(AdvanceBlock, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Advance Block (CDLADVANCEBLOCK).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_advance_block_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_advance_block function does
+///     import talib
+///     res = talib.CDLADVANCEBLOCK(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_advance_block;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_advance_block(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_advance_block(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(AdvanceBlock, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Advance Block (CDLADVANCEBLOCK).
+///
+/// Returns `None` for the first [`cdl_advance_block_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlAdvanceBlock;
+///
+/// let mut block = CdlAdvanceBlock::new();
+/// for _ in 0..10 {
+///     assert_eq!(block.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(block.update(9.6, 10.65, 9.55, 10.6), None);
+/// assert_eq!(block.update(10.3, 11.1, 10.25, 10.9), None);
+/// assert_eq!(block.update(10.7, 11.4, 10.65, 11.0), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlAdvanceBlock {
+    engine: PatternEngine<AdvanceBlock>,
+}
+
+impl Default for CdlAdvanceBlock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlAdvanceBlock {
+    /// Creates a new CDLADVANCEBLOCK state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLADVANCEBLOCK state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlAdvanceBlock {
+            engine: PatternEngine::new(AdvanceBlock, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_advance_block() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.65, 9.55, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.3, 11.1, 10.25, 10.9);
+        (open[12], high[12], low[12], close[12]) = (10.7, 11.4, 10.65, 11.0);
+        let res = cdl_advance_block_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_advance_block_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(-100));
+        // the third candle closes near its high and keeps most of the second real body
+        (high[12], close[12]) = (11.42, 11.4);
+        let res = cdl_advance_block_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_advance_block_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
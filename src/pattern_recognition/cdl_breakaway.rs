@@ -0,0 +1,232 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 16:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_breakaway.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Breakaway
+//!
+//! - a long candle (BodyLong)
+//! - a candle of the same color gapping away from it
+//! - two candles with lower highs and lows after a black first candle, higher after a white one, the second of the
+//!   same color as the first candle
+//! - a candle of the opposite color closing inside the gap between the first two candles
+//!
+//! 100 for the bullish pattern after a black candle, -100 for the bearish one after a white candle.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Breakaway;
+
+impl CandlePattern for Breakaway {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong];
+    const CANDLES: usize = 5;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third, fourth, last) =
+            (window.candle(4), window.candle(3), window.candle(2), window.candle(1), window.candle(0));
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 4)
+            && first.color() == second.color()
+            && second.color() == fourth.color()
+            && fourth.color() == -last.color()
+            && ((first.color() == -1
+                && second.real_body_gap_down(first)
+                && third.high < second.high
+                && third.low < second.low
+                && fourth.high < third.high
+                && fourth.low < third.low
+                && last.close > second.open
+                && last.close < first.close)
+                || (first.color() == 1
+                    && second.real_body_gap_up(first)
+                    && third.high > second.high
+                    && third.low > second.low
+                    && fourth.high > third.high
+                    && fourth.low > third.low
+                    && last.close < second.open
+                    && last.close > first.close))
+        {
+            last.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the BodyLong average period plus 4.
+pub fn cdl_breakaway_lookback(settings: Option<&CandleSettings>) -> usize {
+    Breakaway::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Breakaway (CDLBREAKAWAY) over plain OHLC slices, the polars free core of [`cdl_breakaway`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_breakaway_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_breakaway_slice;
+///
+/// let mut open = vec![10.0; 15];
+/// let mut high = vec![10.6; 15];
+/// let mut low = vec![9.6; 15];
+/// let mut close = vec![10.2; 15];
+/// // a long black candle, three lower candles after a gap, then a white candle closing into the gap
+/// (open[10], high[10], low[10], close[10]) = (10.6, 10.7, 9.5, 9.6);
+/// (open[11], high[11], low[11], close[11]) = (9.4, 9.45, 9.2, 9.3);
+/// (open[12], high[12], low[12], close[12]) = (9.25, 9.35, 9.0, 9.1);
+/// (open[13], high[13], low[13], close[13]) = (9.1, 9.2, 8.8, 8.9);
+/// (open[14], high[14], low[14], close[14]) = (8.9, 9.55, 8.85, 9.5);
+/// let res = cdl_breakaway_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[13], None);
+/// assert_eq!(res[14], Some(100));
+/// ```
+pub fn cdl_breakaway_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Breakaway, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Breakaway (CDLBREAKAWAY).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_breakaway_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_breakaway function does
+///     import talib
+///     res = talib.CDLBREAKAWAY(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_breakaway;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_breakaway(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_breakaway(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Breakaway, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Breakaway (CDLBREAKAWAY).
+///
+/// Returns `None` for the first [`cdl_breakaway_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlBreakaway;
+///
+/// let mut breakaway = CdlBreakaway::new();
+/// for _ in 0..10 {
+///     assert_eq!(breakaway.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(breakaway.update(10.6, 10.7, 9.5, 9.6), None);
+/// assert_eq!(breakaway.update(9.4, 9.45, 9.2, 9.3), None);
+/// assert_eq!(breakaway.update(9.25, 9.35, 9.0, 9.1), None);
+/// assert_eq!(breakaway.update(9.1, 9.2, 8.8, 8.9), None);
+/// assert_eq!(breakaway.update(8.9, 9.55, 8.85, 9.5), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlBreakaway {
+    engine: PatternEngine<Breakaway>,
+}
+
+impl Default for CdlBreakaway {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlBreakaway {
+    /// Creates a new CDLBREAKAWAY state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLBREAKAWAY state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlBreakaway {
+            engine: PatternEngine::new(Breakaway, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_breakaway() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 15];
+        let mut high = vec![10.6; 15];
+        let mut low = vec![9.6; 15];
+        let mut close = vec![10.2; 15];
+        (open[10], high[10], low[10], close[10]) = (10.6, 10.7, 9.5, 9.6);
+        (open[11], high[11], low[11], close[11]) = (9.4, 9.45, 9.2, 9.3);
+        (open[12], high[12], low[12], close[12]) = (9.25, 9.35, 9.0, 9.1);
+        (open[13], high[13], low[13], close[13]) = (9.1, 9.2, 8.8, 8.9);
+        (open[14], high[14], low[14], close[14]) = (8.9, 9.55, 8.85, 9.5);
+        let res = cdl_breakaway_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_breakaway_lookback(None), 14);
+        assert!(res[..14].iter().all(|value| value.is_none()));
+        assert_eq!(res[14], Some(100));
+        // the last candle closes above the gap
+        close[14] = 9.7;
+        let res = cdl_breakaway_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[14], Some(0));
+        assert!(cdl_breakaway_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
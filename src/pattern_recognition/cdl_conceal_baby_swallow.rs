@@ -0,0 +1,218 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 17:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_conceal_baby_swallow.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Concealing Baby Swallow
+//!
+//! - two black marubozu, with very short shadows (ShadowVeryShort)
+//! - a black candle opening with a gap down, its upper shadow reaching into the previous real body
+//! - a black candle engulfing the third one, shadows included
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ConcealBabySwallow;
+
+impl CandlePattern for ConcealBabySwallow {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::ShadowVeryShort];
+    const CANDLES: usize = 4;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let second = window.candle(2);
+        let (third, fourth) = (window.candle(1), window.candle(0));
+        if (0..4).all(|ago| window.candle(ago).color() == -1)
+            && (2..4).all(|ago| {
+                let shadow_very_short = window.average(CandleSettingType::ShadowVeryShort, ago);
+                let candle = window.candle(ago);
+                candle.lower_shadow() < shadow_very_short && candle.upper_shadow() < shadow_very_short
+            })
+            && third.real_body_gap_down(second)
+            && third.upper_shadow() > window.average(CandleSettingType::ShadowVeryShort, 1)
+            && third.high > second.close
+            && fourth.high > third.high
+            && fourth.low < third.low
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the ShadowVeryShort average period plus 3.
+pub fn cdl_conceal_baby_swallow_lookback(settings: Option<&CandleSettings>) -> usize {
+    ConcealBabySwallow::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Concealing Baby Swallow (CDLCONCEALBABYSWALL) over plain OHLC slices, the polars free core of [`cdl_conceal_baby_swallow`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_conceal_baby_swallow_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_conceal_baby_swallow_slice;
+///
+/// let mut open = vec![10.0; 14];
+/// let mut high = vec![10.6; 14];
+/// let mut low = vec![9.6; 14];
+/// let mut close = vec![10.2; 14];
+/// // two black marubozu, a black candle gapping down, then a black candle engulfing it
+/// (open[10], high[10], low[10], close[10]) = (10.6, 10.6, 10.0, 10.0);
+/// (open[11], high[11], low[11], close[11]) = (10.0, 10.0, 9.5, 9.5);
+/// (open[12], high[12], low[12], close[12]) = (9.4, 9.6, 9.15, 9.2);
+/// (open[13], high[13], low[13], close[13]) = (9.65, 9.7, 9.0, 9.05);
+/// let res = cdl_conceal_baby_swallow_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[12], None);
+/// assert_eq!(res[13], Some(100));
+/// ```
+pub fn cdl_conceal_baby_swallow_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ConcealBabySwallow, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Concealing Baby Swallow (CDLCONCEALBABYSWALL).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_conceal_baby_swallow_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_conceal_baby_swallow function does
+///     import talib
+///     res = talib.CDLCONCEALBABYSWALL(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_conceal_baby_swallow;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_conceal_baby_swallow(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_conceal_baby_swallow(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ConcealBabySwallow, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Concealing Baby Swallow (CDLCONCEALBABYSWALL).
+///
+/// Returns `None` for the first [`cdl_conceal_baby_swallow_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlConcealBabySwallow;
+///
+/// let mut swallow = CdlConcealBabySwallow::new();
+/// for _ in 0..10 {
+///     assert_eq!(swallow.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(swallow.update(10.6, 10.6, 10.0, 10.0), None);
+/// assert_eq!(swallow.update(10.0, 10.0, 9.5, 9.5), None);
+/// assert_eq!(swallow.update(9.4, 9.6, 9.15, 9.2), None);
+/// assert_eq!(swallow.update(9.65, 9.7, 9.0, 9.05), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlConcealBabySwallow {
+    engine: PatternEngine<ConcealBabySwallow>,
+}
+
+impl Default for CdlConcealBabySwallow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlConcealBabySwallow {
+    /// Creates a new CDLCONCEALBABYSWALL state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLCONCEALBABYSWALL state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlConcealBabySwallow {
+            engine: PatternEngine::new(ConcealBabySwallow, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_conceal_baby_swallow() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 14];
+        let mut high = vec![10.6; 14];
+        let mut low = vec![9.6; 14];
+        let mut close = vec![10.2; 14];
+        (open[10], high[10], low[10], close[10]) = (10.6, 10.6, 10.0, 10.0);
+        (open[11], high[11], low[11], close[11]) = (10.0, 10.0, 9.5, 9.5);
+        (open[12], high[12], low[12], close[12]) = (9.4, 9.6, 9.15, 9.2);
+        (open[13], high[13], low[13], close[13]) = (9.65, 9.7, 9.0, 9.05);
+        let res = cdl_conceal_baby_swallow_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_conceal_baby_swallow_lookback(None), 13);
+        assert!(res[..13].iter().all(|value| value.is_none()));
+        assert_eq!(res[13], Some(100));
+        // the last candle does not reach below the low of the third one
+        low[13] = 9.2;
+        let res = cdl_conceal_baby_swallow_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[13], Some(0));
+        assert!(cdl_conceal_baby_swallow_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
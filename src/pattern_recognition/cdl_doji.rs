@@ -0,0 +1,194 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 09:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_doji.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Doji
+//!
+//! - a real body of at most a tenth of the average high-low range (BodyDoji)
+//!
+//! 100 when the candle is a doji, 0 otherwise.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Doji;
+
+impl CandlePattern for Doji {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyDoji];
+    const CANDLES: usize = 1;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        if window.candle(0).real_body() <= window.average(CandleSettingType::BodyDoji, 0) {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the BodyDoji average period.
+pub fn cdl_doji_lookback(settings: Option<&CandleSettings>) -> usize {
+    Doji::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Doji (CDLDOJI) over plain OHLC slices, the polars free core of [`cdl_doji`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_doji_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_doji_slice;
+///
+/// let mut open = vec![10.0; 11];
+/// let mut high = vec![10.6; 11];
+/// let mut low = vec![9.6; 11];
+/// let mut close = vec![10.2; 11];
+/// // a body of 2% of the usual high-low range
+/// (open[10], high[10], low[10], close[10]) = (10.0, 10.5, 9.5, 10.02);
+/// let res = cdl_doji_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[9], None);
+/// assert_eq!(res[10], Some(100));
+/// ```
+pub fn cdl_doji_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Doji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Doji (CDLDOJI).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_doji_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_doji function does
+///     import talib
+///     res = talib.CDLDOJI(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_doji;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_doji(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_doji(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Doji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Doji (CDLDOJI).
+///
+/// Returns `None` for the first [`cdl_doji_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlDoji;
+///
+/// let mut doji = CdlDoji::new();
+/// for _ in 0..10 {
+///     assert_eq!(doji.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(doji.update(10.0, 10.5, 9.5, 10.02), Some(100));
+/// assert_eq!(doji.update(10.0, 10.6, 9.6, 10.2), Some(0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlDoji {
+    engine: PatternEngine<Doji>,
+}
+
+impl Default for CdlDoji {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlDoji {
+    /// Creates a new CDLDOJI state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLDOJI state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlDoji {
+            engine: PatternEngine::new(Doji, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_doji() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.0, 10.5, 9.5, 10.02);
+        // a body of 8% of the average high-low range
+        (open[11], high[11], low[11], close[11]) = (10.0, 10.6, 9.6, 10.08);
+        let res = cdl_doji_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_doji_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(100), Some(100), Some(0)]);
+        assert!(cdl_doji_slice(&open, &high, &low, &close[1..], None).is_err());
+        assert!(cdl_doji_slice(&open[..10], &high[..10], &low[..10], &close[..10], None).is_err());
+        Ok(())
+    }
+}
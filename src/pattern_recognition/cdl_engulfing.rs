@@ -0,0 +1,184 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 14:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_engulfing.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Engulfing Pattern
+//!
+//! - a real body engulfing the real body of the previous candle, of the opposite color
+//!
+//! 100 for a white candle engulfing a black one, -100 for a black candle engulfing a white one.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Engulfing;
+
+impl CandlePattern for Engulfing {
+    const SETTINGS: &'static [CandleSettingType] = &[];
+    // TA-Lib's lookback is 2 although the pattern is made of 2 candles
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let prev = window.candle(1);
+        if (candle.color() == 1 && prev.color() == -1 && candle.close > prev.open && candle.open < prev.close)
+            || (candle.color() == -1 && prev.color() == 1 && candle.open > prev.close && candle.close < prev.open)
+        {
+            candle.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, 2 like TA-Lib.
+pub fn cdl_engulfing_lookback() -> usize {
+    Engulfing::lookback(&CandleSettings::new())
+}
+
+/// Recognizes the Engulfing Pattern (CDLENGULFING) over plain OHLC slices, the polars free core of [`cdl_engulfing`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_engulfing_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_engulfing_slice;
+///
+/// let open = [10.0, 10.2, 9.8];
+/// let high = [10.6, 10.3, 10.5];
+/// let low = [9.6, 9.8, 9.7];
+/// let close = [10.2, 9.9, 10.4];
+/// // the white candle opens below the black body and closes above it
+/// let res = cdl_engulfing_slice(&open, &high, &low, &close).unwrap();
+/// assert_eq!(res, [None, None, Some(100)]);
+/// ```
+pub fn cdl_engulfing_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Engulfing, &CandleSettings::new());
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Engulfing Pattern (CDLENGULFING).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_engulfing_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_engulfing function does
+///     import talib
+///     res = talib.CDLENGULFING(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_engulfing;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_engulfing(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_engulfing(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Engulfing, &CandleSettings::new());
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Engulfing Pattern (CDLENGULFING).
+///
+/// Returns `None` for the first [`cdl_engulfing_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlEngulfing;
+///
+/// let mut engulfing = CdlEngulfing::new();
+/// assert_eq!(engulfing.update(10.0, 10.6, 9.6, 10.2), None);
+/// assert_eq!(engulfing.update(10.2, 10.3, 9.8, 9.9), None);
+/// assert_eq!(engulfing.update(9.8, 10.5, 9.7, 10.4), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlEngulfing {
+    engine: PatternEngine<Engulfing>,
+}
+
+impl Default for CdlEngulfing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlEngulfing {
+    /// Creates a new CDLENGULFING state.
+    pub fn new() -> Self {
+        CdlEngulfing {
+            engine: PatternEngine::new(Engulfing, &CandleSettings::new()),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_engulfing() -> Result<(), TalibError> {
+        let open = [10.0, 10.2, 9.8, 10.5, 9.75];
+        let high = [10.6, 10.3, 10.5, 10.6, 10.0];
+        let low = [9.6, 9.8, 9.7, 9.6, 9.7];
+        let close = [10.2, 9.9, 10.4, 9.7, 9.9];
+        let res = cdl_engulfing_slice(&open, &high, &low, &close)?;
+        assert_eq!(cdl_engulfing_lookback(), 2);
+        // the last white candle does not close above the black body
+        assert_eq!(res, [None, None, Some(100), Some(-100), Some(0)]);
+        assert!(cdl_engulfing_slice(&open, &high, &low, &close[1..]).is_err());
+        assert!(cdl_engulfing_slice(&open[..2], &high[..2], &low[..2], &close[..2]).is_err());
+        Ok(())
+    }
+}
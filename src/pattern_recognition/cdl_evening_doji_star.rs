@@ -0,0 +1,227 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 10:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_evening_doji_star.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Evening Doji Star
+//!
+//! - a long white candle (BodyLong)
+//! - a doji gapping up, its real body away from the first one (BodyDoji)
+//! - a black candle longer than short (BodyShort), closing more than `penetration` of the first real body into it
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{
+    check_penetration, pattern_slice, CandlePattern, CandleWindow, PatternEngine,
+};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct EveningDojiStar {
+    penetration: f64,
+}
+
+impl CandlePattern for EveningDojiStar {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::BodyLong,
+        CandleSettingType::BodyDoji,
+        CandleSettingType::BodyShort,
+    ];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, star, last) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && first.color() == 1
+            && star.real_body() <= window.average(CandleSettingType::BodyDoji, 1)
+            && star.real_body_gap_up(first)
+            && last.real_body() > window.average(CandleSettingType::BodyShort, 0)
+            && last.color() == -1
+            && last.close < first.close - first.real_body() * self.penetration
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_evening_doji_star_lookback(settings: Option<&CandleSettings>) -> usize {
+    EveningDojiStar::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Evening Doji Star (CDLEVENINGDOJISTAR) over plain OHLC slices, the polars free core of [`cdl_evening_doji_star`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `penetration` is negative, [`TalibError::LengthMismatch`]
+/// if the inputs have different lengths and [`TalibError::InsufficientData`] if they are not longer
+/// than [`cdl_evening_doji_star_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_evening_doji_star_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.85, 10.95, 10.8, 10.87);
+/// (open[12], high[12], low[12], close[12]) = (10.8, 10.85, 9.9, 10.0);
+/// let res = cdl_evening_doji_star_slice(&open, &high, &low, &close, None, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_evening_doji_star_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    penetration: Option<f64>,
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let rule = EveningDojiStar {
+        penetration: check_penetration(penetration.unwrap_or(0.3))?,
+    };
+    let engine = PatternEngine::new(rule, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Evening Doji Star (CDLEVENINGDOJISTAR).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_evening_doji_star_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_evening_doji_star function does
+///     import talib
+///     res = talib.CDLEVENINGDOJISTAR(open, high, low, close, penetration=0.3)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `penetration` - An optional fraction of the first real body the third candle must close into.
+///   Defaults to 0.3 if not provided.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_evening_doji_star;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_evening_doji_star(&open, &high, &low, &close, None, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_evening_doji_star(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    penetration: Option<f64>,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let rule = EveningDojiStar {
+        penetration: check_penetration(penetration.unwrap_or(0.3))?,
+    };
+    let engine = PatternEngine::new(rule, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Evening Doji Star (CDLEVENINGDOJISTAR).
+///
+/// Returns `None` for the first [`cdl_evening_doji_star_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlEveningDojiStar;
+///
+/// let mut evening_doji_star = CdlEveningDojiStar::new(0.3).unwrap();
+/// for _ in 0..10 {
+///     assert_eq!(evening_doji_star.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(evening_doji_star.update(9.6, 10.7, 9.5, 10.6), None);
+/// assert_eq!(evening_doji_star.update(10.85, 10.95, 10.8, 10.87), None);
+/// assert_eq!(evening_doji_star.update(10.8, 10.85, 9.9, 10.0), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlEveningDojiStar {
+    engine: PatternEngine<EveningDojiStar>,
+}
+
+impl CdlEveningDojiStar {
+    /// Creates a new CDLEVENINGDOJISTAR state with TA-Lib's candle settings, returns an error if `penetration` is negative.
+    pub fn new(penetration: f64) -> Result<Self, TalibError> {
+        Self::with_settings(penetration, &CandleSettings::new())
+    }
+
+    /// Creates a new CDLEVENINGDOJISTAR state with custom candle settings, returns an error if `penetration` is negative.
+    pub fn with_settings(penetration: f64, settings: &CandleSettings) -> Result<Self, TalibError> {
+        let rule = EveningDojiStar {
+            penetration: check_penetration(penetration)?,
+        };
+        Ok(CdlEveningDojiStar {
+            engine: PatternEngine::new(rule, settings),
+        })
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_evening_doji_star() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.85, 10.95, 10.8, 10.87);
+        (open[12], high[12], low[12], close[12]) = (10.8, 10.85, 9.9, 10.0);
+        let res = cdl_evening_doji_star_slice(&open, &high, &low, &close, None, None)?;
+        assert_eq!(cdl_evening_doji_star_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(-100));
+        // a short star that is not a doji
+        (open[11], high[11], low[11], close[11]) = (10.8, 10.95, 10.75, 10.95);
+        let res = cdl_evening_doji_star_slice(&open, &high, &low, &close, None, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_evening_doji_star_slice(&open, &high, &low, &close, Some(-0.1), None).is_err());
+        assert!(CdlEveningDojiStar::new(f64::NAN).is_err());
+        assert!(cdl_evening_doji_star_slice(&open, &high, &low, &close[1..], None, None).is_err());
+        Ok(())
+    }
+}
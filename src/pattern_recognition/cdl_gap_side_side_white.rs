@@ -0,0 +1,219 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 14:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_gap_side_side_white.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Up/Down-gap side-by-side white lines
+//!
+//! - a candle followed by two white candles whose real bodies both gap away from it
+//! - the two white candles of near the same size (Near) and opening at about the same price (Equal)
+//!
+//! 100 when they gap up, -100 when they gap down.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct GapSideSideWhite;
+
+impl CandlePattern for GapSideSideWhite {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::Near, CandleSettingType::Equal];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        let (near, equal) = (window.average(CandleSettingType::Near, 1), window.average(CandleSettingType::Equal, 1));
+        let gap_up = second.real_body_gap_up(first) && third.real_body_gap_up(first);
+        let gap_down = second.real_body_gap_down(first) && third.real_body_gap_down(first);
+        if (gap_up || gap_down)
+            && second.color() == 1
+            && third.color() == 1
+            && third.real_body() >= second.real_body() - near
+            && third.real_body() <= second.real_body() + near
+            && third.open >= second.open - equal
+            && third.open <= second.open + equal
+        {
+            if second.real_body_gap_up(first) {
+                100
+            } else {
+                -100
+            }
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_gap_side_side_white_lookback(settings: Option<&CandleSettings>) -> usize {
+    GapSideSideWhite::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Up/Down-gap side-by-side white lines (CDLGAPSIDESIDEWHITE) over plain OHLC slices, the polars free core of [`cdl_gap_side_side_white`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_gap_side_side_white_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_gap_side_side_white_slice;
+///
+/// let mut open = vec![10.0; 8];
+/// let mut high = vec![10.6; 8];
+/// let mut low = vec![9.6; 8];
+/// let mut close = vec![10.2; 8];
+/// // two white candles side by side, gapping up from the first one
+/// (open[5], high[5], low[5], close[5]) = (10.0, 10.5, 9.9, 10.4);
+/// (open[6], high[6], low[6], close[6]) = (10.6, 11.05, 10.55, 11.0);
+/// (open[7], high[7], low[7], close[7]) = (10.62, 11.1, 10.58, 11.0);
+/// let res = cdl_gap_side_side_white_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[6], None);
+/// assert_eq!(res[7], Some(100));
+/// ```
+pub fn cdl_gap_side_side_white_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(GapSideSideWhite, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Up/Down-gap side-by-side white lines (CDLGAPSIDESIDEWHITE).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_gap_side_side_white_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_gap_side_side_white function does
+///     import talib
+///     res = talib.CDLGAPSIDESIDEWHITE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_gap_side_side_white;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_gap_side_side_white(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_gap_side_side_white(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(GapSideSideWhite, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Up/Down-gap side-by-side white lines (CDLGAPSIDESIDEWHITE).
+///
+/// Returns `None` for the first [`cdl_gap_side_side_white_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlGapSideSideWhite;
+///
+/// let mut side_by_side = CdlGapSideSideWhite::new();
+/// for _ in 0..5 {
+///     assert_eq!(side_by_side.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(side_by_side.update(10.0, 10.5, 9.9, 10.4), None);
+/// assert_eq!(side_by_side.update(10.6, 11.05, 10.55, 11.0), None);
+/// assert_eq!(side_by_side.update(10.62, 11.1, 10.58, 11.0), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlGapSideSideWhite {
+    engine: PatternEngine<GapSideSideWhite>,
+}
+
+impl Default for CdlGapSideSideWhite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlGapSideSideWhite {
+    /// Creates a new CDLGAPSIDESIDEWHITE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLGAPSIDESIDEWHITE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlGapSideSideWhite {
+            engine: PatternEngine::new(GapSideSideWhite, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_gap_side_side_white() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 11];
+        let mut high = vec![10.6; 11];
+        let mut low = vec![9.6; 11];
+        let mut close = vec![10.2; 11];
+        (open[5], high[5], low[5], close[5]) = (10.0, 10.5, 9.9, 10.4);
+        (open[6], high[6], low[6], close[6]) = (10.6, 11.05, 10.55, 11.0);
+        (open[7], high[7], low[7], close[7]) = (10.62, 11.1, 10.58, 11.0);
+        (open[8], high[8], low[8], close[8]) = (10.4, 10.45, 9.95, 10.0);
+        (open[9], high[9], low[9], close[9]) = (9.4, 9.85, 9.35, 9.8);
+        (open[10], high[10], low[10], close[10]) = (9.42, 9.85, 9.4, 9.8);
+        let res = cdl_gap_side_side_white_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_gap_side_side_white_lookback(None), 7);
+        assert!(res[..7].iter().all(|value| value.is_none()));
+        assert_eq!(res[7..], [Some(100), Some(0), Some(0), Some(-100)]);
+        // the last white candle opens well above the previous one
+        open[10] = 9.6;
+        let res = cdl_gap_side_side_white_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[10], Some(0));
+        assert!(cdl_gap_side_side_white_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 10:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_gravestone_doji.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Gravestone Doji
+//!
+//! - a doji body (BodyDoji)
+//! - no, or a very short, lower shadow (ShadowVeryShort)
+//! - an upper shadow that is not very short
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct GravestoneDoji;
+
+impl CandlePattern for GravestoneDoji {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyDoji, CandleSettingType::ShadowVeryShort];
+    const CANDLES: usize = 1;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let shadow_very_short = window.average(CandleSettingType::ShadowVeryShort, 0);
+        if candle.real_body() <= window.average(CandleSettingType::BodyDoji, 0)
+            && candle.lower_shadow() < shadow_very_short
+            && candle.upper_shadow() > shadow_very_short
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of BodyDoji and ShadowVeryShort.
+pub fn cdl_gravestone_doji_lookback(settings: Option<&CandleSettings>) -> usize {
+    GravestoneDoji::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Gravestone Doji (CDLGRAVESTONEDOJI) over plain OHLC slices, the polars free core of [`cdl_gravestone_doji`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_gravestone_doji_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_gravestone_doji_slice;
+///
+/// let mut open = vec![10.0; 11];
+/// let mut high = vec![10.6; 11];
+/// let mut low = vec![9.6; 11];
+/// let mut close = vec![10.2; 11];
+/// // a doji at the bottom of a long upper shadow
+/// (open[10], high[10], low[10], close[10]) = (10.0, 11.0, 9.99, 10.01);
+/// let res = cdl_gravestone_doji_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[9], None);
+/// assert_eq!(res[10], Some(100));
+/// ```
+pub fn cdl_gravestone_doji_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(GravestoneDoji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Gravestone Doji (CDLGRAVESTONEDOJI).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_gravestone_doji_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_gravestone_doji function does
+///     import talib
+///     res = talib.CDLGRAVESTONEDOJI(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_gravestone_doji;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_gravestone_doji(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_gravestone_doji(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(GravestoneDoji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Gravestone Doji (CDLGRAVESTONEDOJI).
+///
+/// Returns `None` for the first [`cdl_gravestone_doji_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlGravestoneDoji;
+///
+/// let mut gravestone_doji = CdlGravestoneDoji::new();
+/// for _ in 0..10 {
+///     assert_eq!(gravestone_doji.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(gravestone_doji.update(10.0, 11.0, 9.99, 10.01), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlGravestoneDoji {
+    engine: PatternEngine<GravestoneDoji>,
+}
+
+impl Default for CdlGravestoneDoji {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlGravestoneDoji {
+    /// Creates a new CDLGRAVESTONEDOJI state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLGRAVESTONEDOJI state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlGravestoneDoji {
+            engine: PatternEngine::new(GravestoneDoji, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_gravestone_doji() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.0, 11.0, 9.99, 10.01);
+        // a dragonfly doji
+        (open[11], high[11], low[11], close[11]) = (10.0, 10.02, 9.0, 10.01);
+        let res = cdl_gravestone_doji_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_gravestone_doji_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(100), Some(0), Some(0)]);
+        assert!(cdl_gravestone_doji_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 11:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_hanging_man.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hanging Man
+//!
+//! - a short real body (BodyShort)
+//! - a long lower shadow (ShadowLong)
+//! - no, or a very short, upper shadow (ShadowVeryShort)
+//! - the body near or above the high of the previous candle (Near)
+//!
+//! -100 when the pattern is found, whether it comes after an uptrend is left to the caller like in TA-Lib.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct HangingMan;
+
+impl CandlePattern for HangingMan {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::BodyShort,
+        CandleSettingType::ShadowLong,
+        CandleSettingType::ShadowVeryShort,
+        CandleSettingType::Near,
+    ];
+    const CANDLES: usize = 2;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        if candle.real_body() < window.average(CandleSettingType::BodyShort, 0)
+            && candle.lower_shadow() > window.average(CandleSettingType::ShadowLong, 0)
+            && candle.upper_shadow() < window.average(CandleSettingType::ShadowVeryShort, 0)
+            && candle.body_bottom() >= window.candle(1).high - window.average(CandleSettingType::Near, 1)
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 1.
+pub fn cdl_hanging_man_lookback(settings: Option<&CandleSettings>) -> usize {
+    HangingMan::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Hanging Man (CDLHANGINGMAN) over plain OHLC slices, the polars free core of [`cdl_hanging_man`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_hanging_man_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_hanging_man_slice;
+///
+/// let mut open = vec![10.0; 12];
+/// let mut high = vec![10.6; 12];
+/// let mut low = vec![9.6; 12];
+/// let mut close = vec![10.2; 12];
+/// // a small body at the high of the previous candle, with a long lower shadow
+/// (open[11], high[11], low[11], close[11]) = (10.55, 10.6, 10.1, 10.6);
+/// let res = cdl_hanging_man_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[10], None);
+/// assert_eq!(res[11], Some(-100));
+/// ```
+pub fn cdl_hanging_man_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(HangingMan, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Hanging Man (CDLHANGINGMAN).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_hanging_man_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_hanging_man function does
+///     import talib
+///     res = talib.CDLHANGINGMAN(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_hanging_man;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_hanging_man(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_hanging_man(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(HangingMan, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Hanging Man (CDLHANGINGMAN).
+///
+/// Returns `None` for the first [`cdl_hanging_man_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlHangingMan;
+///
+/// let mut hanging_man = CdlHangingMan::new();
+/// for _ in 0..11 {
+///     assert_eq!(hanging_man.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(hanging_man.update(10.55, 10.6, 10.1, 10.6), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlHangingMan {
+    engine: PatternEngine<HangingMan>,
+}
+
+impl Default for CdlHangingMan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlHangingMan {
+    /// Creates a new CDLHANGINGMAN state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLHANGINGMAN state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlHangingMan {
+            engine: PatternEngine::new(HangingMan, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_hanging_man() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[11], high[11], low[11], close[11]) = (10.55, 10.6, 10.1, 10.6);
+        // the same shape far below the high of the hanging man
+        (open[12], high[12], low[12], close[12]) = (9.9, 9.95, 9.5, 9.95);
+        let res = cdl_hanging_man_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_hanging_man_lookback(None), 11);
+        assert!(res[..11].iter().all(|value| value.is_none()));
+        assert_eq!(res[11..], [Some(-100), Some(0)]);
+        assert!(cdl_hanging_man_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
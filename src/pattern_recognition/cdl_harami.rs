@@ -0,0 +1,203 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 14:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_harami.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Harami Pattern
+//!
+//! - a long real body (BodyLong)
+//! - then a short real body (BodyShort) inside the previous one
+//!
+//! 100 after a black candle, -100 after a white one.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Harami;
+
+impl CandlePattern for Harami {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong, CandleSettingType::BodyShort];
+    const CANDLES: usize = 2;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let prev = window.candle(1);
+        if prev.real_body() > window.average(CandleSettingType::BodyLong, 1)
+            && candle.real_body() <= window.average(CandleSettingType::BodyShort, 0)
+            && candle.body_top() < prev.body_top()
+            && candle.body_bottom() > prev.body_bottom()
+        {
+            -prev.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of BodyLong and BodyShort plus 1.
+pub fn cdl_harami_lookback(settings: Option<&CandleSettings>) -> usize {
+    Harami::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Harami Pattern (CDLHARAMI) over plain OHLC slices, the polars free core of [`cdl_harami`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_harami_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_harami_slice;
+///
+/// let mut open = vec![10.0; 12];
+/// let mut high = vec![10.6; 12];
+/// let mut low = vec![9.6; 12];
+/// let mut close = vec![10.2; 12];
+/// // a small white body inside a long black one
+/// (open[10], high[10], low[10], close[10]) = (11.0, 11.1, 9.9, 10.0);
+/// (open[11], high[11], low[11], close[11]) = (10.3, 10.5, 10.2, 10.45);
+/// let res = cdl_harami_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[10], None);
+/// assert_eq!(res[11], Some(100));
+/// ```
+pub fn cdl_harami_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Harami, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Harami Pattern (CDLHARAMI).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_harami_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_harami function does
+///     import talib
+///     res = talib.CDLHARAMI(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_harami;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_harami(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_harami(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Harami, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Harami Pattern (CDLHARAMI).
+///
+/// Returns `None` for the first [`cdl_harami_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlHarami;
+///
+/// let mut harami = CdlHarami::new();
+/// for _ in 0..10 {
+///     assert_eq!(harami.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(harami.update(11.0, 11.1, 9.9, 10.0), None);
+/// assert_eq!(harami.update(10.3, 10.5, 10.2, 10.45), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlHarami {
+    engine: PatternEngine<Harami>,
+}
+
+impl Default for CdlHarami {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlHarami {
+    /// Creates a new CDLHARAMI state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLHARAMI state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlHarami {
+            engine: PatternEngine::new(Harami, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_harami() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 14];
+        let mut high = vec![10.6; 14];
+        let mut low = vec![9.6; 14];
+        let mut close = vec![10.2; 14];
+        (open[10], high[10], low[10], close[10]) = (11.0, 11.1, 9.9, 10.0);
+        (open[11], high[11], low[11], close[11]) = (10.3, 10.5, 10.2, 10.45);
+        // a small black body inside a long white one
+        (open[12], high[12], low[12], close[12]) = (10.0, 11.1, 9.9, 11.0);
+        (open[13], high[13], low[13], close[13]) = (10.7, 10.8, 10.4, 10.5);
+        let res = cdl_harami_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_harami_lookback(None), 11);
+        assert!(res[..11].iter().all(|value| value.is_none()));
+        assert_eq!(res[11..], [Some(100), Some(0), Some(-100)]);
+        assert!(cdl_harami_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
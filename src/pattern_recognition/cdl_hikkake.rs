@@ -0,0 +1,265 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 15:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_hikkake.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Hikkake Pattern
+//!
+//! - an inside bar: a lower high and a higher low than the previous bar
+//! - a bar breaking out of it: a lower high and a lower low (bullish) or a higher high and a higher low (bearish)
+//! - up to 3 bars later, a close above the high (bullish) or under the low (bearish) of the inside bar confirms it
+//!
+//! 100 (-100) on the bar of a bullish (bearish) hikkake, 200 (-200) on the bar confirming it. Like TA-Lib, the 3 bars
+//! before the lookback are scanned for a hikkake still waiting for its confirmation.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+/// A hikkake waiting for its confirmation, shared with CDLHIKKAKEMOD.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HikkakeConfirmation {
+    /// 100 for the last bullish hikkake, -100 for a bearish one
+    value: i32,
+    /// bars since the last hikkake, `None` once it is confirmed or more than 3 bars old
+    since: Option<usize>,
+    /// whether the 3 bars before the lookback were scanned
+    primed: bool,
+}
+
+impl HikkakeConfirmation {
+    /// The value of the bar `ago` bars before the current one, `found` tells whether it completes a hikkake.
+    fn step(&mut self, window: &CandleWindow, ago: usize, found: bool) -> i32 {
+        self.since = self.since.map(|since| since + 1).filter(|since| *since <= 3);
+        let bar = window.candle(ago);
+        if found {
+            self.value = if bar.high < window.candle(ago + 1).high { 100 } else { -100 };
+            self.since = Some(0);
+            return self.value;
+        }
+        let Some(since) = self.since else {
+            return 0;
+        };
+        let inside = window.candle(ago + since + 1);
+        if (self.value > 0 && bar.close > inside.high) || (self.value < 0 && bar.close < inside.low) {
+            self.since = None;
+            2 * self.value
+        } else {
+            0
+        }
+    }
+
+    /// The value of the current bar, `found(ago)` tells whether the bar `ago` bars before completes a hikkake.
+    pub(crate) fn detect(&mut self, window: &CandleWindow, found: impl Fn(usize) -> bool) -> i32 {
+        if !self.primed {
+            self.primed = true;
+            for ago in (1..4).rev() {
+                self.step(window, ago, found(ago));
+            }
+        }
+        self.step(window, 0, found(0))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Hikkake {
+    confirmation: HikkakeConfirmation,
+}
+
+impl CandlePattern for Hikkake {
+    const SETTINGS: &'static [CandleSettingType] = &[];
+    const CANDLES: usize = 6;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        self.confirmation.detect(window, |ago| {
+            let (first, second, third) = (window.candle(ago + 2), window.candle(ago + 1), window.candle(ago));
+            second.high < first.high
+                && second.low > first.low
+                && ((third.high < second.high && third.low < second.low)
+                    || (third.high > second.high && third.low > second.low))
+        })
+    }
+
+    fn reset(&mut self) {
+        *self = Hikkake::default();
+    }
+}
+
+/// Number of leading bars without a pattern value, 5 like TA-Lib.
+pub fn cdl_hikkake_lookback() -> usize {
+    Hikkake::lookback(&CandleSettings::new())
+}
+
+/// Recognizes the Hikkake Pattern (CDLHIKKAKE) over plain OHLC slices, the polars free core of [`cdl_hikkake`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_hikkake_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_hikkake_slice;
+///
+/// let open = [10.0, 10.0, 10.0, 10.0, 9.8, 9.6, 9.9];
+/// let high = [10.6, 10.6, 11.0, 10.5, 10.4, 10.0, 10.8];
+/// let low = [9.6, 9.6, 9.0, 9.5, 9.3, 9.2, 9.8];
+/// let close = [10.2, 10.2, 10.2, 10.2, 9.5, 9.9, 10.7];
+/// // a bullish hikkake at 4, before the lookback, confirmed by the close above the inside bar high at 6
+/// let res = cdl_hikkake_slice(&open, &high, &low, &close).unwrap();
+/// assert_eq!(res, [None, None, None, None, None, Some(0), Some(200)]);
+/// ```
+pub fn cdl_hikkake_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Hikkake::default(), &CandleSettings::new());
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Hikkake Pattern (CDLHIKKAKE).
+///
+/// Returns an Int32 Series of 100, 200, -100, -200 and 0, null for the first [`cdl_hikkake_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_hikkake function does
+///     import talib
+///     res = talib.CDLHIKKAKE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_hikkake;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_hikkake(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_hikkake(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Hikkake::default(), &CandleSettings::new());
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Hikkake Pattern (CDLHIKKAKE).
+///
+/// Returns `None` for the first [`cdl_hikkake_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlHikkake;
+///
+/// let mut hikkake = CdlHikkake::new();
+/// for _ in 0..2 {
+///     assert_eq!(hikkake.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(hikkake.update(10.0, 11.0, 9.0, 10.2), None);
+/// assert_eq!(hikkake.update(10.0, 10.5, 9.5, 10.2), None);
+/// assert_eq!(hikkake.update(9.8, 10.4, 9.3, 9.5), None);
+/// assert_eq!(hikkake.update(9.6, 10.0, 9.2, 9.9), Some(0));
+/// assert_eq!(hikkake.update(9.9, 10.8, 9.8, 10.7), Some(200));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlHikkake {
+    engine: PatternEngine<Hikkake>,
+}
+
+impl Default for CdlHikkake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlHikkake {
+    /// Creates a new CDLHIKKAKE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLHIKKAKE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlHikkake {
+            engine: PatternEngine::new(Hikkake::default(), settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_hikkake() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 11];
+        let mut high = vec![10.6; 11];
+        let mut low = vec![9.6; 11];
+        let mut close = vec![10.2; 11];
+        (open[2], high[2], low[2], close[2]) = (10.0, 11.0, 9.0, 10.2);
+        (open[3], high[3], low[3], close[3]) = (10.0, 10.5, 9.5, 10.2);
+        (open[4], high[4], low[4], close[4]) = (9.8, 10.4, 9.3, 9.5);
+        (open[5], high[5], low[5], close[5]) = (9.6, 10.0, 9.2, 9.9);
+        (open[6], high[6], low[6], close[6]) = (9.9, 10.8, 9.8, 10.7);
+        // a bearish hikkake at 9, confirmed by the close under the inside bar low at 10
+        (open[7], high[7], low[7], close[7]) = (10.5, 11.5, 9.5, 10.6);
+        (open[8], high[8], low[8], close[8]) = (10.5, 11.0, 10.0, 10.4);
+        (open[9], high[9], low[9], close[9]) = (10.6, 11.2, 10.2, 10.9);
+        (open[10], high[10], low[10], close[10]) = (10.8, 10.9, 9.8, 9.9);
+        let res = cdl_hikkake_slice(&open, &high, &low, &close)?;
+        assert_eq!(cdl_hikkake_lookback(), 5);
+        assert!(res[..5].iter().all(|value| value.is_none()));
+        assert_eq!(res[5..], [Some(0), Some(200), Some(0), Some(0), Some(-100), Some(-200)]);
+        // without the scan before the lookback the bullish hikkake would not be confirmed
+        let res = cdl_hikkake_slice(&open[2..], &high[2..], &low[2..], &close[2..])?;
+        assert_eq!(res[5..], [Some(0), Some(0), Some(-100), Some(-200)]);
+        let mut hikkake = CdlHikkake::new();
+        for i in 0..open.len() {
+            hikkake.update(open[i], high[i], low[i], close[i]);
+        }
+        hikkake.reset();
+        for i in 2..open.len() {
+            assert_eq!(hikkake.update(open[i], high[i], low[i], close[i]), res[i - 2]);
+        }
+        assert!(cdl_hikkake_slice(&open, &high, &low, &close[1..]).is_err());
+        Ok(())
+    }
+}
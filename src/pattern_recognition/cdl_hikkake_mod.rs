@@ -0,0 +1,231 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 16:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_hikkake_mod.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Modified Hikkake Pattern
+//!
+//! - two inside bars in a row, each with a lower high and a higher low than the previous bar, the first one closing
+//!   near its low (Near) for a bullish hikkake, near its high for a bearish one
+//! - a bar breaking out of them: a lower high and a lower low (bullish) or a higher high and a higher low (bearish)
+//! - up to 3 bars later, a close above the high (bullish) or under the low (bearish) of the second inside bar
+//!   confirms it
+//!
+//! 100 (-100) on the bar of a bullish (bearish) hikkake, 200 (-200) on the bar confirming it. Like TA-Lib, the 3 bars
+//! before the lookback are scanned for a hikkake still waiting for its confirmation.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::cdl_hikkake::HikkakeConfirmation;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone, Default)]
+struct HikkakeMod {
+    confirmation: HikkakeConfirmation,
+}
+
+impl CandlePattern for HikkakeMod {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::Near];
+    const CANDLES: usize = 6;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        self.confirmation.detect(window, |ago| {
+            let (first, second) = (window.candle(ago + 3), window.candle(ago + 2));
+            let (third, fourth) = (window.candle(ago + 1), window.candle(ago));
+            let near = window.average(CandleSettingType::Near, ago + 2);
+            second.high < first.high
+                && second.low > first.low
+                && third.high < second.high
+                && third.low > second.low
+                && ((fourth.high < third.high && fourth.low < third.low && second.close <= second.low + near)
+                    || (fourth.high > third.high && fourth.low > third.low && second.close >= second.high - near))
+        })
+    }
+
+    /// TA-Lib reads the Near average of at least one bar before the pattern.
+    fn lookback(settings: &CandleSettings) -> usize {
+        settings.avg_period(Self::SETTINGS).max(1) + Self::CANDLES - 1
+    }
+
+    fn reset(&mut self) {
+        *self = HikkakeMod::default();
+    }
+}
+
+/// Number of leading bars without a pattern value, the Near average period, at least 1, plus 5.
+pub fn cdl_hikkake_mod_lookback(settings: Option<&CandleSettings>) -> usize {
+    HikkakeMod::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Modified Hikkake Pattern (CDLHIKKAKEMOD) over plain OHLC slices, the polars free core of [`cdl_hikkake_mod`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_hikkake_mod_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_hikkake_mod_slice;
+///
+/// let mut open = vec![10.0; 16];
+/// let mut high = vec![10.6; 16];
+/// let mut low = vec![9.6; 16];
+/// let mut close = vec![10.2; 16];
+/// // a bullish hikkake at 13 confirmed by the close above the second inside bar high at 15
+/// (open[10], high[10], low[10], close[10]) = (10.0, 11.0, 9.0, 10.2);
+/// (open[11], high[11], low[11], close[11]) = (10.4, 10.8, 9.2, 9.3);
+/// (open[12], high[12], low[12], close[12]) = (9.6, 10.6, 9.4, 10.0);
+/// (open[13], high[13], low[13], close[13]) = (9.8, 10.5, 9.3, 9.5);
+/// (open[14], high[14], low[14], close[14]) = (9.5, 10.0, 9.4, 9.9);
+/// (open[15], high[15], low[15], close[15]) = (9.9, 10.8, 9.8, 10.7);
+/// let res = cdl_hikkake_mod_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[13..], [Some(100), Some(0), Some(200)]);
+/// ```
+pub fn cdl_hikkake_mod_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(HikkakeMod::default(), settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Modified Hikkake Pattern (CDLHIKKAKEMOD).
+///
+/// Returns an Int32 Series of 100, 200, -100, -200 and 0, null for the first [`cdl_hikkake_mod_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_hikkake_mod function does
+///     import talib
+///     res = talib.CDLHIKKAKEMOD(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_hikkake_mod;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_hikkake_mod(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_hikkake_mod(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(HikkakeMod::default(), settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Modified Hikkake Pattern (CDLHIKKAKEMOD).
+///
+/// Returns `None` for the first [`cdl_hikkake_mod_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlHikkakeMod;
+///
+/// let mut hikkake = CdlHikkakeMod::new();
+/// for _ in 0..10 {
+///     assert_eq!(hikkake.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(hikkake.update(10.0, 11.0, 9.0, 10.2), Some(0));
+/// assert_eq!(hikkake.update(10.4, 10.8, 9.2, 9.3), Some(0));
+/// assert_eq!(hikkake.update(9.6, 10.6, 9.4, 10.0), Some(0));
+/// assert_eq!(hikkake.update(9.8, 10.5, 9.3, 9.5), Some(100));
+/// assert_eq!(hikkake.update(9.5, 10.0, 9.4, 9.9), Some(0));
+/// assert_eq!(hikkake.update(9.9, 10.8, 9.8, 10.7), Some(200));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlHikkakeMod {
+    engine: PatternEngine<HikkakeMod>,
+}
+
+impl Default for CdlHikkakeMod {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlHikkakeMod {
+    /// Creates a new CDLHIKKAKEMOD state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLHIKKAKEMOD state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlHikkakeMod {
+            engine: PatternEngine::new(HikkakeMod::default(), settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_hikkake_mod() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 16];
+        let mut high = vec![10.6; 16];
+        let mut low = vec![9.6; 16];
+        let mut close = vec![10.2; 16];
+        (open[10], high[10], low[10], close[10]) = (10.0, 11.0, 9.0, 10.2);
+        (open[11], high[11], low[11], close[11]) = (10.4, 10.8, 9.2, 9.3);
+        (open[12], high[12], low[12], close[12]) = (9.6, 10.6, 9.4, 10.0);
+        (open[13], high[13], low[13], close[13]) = (9.8, 10.5, 9.3, 9.5);
+        (open[14], high[14], low[14], close[14]) = (9.5, 10.0, 9.4, 9.9);
+        (open[15], high[15], low[15], close[15]) = (9.9, 10.8, 9.8, 10.7);
+        let res = cdl_hikkake_mod_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_hikkake_mod_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(0), Some(0), Some(0), Some(100), Some(0), Some(200)]);
+        // the first inside bar does not close near its low
+        close[11] = 10.0;
+        let res = cdl_hikkake_mod_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[13..], [Some(0), Some(0), Some(0)]);
+        assert!(cdl_hikkake_mod_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 10:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_identical_3_crows.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Identical Three Crows
+//!
+//! - three black candles with no, or very short, lower shadows (ShadowVeryShort) and lower and lower closes
+//! - each candle opening at, or very near, the close of the previous one (Equal)
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct IdenticalThreeCrows;
+
+impl CandlePattern for IdenticalThreeCrows {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::ShadowVeryShort, CandleSettingType::Equal];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if (0..3).all(|ago| {
+            window.candle(ago).color() == -1
+                && window.candle(ago).lower_shadow() < window.average(CandleSettingType::ShadowVeryShort, ago)
+        }) && first.close > second.close
+            && second.close > third.close
+            && second.open <= first.close + window.average(CandleSettingType::Equal, 2)
+            && second.open >= first.close - window.average(CandleSettingType::Equal, 2)
+            && third.open <= second.close + window.average(CandleSettingType::Equal, 1)
+            && third.open >= second.close - window.average(CandleSettingType::Equal, 1)
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_identical_3_crows_lookback(settings: Option<&CandleSettings>) -> usize {
+    IdenticalThreeCrows::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Identical Three Crows (CDLIDENTICAL3CROWS) over plain OHLC slices, the polars free core of [`cdl_identical_3_crows`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_identical_3_crows_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_identical_3_crows_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // three black candles, each opening at the previous close and closing at its low
+/// (open[10], high[10], low[10], close[10]) = (10.5, 10.55, 9.9, 9.9);
+/// (open[11], high[11], low[11], close[11]) = (9.9, 9.95, 9.3, 9.3);
+/// (open[12], high[12], low[12], close[12]) = (9.3, 9.35, 8.7, 8.7);
+/// let res = cdl_identical_3_crows_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_identical_3_crows_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(IdenticalThreeCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Identical Three Crows (CDLIDENTICAL3CROWS).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_identical_3_crows_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_identical_3_crows function does
+///     import talib
+///     res = talib.CDLIDENTICAL3CROWS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_identical_3_crows;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_identical_3_crows(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_identical_3_crows(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(IdenticalThreeCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Identical Three Crows (CDLIDENTICAL3CROWS).
+///
+/// Returns `None` for the first [`cdl_identical_3_crows_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlIdentical3Crows;
+///
+/// let mut crows = CdlIdentical3Crows::new();
+/// for _ in 0..10 {
+///     assert_eq!(crows.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(crows.update(10.5, 10.55, 9.9, 9.9), None);
+/// assert_eq!(crows.update(9.9, 9.95, 9.3, 9.3), None);
+/// assert_eq!(crows.update(9.3, 9.35, 8.7, 8.7), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlIdentical3Crows {
+    engine: PatternEngine<IdenticalThreeCrows>,
+}
+
+impl Default for CdlIdentical3Crows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlIdentical3Crows {
+    /// Creates a new CDLIDENTICAL3CROWS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLIDENTICAL3CROWS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlIdentical3Crows {
+            engine: PatternEngine::new(IdenticalThreeCrows, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_identical_3_crows() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.5, 10.55, 9.9, 9.9);
+        (open[11], high[11], low[11], close[11]) = (9.9, 9.95, 9.3, 9.3);
+        (open[12], high[12], low[12], close[12]) = (9.3, 9.35, 8.7, 8.7);
+        let res = cdl_identical_3_crows_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_identical_3_crows_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(-100));
+        // the third candle opens inside the second real body
+        open[12] = 9.6;
+        let res = cdl_identical_3_crows_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_identical_3_crows_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 16:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_ladder_bottom.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Ladder Bottom
+//!
+//! - three black candles with consecutively lower opens and closes
+//! - a black candle with an upper shadow that is not very short (ShadowVeryShort)
+//! - a white candle opening above the open of the previous one and closing above its high
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct LadderBottom;
+
+impl CandlePattern for LadderBottom {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::ShadowVeryShort];
+    const CANDLES: usize = 5;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third, fourth, last) =
+            (window.candle(4), window.candle(3), window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == -1
+            && second.color() == -1
+            && third.color() == -1
+            && first.open > second.open
+            && second.open > third.open
+            && first.close > second.close
+            && second.close > third.close
+            && fourth.color() == -1
+            && fourth.upper_shadow() > window.average(CandleSettingType::ShadowVeryShort, 1)
+            && last.color() == 1
+            && last.open > fourth.open
+            && last.close > fourth.high
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the ShadowVeryShort average period plus 4.
+pub fn cdl_ladder_bottom_lookback(settings: Option<&CandleSettings>) -> usize {
+    LadderBottom::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Ladder Bottom (CDLLADDERBOTTOM) over plain OHLC slices, the polars free core of [`cdl_ladder_bottom`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_ladder_bottom_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_ladder_bottom_slice;
+///
+/// let mut open = vec![10.0; 15];
+/// let mut high = vec![10.6; 15];
+/// let mut low = vec![9.6; 15];
+/// let mut close = vec![10.2; 15];
+/// // four falling black candles, the last with an upper shadow, then a white candle above its high
+/// (open[10], high[10], low[10], close[10]) = (10.4, 10.5, 9.9, 10.0);
+/// (open[11], high[11], low[11], close[11]) = (10.2, 10.3, 9.7, 9.8);
+/// (open[12], high[12], low[12], close[12]) = (10.0, 10.1, 9.5, 9.6);
+/// (open[13], high[13], low[13], close[13]) = (9.7, 9.9, 9.4, 9.5);
+/// (open[14], high[14], low[14], close[14]) = (9.8, 10.3, 9.75, 10.2);
+/// let res = cdl_ladder_bottom_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[13], None);
+/// assert_eq!(res[14], Some(100));
+/// ```
+pub fn cdl_ladder_bottom_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(LadderBottom, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Ladder Bottom (CDLLADDERBOTTOM).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_ladder_bottom_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_ladder_bottom function does
+///     import talib
+///     res = talib.CDLLADDERBOTTOM(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_ladder_bottom;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_ladder_bottom(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_ladder_bottom(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(LadderBottom, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Ladder Bottom (CDLLADDERBOTTOM).
+///
+/// Returns `None` for the first [`cdl_ladder_bottom_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlLadderBottom;
+///
+/// let mut ladder_bottom = CdlLadderBottom::new();
+/// for _ in 0..10 {
+///     assert_eq!(ladder_bottom.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(ladder_bottom.update(10.4, 10.5, 9.9, 10.0), None);
+/// assert_eq!(ladder_bottom.update(10.2, 10.3, 9.7, 9.8), None);
+/// assert_eq!(ladder_bottom.update(10.0, 10.1, 9.5, 9.6), None);
+/// assert_eq!(ladder_bottom.update(9.7, 9.9, 9.4, 9.5), None);
+/// assert_eq!(ladder_bottom.update(9.8, 10.3, 9.75, 10.2), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlLadderBottom {
+    engine: PatternEngine<LadderBottom>,
+}
+
+impl Default for CdlLadderBottom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlLadderBottom {
+    /// Creates a new CDLLADDERBOTTOM state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLLADDERBOTTOM state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlLadderBottom {
+            engine: PatternEngine::new(LadderBottom, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_ladder_bottom() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 15];
+        let mut high = vec![10.6; 15];
+        let mut low = vec![9.6; 15];
+        let mut close = vec![10.2; 15];
+        (open[10], high[10], low[10], close[10]) = (10.4, 10.5, 9.9, 10.0);
+        (open[11], high[11], low[11], close[11]) = (10.2, 10.3, 9.7, 9.8);
+        (open[12], high[12], low[12], close[12]) = (10.0, 10.1, 9.5, 9.6);
+        (open[13], high[13], low[13], close[13]) = (9.7, 9.9, 9.4, 9.5);
+        (open[14], high[14], low[14], close[14]) = (9.8, 10.3, 9.75, 10.2);
+        let res = cdl_ladder_bottom_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_ladder_bottom_lookback(None), 14);
+        assert!(res[..14].iter().all(|value| value.is_none()));
+        assert_eq!(res[14], Some(100));
+        // the fourth candle has no upper shadow
+        high[13] = 9.7;
+        let res = cdl_ladder_bottom_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[14], Some(0));
+        assert!(cdl_ladder_bottom_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 10:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_long_legged_doji.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Long Legged Doji
+//!
+//! - a doji body (BodyDoji)
+//! - one or two long shadows (ShadowLong)
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct LongLeggedDoji;
+
+impl CandlePattern for LongLeggedDoji {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyDoji, CandleSettingType::ShadowLong];
+    const CANDLES: usize = 1;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let shadow_long = window.average(CandleSettingType::ShadowLong, 0);
+        if candle.real_body() <= window.average(CandleSettingType::BodyDoji, 0)
+            && (candle.lower_shadow() > shadow_long || candle.upper_shadow() > shadow_long)
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of BodyDoji and ShadowLong.
+pub fn cdl_long_legged_doji_lookback(settings: Option<&CandleSettings>) -> usize {
+    LongLeggedDoji::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Long Legged Doji (CDLLONGLEGGEDDOJI) over plain OHLC slices, the polars free core of [`cdl_long_legged_doji`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_long_legged_doji_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_long_legged_doji_slice;
+///
+/// let mut open = vec![10.0; 11];
+/// let mut high = vec![10.6; 11];
+/// let mut low = vec![9.6; 11];
+/// let mut close = vec![10.2; 11];
+/// // a doji in the middle of a wide range
+/// (open[10], high[10], low[10], close[10]) = (10.0, 10.8, 9.2, 10.01);
+/// let res = cdl_long_legged_doji_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[9], None);
+/// assert_eq!(res[10], Some(100));
+/// ```
+pub fn cdl_long_legged_doji_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(LongLeggedDoji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Long Legged Doji (CDLLONGLEGGEDDOJI).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_long_legged_doji_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_long_legged_doji function does
+///     import talib
+///     res = talib.CDLLONGLEGGEDDOJI(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_long_legged_doji;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_long_legged_doji(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_long_legged_doji(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(LongLeggedDoji, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Long Legged Doji (CDLLONGLEGGEDDOJI).
+///
+/// Returns `None` for the first [`cdl_long_legged_doji_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlLongLeggedDoji;
+///
+/// let mut long_legged_doji = CdlLongLeggedDoji::new();
+/// for _ in 0..10 {
+///     assert_eq!(long_legged_doji.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(long_legged_doji.update(10.0, 10.8, 9.2, 10.01), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlLongLeggedDoji {
+    engine: PatternEngine<LongLeggedDoji>,
+}
+
+impl Default for CdlLongLeggedDoji {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlLongLeggedDoji {
+    /// Creates a new CDLLONGLEGGEDDOJI state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLLONGLEGGEDDOJI state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlLongLeggedDoji {
+            engine: PatternEngine::new(LongLeggedDoji, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_long_legged_doji() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.0, 10.8, 9.2, 10.01);
+        // a doji without shadows
+        (open[11], high[11], low[11], close[11]) = (10.0, 10.0, 10.0, 10.0);
+        let res = cdl_long_legged_doji_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_long_legged_doji_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(100), Some(0), Some(0)]);
+        assert!(cdl_long_legged_doji_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
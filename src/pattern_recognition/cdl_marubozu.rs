@@ -0,0 +1,199 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/30 15:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_marubozu.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Marubozu
+//!
+//! - a long real body (BodyLong)
+//! - no, or very short, upper and lower shadows (ShadowVeryShort)
+//!
+//! 100 for a white candle, -100 for a black one.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Marubozu;
+
+impl CandlePattern for Marubozu {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong, CandleSettingType::ShadowVeryShort];
+    const CANDLES: usize = 1;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let shadow_very_short = window.average(CandleSettingType::ShadowVeryShort, 0);
+        if candle.real_body() > window.average(CandleSettingType::BodyLong, 0)
+            && candle.upper_shadow() < shadow_very_short
+            && candle.lower_shadow() < shadow_very_short
+        {
+            candle.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of BodyLong and ShadowVeryShort.
+pub fn cdl_marubozu_lookback(settings: Option<&CandleSettings>) -> usize {
+    Marubozu::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Marubozu (CDLMARUBOZU) over plain OHLC slices, the polars free core of [`cdl_marubozu`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_marubozu_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_marubozu_slice;
+///
+/// let mut open = vec![10.0; 11];
+/// let mut high = vec![10.6; 11];
+/// let mut low = vec![9.6; 11];
+/// let mut close = vec![10.2; 11];
+/// // a long white body with almost no shadows
+/// (open[10], high[10], low[10], close[10]) = (10.0, 11.02, 9.98, 11.0);
+/// let res = cdl_marubozu_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[9], None);
+/// assert_eq!(res[10], Some(100));
+/// ```
+pub fn cdl_marubozu_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Marubozu, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Marubozu (CDLMARUBOZU).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_marubozu_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_marubozu function does
+///     import talib
+///     res = talib.CDLMARUBOZU(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_marubozu;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_marubozu(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_marubozu(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Marubozu, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Marubozu (CDLMARUBOZU).
+///
+/// Returns `None` for the first [`cdl_marubozu_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlMarubozu;
+///
+/// let mut marubozu = CdlMarubozu::new();
+/// for _ in 0..10 {
+///     assert_eq!(marubozu.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(marubozu.update(11.0, 11.02, 9.97, 10.0), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlMarubozu {
+    engine: PatternEngine<Marubozu>,
+}
+
+impl Default for CdlMarubozu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlMarubozu {
+    /// Creates a new CDLMARUBOZU state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLMARUBOZU state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlMarubozu {
+            engine: PatternEngine::new(Marubozu, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_marubozu() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.0, 11.02, 9.98, 11.0);
+        (open[11], high[11], low[11], close[11]) = (11.0, 11.05, 9.97, 10.0);
+        // a long white body with a long upper shadow
+        (open[12], high[12], low[12], close[12]) = (10.0, 11.5, 9.98, 11.0);
+        let res = cdl_marubozu_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_marubozu_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(100), Some(-100), Some(0)]);
+        assert!(cdl_marubozu_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 15:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_mat_hold.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Mat Hold
+//!
+//! - a long white candle (BodyLong)
+//! - a short black candle gapping up (BodyShort)
+//! - two short candles (BodyShort) falling back into the first real body, by less than `penetration` of it
+//! - a white candle opening above the previous close and closing above the highs of the three reaction candles
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{
+    check_penetration, pattern_slice, CandlePattern, CandleWindow, PatternEngine,
+};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct MatHold {
+    penetration: f64,
+}
+
+impl CandlePattern for MatHold {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyShort, CandleSettingType::BodyLong];
+    const CANDLES: usize = 5;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third, fourth, last) =
+            (window.candle(4), window.candle(3), window.candle(2), window.candle(1), window.candle(0));
+        let low_limit = first.close - first.real_body() * self.penetration;
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 4)
+            && (1..4).all(|ago| window.candle(ago).real_body() < window.average(CandleSettingType::BodyShort, ago))
+            && first.color() == 1
+            && second.color() == -1
+            && last.color() == 1
+            && second.real_body_gap_up(first)
+            && third.body_bottom() < first.close
+            && fourth.body_bottom() < first.close
+            && third.body_bottom() > low_limit
+            && fourth.body_bottom() > low_limit
+            && third.body_top() < second.open
+            && fourth.body_top() < third.body_top()
+            && last.open > fourth.close
+            && last.close > second.high.max(third.high).max(fourth.high)
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 4.
+pub fn cdl_mat_hold_lookback(settings: Option<&CandleSettings>) -> usize {
+    MatHold::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Mat Hold (CDLMATHOLD) over plain OHLC slices, the polars free core of [`cdl_mat_hold`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `penetration` is negative, [`TalibError::LengthMismatch`]
+/// if the inputs have different lengths and [`TalibError::InsufficientData`] if they are not longer
+/// than [`cdl_mat_hold_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_mat_hold_slice;
+///
+/// let mut open = vec![10.0; 15];
+/// let mut high = vec![10.6; 15];
+/// let mut low = vec![9.6; 15];
+/// let mut close = vec![10.2; 15];
+/// // a long white candle, three small reaction candles, then a white candle above their highs
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.9, 11.0, 10.7, 10.8);
+/// (open[12], high[12], low[12], close[12]) = (10.7, 10.75, 10.45, 10.5);
+/// (open[13], high[13], low[13], close[13]) = (10.5, 10.55, 10.3, 10.4);
+/// (open[14], high[14], low[14], close[14]) = (10.45, 11.3, 10.4, 11.2);
+/// let res = cdl_mat_hold_slice(&open, &high, &low, &close, None, None).unwrap();
+/// assert_eq!(res[13], None);
+/// assert_eq!(res[14], Some(100));
+/// ```
+pub fn cdl_mat_hold_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    penetration: Option<f64>,
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let rule = MatHold {
+        penetration: check_penetration(penetration.unwrap_or(0.5))?,
+    };
+    let engine = PatternEngine::new(rule, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Mat Hold (CDLMATHOLD).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_mat_hold_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_mat_hold function does
+///     import talib
+///     res = talib.CDLMATHOLD(open, high, low, close, penetration=0.5)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `penetration` - An optional fraction of the first real body the reaction candles may fall into.
+///   Defaults to 0.5 if not provided.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_mat_hold;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_mat_hold(&open, &high, &low, &close, None, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_mat_hold(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    penetration: Option<f64>,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let rule = MatHold {
+        penetration: check_penetration(penetration.unwrap_or(0.5))?,
+    };
+    let engine = PatternEngine::new(rule, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Mat Hold (CDLMATHOLD).
+///
+/// Returns `None` for the first [`cdl_mat_hold_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlMatHold;
+///
+/// let mut mat_hold = CdlMatHold::new(0.5).unwrap();
+/// for _ in 0..10 {
+///     assert_eq!(mat_hold.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(mat_hold.update(9.6, 10.7, 9.5, 10.6), None);
+/// assert_eq!(mat_hold.update(10.9, 11.0, 10.7, 10.8), None);
+/// assert_eq!(mat_hold.update(10.7, 10.75, 10.45, 10.5), None);
+/// assert_eq!(mat_hold.update(10.5, 10.55, 10.3, 10.4), None);
+/// assert_eq!(mat_hold.update(10.45, 11.3, 10.4, 11.2), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlMatHold {
+    engine: PatternEngine<MatHold>,
+}
+
+impl CdlMatHold {
+    /// Creates a new CDLMATHOLD state with TA-Lib's candle settings, returns an error if `penetration` is negative.
+    pub fn new(penetration: f64) -> Result<Self, TalibError> {
+        Self::with_settings(penetration, &CandleSettings::new())
+    }
+
+    /// Creates a new CDLMATHOLD state with custom candle settings, returns an error if `penetration` is negative.
+    pub fn with_settings(penetration: f64, settings: &CandleSettings) -> Result<Self, TalibError> {
+        let rule = MatHold {
+            penetration: check_penetration(penetration)?,
+        };
+        Ok(CdlMatHold {
+            engine: PatternEngine::new(rule, settings),
+        })
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_mat_hold() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 15];
+        let mut high = vec![10.6; 15];
+        let mut low = vec![9.6; 15];
+        let mut close = vec![10.2; 15];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.9, 11.0, 10.7, 10.8);
+        (open[12], high[12], low[12], close[12]) = (10.7, 10.75, 10.45, 10.5);
+        (open[13], high[13], low[13], close[13]) = (10.5, 10.55, 10.3, 10.4);
+        (open[14], high[14], low[14], close[14]) = (10.45, 11.3, 10.4, 11.2);
+        let res = cdl_mat_hold_slice(&open, &high, &low, &close, None, None)?;
+        assert_eq!(cdl_mat_hold_lookback(None), 14);
+        assert!(res[..14].iter().all(|value| value.is_none()));
+        assert_eq!(res[14], Some(100));
+        // the third candle sinks 10% into the first real body
+        let res = cdl_mat_hold_slice(&open, &high, &low, &close, Some(0.1), None)?;
+        assert_eq!(res[14], Some(0));
+        assert!(cdl_mat_hold_slice(&open, &high, &low, &close, Some(-0.5), None).is_err());
+        assert!(CdlMatHold::new(f64::INFINITY).is_err());
+        assert!(cdl_mat_hold_slice(&open, &high, &low, &close[1..], None, None).is_err());
+        Ok(())
+    }
+}
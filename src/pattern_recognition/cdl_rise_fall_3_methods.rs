@@ -0,0 +1,228 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/31 15:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_rise_fall_3_methods.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Rising/Falling Three Methods
+//!
+//! - a long candle (BodyLong)
+//! - three short candles of the opposite color (BodyShort), their real bodies within the range of the first one,
+//!   closing lower and lower in a rise, higher and higher in a fall
+//! - a long candle of the color of the first one (BodyLong), opening beyond the last close and closing beyond the
+//!   close of the first candle
+//!
+//! 100 for rising three methods, -100 for falling three methods.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct RiseFallThreeMethods;
+
+impl CandlePattern for RiseFallThreeMethods {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyShort, CandleSettingType::BodyLong];
+    const CANDLES: usize = 5;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let first = window.candle(4);
+        let last = window.candle(0);
+        let color = first.color();
+        let sign = f64::from(color);
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 4)
+            && (1..4).all(|ago| window.candle(ago).real_body() < window.average(CandleSettingType::BodyShort, ago))
+            && last.real_body() > window.average(CandleSettingType::BodyLong, 0)
+            && (1..4).all(|ago| window.candle(ago).color() == -color)
+            && last.color() == color
+            && (1..4).all(|ago| {
+                let candle = window.candle(ago);
+                candle.body_bottom() < first.high && candle.body_top() > first.low
+            })
+            && window.candle(2).close * sign < window.candle(3).close * sign
+            && window.candle(1).close * sign < window.candle(2).close * sign
+            && last.open * sign > window.candle(1).close * sign
+            && last.close * sign > first.close * sign
+        {
+            100 * color
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 4.
+pub fn cdl_rise_fall_3_methods_lookback(settings: Option<&CandleSettings>) -> usize {
+    RiseFallThreeMethods::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Rising/Falling Three Methods (CDLRISEFALL3METHODS) over plain OHLC slices, the polars free core of [`cdl_rise_fall_3_methods`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_rise_fall_3_methods_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_rise_fall_3_methods_slice;
+///
+/// let mut open = vec![10.0; 15];
+/// let mut high = vec![10.6; 15];
+/// let mut low = vec![9.6; 15];
+/// let mut close = vec![10.2; 15];
+/// // a long white candle, three small falling black ones within its range, then a long white one
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.5, 10.55, 10.35, 10.4);
+/// (open[12], high[12], low[12], close[12]) = (10.4, 10.45, 10.25, 10.3);
+/// (open[13], high[13], low[13], close[13]) = (10.3, 10.35, 10.15, 10.2);
+/// (open[14], high[14], low[14], close[14]) = (10.25, 11.0, 10.2, 10.9);
+/// let res = cdl_rise_fall_3_methods_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[13], None);
+/// assert_eq!(res[14], Some(100));
+/// ```
+pub fn cdl_rise_fall_3_methods_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(RiseFallThreeMethods, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Rising/Falling Three Methods (CDLRISEFALL3METHODS).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_rise_fall_3_methods_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_rise_fall_3_methods function does
+///     import talib
+///     res = talib.CDLRISEFALL3METHODS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_rise_fall_3_methods;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_rise_fall_3_methods(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_rise_fall_3_methods(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(RiseFallThreeMethods, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Rising/Falling Three Methods (CDLRISEFALL3METHODS).
+///
+/// Returns `None` for the first [`cdl_rise_fall_3_methods_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlRiseFall3Methods;
+///
+/// let mut methods = CdlRiseFall3Methods::new();
+/// for _ in 0..10 {
+///     assert_eq!(methods.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(methods.update(9.6, 10.7, 9.5, 10.6), None);
+/// assert_eq!(methods.update(10.5, 10.55, 10.35, 10.4), None);
+/// assert_eq!(methods.update(10.4, 10.45, 10.25, 10.3), None);
+/// assert_eq!(methods.update(10.3, 10.35, 10.15, 10.2), None);
+/// assert_eq!(methods.update(10.25, 11.0, 10.2, 10.9), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlRiseFall3Methods {
+    engine: PatternEngine<RiseFallThreeMethods>,
+}
+
+impl Default for CdlRiseFall3Methods {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlRiseFall3Methods {
+    /// Creates a new CDLRISEFALL3METHODS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLRISEFALL3METHODS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlRiseFall3Methods {
+            engine: PatternEngine::new(RiseFallThreeMethods, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_rise_fall_3_methods() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 15];
+        let mut high = vec![10.6; 15];
+        let mut low = vec![9.6; 15];
+        let mut close = vec![10.2; 15];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.5, 10.55, 10.35, 10.4);
+        (open[12], high[12], low[12], close[12]) = (10.4, 10.45, 10.25, 10.3);
+        (open[13], high[13], low[13], close[13]) = (10.3, 10.35, 10.15, 10.2);
+        (open[14], high[14], low[14], close[14]) = (10.25, 11.0, 10.2, 10.9);
+        let res = cdl_rise_fall_3_methods_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_rise_fall_3_methods_lookback(None), 14);
+        assert!(res[..14].iter().all(|value| value.is_none()));
+        assert_eq!(res[14], Some(100));
+        // the same candles mirrored around 10 make a falling three methods
+        let mirror = |values: &[f64]| values.iter().map(|value| 20.0 - value).collect::<Vec<f64>>();
+        let (open, high, low, close) = (mirror(&open), mirror(&low), mirror(&high), mirror(&close));
+        let res = cdl_rise_fall_3_methods_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[14], Some(-100));
+        assert!(cdl_rise_fall_3_methods_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
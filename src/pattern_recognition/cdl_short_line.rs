@@ -0,0 +1,206 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/29 14:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_short_line.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Short Line Candle
+//!
+//! - a short real body
+//! - short upper and lower shadows
+//!
+//! 100 for a white candle, -100 for a black one.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct ShortLine;
+
+impl CandlePattern for ShortLine {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyShort, CandleSettingType::ShadowShort];
+    const CANDLES: usize = 1;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let candle = window.candle(0);
+        let shadow_short = window.average(CandleSettingType::ShadowShort, 0);
+        if candle.real_body() < window.average(CandleSettingType::BodyShort, 0)
+            && candle.upper_shadow() < shadow_short
+            && candle.lower_shadow() < shadow_short
+        {
+            candle.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of BodyShort and ShadowShort.
+pub fn cdl_short_line_lookback(settings: Option<&CandleSettings>) -> usize {
+    ShortLine::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Short Line Candle (CDLSHORTLINE) over plain OHLC slices, the polars free core of [`cdl_short_line`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_short_line_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_short_line_slice;
+///
+/// let mut open = vec![10.0; 11];
+/// let mut high = vec![10.6; 11];
+/// let mut low = vec![9.6; 11];
+/// let mut close = vec![10.2; 11];
+/// // a white candle with a quarter of the usual body and short shadows
+/// (open[10], high[10], low[10], close[10]) = (10.0, 10.1, 9.95, 10.05);
+/// let res = cdl_short_line_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[9], None);
+/// assert_eq!(res[10], Some(100));
+/// ```
+pub fn cdl_short_line_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(ShortLine, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Short Line Candle (CDLSHORTLINE).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_short_line_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_short_line function does
+///     import talib
+///     res = talib.CDLSHORTLINE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_short_line;
+///
+///  let open = Series::new("open", [10.0; 12]);
+///  let high = Series::new("high", [10.6; 12]);
+///  let low = Series::new("low", [9.6; 12]);
+///  let close = Series::new("close", [10.2; 12]);
+///  let res = cdl_short_line(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_short_line(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(ShortLine, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Short Line Candle (CDLSHORTLINE).
+///
+/// Returns `None` for the first [`cdl_short_line_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlShortLine;
+///
+/// let mut short_line = CdlShortLine::new();
+/// for _ in 0..10 {
+///     assert_eq!(short_line.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(short_line.update(10.05, 10.1, 9.95, 10.0), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlShortLine {
+    engine: PatternEngine<ShortLine>,
+}
+
+impl Default for CdlShortLine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlShortLine {
+    /// Creates a new CDLSHORTLINE state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLSHORTLINE state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlShortLine {
+            engine: PatternEngine::new(ShortLine, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CandleSetting, RangeType};
+
+    #[test]
+    fn test_cdl_short_line() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.05, 10.1, 9.95, 10.0);
+        // a short body with a long lower shadow
+        (open[11], high[11], low[11], close[11]) = (10.0, 10.1, 9.0, 10.05);
+        let res = cdl_short_line_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_short_line_lookback(None), 10);
+        assert!(res[..10].iter().all(|value| value.is_none()));
+        assert_eq!(res[10..], [Some(-100), Some(0), Some(0)]);
+
+        let mut settings = CandleSettings::new();
+        settings.set(CandleSettingType::BodyShort, CandleSetting::new(RangeType::RealBody, 3, 1.0))?;
+        assert_eq!(cdl_short_line_lookback(Some(&settings)), 10);
+        settings.set(CandleSettingType::ShadowShort, CandleSetting::new(RangeType::Shadows, 20, 1.0))?;
+        assert_eq!(cdl_short_line_lookback(Some(&settings)), 20);
+        assert!(cdl_short_line_slice(&open, &high, &low, &close, Some(&settings)).is_err());
+        assert!(cdl_short_line_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 12:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_stalled_pattern.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Stalled Pattern
+//!
+//! - three white candles with consecutively higher closes
+//! - the first two long (BodyLong), the second with a very short upper shadow (ShadowVeryShort), opening within or
+//!   near the real body of the first one (Near)
+//! - a short third candle (BodyShort) riding on the shoulder of the second one (Near)
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct StalledPattern;
+
+impl CandlePattern for StalledPattern {
+    const SETTINGS: &'static [CandleSettingType] = &[
+        CandleSettingType::BodyLong,
+        CandleSettingType::BodyShort,
+        CandleSettingType::ShadowVeryShort,
+        CandleSettingType::Near,
+    ];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == 1
+            && second.color() == 1
+            && third.color() == 1
+            && third.close > second.close
+            && second.close > first.close
+            && first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && second.real_body() > window.average(CandleSettingType::BodyLong, 1)
+            && second.upper_shadow() < window.average(CandleSettingType::ShadowVeryShort, 1)
+            && second.open > first.open
+            && second.open <= first.close + window.average(CandleSettingType::Near, 2)
+            && third.real_body() < window.average(CandleSettingType::BodyShort, 0)
+            && third.open >= second.close - third.real_body() - window.average(CandleSettingType::Near, 1)
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_stalled_pattern_lookback(settings: Option<&CandleSettings>) -> usize {
+    StalledPattern::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Stalled Pattern (CDLSTALLEDPATTERN) over plain OHLC slices, the polars free core of [`cdl_stalled_pattern`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_stalled_pattern_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_stalled_pattern_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // two long white candles, then a small one at the top of the second
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.65, 9.55, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (10.5, 11.52, 10.45, 11.5);
+/// (open[12], high[12], low[12], close[12]) = (11.5, 11.7, 11.45, 11.6);
+/// let res = cdl_stalled_pattern_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_stalled_pattern_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(StalledPattern, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Stalled Pattern (CDLSTALLEDPATTERN).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_stalled_pattern_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_stalled_pattern function does
+///     import talib
+///     res = talib.CDLSTALLEDPATTERN(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_stalled_pattern;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_stalled_pattern(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_stalled_pattern(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(StalledPattern, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Stalled Pattern (CDLSTALLEDPATTERN).
+///
+/// Returns `None` for the first [`cdl_stalled_pattern_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlStalledPattern;
+///
+/// let mut stalled = CdlStalledPattern::new();
+/// for _ in 0..10 {
+///     assert_eq!(stalled.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(stalled.update(9.6, 10.65, 9.55, 10.6), None);
+/// assert_eq!(stalled.update(10.5, 11.52, 10.45, 11.5), None);
+/// assert_eq!(stalled.update(11.5, 11.7, 11.45, 11.6), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlStalledPattern {
+    engine: PatternEngine<StalledPattern>,
+}
+
+impl Default for CdlStalledPattern {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlStalledPattern {
+    /// Creates a new CDLSTALLEDPATTERN state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLSTALLEDPATTERN state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlStalledPattern {
+            engine: PatternEngine::new(StalledPattern, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_stalled_pattern() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.65, 9.55, 10.6);
+        (open[11], high[11], low[11], close[11]) = (10.5, 11.52, 10.45, 11.5);
+        (open[12], high[12], low[12], close[12]) = (11.5, 11.7, 11.45, 11.6);
+        let res = cdl_stalled_pattern_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_stalled_pattern_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(-100));
+        // the third candle opens well inside the second real body
+        open[12] = 11.0;
+        let res = cdl_stalled_pattern_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_stalled_pattern_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
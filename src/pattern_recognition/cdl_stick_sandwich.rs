@@ -0,0 +1,210 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 15:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_stick_sandwich.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Stick Sandwich
+//!
+//! - a black candle
+//! - a white candle trading above the close of the first one
+//! - a black candle closing at, or very near, the close of the first one (Equal)
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct StickSandwich;
+
+impl CandlePattern for StickSandwich {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::Equal];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        let equal = window.average(CandleSettingType::Equal, 2);
+        if first.color() == -1
+            && second.color() == 1
+            && third.color() == -1
+            && second.low > first.close
+            && third.close <= first.close + equal
+            && third.close >= first.close - equal
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the Equal average period plus 2.
+pub fn cdl_stick_sandwich_lookback(settings: Option<&CandleSettings>) -> usize {
+    StickSandwich::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Stick Sandwich (CDLSTICKSANDWICH) over plain OHLC slices, the polars free core of [`cdl_stick_sandwich`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_stick_sandwich_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_stick_sandwich_slice;
+///
+/// let mut open = vec![10.0; 8];
+/// let mut high = vec![10.6; 8];
+/// let mut low = vec![9.6; 8];
+/// let mut close = vec![10.2; 8];
+/// // a white candle between two black candles closing at the same price
+/// (open[5], high[5], low[5], close[5]) = (10.4, 10.45, 9.95, 10.0);
+/// (open[6], high[6], low[6], close[6]) = (10.1, 10.55, 10.05, 10.5);
+/// (open[7], high[7], low[7], close[7]) = (10.45, 10.5, 9.95, 10.01);
+/// let res = cdl_stick_sandwich_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[6], None);
+/// assert_eq!(res[7], Some(100));
+/// ```
+pub fn cdl_stick_sandwich_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(StickSandwich, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Stick Sandwich (CDLSTICKSANDWICH).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_stick_sandwich_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_stick_sandwich function does
+///     import talib
+///     res = talib.CDLSTICKSANDWICH(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_stick_sandwich;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_stick_sandwich(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_stick_sandwich(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(StickSandwich, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Stick Sandwich (CDLSTICKSANDWICH).
+///
+/// Returns `None` for the first [`cdl_stick_sandwich_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlStickSandwich;
+///
+/// let mut sandwich = CdlStickSandwich::new();
+/// for _ in 0..5 {
+///     assert_eq!(sandwich.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(sandwich.update(10.4, 10.45, 9.95, 10.0), None);
+/// assert_eq!(sandwich.update(10.1, 10.55, 10.05, 10.5), None);
+/// assert_eq!(sandwich.update(10.45, 10.5, 9.95, 10.01), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlStickSandwich {
+    engine: PatternEngine<StickSandwich>,
+}
+
+impl Default for CdlStickSandwich {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlStickSandwich {
+    /// Creates a new CDLSTICKSANDWICH state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLSTICKSANDWICH state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlStickSandwich {
+            engine: PatternEngine::new(StickSandwich, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_stick_sandwich() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 8];
+        let mut high = vec![10.6; 8];
+        let mut low = vec![9.6; 8];
+        let mut close = vec![10.2; 8];
+        (open[5], high[5], low[5], close[5]) = (10.4, 10.45, 9.95, 10.0);
+        (open[6], high[6], low[6], close[6]) = (10.1, 10.55, 10.05, 10.5);
+        (open[7], high[7], low[7], close[7]) = (10.45, 10.5, 9.95, 10.01);
+        let res = cdl_stick_sandwich_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_stick_sandwich_lookback(None), 7);
+        assert!(res[..7].iter().all(|value| value.is_none()));
+        assert_eq!(res[7], Some(100));
+        // the last candle closes under the first close
+        close[7] = 9.9;
+        let res = cdl_stick_sandwich_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[7], Some(0));
+        assert!(cdl_stick_sandwich_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 14:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_tasuki_gap.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Tasuki Gap
+//!
+//! - a candle whose real body gaps away from the previous one, white gapping up or black gapping down
+//! - a candle of the opposite color opening within its real body and closing inside the gap
+//! - the last two real bodies of near the same size (Near)
+//!
+//! 100 for the upside gap, -100 for the downside gap.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct TasukiGap;
+
+impl CandlePattern for TasukiGap {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::Near];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        let near = window.average(CandleSettingType::Near, 1);
+        if (second.real_body() - third.real_body()).abs() < near
+            && ((second.real_body_gap_up(first)
+                && second.color() == 1
+                && third.color() == -1
+                && third.open < second.close
+                && third.open > second.open
+                && third.close < second.open
+                && third.close > first.body_top())
+                || (second.real_body_gap_down(first)
+                    && second.color() == -1
+                    && third.color() == 1
+                    && third.open < second.open
+                    && third.open > second.close
+                    && third.close > second.open
+                    && third.close < first.body_bottom()))
+        {
+            second.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the Near average period plus 2.
+pub fn cdl_tasuki_gap_lookback(settings: Option<&CandleSettings>) -> usize {
+    TasukiGap::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Tasuki Gap (CDLTASUKIGAP) over plain OHLC slices, the polars free core of [`cdl_tasuki_gap`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_tasuki_gap_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_tasuki_gap_slice;
+///
+/// let mut open = vec![10.0; 8];
+/// let mut high = vec![10.6; 8];
+/// let mut low = vec![9.6; 8];
+/// let mut close = vec![10.2; 8];
+/// // a white candle gapping up, then a black one closing inside the gap
+/// (open[5], high[5], low[5], close[5]) = (10.0, 10.5, 9.9, 10.4);
+/// (open[6], high[6], low[6], close[6]) = (10.6, 11.05, 10.55, 11.0);
+/// (open[7], high[7], low[7], close[7]) = (10.9, 10.95, 10.45, 10.5);
+/// let res = cdl_tasuki_gap_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[6], None);
+/// assert_eq!(res[7], Some(100));
+/// ```
+pub fn cdl_tasuki_gap_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(TasukiGap, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Tasuki Gap (CDLTASUKIGAP).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_tasuki_gap_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_tasuki_gap function does
+///     import talib
+///     res = talib.CDLTASUKIGAP(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_tasuki_gap;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_tasuki_gap(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_tasuki_gap(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(TasukiGap, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Tasuki Gap (CDLTASUKIGAP).
+///
+/// Returns `None` for the first [`cdl_tasuki_gap_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlTasukiGap;
+///
+/// let mut tasuki = CdlTasukiGap::new();
+/// for _ in 0..5 {
+///     assert_eq!(tasuki.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(tasuki.update(10.0, 10.5, 9.9, 10.4), None);
+/// assert_eq!(tasuki.update(10.6, 11.05, 10.55, 11.0), None);
+/// assert_eq!(tasuki.update(10.9, 10.95, 10.45, 10.5), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlTasukiGap {
+    engine: PatternEngine<TasukiGap>,
+}
+
+impl Default for CdlTasukiGap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlTasukiGap {
+    /// Creates a new CDLTASUKIGAP state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLTASUKIGAP state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlTasukiGap {
+            engine: PatternEngine::new(TasukiGap, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_tasuki_gap() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 11];
+        let mut high = vec![10.6; 11];
+        let mut low = vec![9.6; 11];
+        let mut close = vec![10.2; 11];
+        (open[5], high[5], low[5], close[5]) = (10.0, 10.5, 9.9, 10.4);
+        (open[6], high[6], low[6], close[6]) = (10.6, 11.05, 10.55, 11.0);
+        (open[7], high[7], low[7], close[7]) = (10.9, 10.95, 10.45, 10.5);
+        (open[8], high[8], low[8], close[8]) = (10.4, 10.45, 9.95, 10.0);
+        (open[9], high[9], low[9], close[9]) = (9.8, 9.85, 9.35, 9.4);
+        (open[10], high[10], low[10], close[10]) = (9.5, 9.95, 9.45, 9.9);
+        let res = cdl_tasuki_gap_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_tasuki_gap_lookback(None), 7);
+        assert!(res[..7].iter().all(|value| value.is_none()));
+        assert_eq!(res[7..], [Some(100), Some(0), Some(0), Some(-100)]);
+        // the white candle closes the gap
+        close[10] = 10.05;
+        let res = cdl_tasuki_gap_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[10], Some(0));
+        assert!(cdl_tasuki_gap_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 12:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_tristar.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Tristar Pattern
+//!
+//! - three dojis (BodyDoji), all measured against the average before the first one like TA-Lib
+//! - the second one gapping up, the third not as high: bearish
+//! - the second one gapping down, the third not as low: bullish
+//!
+//! 100 for the bullish pattern, -100 for the bearish one.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct Tristar;
+
+impl CandlePattern for Tristar {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyDoji];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        let doji = window.average(CandleSettingType::BodyDoji, 2);
+        if first.real_body() > doji || second.real_body() > doji || third.real_body() > doji {
+            0
+        } else if second.real_body_gap_down(first) && third.body_bottom() > second.body_bottom() {
+            100
+        } else if second.real_body_gap_up(first) && third.body_top() < second.body_top() {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the BodyDoji average period plus 2.
+pub fn cdl_tristar_lookback(settings: Option<&CandleSettings>) -> usize {
+    Tristar::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Tristar Pattern (CDLTRISTAR) over plain OHLC slices, the polars free core of [`cdl_tristar`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_tristar_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_tristar_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // three dojis, the second one gapping up
+/// (open[10], high[10], low[10], close[10]) = (10.2, 10.6, 9.8, 10.2);
+/// (open[11], high[11], low[11], close[11]) = (10.5, 10.9, 10.1, 10.52);
+/// (open[12], high[12], low[12], close[12]) = (10.3, 10.7, 9.9, 10.32);
+/// let res = cdl_tristar_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_tristar_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(Tristar, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Tristar Pattern (CDLTRISTAR).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_tristar_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_tristar function does
+///     import talib
+///     res = talib.CDLTRISTAR(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_tristar;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_tristar(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_tristar(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(Tristar, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Tristar Pattern (CDLTRISTAR).
+///
+/// Returns `None` for the first [`cdl_tristar_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlTristar;
+///
+/// let mut tristar = CdlTristar::new();
+/// for _ in 0..10 {
+///     assert_eq!(tristar.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(tristar.update(10.2, 10.6, 9.8, 10.2), None);
+/// assert_eq!(tristar.update(10.5, 10.9, 10.1, 10.52), None);
+/// assert_eq!(tristar.update(10.3, 10.7, 9.9, 10.32), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlTristar {
+    engine: PatternEngine<Tristar>,
+}
+
+impl Default for CdlTristar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlTristar {
+    /// Creates a new CDLTRISTAR state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLTRISTAR state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlTristar {
+            engine: PatternEngine::new(Tristar, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_tristar() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 16];
+        let mut high = vec![10.6; 16];
+        let mut low = vec![9.6; 16];
+        let mut close = vec![10.2; 16];
+        (open[10], high[10], low[10], close[10]) = (10.2, 10.6, 9.8, 10.2);
+        (open[11], high[11], low[11], close[11]) = (10.5, 10.9, 10.1, 10.52);
+        (open[12], high[12], low[12], close[12]) = (10.3, 10.7, 9.9, 10.32);
+        (open[13], high[13], low[13], close[13]) = (10.2, 10.6, 9.8, 10.2);
+        (open[14], high[14], low[14], close[14]) = (9.9, 10.3, 9.5, 9.88);
+        (open[15], high[15], low[15], close[15]) = (10.1, 10.5, 9.7, 10.08);
+        let res = cdl_tristar_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_tristar_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12..], [Some(-100), Some(0), Some(0), Some(100)]);
+        // the third candle is not a doji
+        close[15] = 10.3;
+        let res = cdl_tristar_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[15], Some(0));
+        assert!(cdl_tristar_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 13:10
+ * @Email: uyplayer@qq.com
+ * @File: cdl_unique_3_river.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Unique 3 River
+//!
+//! - a long black candle (BodyLong)
+//! - a black harami: its real body within the first one, with a lower low
+//! - a short white candle (BodyShort) opening above the low of the second one
+//!
+//! 100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct UniqueThreeRiver;
+
+impl CandlePattern for UniqueThreeRiver {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong, CandleSettingType::BodyShort];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && first.color() == -1
+            && second.color() == -1
+            && second.close > first.close
+            && second.open <= first.open
+            && second.low < first.low
+            && third.real_body() < window.average(CandleSettingType::BodyShort, 0)
+            && third.color() == 1
+            && third.open > second.low
+        {
+            100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_unique_3_river_lookback(settings: Option<&CandleSettings>) -> usize {
+    UniqueThreeRiver::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Unique 3 River (CDLUNIQUE3RIVER) over plain OHLC slices, the polars free core of [`cdl_unique_3_river`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_unique_3_river_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_unique_3_river_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // a long black candle, a black harami with a lower low, then a small white candle
+/// (open[10], high[10], low[10], close[10]) = (10.6, 10.7, 9.5, 9.6);
+/// (open[11], high[11], low[11], close[11]) = (10.2, 10.3, 9.2, 9.8);
+/// (open[12], high[12], low[12], close[12]) = (9.4, 9.6, 9.35, 9.5);
+/// let res = cdl_unique_3_river_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(100));
+/// ```
+pub fn cdl_unique_3_river_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(UniqueThreeRiver, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Unique 3 River (CDLUNIQUE3RIVER).
+///
+/// Returns an Int32 Series of 100 and 0, null for the first [`cdl_unique_3_river_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_unique_3_river function does
+///     import talib
+///     res = talib.CDLUNIQUE3RIVER(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_unique_3_river;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_unique_3_river(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_unique_3_river(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(UniqueThreeRiver, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Unique 3 River (CDLUNIQUE3RIVER).
+///
+/// Returns `None` for the first [`cdl_unique_3_river_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlUnique3River;
+///
+/// let mut river = CdlUnique3River::new();
+/// for _ in 0..10 {
+///     assert_eq!(river.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(river.update(10.6, 10.7, 9.5, 9.6), None);
+/// assert_eq!(river.update(10.2, 10.3, 9.2, 9.8), None);
+/// assert_eq!(river.update(9.4, 9.6, 9.35, 9.5), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlUnique3River {
+    engine: PatternEngine<UniqueThreeRiver>,
+}
+
+impl Default for CdlUnique3River {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlUnique3River {
+    /// Creates a new CDLUNIQUE3RIVER state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLUNIQUE3RIVER state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlUnique3River {
+            engine: PatternEngine::new(UniqueThreeRiver, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_unique_3_river() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (10.6, 10.7, 9.5, 9.6);
+        (open[11], high[11], low[11], close[11]) = (10.2, 10.3, 9.2, 9.8);
+        (open[12], high[12], low[12], close[12]) = (9.4, 9.6, 9.35, 9.5);
+        let res = cdl_unique_3_river_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_unique_3_river_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(100));
+        // the second candle does not make a lower low
+        low[11] = 9.55;
+        let res = cdl_unique_3_river_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_unique_3_river_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 09:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_upside_gap_2_crows.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Upside Gap Two Crows
+//!
+//! - a long white candle (BodyLong)
+//! - a short black candle (BodyShort) whose real body gaps up
+//! - a black candle engulfing the real body of the second one, still closing above the close of the first
+//!
+//! -100 when the pattern is found.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct UpsideGapTwoCrows;
+
+impl CandlePattern for UpsideGapTwoCrows {
+    const SETTINGS: &'static [CandleSettingType] = &[CandleSettingType::BodyLong, CandleSettingType::BodyShort];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == 1
+            && first.real_body() > window.average(CandleSettingType::BodyLong, 2)
+            && second.color() == -1
+            && second.real_body() <= window.average(CandleSettingType::BodyShort, 1)
+            && second.real_body_gap_up(first)
+            && third.color() == -1
+            && third.open > second.open
+            && third.close < second.close
+            && third.close > first.close
+        {
+            -100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, the longest average period of its candle settings plus 2.
+pub fn cdl_upside_gap_2_crows_lookback(settings: Option<&CandleSettings>) -> usize {
+    UpsideGapTwoCrows::lookback(settings.unwrap_or(&CandleSettings::new()))
+}
+
+/// Recognizes the Upside Gap Two Crows (CDLUPSIDEGAP2CROWS) over plain OHLC slices, the polars free core of [`cdl_upside_gap_2_crows`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_upside_gap_2_crows_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_upside_gap_2_crows_slice;
+///
+/// let mut open = vec![10.0; 13];
+/// let mut high = vec![10.6; 13];
+/// let mut low = vec![9.6; 13];
+/// let mut close = vec![10.2; 13];
+/// // a long white candle, a small black one gapping up, then a black one engulfing it above the gap
+/// (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+/// (open[11], high[11], low[11], close[11]) = (11.0, 11.1, 10.8, 10.85);
+/// (open[12], high[12], low[12], close[12]) = (11.1, 11.2, 10.65, 10.7);
+/// let res = cdl_upside_gap_2_crows_slice(&open, &high, &low, &close, None).unwrap();
+/// assert_eq!(res[11], None);
+/// assert_eq!(res[12], Some(-100));
+/// ```
+pub fn cdl_upside_gap_2_crows_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    settings: Option<&CandleSettings>,
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(UpsideGapTwoCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Upside Gap Two Crows (CDLUPSIDEGAP2CROWS).
+///
+/// Returns an Int32 Series of -100 and 0, null for the first [`cdl_upside_gap_2_crows_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_upside_gap_2_crows function does
+///     import talib
+///     res = talib.CDLUPSIDEGAP2CROWS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `settings` - Optional candle settings. Defaults to TA-Lib's if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_upside_gap_2_crows;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_upside_gap_2_crows(&open, &high, &low, &close, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_upside_gap_2_crows(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    settings: Option<&CandleSettings>,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(UpsideGapTwoCrows, settings.unwrap_or(&CandleSettings::new()));
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Upside Gap Two Crows (CDLUPSIDEGAP2CROWS).
+///
+/// Returns `None` for the first [`cdl_upside_gap_2_crows_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlUpsideGap2Crows;
+///
+/// let mut crows = CdlUpsideGap2Crows::new();
+/// for _ in 0..10 {
+///     assert_eq!(crows.update(10.0, 10.6, 9.6, 10.2), None);
+/// }
+/// assert_eq!(crows.update(9.6, 10.7, 9.5, 10.6), None);
+/// assert_eq!(crows.update(11.0, 11.1, 10.8, 10.85), None);
+/// assert_eq!(crows.update(11.1, 11.2, 10.65, 10.7), Some(-100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlUpsideGap2Crows {
+    engine: PatternEngine<UpsideGapTwoCrows>,
+}
+
+impl Default for CdlUpsideGap2Crows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlUpsideGap2Crows {
+    /// Creates a new CDLUPSIDEGAP2CROWS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLUPSIDEGAP2CROWS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlUpsideGap2Crows {
+            engine: PatternEngine::new(UpsideGapTwoCrows, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_upside_gap_2_crows() -> Result<(), TalibError> {
+        let mut open = vec![10.0; 13];
+        let mut high = vec![10.6; 13];
+        let mut low = vec![9.6; 13];
+        let mut close = vec![10.2; 13];
+        (open[10], high[10], low[10], close[10]) = (9.6, 10.7, 9.5, 10.6);
+        (open[11], high[11], low[11], close[11]) = (11.0, 11.1, 10.8, 10.85);
+        (open[12], high[12], low[12], close[12]) = (11.1, 11.2, 10.65, 10.7);
+        let res = cdl_upside_gap_2_crows_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(cdl_upside_gap_2_crows_lookback(None), 12);
+        assert!(res[..12].iter().all(|value| value.is_none()));
+        assert_eq!(res[12], Some(-100));
+        // the third candle fills the gap
+        close[12] = 10.5;
+        let res = cdl_upside_gap_2_crows_slice(&open, &high, &low, &close, None)?;
+        assert_eq!(res[12], Some(0));
+        assert!(cdl_upside_gap_2_crows_slice(&open, &high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2024/01/02 13:40
+ * @Email: uyplayer@qq.com
+ * @File: cdl_xside_gap_3_methods.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Upside/Downside Gap Three Methods
+//!
+//! - two candles of the same color, the real body of the second gapping away from the first one, up when white
+//!   and down when black
+//! - a candle of the opposite color opening within the second real body and closing within the first one
+//!
+//! 100 for the upside gap, -100 for the downside gap.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::pattern_recognition::pattern_engine::pattern_series;
+use crate::pattern_recognition::pattern_engine::{pattern_slice, CandlePattern, CandleWindow, PatternEngine};
+use crate::{CandleSettingType, CandleSettings, TalibError};
+
+#[derive(Debug, Clone)]
+struct XSideGapThreeMethods;
+
+impl CandlePattern for XSideGapThreeMethods {
+    const SETTINGS: &'static [CandleSettingType] = &[];
+    const CANDLES: usize = 3;
+
+    fn detect(&mut self, window: &CandleWindow) -> i32 {
+        let (first, second, third) = (window.candle(2), window.candle(1), window.candle(0));
+        if first.color() == second.color()
+            && second.color() == -third.color()
+            && third.open < second.body_top()
+            && third.open > second.body_bottom()
+            && third.close < first.body_top()
+            && third.close > first.body_bottom()
+            && ((first.color() == 1 && second.real_body_gap_up(first))
+                || (first.color() == -1 && second.real_body_gap_down(first)))
+        {
+            first.color() * 100
+        } else {
+            0
+        }
+    }
+}
+
+/// Number of leading bars without a pattern value, 2 like TA-Lib.
+pub fn cdl_xside_gap_3_methods_lookback() -> usize {
+    XSideGapThreeMethods::lookback(&CandleSettings::new())
+}
+
+/// Recognizes the Upside/Downside Gap Three Methods (CDLXSIDEGAP3METHODS) over plain OHLC slices, the polars free core of [`cdl_xside_gap_3_methods`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are not longer than [`cdl_xside_gap_3_methods_lookback`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::cdl_xside_gap_3_methods_slice;
+///
+/// let open = [10.0, 10.7, 10.9];
+/// let high = [10.6, 11.2, 11.0];
+/// let low = [9.9, 10.6, 10.1];
+/// let close = [10.5, 11.1, 10.2];
+/// // two white candles with a gap, then a black one closing into the first real body
+/// let res = cdl_xside_gap_3_methods_slice(&open, &high, &low, &close).unwrap();
+/// assert_eq!(res, [None, None, Some(100)]);
+/// ```
+pub fn cdl_xside_gap_3_methods_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<Vec<Option<i32>>, TalibError> {
+    let engine = PatternEngine::new(XSideGapThreeMethods, &CandleSettings::new());
+    pattern_slice(engine, open, high, low, close)
+}
+
+/// Recognizes the Upside/Downside Gap Three Methods (CDLXSIDEGAP3METHODS).
+///
+/// Returns an Int32 Series of 100, -100 and 0, null for the first [`cdl_xside_gap_3_methods_lookback`] bars.
+///
+/// ``` python
+///     #  This Python code produces the same result as the cdl_xside_gap_3_methods function does
+///     import talib
+///     res = talib.CDLXSIDEGAP3METHODS(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::cdl_xside_gap_3_methods;
+///
+///  let open = Series::new("open", [10.0; 15]);
+///  let high = Series::new("high", [10.6; 15]);
+///  let low = Series::new("low", [9.6; 15]);
+///  let close = Series::new("close", [10.2; 15]);
+///  let res = cdl_xside_gap_3_methods(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn cdl_xside_gap_3_methods(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<Series, TalibError> {
+    let engine = PatternEngine::new(XSideGapThreeMethods, &CandleSettings::new());
+    pattern_series(engine, open, high, low, close)
+}
+
+/// Streaming Upside/Downside Gap Three Methods (CDLXSIDEGAP3METHODS).
+///
+/// Returns `None` for the first [`cdl_xside_gap_3_methods_lookback`] bars.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CdlXSideGap3Methods;
+///
+/// let mut methods = CdlXSideGap3Methods::new();
+/// assert_eq!(methods.update(10.0, 10.6, 9.9, 10.5), None);
+/// assert_eq!(methods.update(10.7, 11.2, 10.6, 11.1), None);
+/// assert_eq!(methods.update(10.9, 11.0, 10.1, 10.2), Some(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlXSideGap3Methods {
+    engine: PatternEngine<XSideGapThreeMethods>,
+}
+
+impl Default for CdlXSideGap3Methods {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdlXSideGap3Methods {
+    /// Creates a new CDLXSIDEGAP3METHODS state with TA-Lib's candle settings.
+    pub fn new() -> Self {
+        Self::with_settings(&CandleSettings::new())
+    }
+
+    /// Creates a new CDLXSIDEGAP3METHODS state with custom candle settings.
+    pub fn with_settings(settings: &CandleSettings) -> Self {
+        CdlXSideGap3Methods {
+            engine: PatternEngine::new(XSideGapThreeMethods, settings),
+        }
+    }
+
+    /// Feeds the next bar and returns the pattern value.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.engine.update(open, high, low, close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_xside_gap_3_methods() -> Result<(), TalibError> {
+        let open = [10.0, 10.7, 10.9, 10.5, 9.8, 9.5];
+        let high = [10.6, 11.2, 11.0, 10.6, 9.9, 10.3];
+        let low = [9.9, 10.6, 10.1, 9.9, 9.2, 9.4];
+        let mut close = [10.5, 11.1, 10.2, 10.0, 9.3, 10.2];
+        let res = cdl_xside_gap_3_methods_slice(&open, &high, &low, &close)?;
+        assert_eq!(cdl_xside_gap_3_methods_lookback(), 2);
+        // two black candles with a gap at 3 and 4, then a white one closing into the first real body
+        assert_eq!(res, [None, None, Some(100), Some(0), Some(0), Some(-100)]);
+        // the white candle closes above the first real body
+        close[5] = 10.55;
+        let res = cdl_xside_gap_3_methods_slice(&open, &high, &low, &close)?;
+        assert_eq!(res[5], Some(0));
+        assert!(cdl_xside_gap_3_methods_slice(&open, &high, &low, &close[1..]).is_err());
+        assert!(cdl_xside_gap_3_methods_slice(&open[..2], &high[..2], &low[..2], &close[..2]).is_err());
+        Ok(())
+    }
+}
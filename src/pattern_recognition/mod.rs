@@ -4,7 +4,238 @@
  * @Email: uyplayer@qq.com
  * @File: mod.rs
  * @Software: RustRover
- * @Dir: rusty-talib / src/cycle_indicators
+ * @Dir: rusty-talib / src/pattern_recognition
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+
+// | Function            | Description                          | Status  |
+// |---------------------|--------------------------------------|---------|
+// | CDL2CROWS           | Two Crows                            | Done    |
+// | CDL3BLACKCROWS      | Three Black Crows                    | Done    |
+// | CDL3INSIDE          | Three Inside Up/Down                 | Done    |
+// | CDL3LINESTRIKE      | Three-Line Strike                    | Done    |
+// | CDL3OUTSIDE         | Three Outside Up/Down                | Done    |
+// | CDL3STARSINSOUTH    | Three Stars In The South             | Done    |
+// | CDL3WHITESOLDIERS   | Three Advancing White Soldiers       | Done    |
+// | CDLABANDONEDBABY    | Abandoned Baby                       | Done    |
+// | CDLADVANCEBLOCK     | Advance Block                        | Done    |
+// | CDLBREAKAWAY        | Breakaway                            | Done    |
+// | CDLCONCEALBABYSWALL | Concealing Baby Swallow              | Done    |
+// | CDLDOJI             | Doji                                 | Done    |
+// | CDLDRAGONFLYDOJI    | Dragonfly Doji                       | Done    |
+// | CDLENGULFING        | Engulfing Pattern                    | Done    |
+// | CDLEVENINGDOJISTAR  | Evening Doji Star                    | Done    |
+// | CDLEVENINGSTAR      | Evening Star                         | Done    |
+// | CDLGAPSIDESIDEWHITE | Up/Down-gap side-by-side white lines | Done    |
+// | CDLGRAVESTONEDOJI   | Gravestone Doji                      | Done    |
+// | CDLHAMMER           | Hammer                               | Done    |
+// | CDLHANGINGMAN       | Hanging Man                          | Done    |
+// | CDLHARAMI           | Harami Pattern                       | Done    |
+// | CDLHARAMICROSS      | Harami Cross Pattern                 | Done    |
+// | CDLHIKKAKE          | Hikkake Pattern                      | Done    |
+// | CDLHIKKAKEMOD       | Modified Hikkake Pattern             | Done    |
+// | CDLIDENTICAL3CROWS  | Identical Three Crows                | Done    |
+// | CDLINVERTEDHAMMER   | Inverted Hammer                      | Done    |
+// | CDLLADDERBOTTOM     | Ladder Bottom                        | Done    |
+// | CDLLONGLEGGEDDOJI   | Long Legged Doji                     | Done    |
+// | CDLLONGLINE         | Long Line Candle                     | Done    |
+// | CDLMARUBOZU         | Marubozu                             | Done    |
+// | CDLMATHOLD          | Mat Hold                             | Done    |
+// | CDLMORNINGDOJISTAR  | Morning Doji Star                    | Done    |
+// | CDLMORNINGSTAR      | Morning Star                         | Done    |
+// | CDLRISEFALL3METHODS | Rising/Falling Three Methods         | Done    |
+// | CDLSHOOTINGSTAR     | Shooting Star                        | Done    |
+// | CDLSHORTLINE        | Short Line Candle                    | Done    |
+// | CDLSPINNINGTOP      | Spinning Top                         | Done    |
+// | CDLSTALLEDPATTERN   | Stalled Pattern                      | Done    |
+// | CDLSTICKSANDWICH    | Stick Sandwich                       | Done    |
+// | CDLTASUKIGAP        | Tasuki Gap                           | Done    |
+// | CDLTRISTAR          | Tristar Pattern                      | Done    |
+// | CDLUNIQUE3RIVER     | Unique 3 River                       | Done    |
+// | CDLUPSIDEGAP2CROWS  | Upside Gap Two Crows                 | Done    |
+// | CDLXSIDEGAP3METHODS | Upside/Downside Gap Three Methods    | Done    |
+
+/// This module contains the candlestick pattern recognition.
+mod candle_settings;
+mod pattern_engine;
+mod cdl_2_crows;
+mod cdl_3_black_crows;
+mod cdl_3_inside;
+mod cdl_3_line_strike;
+mod cdl_3_outside;
+mod cdl_3_stars_in_south;
+mod cdl_3_white_soldiers;
+mod cdl_abandoned_baby;
+mod cdl_advance_block;
+mod cdl_breakaway;
+mod cdl_conceal_baby_swallow;
+mod cdl_doji;
+mod cdl_dragonfly_doji;
+mod cdl_engulfing;
+mod cdl_evening_doji_star;
+mod cdl_evening_star;
+mod cdl_gap_side_side_white;
+mod cdl_gravestone_doji;
+mod cdl_hammer;
+mod cdl_hanging_man;
+mod cdl_harami;
+mod cdl_harami_cross;
+mod cdl_hikkake;
+mod cdl_hikkake_mod;
+mod cdl_identical_3_crows;
+mod cdl_inverted_hammer;
+mod cdl_ladder_bottom;
+mod cdl_long_legged_doji;
+mod cdl_long_line;
+mod cdl_marubozu;
+mod cdl_mat_hold;
+mod cdl_morning_doji_star;
+mod cdl_morning_star;
+mod cdl_rise_fall_3_methods;
+mod cdl_shooting_star;
+mod cdl_short_line;
+mod cdl_spinning_top;
+mod cdl_stalled_pattern;
+mod cdl_stick_sandwich;
+mod cdl_tasuki_gap;
+mod cdl_tristar;
+mod cdl_unique_3_river;
+mod cdl_upside_gap_2_crows;
+mod cdl_xside_gap_3_methods;
+
+
+pub use candle_settings::{CandleSetting, CandleSettingType, CandleSettings, RangeType};
+pub use cdl_2_crows::{cdl_2_crows_lookback, cdl_2_crows_slice, Cdl2Crows};
+pub use cdl_3_black_crows::{cdl_3_black_crows_lookback, cdl_3_black_crows_slice, Cdl3BlackCrows};
+pub use cdl_3_inside::{cdl_3_inside_lookback, cdl_3_inside_slice, Cdl3Inside};
+pub use cdl_3_line_strike::{cdl_3_line_strike_lookback, cdl_3_line_strike_slice, Cdl3LineStrike};
+pub use cdl_3_outside::{cdl_3_outside_lookback, cdl_3_outside_slice, Cdl3Outside};
+pub use cdl_3_stars_in_south::{cdl_3_stars_in_south_lookback, cdl_3_stars_in_south_slice, Cdl3StarsInSouth};
+pub use cdl_3_white_soldiers::{cdl_3_white_soldiers_lookback, cdl_3_white_soldiers_slice, Cdl3WhiteSoldiers};
+pub use cdl_abandoned_baby::{cdl_abandoned_baby_lookback, cdl_abandoned_baby_slice, CdlAbandonedBaby};
+pub use cdl_advance_block::{cdl_advance_block_lookback, cdl_advance_block_slice, CdlAdvanceBlock};
+pub use cdl_breakaway::{cdl_breakaway_lookback, cdl_breakaway_slice, CdlBreakaway};
+pub use cdl_conceal_baby_swallow::{cdl_conceal_baby_swallow_lookback, cdl_conceal_baby_swallow_slice, CdlConcealBabySwallow};
+pub use cdl_doji::{cdl_doji_lookback, cdl_doji_slice, CdlDoji};
+pub use cdl_dragonfly_doji::{cdl_dragonfly_doji_lookback, cdl_dragonfly_doji_slice, CdlDragonflyDoji};
+pub use cdl_engulfing::{cdl_engulfing_lookback, cdl_engulfing_slice, CdlEngulfing};
+pub use cdl_evening_doji_star::{cdl_evening_doji_star_lookback, cdl_evening_doji_star_slice, CdlEveningDojiStar};
+pub use cdl_evening_star::{cdl_evening_star_lookback, cdl_evening_star_slice, CdlEveningStar};
+pub use cdl_gap_side_side_white::{cdl_gap_side_side_white_lookback, cdl_gap_side_side_white_slice, CdlGapSideSideWhite};
+pub use cdl_gravestone_doji::{cdl_gravestone_doji_lookback, cdl_gravestone_doji_slice, CdlGravestoneDoji};
+pub use cdl_hammer::{cdl_hammer_lookback, cdl_hammer_slice, CdlHammer};
+pub use cdl_hanging_man::{cdl_hanging_man_lookback, cdl_hanging_man_slice, CdlHangingMan};
+pub use cdl_harami::{cdl_harami_lookback, cdl_harami_slice, CdlHarami};
+pub use cdl_harami_cross::{cdl_harami_cross_lookback, cdl_harami_cross_slice, CdlHaramiCross};
+pub use cdl_hikkake::{cdl_hikkake_lookback, cdl_hikkake_slice, CdlHikkake};
+pub use cdl_hikkake_mod::{cdl_hikkake_mod_lookback, cdl_hikkake_mod_slice, CdlHikkakeMod};
+pub use cdl_identical_3_crows::{cdl_identical_3_crows_lookback, cdl_identical_3_crows_slice, CdlIdentical3Crows};
+pub use cdl_inverted_hammer::{cdl_inverted_hammer_lookback, cdl_inverted_hammer_slice, CdlInvertedHammer};
+pub use cdl_ladder_bottom::{cdl_ladder_bottom_lookback, cdl_ladder_bottom_slice, CdlLadderBottom};
+pub use cdl_long_legged_doji::{cdl_long_legged_doji_lookback, cdl_long_legged_doji_slice, CdlLongLeggedDoji};
+pub use cdl_long_line::{cdl_long_line_lookback, cdl_long_line_slice, CdlLongLine};
+pub use cdl_marubozu::{cdl_marubozu_lookback, cdl_marubozu_slice, CdlMarubozu};
+pub use cdl_mat_hold::{cdl_mat_hold_lookback, cdl_mat_hold_slice, CdlMatHold};
+pub use cdl_morning_doji_star::{cdl_morning_doji_star_lookback, cdl_morning_doji_star_slice, CdlMorningDojiStar};
+pub use cdl_morning_star::{cdl_morning_star_lookback, cdl_morning_star_slice, CdlMorningStar};
+pub use cdl_rise_fall_3_methods::{cdl_rise_fall_3_methods_lookback, cdl_rise_fall_3_methods_slice, CdlRiseFall3Methods};
+pub use cdl_shooting_star::{cdl_shooting_star_lookback, cdl_shooting_star_slice, CdlShootingStar};
+pub use cdl_short_line::{cdl_short_line_lookback, cdl_short_line_slice, CdlShortLine};
+pub use cdl_spinning_top::{cdl_spinning_top_lookback, cdl_spinning_top_slice, CdlSpinningTop};
+pub use cdl_stalled_pattern::{cdl_stalled_pattern_lookback, cdl_stalled_pattern_slice, CdlStalledPattern};
+pub use cdl_stick_sandwich::{cdl_stick_sandwich_lookback, cdl_stick_sandwich_slice, CdlStickSandwich};
+pub use cdl_tasuki_gap::{cdl_tasuki_gap_lookback, cdl_tasuki_gap_slice, CdlTasukiGap};
+pub use cdl_tristar::{cdl_tristar_lookback, cdl_tristar_slice, CdlTristar};
+pub use cdl_unique_3_river::{cdl_unique_3_river_lookback, cdl_unique_3_river_slice, CdlUnique3River};
+pub use cdl_upside_gap_2_crows::{cdl_upside_gap_2_crows_lookback, cdl_upside_gap_2_crows_slice, CdlUpsideGap2Crows};
+pub use cdl_xside_gap_3_methods::{cdl_xside_gap_3_methods_lookback, cdl_xside_gap_3_methods_slice, CdlXSideGap3Methods};
+
+#[cfg(feature = "polars")]
+pub use cdl_2_crows::cdl_2_crows;
+#[cfg(feature = "polars")]
+pub use cdl_3_black_crows::cdl_3_black_crows;
+#[cfg(feature = "polars")]
+pub use cdl_3_inside::cdl_3_inside;
+#[cfg(feature = "polars")]
+pub use cdl_3_line_strike::cdl_3_line_strike;
+#[cfg(feature = "polars")]
+pub use cdl_3_outside::cdl_3_outside;
+#[cfg(feature = "polars")]
+pub use cdl_3_stars_in_south::cdl_3_stars_in_south;
+#[cfg(feature = "polars")]
+pub use cdl_3_white_soldiers::cdl_3_white_soldiers;
+#[cfg(feature = "polars")]
+pub use cdl_abandoned_baby::cdl_abandoned_baby;
+#[cfg(feature = "polars")]
+pub use cdl_advance_block::cdl_advance_block;
+#[cfg(feature = "polars")]
+pub use cdl_breakaway::cdl_breakaway;
+#[cfg(feature = "polars")]
+pub use cdl_conceal_baby_swallow::cdl_conceal_baby_swallow;
+#[cfg(feature = "polars")]
+pub use cdl_doji::cdl_doji;
+#[cfg(feature = "polars")]
+pub use cdl_dragonfly_doji::cdl_dragonfly_doji;
+#[cfg(feature = "polars")]
+pub use cdl_engulfing::cdl_engulfing;
+#[cfg(feature = "polars")]
+pub use cdl_evening_doji_star::cdl_evening_doji_star;
+#[cfg(feature = "polars")]
+pub use cdl_evening_star::cdl_evening_star;
+#[cfg(feature = "polars")]
+pub use cdl_gap_side_side_white::cdl_gap_side_side_white;
+#[cfg(feature = "polars")]
+pub use cdl_gravestone_doji::cdl_gravestone_doji;
+#[cfg(feature = "polars")]
+pub use cdl_hammer::cdl_hammer;
+#[cfg(feature = "polars")]
+pub use cdl_hanging_man::cdl_hanging_man;
+#[cfg(feature = "polars")]
+pub use cdl_harami::cdl_harami;
+#[cfg(feature = "polars")]
+pub use cdl_harami_cross::cdl_harami_cross;
+#[cfg(feature = "polars")]
+pub use cdl_hikkake::cdl_hikkake;
+#[cfg(feature = "polars")]
+pub use cdl_hikkake_mod::cdl_hikkake_mod;
+#[cfg(feature = "polars")]
+pub use cdl_identical_3_crows::cdl_identical_3_crows;
+#[cfg(feature = "polars")]
+pub use cdl_inverted_hammer::cdl_inverted_hammer;
+#[cfg(feature = "polars")]
+pub use cdl_ladder_bottom::cdl_ladder_bottom;
+#[cfg(feature = "polars")]
+pub use cdl_long_legged_doji::cdl_long_legged_doji;
+#[cfg(feature = "polars")]
+pub use cdl_long_line::cdl_long_line;
+#[cfg(feature = "polars")]
+pub use cdl_marubozu::cdl_marubozu;
+#[cfg(feature = "polars")]
+pub use cdl_mat_hold::cdl_mat_hold;
+#[cfg(feature = "polars")]
+pub use cdl_morning_doji_star::cdl_morning_doji_star;
+#[cfg(feature = "polars")]
+pub use cdl_morning_star::cdl_morning_star;
+#[cfg(feature = "polars")]
+pub use cdl_rise_fall_3_methods::cdl_rise_fall_3_methods;
+#[cfg(feature = "polars")]
+pub use cdl_shooting_star::cdl_shooting_star;
+#[cfg(feature = "polars")]
+pub use cdl_short_line::cdl_short_line;
+#[cfg(feature = "polars")]
+pub use cdl_spinning_top::cdl_spinning_top;
+#[cfg(feature = "polars")]
+pub use cdl_stalled_pattern::cdl_stalled_pattern;
+#[cfg(feature = "polars")]
+pub use cdl_stick_sandwich::cdl_stick_sandwich;
+#[cfg(feature = "polars")]
+pub use cdl_tasuki_gap::cdl_tasuki_gap;
+#[cfg(feature = "polars")]
+pub use cdl_tristar::cdl_tristar;
+#[cfg(feature = "polars")]
+pub use cdl_unique_3_river::cdl_unique_3_river;
+#[cfg(feature = "polars")]
+pub use cdl_upside_gap_2_crows::cdl_upside_gap_2_crows;
+#[cfg(feature = "polars")]
+pub use cdl_xside_gap_3_methods::cdl_xside_gap_3_methods;
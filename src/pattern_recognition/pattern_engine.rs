@@ -0,0 +1,320 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/29 11:00
+ * @Email: uyplayer@qq.com
+ * @File: pattern_engine.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/pattern_recognition
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Candlestick pattern engine shared by the CDL* functions.
+//!
+//! A pattern is a [`CandlePattern`] rule over a [`CandleWindow`]: the last candles of the series, each
+//! with the averages of the candle settings the pattern reads, kept as rolling sums like TA-Lib's
+//! `<Setting>PeriodTotal`. [`PatternEngine`] feeds the window one bar at a time and evaluates the rule
+//! once the window holds the pattern's lookback.
+
+use std::collections::VecDeque;
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{int_vec_to_series, series_to_vec};
+use crate::{CandleSettingType, CandleSettings, RangeType, TalibError};
+
+/// One OHLC bar.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Candle {
+    pub(crate) open: f64,
+    pub(crate) high: f64,
+    pub(crate) low: f64,
+    pub(crate) close: f64,
+}
+
+impl Candle {
+    /// `|close - open|`
+    pub(crate) fn real_body(&self) -> f64 {
+        (self.close - self.open).abs()
+    }
+
+    /// `max(open, close)`
+    pub(crate) fn body_top(&self) -> f64 {
+        self.close.max(self.open)
+    }
+
+    /// `min(open, close)`
+    pub(crate) fn body_bottom(&self) -> f64 {
+        self.close.min(self.open)
+    }
+
+    /// `high - max(open, close)`
+    pub(crate) fn upper_shadow(&self) -> f64 {
+        self.high - self.body_top()
+    }
+
+    /// `min(open, close) - low`
+    pub(crate) fn lower_shadow(&self) -> f64 {
+        self.body_bottom() - self.low
+    }
+
+    /// Whether the real body is above the real body of `prev`, TA-Lib's `TA_REALBODYGAPUP`.
+    pub(crate) fn real_body_gap_up(&self, prev: &Candle) -> bool {
+        self.body_bottom() > prev.body_top()
+    }
+
+    /// Whether the real body is below the real body of `prev`, TA-Lib's `TA_REALBODYGAPDOWN`.
+    pub(crate) fn real_body_gap_down(&self, prev: &Candle) -> bool {
+        self.body_top() < prev.body_bottom()
+    }
+
+    /// Whether the candle is above the high of `prev`, shadows included, TA-Lib's `TA_CANDLEGAPUP`.
+    pub(crate) fn gap_up(&self, prev: &Candle) -> bool {
+        self.low > prev.high
+    }
+
+    /// Whether the candle is below the low of `prev`, shadows included, TA-Lib's `TA_CANDLEGAPDOWN`.
+    pub(crate) fn gap_down(&self, prev: &Candle) -> bool {
+        self.high < prev.low
+    }
+
+    /// 1 for a white (rising) candle, -1 for a black one, a candle with `close == open` is white.
+    pub(crate) fn color(&self) -> i32 {
+        if self.close >= self.open {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// The part of the candle `range_type` measures.
+    fn range(&self, range_type: RangeType) -> f64 {
+        match range_type {
+            RangeType::RealBody => self.real_body(),
+            RangeType::HighLow => self.high - self.low,
+            RangeType::Shadows => self.upper_shadow() + self.lower_shadow(),
+        }
+    }
+}
+
+/// The last candles of a series with the candle setting averages of each of them.
+#[derive(Debug, Clone)]
+pub(crate) struct CandleWindow {
+    settings: CandleSettings,
+    kinds: &'static [CandleSettingType],
+    capacity: usize,
+    /// rolling sums of the ranges of the `avg_period` candles before the next one
+    totals: [f64; 11],
+    candles: VecDeque<(Candle, [f64; 11])>,
+}
+
+impl CandleWindow {
+    /// Creates a window of `lookback + 1` candles averaging `kinds`, `lookback` covers their periods.
+    pub(crate) fn new(settings: CandleSettings, kinds: &'static [CandleSettingType], lookback: usize) -> Self {
+        CandleWindow {
+            settings,
+            kinds,
+            capacity: lookback + 1,
+            totals: [0.0; 11],
+            candles: VecDeque::with_capacity(lookback + 2),
+        }
+    }
+
+    /// Adds the next candle.
+    pub(crate) fn push(&mut self, candle: Candle) {
+        let mut averages = [0.0; 11];
+        for kind in self.kinds {
+            let setting = self.settings.get(*kind);
+            let range = candle.range(setting.range_type);
+            let total = &mut self.totals[*kind as usize];
+            let average = if setting.avg_period == 0 {
+                range
+            } else {
+                *total / setting.avg_period as f64
+            };
+            let divisor = if setting.range_type == RangeType::Shadows { 2.0 } else { 1.0 };
+            averages[*kind as usize] = setting.factor * average / divisor;
+
+            if setting.avg_period > 0 {
+                let trailing = self.candles.get(setting.avg_period - 1).map_or(0.0, |(old, _)| old.range(setting.range_type));
+                *total += range - trailing;
+            }
+        }
+        self.candles.push_front((candle, averages));
+        self.candles.truncate(self.capacity);
+    }
+
+    /// Whether the window holds the lookback and the current candle.
+    pub(crate) fn is_ready(&self) -> bool {
+        self.candles.len() == self.capacity
+    }
+
+    /// The candle `ago` bars before the current one.
+    pub(crate) fn candle(&self, ago: usize) -> &Candle {
+        &self.candles[ago].0
+    }
+
+    /// The average of `kind` for the candle `ago` bars before the current one, TA-Lib's `TA_CANDLEAVERAGE`.
+    pub(crate) fn average(&self, kind: CandleSettingType, ago: usize) -> f64 {
+        self.candles[ago].1[kind as usize]
+    }
+
+    /// Clears all candles.
+    pub(crate) fn reset(&mut self) {
+        self.totals = [0.0; 11];
+        self.candles.clear();
+    }
+}
+
+/// A candlestick pattern rule.
+pub(crate) trait CandlePattern {
+    /// The candle settings the rule reads.
+    const SETTINGS: &'static [CandleSettingType];
+    /// Number of candles the rule looks at, the current one included.
+    const CANDLES: usize;
+
+    /// Number of leading bars without a pattern value, like TA-Lib.
+    fn lookback(settings: &CandleSettings) -> usize {
+        settings.avg_period(Self::SETTINGS) + Self::CANDLES - 1
+    }
+
+    /// Returns 100 for a bullish pattern, -100 for a bearish one and 0 when there is none.
+    fn detect(&mut self, window: &CandleWindow) -> i32;
+
+    /// Clears what the rule keeps from one bar to the next, most rules keep nothing.
+    fn reset(&mut self) {}
+}
+
+/// Streaming candlestick pattern recognition, one OHLC bar at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternEngine<P> {
+    pattern: P,
+    window: CandleWindow,
+}
+
+impl<P: CandlePattern> PatternEngine<P> {
+    pub(crate) fn new(pattern: P, settings: &CandleSettings) -> Self {
+        let lookback = P::lookback(settings);
+        PatternEngine {
+            pattern,
+            window: CandleWindow::new(settings.clone(), P::SETTINGS, lookback),
+        }
+    }
+
+    /// Feeds the next bar, returns `None` for the first `lookback` bars.
+    pub(crate) fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+        self.window.push(Candle { open, high, low, close });
+        if !self.window.is_ready() {
+            return None;
+        }
+        Some(self.pattern.detect(&self.window))
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.pattern.reset();
+        self.window.reset();
+    }
+}
+
+/// Checks the `penetration` of the star, abandoned baby and mat hold patterns, which TA-Lib keeps at 0 or above.
+pub(crate) fn check_penetration(penetration: f64) -> Result<f64, TalibError> {
+    if !penetration.is_finite() || penetration < 0.0 {
+        return Err(TalibError::InvalidParameter {
+            name: "penetration",
+            value: penetration,
+            reason: "must be greater than or equal to 0",
+        });
+    }
+    Ok(penetration)
+}
+
+/// Runs `engine` over plain OHLC slices, the core of every `cdl_*_slice` function.
+pub(crate) fn pattern_slice<P: CandlePattern>(
+    mut engine: PatternEngine<P>,
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<Vec<Option<i32>>, TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    let lookback = engine.window.capacity - 1;
+    if open.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: open.len(),
+        });
+    }
+    Ok((0..open.len())
+        .map(|i| engine.update(open[i], high[i], low[i], close[i]))
+        .collect())
+}
+
+/// Runs `engine` over OHLC Series, the core of every `cdl_*` function, returns an Int32 Series.
+#[cfg(feature = "polars")]
+pub(crate) fn pattern_series<P: CandlePattern>(
+    engine: PatternEngine<P>,
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<Series, TalibError> {
+    let values = pattern_slice(
+        engine,
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok(int_vec_to_series("data", values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CandleSetting;
+
+    #[test]
+    fn test_candle_window_average() -> Result<(), TalibError> {
+        use CandleSettingType::*;
+        let mut settings = CandleSettings::new();
+        settings.set(Near, CandleSetting::new(RangeType::HighLow, 3, 0.5))?;
+        let kinds: &'static [CandleSettingType] = &[BodyLong, ShadowShort, ShadowLong, Near];
+        let mut window = CandleWindow::new(settings, kinds, 12);
+        let candles: Vec<Candle> = (0..30)
+            .map(|i| {
+                let open = 10.0 + (i as f64 * 0.9).sin();
+                let close = 10.0 + (i as f64 * 0.9 + 2.0).sin();
+                Candle { open, high: open.max(close) + 0.1 * (i % 5) as f64, low: open.min(close) - 0.2, close }
+            })
+            .collect();
+        for (i, candle) in candles.iter().enumerate() {
+            window.push(*candle);
+            assert_eq!(window.is_ready(), i >= 12);
+            if i < 12 {
+                continue;
+            }
+            for ago in 0..3 {
+                let before = &candles[i - ago - 10..i - ago];
+                let body = before.iter().map(Candle::real_body).sum::<f64>() / 10.0;
+                let shadows = before.iter().map(|c| c.upper_shadow() + c.lower_shadow()).sum::<f64>() / 10.0 / 2.0;
+                let near = 0.5 * candles[i - ago - 3..i - ago].iter().map(|c| c.high - c.low).sum::<f64>() / 3.0;
+                assert!((window.average(BodyLong, ago) - body).abs() < 1e-12);
+                assert!((window.average(ShadowShort, ago) - shadows).abs() < 1e-12);
+                assert!((window.average(Near, ago) - near).abs() < 1e-12);
+                // an average period of 0 measures the candle itself
+                assert_eq!(window.average(ShadowLong, ago), candles[i - ago].real_body());
+                assert_eq!(window.candle(ago).open, candles[i - ago].open);
+            }
+        }
+        window.reset();
+        assert!(!window.is_ready());
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 15:00
+ * @Email: uyplayer@qq.com
+ * @File: avgprice.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/price_transform
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Average Price
+//!
+//! avgprice = (open + high + low + close) / 4
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a AVGPRICE value, always 0.
+pub fn avgprice_lookback() -> usize {
+    0
+}
+
+/// Calculates the Average Price (AVGPRICE) over plain `open`, `high`, `low` and `close` slices,
+/// the polars free core of [`avgprice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::avgprice_slice;
+///
+/// let avgprice = avgprice_slice(&[10.0, 11.0], &[12.0, 11.5], &[9.0, 10.0], &[11.0, 10.5]).unwrap();
+/// assert_eq!(avgprice, [10.5, 10.75]);
+/// ```
+pub fn avgprice_slice(open: &[f64], high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    if open.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = AvgPrice::new();
+    Ok(open
+        .iter()
+        .zip(high.iter())
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|(((o, h), l), c)| state.update(*o, *h, *l, *c))
+        .collect())
+}
+
+/// Calculates the Average Price (AVGPRICE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the avgprice function does
+///     import talib
+///     avgprice = talib.AVGPRICE(open, high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::avgprice;
+///
+///  let open = Series::new("open", [9.2, 9.8, 10.4, 9.9, 11.6, 12.1]);
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = avgprice(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn avgprice(open: &Series, high: &Series, low: &Series, close: &Series) -> Result<Series, TalibError> {
+    let avgprice = avgprice_slice(
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok(vec_to_series("data", avgprice))
+}
+
+/// Streaming Average Price (AVGPRICE).
+///
+/// AVGPRICE has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::AvgPrice;
+///
+/// let mut avgprice = AvgPrice::new();
+/// assert_eq!(avgprice.update(10.0, 12.0, 9.0, 11.0), 10.5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AvgPrice;
+
+impl AvgPrice {
+    /// Creates a new AVGPRICE state.
+    pub fn new() -> Self {
+        AvgPrice
+    }
+
+    /// Feeds the next bar and returns its average price.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> f64 {
+        (open + high + low + close) / 4.0
+    }
+
+    /// Does nothing, AVGPRICE keeps no state between bars.
+    pub fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avgprice() -> Result<(), TalibError> {
+        let res = avgprice_slice(&[10.0, 11.0, 12.0], &[12.0, 11.5, 12.0], &[9.0, 10.0, 8.0], &[11.0, 10.5, 8.0])?;
+        assert_eq!(res, [10.5, 10.75, 10.0]);
+        assert!(avgprice_slice(&[1.0], &[1.0, 2.0], &[1.0], &[1.0]).is_err());
+        assert!(avgprice_slice(&[], &[], &[], &[]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_avgprice_series() -> Result<(), TalibError> {
+        let series = avgprice(
+            &Series::new("open", [10.0]),
+            &Series::new("high", [12.0]),
+            &Series::new("low", [9.0]),
+            &Series::new("close", [11.0]),
+        )?;
+        assert_eq!(series.f64()?.get(0), Some(10.5));
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 16:30
+ * @Email: uyplayer@qq.com
+ * @File: heikin_ashi.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/price_transform
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Heikin-Ashi
+//!
+//! - ha close = (open + high + low + close) / 4
+//! - ha open = (previous ha open + previous ha close) / 2, (open + close) / 2 on the first bar
+//! - ha high = max(high, ha open, ha close)
+//! - ha low = min(low, ha open, ha close)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{AvgPrice, TalibError};
+
+/// Number of leading bars without a Heikin-Ashi candle, always 0.
+pub fn heikin_ashi_lookback() -> usize {
+    0
+}
+
+/// Calculates the Heikin-Ashi candles over plain `open`, `high`, `low` and `close` slices,
+/// the polars free core of [`heikin_ashi`].
+///
+/// Returns `(open, high, low, close)` of the Heikin-Ashi candles.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::heikin_ashi_slice;
+///
+/// let (open, high, low, close) = heikin_ashi_slice(&[10.0, 11.0], &[12.0, 13.0], &[9.0, 10.0], &[11.0, 12.0]).unwrap();
+/// assert_eq!(open, [10.5, 10.5]);
+/// assert_eq!(high, [12.0, 13.0]);
+/// assert_eq!(low, [9.0, 10.0]);
+/// assert_eq!(close, [10.5, 11.5]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn heikin_ashi_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    if open.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = HeikinAshi::new();
+    let mut ha_open = Vec::with_capacity(open.len());
+    let mut ha_high = Vec::with_capacity(open.len());
+    let mut ha_low = Vec::with_capacity(open.len());
+    let mut ha_close = Vec::with_capacity(open.len());
+    for (((o, h), l), c) in open.iter().zip(high.iter()).zip(low.iter()).zip(close.iter()) {
+        let (o, h, l, c) = state.update(*o, *h, *l, *c);
+        ha_open.push(o);
+        ha_high.push(h);
+        ha_low.push(l);
+        ha_close.push(c);
+    }
+    Ok((ha_open, ha_high, ha_low, ha_close))
+}
+
+/// Calculates the Heikin-Ashi candles.
+///
+/// Returns the `(open, high, low, close)` Series of the Heikin-Ashi candles.
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::heikin_ashi;
+///
+///  let open = Series::new("open", [9.2, 9.8, 10.4, 9.9, 11.6, 12.1]);
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = heikin_ashi(&open, &high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn heikin_ashi(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+) -> Result<(Series, Series, Series, Series), TalibError> {
+    let (ha_open, ha_high, ha_low, ha_close) = heikin_ashi_slice(
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok((
+        vec_to_series("HA_OPEN", ha_open),
+        vec_to_series("HA_HIGH", ha_high),
+        vec_to_series("HA_LOW", ha_low),
+        vec_to_series("HA_CLOSE", ha_close),
+    ))
+}
+
+/// Streaming Heikin-Ashi candles.
+///
+/// Heikin-Ashi has no warm-up, so `update` returns a candle for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::HeikinAshi;
+///
+/// let mut heikin_ashi = HeikinAshi::new();
+/// assert_eq!(heikin_ashi.update(10.0, 12.0, 9.0, 11.0), (10.5, 12.0, 9.0, 10.5));
+/// assert_eq!(heikin_ashi.update(11.0, 13.0, 10.0, 12.0), (10.5, 13.0, 10.0, 11.5));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HeikinAshi {
+    avgprice: AvgPrice,
+    prev: Option<(f64, f64)>,
+}
+
+impl HeikinAshi {
+    /// Creates a new Heikin-Ashi state.
+    pub fn new() -> Self {
+        HeikinAshi::default()
+    }
+
+    /// Feeds the next bar and returns its Heikin-Ashi `(open, high, low, close)`.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> (f64, f64, f64, f64) {
+        let ha_close = self.avgprice.update(open, high, low, close);
+        let ha_open = match self.prev {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (open + close) / 2.0,
+        };
+        self.prev = Some((ha_open, ha_close));
+        (ha_open, high.max(ha_open).max(ha_close), low.min(ha_open).min(ha_close), ha_close)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heikin_ashi() -> Result<(), TalibError> {
+        let open = [10.0, 11.0, 9.0];
+        let high = [12.0, 13.0, 9.5];
+        let low = [9.0, 10.0, 8.5];
+        let close = [11.0, 12.0, 9.0];
+        let (ha_open, ha_high, ha_low, ha_close) = heikin_ashi_slice(&open, &high, &low, &close)?;
+        assert_eq!(ha_open, [10.5, 10.5, 11.0]);
+        assert_eq!(ha_close, [10.5, 11.5, 9.0]);
+        // after a gap down the high is the candle body
+        assert_eq!(ha_high, [12.0, 13.0, 11.0]);
+        assert_eq!(ha_low, [9.0, 10.0, 8.5]);
+        assert!(heikin_ashi_slice(&open, &high, &low, &close[1..]).is_err());
+        assert!(heikin_ashi_slice(&[], &[], &[], &[]).is_err());
+        Ok(())
+    }
+}
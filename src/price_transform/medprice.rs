@@ -0,0 +1,147 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 15:20
+ * @Email: uyplayer@qq.com
+ * @File: medprice.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/price_transform
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Median Price
+//!
+//! medprice = (high + low) / 2
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a MEDPRICE value, always 0.
+pub fn medprice_lookback() -> usize {
+    0
+}
+
+/// Calculates the Median Price (MEDPRICE) over plain `high` and `low` slices,
+/// the polars free core of [`medprice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::medprice_slice;
+///
+/// let medprice = medprice_slice(&[12.0, 11.5], &[9.0, 10.0]).unwrap();
+/// assert_eq!(medprice, [10.5, 10.75]);
+/// ```
+pub fn medprice_slice(high: &[f64], low: &[f64]) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    if high.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = MedPrice::new();
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l))
+        .collect())
+}
+
+/// Calculates the Median Price (MEDPRICE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the medprice function does
+///     import talib
+///     medprice = talib.MEDPRICE(high, low)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::medprice;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let res = medprice(&high, &low);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn medprice(high: &Series, low: &Series) -> Result<Series, TalibError> {
+    let medprice = medprice_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+    )?;
+    Ok(vec_to_series("data", medprice))
+}
+
+/// Streaming Median Price (MEDPRICE).
+///
+/// MEDPRICE has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::MedPrice;
+///
+/// let mut medprice = MedPrice::new();
+/// assert_eq!(medprice.update(12.0, 9.0), 10.5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MedPrice;
+
+impl MedPrice {
+    /// Creates a new MEDPRICE state.
+    pub fn new() -> Self {
+        MedPrice
+    }
+
+    /// Feeds the next bar and returns its median price.
+    pub fn update(&mut self, high: f64, low: f64) -> f64 {
+        (high + low) / 2.0
+    }
+
+    /// Does nothing, MEDPRICE keeps no state between bars.
+    pub fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_medprice() -> Result<(), TalibError> {
+        let res = medprice_slice(&[12.0, 11.5, 12.0], &[9.0, 10.0, 8.0])?;
+        assert_eq!(res, [10.5, 10.75, 10.0]);
+        assert!(medprice_slice(&[1.0], &[1.0, 2.0]).is_err());
+        assert!(medprice_slice(&[], &[]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_medprice_series() -> Result<(), TalibError> {
+        let series = medprice(
+            &Series::new("high", [12.0]),
+            &Series::new("low", [9.0]),
+        )?;
+        assert_eq!(series.f64()?.get(0), Some(10.5));
+        Ok(())
+    }
+}
@@ -4,7 +4,40 @@
  * @Email: uyplayer@qq.com
  * @File: mod.rs
  * @Software: RustRover
- * @Dir: rusty-talib / src/cycle_indicators
+ * @Dir: rusty-talib / src/price_transform
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+
+// | Function    | Description          | Status  |
+// |-------------|----------------------|---------|
+// | AVGPRICE    | Average Price        | Done    |
+// | HEIKIN_ASHI | Heikin-Ashi          | Done    |
+// | MEDPRICE    | Median Price         | Done    |
+// | TYPPRICE    | Typical Price        | Done    |
+// | WCLPRICE    | Weighted Close Price | Done    |
+
+/// This module contains the price transforms.
+mod avgprice;
+mod heikin_ashi;
+mod medprice;
+mod typprice;
+mod wclprice;
+
+
+pub use avgprice::{avgprice_lookback, avgprice_slice, AvgPrice};
+pub use heikin_ashi::{heikin_ashi_lookback, heikin_ashi_slice, HeikinAshi};
+pub use medprice::{medprice_lookback, medprice_slice, MedPrice};
+pub use typprice::{typprice_lookback, typprice_slice, TypPrice};
+pub use wclprice::{wclprice_lookback, wclprice_slice, WclPrice};
+
+#[cfg(feature = "polars")]
+pub use avgprice::avgprice;
+#[cfg(feature = "polars")]
+pub use heikin_ashi::heikin_ashi;
+#[cfg(feature = "polars")]
+pub use medprice::medprice;
+#[cfg(feature = "polars")]
+pub use typprice::typprice;
+#[cfg(feature = "polars")]
+pub use wclprice::wclprice;
@@ -0,0 +1,154 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 15:40
+ * @Email: uyplayer@qq.com
+ * @File: typprice.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/price_transform
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Typical Price
+//!
+//! typprice = (high + low + close) / 3
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a TYPPRICE value, always 0.
+pub fn typprice_lookback() -> usize {
+    0
+}
+
+/// Calculates the Typical Price (TYPPRICE) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`typprice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::typprice_slice;
+///
+/// let typprice = typprice_slice(&[12.0, 11.5], &[9.0, 10.0], &[12.0, 9.5]).unwrap();
+/// assert_eq!(typprice, [11.0, 10.333333333333334]);
+/// ```
+pub fn typprice_slice(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    if high.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = TypPrice::new();
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c))
+        .collect())
+}
+
+/// Calculates the Typical Price (TYPPRICE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the typprice function does
+///     import talib
+///     typprice = talib.TYPPRICE(high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::typprice;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = typprice(&high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn typprice(high: &Series, low: &Series, close: &Series) -> Result<Series, TalibError> {
+    let typprice = typprice_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok(vec_to_series("data", typprice))
+}
+
+/// Streaming Typical Price (TYPPRICE).
+///
+/// TYPPRICE has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::TypPrice;
+///
+/// let mut typprice = TypPrice::new();
+/// assert_eq!(typprice.update(12.0, 9.0, 12.0), 11.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypPrice;
+
+impl TypPrice {
+    /// Creates a new TYPPRICE state.
+    pub fn new() -> Self {
+        TypPrice
+    }
+
+    /// Feeds the next bar and returns its typical price.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> f64 {
+        (high + low + close) / 3.0
+    }
+
+    /// Does nothing, TYPPRICE keeps no state between bars.
+    pub fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typprice() -> Result<(), TalibError> {
+        let res = typprice_slice(&[12.0, 11.5, 12.0], &[9.0, 10.0, 8.0], &[12.0, 9.0, 7.0])?;
+        assert_eq!(res, [11.0, 10.166666666666666, 9.0]);
+        assert!(typprice_slice(&[1.0], &[1.0, 2.0], &[1.0]).is_err());
+        assert!(typprice_slice(&[], &[], &[]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_typprice_series() -> Result<(), TalibError> {
+        let series = typprice(
+            &Series::new("high", [12.0]),
+            &Series::new("low", [9.0]),
+            &Series::new("close", [11.0]),
+        )?;
+        assert_eq!(series.f64()?.get(0), Some(32.0 / 3.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 16:00
+ * @Email: uyplayer@qq.com
+ * @File: wclprice.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/price_transform
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Weighted Close Price
+//!
+//! wclprice = (high + low + close * 2) / 4
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a WCLPRICE value, always 0.
+pub fn wclprice_lookback() -> usize {
+    0
+}
+
+/// Calculates the Weighted Close Price (WCLPRICE) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`wclprice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::wclprice_slice;
+///
+/// let wclprice = wclprice_slice(&[12.0, 11.5], &[9.0, 10.0], &[11.5, 10.25]).unwrap();
+/// assert_eq!(wclprice, [11.0, 10.5]);
+/// ```
+pub fn wclprice_slice(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    if high.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = WclPrice::new();
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c))
+        .collect())
+}
+
+/// Calculates the Weighted Close Price (WCLPRICE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the wclprice function does
+///     import talib
+///     wclprice = talib.WCLPRICE(high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::wclprice;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = wclprice(&high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn wclprice(high: &Series, low: &Series, close: &Series) -> Result<Series, TalibError> {
+    let wclprice = wclprice_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+    )?;
+    Ok(vec_to_series("data", wclprice))
+}
+
+/// Streaming Weighted Close Price (WCLPRICE).
+///
+/// WCLPRICE has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::WclPrice;
+///
+/// let mut wclprice = WclPrice::new();
+/// assert_eq!(wclprice.update(12.0, 9.0, 11.5), 11.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WclPrice;
+
+impl WclPrice {
+    /// Creates a new WCLPRICE state.
+    pub fn new() -> Self {
+        WclPrice
+    }
+
+    /// Feeds the next bar and returns its weighted close price.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> f64 {
+        (high + low + close * 2.0) / 4.0
+    }
+
+    /// Does nothing, WCLPRICE keeps no state between bars.
+    pub fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wclprice() -> Result<(), TalibError> {
+        let res = wclprice_slice(&[12.0, 11.5, 12.0], &[9.0, 10.0, 8.0], &[11.5, 10.25, 8.0])?;
+        assert_eq!(res, [11.0, 10.5, 9.0]);
+        assert!(wclprice_slice(&[1.0], &[1.0, 2.0], &[1.0]).is_err());
+        assert!(wclprice_slice(&[], &[], &[]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn test_wclprice_series() -> Result<(), TalibError> {
+        let series = wclprice(
+            &Series::new("high", [12.0]),
+            &Series::new("low", [9.0]),
+            &Series::new("close", [11.0]),
+        )?;
+        assert_eq!(series.f64()?.get(0), Some(10.75));
+        Ok(())
+    }
+}
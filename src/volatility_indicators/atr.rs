@@ -0,0 +1,203 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/20 19:45
+ * @Email: uyplayer@qq.com
+ * @File: atr.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Average True Range
+//!
+//! - the first atr is the simple average of the first `time_period` true ranges
+//! - then atr = (previous atr * (time_period - 1) + true range) / time_period, Wilder's smoothing
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{trange_lookback, TRange, TalibError};
+
+/// Number of leading bars without an ATR value, `time_period` like TA-Lib.
+pub fn atr_lookback(time_period: Option<usize>) -> usize {
+    trange_lookback() + time_period.unwrap_or(14).saturating_sub(1)
+}
+
+/// Calculates the Average True Range (ATR) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`atr`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::atr_slice;
+///
+/// let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+/// let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+/// let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+/// let atr = atr_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(atr[1].is_nan());
+/// // the true ranges are 3, 0.5, 4.25 and 6
+/// assert_eq!(atr[2..], [1.75, 3.0, 4.5]);
+/// ```
+pub fn atr_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Atr::new(time_period)?;
+    let lookback = atr_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Average True Range (ATR).
+///
+/// ``` python
+///     #  This Python code produces the same result as the atr function does
+///     import talib
+///     atr = talib.ATR(high, low, close, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::atr;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = atr(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn atr(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let atr = atr_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", atr))
+}
+
+/// Streaming Average True Range (ATR), the same values as [`atr_slice`] one bar at a time.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Atr;
+///
+/// let mut atr = Atr::new(2).unwrap();
+/// assert_eq!(atr.update(10.0, 8.0, 9.0), None);
+/// assert_eq!(atr.update(12.0, 9.0, 11.0), None);
+/// assert_eq!(atr.update(11.0, 10.5, 10.75), Some(1.75));
+/// assert_eq!(atr.value(), Some(1.75));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Atr {
+    time_period: usize,
+    trange: TRange,
+    count: usize,
+    sum: f64,
+    value: Option<f64>,
+}
+
+impl Atr {
+    /// Creates a new ATR state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(Atr {
+            time_period,
+            trange: TRange::new(),
+            count: 0,
+            sum: 0.0,
+            value: None,
+        })
+    }
+
+    /// Feeds the next bar and returns the current ATR once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let true_range = self.trange.update(high, low, close)?;
+        let period = self.time_period as f64;
+        match self.value {
+            Some(prev) => {
+                self.value = Some((prev * (period - 1.0) + true_range) / period);
+            }
+            None => {
+                self.count += 1;
+                self.sum += true_range;
+                if self.count == self.time_period {
+                    self.value = Some(self.sum / period);
+                }
+            }
+        }
+        self.value
+    }
+
+    /// Returns the ATR of the last bar fed, `None` while warming up.
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.trange.reset();
+        self.count = 0;
+        self.sum = 0.0;
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atr() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+        let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+        let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+        let res = atr_slice(&high, &low, &close, Some(3))?;
+        assert_eq!(atr_lookback(Some(3)), 3);
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        assert_eq!(res[3], 7.75 / 3.0);
+        assert!((res[4] - (7.75 / 3.0 * 2.0 + 6.0) / 3.0).abs() < 1e-12);
+        // a period of 1 is the true range
+        let trange = crate::trange_slice(&high, &low, &close)?;
+        assert_eq!(atr_slice(&high, &low, &close, Some(1))?[1..], trange[1..]);
+        assert!(atr_slice(&high, &low, &close, Some(0)).is_err());
+        assert!(atr_slice(&high, &low, &close, Some(5)).is_err());
+        Ok(())
+    }
+}
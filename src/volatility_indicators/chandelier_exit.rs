@@ -0,0 +1,195 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/22 20:40
+ * @Email: uyplayer@qq.com
+ * @File: chandelier_exit.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Chandelier Exit
+//!
+//! - long exit = highest high over `time_period` bars - multiplier * ATR(time_period)
+//! - short exit = lowest low over `time_period` bars + multiplier * ATR(time_period)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::{atr_lookback, Atr, TalibError};
+
+/// Number of leading bars without an exit, the ATR lookback `time_period`.
+pub fn chandelier_exit_lookback(time_period: Option<usize>) -> usize {
+    let time_period = time_period.unwrap_or(22);
+    time_period.saturating_sub(1).max(atr_lookback(Some(time_period)))
+}
+
+/// Calculates the Chandelier Exit over plain `high`, `low` and `close` slices,
+/// the polars free core of [`chandelier_exit`].
+///
+/// Returns `(long_exit, short_exit)`, the stop below the highest high for long positions
+/// and the stop above the lowest low for short positions.
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::chandelier_exit_slice;
+///
+/// let high = [10.0, 12.0, 11.0, 15.0];
+/// let low = [8.0, 9.0, 10.5, 13.0];
+/// let close = [9.0, 11.0, 10.75, 14.0];
+/// let (long_exit, short_exit) = chandelier_exit_slice(&high, &low, &close, Some(2), Some(2.0)).unwrap();
+/// assert!(long_exit[1].is_nan());
+/// // the ATR is 1.75, the window holds the bars 12/9 and 11/10.5
+/// assert_eq!((long_exit[2], short_exit[2]), (8.5, 12.5));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn chandelier_exit_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period: Option<usize>,
+    multiplier: Option<f64>,
+) -> Result<(Vec<f64>, Vec<f64>), TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(22);
+    let mut state = ChandelierExit::new(time_period, multiplier.unwrap_or(3.0))?;
+    let lookback = chandelier_exit_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut long_exit = Vec::with_capacity(high.len());
+    let mut short_exit = Vec::with_capacity(high.len());
+    for ((h, l), c) in high.iter().zip(low.iter()).zip(close.iter()) {
+        let (long, short) = state.update(*h, *l, *c).unwrap_or((f64::NAN, f64::NAN));
+        long_exit.push(long);
+        short_exit.push(short);
+    }
+    Ok((long_exit, short_exit))
+}
+
+/// Calculates the Chandelier Exit.
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional period of the highest high, the lowest low and the ATR. Defaults to 22 if not provided.
+/// * `multiplier` - An optional number of ATRs between the extreme and the exit. Defaults to 3.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::chandelier_exit;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = chandelier_exit(&high, &low, &close, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn chandelier_exit(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    time_period: Option<usize>,
+    multiplier: Option<f64>,
+) -> Result<(Series, Series), TalibError> {
+    let (long_exit, short_exit) = chandelier_exit_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        time_period,
+        multiplier,
+    )?;
+    Ok((vec_to_series("CHANDELIER_LONG", long_exit), vec_to_series("CHANDELIER_SHORT", short_exit)))
+}
+
+/// Streaming Chandelier Exit.
+///
+/// Returns `(long_exit, short_exit)` once the ATR is warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ChandelierExit;
+///
+/// let mut chandelier = ChandelierExit::new(2, 2.0).unwrap();
+/// assert_eq!(chandelier.update(10.0, 8.0, 9.0), None);
+/// assert_eq!(chandelier.update(12.0, 9.0, 11.0), None);
+/// assert_eq!(chandelier.update(11.0, 10.5, 10.75), Some((8.5, 12.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChandelierExit {
+    multiplier: f64,
+    extremes: RollingExtremes,
+    atr: Atr,
+}
+
+impl ChandelierExit {
+    /// Creates a new Chandelier Exit state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize, multiplier: f64) -> Result<Self, TalibError> {
+        Ok(ChandelierExit {
+            multiplier,
+            atr: Atr::new(time_period)?,
+            extremes: RollingExtremes::new(time_period),
+        })
+    }
+
+    /// Feeds the next bar and returns `(long_exit, short_exit)` once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<(f64, f64)> {
+        let extremes = self.extremes.update(high, low);
+        let atr = self.atr.update(high, low, close);
+        let ((highest, lowest), atr) = (extremes?, atr?);
+        let distance = self.multiplier * atr;
+        Some((highest - distance, lowest + distance))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chandelier_exit() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+        let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+        let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+        let (long_exit, short_exit) = chandelier_exit_slice(&high, &low, &close, Some(3), Some(1.0))?;
+        assert_eq!(chandelier_exit_lookback(Some(3)), 3);
+        let atr = crate::atr_slice(&high, &low, &close, Some(3))?;
+        assert!(long_exit[..3].iter().all(|value| value.is_nan()));
+        assert_eq!((long_exit[3], short_exit[3]), (15.0 - atr[3], 9.0 + atr[3]));
+        assert_eq!((long_exit[4], short_exit[4]), (15.0 - atr[4], 8.0 + atr[4]));
+        assert!(chandelier_exit_slice(&high, &low[1..], &close, None, None).is_err());
+        assert!(chandelier_exit_slice(&high, &low, &close, Some(0), None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/23 15:40
+ * @Email: uyplayer@qq.com
+ * @File: close_to_close_volatility.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Close-to-Close Historical Volatility
+//!
+//! - r = ln(close / previous close)
+//! - volatility = sqrt(annualization * sample variance of r over `time_period` bars)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::volatility_indicators::realized_variance::{annualized, check_annualization, RollingVariance, TRADING_DAYS};
+use crate::TalibError;
+
+/// Number of leading bars without a volatility, `time_period` because the first bar has no return.
+pub fn close_to_close_volatility_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20)
+}
+
+/// Calculates the Close-to-Close Historical Volatility over a plain `close` slice,
+/// the polars free core of [`close_to_close_volatility`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::InvalidParameter`] if `time_period` is less than 2
+/// or `annualization` is not greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::close_to_close_volatility_slice;
+///
+/// let close = [100.0, 110.0, 100.0, 110.0];
+/// let volatility = close_to_close_volatility_slice(&close, Some(2), Some(1.0)).unwrap();
+/// assert!(volatility[1].is_nan());
+/// // two returns of +-ln(1.1) have a sample standard deviation of sqrt(2) * ln(1.1)
+/// assert!((volatility[2] - 2f64.sqrt() * 1.1f64.ln()).abs() < 1e-12);
+/// ```
+pub fn close_to_close_volatility_slice(
+    close: &[f64],
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    let time_period = time_period.unwrap_or(20);
+    let mut state = CloseToCloseVolatility::new(time_period, annualization.unwrap_or(TRADING_DAYS))?;
+    let lookback = close_to_close_volatility_lookback(Some(time_period));
+    if close.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: close.len(),
+        });
+    }
+    Ok(close.iter().map(|c| state.update(*c).unwrap_or(f64::NAN)).collect())
+}
+
+/// Calculates the Close-to-Close Historical Volatility.
+///
+/// # Arguments
+///
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional number of returns in the window. Defaults to 20 if not provided.
+/// * `annualization` - An optional number of bars per year. Defaults to 252, 1 gives the per-bar volatility.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::close_to_close_volatility;
+///
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = close_to_close_volatility(&close, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn close_to_close_volatility(
+    close: &Series,
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Series, TalibError> {
+    let volatility = close_to_close_volatility_slice(&series_to_vec(close)?, time_period, annualization)?;
+    Ok(vec_to_series("data", volatility))
+}
+
+/// Streaming Close-to-Close Historical Volatility.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::CloseToCloseVolatility;
+///
+/// let mut volatility = CloseToCloseVolatility::new(2, 252.0).unwrap();
+/// for close in [100.0, 101.0, 99.5, 100.5] {
+///     eprintln!("{:?}", volatility.update(close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CloseToCloseVolatility {
+    annualization: f64,
+    prev_close: Option<f64>,
+    returns: RollingVariance,
+}
+
+impl CloseToCloseVolatility {
+    /// Creates a new close-to-close volatility state, returns an error if `time_period` is less than 2
+    /// or `annualization` is not greater than 0.
+    pub fn new(time_period: usize, annualization: f64) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        Ok(CloseToCloseVolatility {
+            annualization: check_annualization(annualization)?,
+            prev_close: None,
+            returns: RollingVariance::new(time_period),
+        })
+    }
+
+    /// Feeds the next close and returns the current volatility once the window is full.
+    pub fn update(&mut self, close: f64) -> Option<f64> {
+        let prev_close = self.prev_close.replace(close)?;
+        self.returns.update((close / prev_close).ln())?;
+        Some(annualized(self.returns.sample_variance(), self.annualization))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+        self.returns.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close_to_close_volatility() -> Result<(), TalibError> {
+        let close = [100.0, 102.0, 101.0, 104.0, 103.0];
+        let res = close_to_close_volatility_slice(&close, Some(3), Some(4.0))?;
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        let returns: Vec<f64> = close.windows(2).map(|pair| (pair[1] / pair[0]).ln()).collect();
+        for i in 3..close.len() {
+            let window = &returns[i - 3..i];
+            let mean = window.iter().sum::<f64>() / 3.0;
+            let variance = window.iter().map(|r| (r - mean) * (r - mean)).sum::<f64>() / 2.0;
+            assert!((res[i] - (variance * 4.0).sqrt()).abs() < 1e-12);
+        }
+        assert!(close_to_close_volatility_slice(&close, Some(1), None).is_err());
+        assert!(close_to_close_volatility_slice(&close, Some(3), Some(0.0)).is_err());
+        assert!(close_to_close_volatility_slice(&close, Some(5), None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/22 20:10
+ * @Email: uyplayer@qq.com
+ * @File: donchian_channels.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Donchian Channels
+//!
+//! - upper band = highest high over `time_period` bars
+//! - lower band = lowest low over `time_period` bars
+//! - middle band = (upper band + lower band) / 2
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::RollingExtremes;
+use crate::TalibError;
+
+/// Number of leading bars without a band, `time_period - 1`.
+pub fn donchian_channels_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20).saturating_sub(1)
+}
+
+/// Calculates the Donchian Channels over plain `high` and `low` slices,
+/// the polars free core of [`donchian_channels`].
+///
+/// Returns `(middle_band, upper_band, lower_band)` like [`crate::b_bands_slice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::donchian_channels_slice;
+///
+/// let (middle, upper, lower) = donchian_channels_slice(&[2.0, 6.0, 4.0, 3.0], &[1.0, 2.0, 3.0, 1.5], Some(3)).unwrap();
+/// assert!(middle[1].is_nan());
+/// assert_eq!((middle[2], upper[2], lower[2]), (3.5, 6.0, 1.0));
+/// assert_eq!((middle[3], upper[3], lower[3]), (3.75, 6.0, 1.5));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn donchian_channels_slice(
+    high: &[f64],
+    low: &[f64],
+    time_period: Option<usize>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = DonchianChannels::new(time_period)?;
+    let lookback = donchian_channels_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut middle_band = Vec::with_capacity(high.len());
+    let mut upper_band = Vec::with_capacity(high.len());
+    let mut lower_band = Vec::with_capacity(high.len());
+    for (h, l) in high.iter().zip(low.iter()) {
+        let (middle, upper, lower) = state.update(*h, *l).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+        middle_band.push(middle);
+        upper_band.push(upper);
+        lower_band.push(lower);
+    }
+    Ok((middle_band, upper_band, lower_band))
+}
+
+/// Calculates the Donchian Channels.
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 20 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::donchian_channels;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let res = donchian_channels(&high, &low, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn donchian_channels(
+    high: &Series,
+    low: &Series,
+    time_period: Option<usize>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (middle_band, upper_band, lower_band) =
+        donchian_channels_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period)?;
+    Ok((
+        vec_to_series("DONCHIAN_MIDDLE", middle_band),
+        vec_to_series("DONCHIAN_UPPER", upper_band),
+        vec_to_series("DONCHIAN_LOWER", lower_band),
+    ))
+}
+
+/// Streaming Donchian Channels.
+///
+/// Returns `(middle_band, upper_band, lower_band)` once the window is full.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::DonchianChannels;
+///
+/// let mut donchian = DonchianChannels::new(2).unwrap();
+/// assert_eq!(donchian.update(2.0, 1.0), None);
+/// assert_eq!(donchian.update(6.0, 2.0), Some((3.5, 6.0, 1.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DonchianChannels {
+    extremes: RollingExtremes,
+}
+
+impl DonchianChannels {
+    /// Creates a new Donchian Channels state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(DonchianChannels {
+            extremes: RollingExtremes::new(time_period),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns `(middle_band, upper_band, lower_band)` once the window is full.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<(f64, f64, f64)> {
+        let (highest, lowest) = self.extremes.update(high, low)?;
+        Some(((highest + lowest) / 2.0, highest, lowest))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.extremes.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_donchian_channels() -> Result<(), TalibError> {
+        let high = [2.0, 6.0, 4.0, 3.0, 5.0];
+        let low = [1.0, 2.0, 3.0, 1.0, 4.0];
+        let (middle, upper, lower) = donchian_channels_slice(&high, &low, Some(3))?;
+        assert!(upper[..2].iter().all(|value| value.is_nan()));
+        assert_eq!(upper[2..], [6.0, 6.0, 5.0]);
+        assert_eq!(lower[2..], [1.0, 1.0, 1.0]);
+        // the middle band is MIDPRICE
+        assert_eq!(middle[2..], crate::midprice_slice(&high, &low, Some(3))?[2..]);
+        assert!(donchian_channels_slice(&high, &low[1..], None).is_err());
+        assert!(donchian_channels_slice(&high, &low, Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/22 19:30
+ * @Email: uyplayer@qq.com
+ * @File: keltner_channels.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Keltner Channels
+//!
+//! - middle band = EMA(close, time_period)
+//! - upper band = middle band + multiplier * ATR(atr_period)
+//! - lower band = middle band - multiplier * ATR(atr_period)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{atr_lookback, exponential_moving_average_lookback, Atr, Ema, TalibError};
+
+/// Number of leading bars without a band, the longer of the EMA and the ATR lookback.
+pub fn keltner_channels_lookback(time_period: Option<usize>, atr_period: Option<usize>) -> usize {
+    exponential_moving_average_lookback(Some(time_period.unwrap_or(20))).max(atr_lookback(Some(atr_period.unwrap_or(10))))
+}
+
+/// Calculates the Keltner Channels over plain `high`, `low` and `close` slices,
+/// the polars free core of [`keltner_channels`].
+///
+/// Returns `(middle_band, upper_band, lower_band)` like [`crate::b_bands_slice`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` or `atr_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::keltner_channels_slice;
+///
+/// let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+/// let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+/// let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+/// let (middle, upper, lower) = keltner_channels_slice(&high, &low, &close, Some(2), Some(2), Some(1.0)).unwrap();
+/// assert!(middle[1].is_nan());
+/// // an EMA of 10.5 plus and minus an ATR of 1.75
+/// assert_eq!((middle[2], upper[2], lower[2]), (10.5, 12.25, 8.75));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn keltner_channels_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period: Option<usize>,
+    atr_period: Option<usize>,
+    multiplier: Option<f64>,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(20);
+    let atr_period = atr_period.unwrap_or(10);
+    let mut state = KeltnerChannels::new(time_period, atr_period, multiplier.unwrap_or(2.0))?;
+    let lookback = keltner_channels_lookback(Some(time_period), Some(atr_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut middle_band = Vec::with_capacity(high.len());
+    let mut upper_band = Vec::with_capacity(high.len());
+    let mut lower_band = Vec::with_capacity(high.len());
+    for ((h, l), c) in high.iter().zip(low.iter()).zip(close.iter()) {
+        let (middle, upper, lower) = state.update(*h, *l, *c).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+        middle_band.push(middle);
+        upper_band.push(upper);
+        lower_band.push(lower);
+    }
+    Ok((middle_band, upper_band, lower_band))
+}
+
+/// Calculates the Keltner Channels.
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional period of the EMA middle band. Defaults to 20 if not provided.
+/// * `atr_period` - An optional period of the ATR. Defaults to 10 if not provided.
+/// * `multiplier` - An optional number of ATRs between the middle band and the outer bands. Defaults to 2.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::keltner_channels;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = keltner_channels(&high, &low, &close, Some(3), Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn keltner_channels(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    time_period: Option<usize>,
+    atr_period: Option<usize>,
+    multiplier: Option<f64>,
+) -> Result<(Series, Series, Series), TalibError> {
+    let (middle_band, upper_band, lower_band) = keltner_channels_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        time_period,
+        atr_period,
+        multiplier,
+    )?;
+    Ok((
+        vec_to_series("KELTNER_MIDDLE", middle_band),
+        vec_to_series("KELTNER_UPPER", upper_band),
+        vec_to_series("KELTNER_LOWER", lower_band),
+    ))
+}
+
+/// Streaming Keltner Channels.
+///
+/// Returns `(middle_band, upper_band, lower_band)` once both the EMA and the ATR are warmed up.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::KeltnerChannels;
+///
+/// let mut keltner = KeltnerChannels::new(2, 2, 1.0).unwrap();
+/// assert_eq!(keltner.update(10.0, 8.0, 9.0), None);
+/// assert_eq!(keltner.update(12.0, 9.0, 11.0), None);
+/// assert_eq!(keltner.update(11.0, 10.5, 10.75), Some((10.5, 12.25, 8.75)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeltnerChannels {
+    multiplier: f64,
+    ema: Ema,
+    atr: Atr,
+}
+
+impl KeltnerChannels {
+    /// Creates a new Keltner Channels state, returns an error if `time_period` or `atr_period` is 0.
+    pub fn new(time_period: usize, atr_period: usize, multiplier: f64) -> Result<Self, TalibError> {
+        Ok(KeltnerChannels {
+            multiplier,
+            ema: Ema::new(time_period)?,
+            atr: Atr::new(atr_period)?,
+        })
+    }
+
+    /// Feeds the next bar and returns `(middle_band, upper_band, lower_band)` once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<(f64, f64, f64)> {
+        let middle = self.ema.update(close);
+        let atr = self.atr.update(high, low, close);
+        let (middle, atr) = (middle?, atr?);
+        let width = self.multiplier * atr;
+        Some((middle, middle + width, middle - width))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.ema.reset();
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keltner_channels() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+        let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+        let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+        let (middle, upper, lower) = keltner_channels_slice(&high, &low, &close, Some(3), Some(2), None)?;
+        assert_eq!(keltner_channels_lookback(Some(3), Some(2)), 2);
+        let ema = crate::exponential_moving_average_slice(&close, Some(3))?;
+        let atr = crate::atr_slice(&high, &low, &close, Some(2))?;
+        assert!(middle[..2].iter().all(|value| value.is_nan()));
+        for i in 2..close.len() {
+            assert_eq!(middle[i], ema[i]);
+            assert_eq!(upper[i], ema[i] + 2.0 * atr[i]);
+            assert_eq!(lower[i], ema[i] - 2.0 * atr[i]);
+        }
+        assert!(keltner_channels_slice(&high, &low, &close[1..], None, None, None).is_err());
+        assert!(keltner_channels_slice(&high, &low, &close, Some(0), Some(2), None).is_err());
+        Ok(())
+    }
+}
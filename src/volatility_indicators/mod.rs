@@ -4,7 +4,71 @@
  * @Email: uyplayer@qq.com
  * @File: mod.rs
  * @Software: RustRover
- * @Dir: rusty-talib / src/cycle_indicators
+ * @Dir: rusty-talib / src/volatility_indicators
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+
+// | Function                   | Description                           | Status  |
+// |----------------------------|---------------------------------------|---------|
+// | ATR                        | Average True Range                    | Done    |
+// | CHANDELIER_EXIT            | Chandelier Exit                       | Done    |
+// | CLOSE_TO_CLOSE_VOLATILITY  | Close-to-Close Historical Volatility  | Done    |
+// | DONCHIAN_CHANNELS          | Donchian Channels                     | Done    |
+// | GARMAN_KLASS_VOLATILITY    | Garman-Klass Historical Volatility    | Done    |
+// | KELTNER_CHANNELS           | Keltner Channels                      | Done    |
+// | NATR                       | Normalized Average True Range         | Done    |
+// | PARKINSON_VOLATILITY       | Parkinson Historical Volatility       | Done    |
+// | ROGERS_SATCHELL_VOLATILITY | Rogers-Satchell Historical Volatility | Done    |
+// | TRANGE                     | True Range                            | Done    |
+// | YANG_ZHANG_VOLATILITY      | Yang-Zhang Historical Volatility      | Done    |
+
+/// This module contains the volatility indicators.
+mod realized_variance;
+mod atr;
+mod chandelier_exit;
+mod close_to_close_volatility;
+mod donchian_channels;
+mod garman_klass_volatility;
+mod keltner_channels;
+mod natr;
+mod parkinson_volatility;
+mod rogers_satchell_volatility;
+mod trange;
+mod yang_zhang_volatility;
+
+
+pub use atr::{atr_lookback, atr_slice, Atr};
+pub use chandelier_exit::{chandelier_exit_lookback, chandelier_exit_slice, ChandelierExit};
+pub use close_to_close_volatility::{close_to_close_volatility_lookback, close_to_close_volatility_slice, CloseToCloseVolatility};
+pub use donchian_channels::{donchian_channels_lookback, donchian_channels_slice, DonchianChannels};
+pub use garman_klass_volatility::{garman_klass_volatility_lookback, garman_klass_volatility_slice, GarmanKlassVolatility};
+pub use keltner_channels::{keltner_channels_lookback, keltner_channels_slice, KeltnerChannels};
+pub use natr::{natr_lookback, natr_slice, Natr};
+pub use parkinson_volatility::{parkinson_volatility_lookback, parkinson_volatility_slice, ParkinsonVolatility};
+pub use rogers_satchell_volatility::{rogers_satchell_volatility_lookback, rogers_satchell_volatility_slice, RogersSatchellVolatility};
+pub use trange::{trange_lookback, trange_slice, TRange};
+pub use yang_zhang_volatility::{yang_zhang_volatility_lookback, yang_zhang_volatility_slice, YangZhangVolatility};
+
+#[cfg(feature = "polars")]
+pub use atr::atr;
+#[cfg(feature = "polars")]
+pub use chandelier_exit::chandelier_exit;
+#[cfg(feature = "polars")]
+pub use close_to_close_volatility::close_to_close_volatility;
+#[cfg(feature = "polars")]
+pub use donchian_channels::donchian_channels;
+#[cfg(feature = "polars")]
+pub use garman_klass_volatility::garman_klass_volatility;
+#[cfg(feature = "polars")]
+pub use keltner_channels::keltner_channels;
+#[cfg(feature = "polars")]
+pub use natr::natr;
+#[cfg(feature = "polars")]
+pub use parkinson_volatility::parkinson_volatility;
+#[cfg(feature = "polars")]
+pub use rogers_satchell_volatility::rogers_satchell_volatility;
+#[cfg(feature = "polars")]
+pub use trange::trange;
+#[cfg(feature = "polars")]
+pub use yang_zhang_volatility::yang_zhang_volatility;
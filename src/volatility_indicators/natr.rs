@@ -0,0 +1,164 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/20 20:20
+ * @Email: uyplayer@qq.com
+ * @File: natr.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Normalized Average True Range
+//!
+//! natr = 100 * atr / close, 0 when the close is 0 like TA-Lib
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::helper::ZERO;
+use crate::{atr_lookback, Atr, TalibError};
+
+/// Number of leading bars without a NATR value, the ATR lookback.
+pub fn natr_lookback(time_period: Option<usize>) -> usize {
+    atr_lookback(time_period)
+}
+
+/// Calculates the Normalized Average True Range (NATR) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`natr`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::natr_slice;
+///
+/// let high = [10.0, 12.0, 11.0, 15.0];
+/// let low = [8.0, 9.0, 10.5, 13.0];
+/// let close = [9.0, 11.0, 10.75, 14.0];
+/// let natr = natr_slice(&high, &low, &close, Some(2)).unwrap();
+/// assert!(natr[1].is_nan());
+/// // an ATR of 3 at a close of 14
+/// assert!((natr[3] - 300.0 / 14.0).abs() < 1e-9);
+/// ```
+pub fn natr_slice(high: &[f64], low: &[f64], close: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(14);
+    let mut state = Natr::new(time_period)?;
+    let lookback = natr_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Normalized Average True Range (NATR).
+///
+/// ``` python
+///     #  This Python code produces the same result as the natr function does
+///     import talib
+///     natr = talib.NATR(high, low, close, timeperiod=14)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional parameter representing the time period. Defaults to 14 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::natr;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = natr(&high, &low, &close, Some(3));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn natr(high: &Series, low: &Series, close: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let natr = natr_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?, time_period)?;
+    Ok(vec_to_series("data", natr))
+}
+
+/// Streaming Normalized Average True Range (NATR).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Natr;
+///
+/// let mut natr = Natr::new(2).unwrap();
+/// for (high, low, close) in [(10.0, 8.0, 9.0), (12.0, 9.0, 11.0), (11.0, 10.5, 10.75)] {
+///     eprintln!("{:?}", natr.update(high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Natr {
+    atr: Atr,
+}
+
+impl Natr {
+    /// Creates a new NATR state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(Natr {
+            atr: Atr::new(time_period)?,
+        })
+    }
+
+    /// Feeds the next bar and returns the current NATR once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let atr = self.atr.update(high, low, close)?;
+        Some(if close.abs() < ZERO { 0.0 } else { (atr / close) * 100.0 })
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natr() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 15.0, 1.0];
+        let low = [8.0, 9.0, 10.5, 13.0, 0.0];
+        let close = [9.0, 11.0, 10.75, 14.0, 0.0];
+        let res = natr_slice(&high, &low, &close, Some(2))?;
+        let atr = crate::atr_slice(&high, &low, &close, Some(2))?;
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        for i in 2..4 {
+            assert!((res[i] - 100.0 * atr[i] / close[i]).abs() < 1e-12);
+        }
+        assert_eq!(res[4], 0.0);
+        assert!(natr_slice(&high, &low, &close[1..], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/23 16:20
+ * @Email: uyplayer@qq.com
+ * @File: parkinson_volatility.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Parkinson Historical Volatility
+//!
+//! - per-bar variance = ln(high / low)^2 / (4 * ln 2)
+//! - volatility = sqrt(annualization * mean per-bar variance over `time_period` bars)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::volatility_indicators::realized_variance::{annualized, check_annualization, RollingVariance, TRADING_DAYS};
+use crate::TalibError;
+
+/// Number of leading bars without a volatility, `time_period - 1`.
+pub fn parkinson_volatility_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20).saturating_sub(1)
+}
+
+/// Calculates the Parkinson Historical Volatility over plain `high` and `low` slices,
+/// the polars free core of [`parkinson_volatility`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `high` and `low` have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0 or `annualization` is not greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::parkinson_volatility_slice;
+///
+/// let high = [2f64.exp(), 1f64.exp()];
+/// let low = [1.0, 1.0];
+/// let volatility = parkinson_volatility_slice(&high, &low, Some(2), Some(1.0)).unwrap();
+/// assert!(volatility[0].is_nan());
+/// // ranges of 2 and 1 in log space
+/// assert!((volatility[1] - (2.5 / (4.0 * 2f64.ln())).sqrt()).abs() < 1e-12);
+/// ```
+pub fn parkinson_volatility_slice(
+    high: &[f64],
+    low: &[f64],
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    if high.len() != low.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: high.len(),
+            got: low.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = ParkinsonVolatility::new(time_period, annualization.unwrap_or(TRADING_DAYS))?;
+    let lookback = parkinson_volatility_lookback(Some(time_period));
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .map(|(h, l)| state.update(*h, *l).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Parkinson Historical Volatility.
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `time_period` - An optional number of bars in the window. Defaults to 20 if not provided.
+/// * `annualization` - An optional number of bars per year. Defaults to 252, 1 gives the per-bar volatility.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::parkinson_volatility;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let res = parkinson_volatility(&high, &low, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn parkinson_volatility(
+    high: &Series,
+    low: &Series,
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Series, TalibError> {
+    let volatility = parkinson_volatility_slice(&series_to_vec(high)?, &series_to_vec(low)?, time_period, annualization)?;
+    Ok(vec_to_series("data", volatility))
+}
+
+/// Streaming Parkinson Historical Volatility.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ParkinsonVolatility;
+///
+/// let mut volatility = ParkinsonVolatility::new(2, 252.0).unwrap();
+/// for (high, low) in [(10.0, 9.0), (11.0, 9.5), (10.5, 9.0)] {
+///     eprintln!("{:?}", volatility.update(high, low));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParkinsonVolatility {
+    annualization: f64,
+    variances: RollingVariance,
+}
+
+impl ParkinsonVolatility {
+    /// Creates a new Parkinson volatility state, returns an error if `time_period` is 0
+    /// or `annualization` is not greater than 0.
+    pub fn new(time_period: usize, annualization: f64) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(ParkinsonVolatility {
+            annualization: check_annualization(annualization)?,
+            variances: RollingVariance::new(time_period),
+        })
+    }
+
+    /// Feeds the next high/low pair and returns the current volatility once the window is full.
+    pub fn update(&mut self, high: f64, low: f64) -> Option<f64> {
+        let range = (high / low).ln();
+        let variance = self.variances.update(range * range / (4.0 * std::f64::consts::LN_2))?;
+        Some(annualized(variance, self.annualization))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.variances.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parkinson_volatility() -> Result<(), TalibError> {
+        let high = [10.0, 11.0, 10.5, 12.0];
+        let low = [9.0, 9.5, 9.0, 10.0];
+        let res = parkinson_volatility_slice(&high, &low, Some(2), None)?;
+        assert!(res[0].is_nan());
+        let variance = |i: usize| (high[i] / low[i]).ln().powi(2) / (4.0 * 2f64.ln());
+        for (i, value) in res.iter().enumerate().skip(1) {
+            let expected = (252.0 * (variance(i - 1) + variance(i)) / 2.0).sqrt();
+            assert!((value - expected).abs() < 1e-12);
+        }
+        // no range, no volatility
+        assert_eq!(parkinson_volatility_slice(&[1.0], &[1.0], Some(1), None)?, [0.0]);
+        assert!(parkinson_volatility_slice(&high, &low[1..], None, None).is_err());
+        assert!(parkinson_volatility_slice(&high, &low, Some(0), None).is_err());
+        assert!(parkinson_volatility_slice(&high, &low, Some(2), Some(f64::NAN)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/23 15:10
+ * @Email: uyplayer@qq.com
+ * @File: realized_variance.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The rolling window and the annualization shared by the historical volatility estimators.
+
+use std::collections::VecDeque;
+use crate::TalibError;
+
+/// Bars per year used when no annualization factor is given, the trading days of a year.
+pub(crate) const TRADING_DAYS: f64 = 252.0;
+
+/// Returns an error unless `annualization` is a finite number greater than 0.
+pub(crate) fn check_annualization(annualization: f64) -> Result<f64, TalibError> {
+    if !annualization.is_finite() || annualization <= 0.0 {
+        return Err(TalibError::InvalidParameter {
+            name: "annualization",
+            value: annualization,
+            reason: "must be greater than 0",
+        });
+    }
+    Ok(annualization)
+}
+
+/// Turns a per-bar variance into an annualized volatility, a variance rounded below 0 counts as 0.
+pub(crate) fn annualized(variance: f64, annualization: f64) -> f64 {
+    (variance.max(0.0) * annualization).sqrt()
+}
+
+/// Mean and sample variance of the last `time_period` values.
+///
+/// Like [`crate::BBands`], the mean comes from a running sum updated as values enter and leave
+/// the window, while the squared deviations are recomputed over the whole window on each call.
+#[derive(Debug, Clone)]
+pub(crate) struct RollingVariance {
+    time_period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl RollingVariance {
+    pub(crate) fn new(time_period: usize) -> Self {
+        RollingVariance {
+            time_period,
+            window: VecDeque::with_capacity(time_period + 1),
+            sum: 0.0,
+        }
+    }
+
+    /// Feeds the next value, returns the mean of the window once it is full.
+    pub(crate) fn update(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        self.sum += value;
+        if self.window.len() > self.time_period {
+            if let Some(old) = self.window.pop_front() {
+                self.sum -= old;
+            }
+        }
+        if self.window.len() < self.time_period {
+            return None;
+        }
+        Some(self.sum / self.time_period as f64)
+    }
+
+    /// Returns the sample variance of a full window, with `time_period - 1` degrees of freedom.
+    pub(crate) fn sample_variance(&self) -> f64 {
+        let mean = self.sum / self.window.len() as f64;
+        let sq: f64 = self.window.iter().map(|value| (value - mean) * (value - mean)).sum();
+        sq / (self.window.len() as f64 - 1.0)
+    }
+
+    /// Clears all accumulated values.
+    pub(crate) fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
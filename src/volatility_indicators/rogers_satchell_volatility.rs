@@ -0,0 +1,201 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/23 17:30
+ * @Email: uyplayer@qq.com
+ * @File: rogers_satchell_volatility.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Rogers-Satchell Historical Volatility
+//!
+//! - per-bar variance = ln(high / close) * ln(high / open) + ln(low / close) * ln(low / open)
+//! - volatility = sqrt(annualization * mean per-bar variance over `time_period` bars)
+//!
+//! Unlike Parkinson and Garman-Klass the estimate stays unbiased when the price drifts.
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::volatility_indicators::realized_variance::{annualized, check_annualization, RollingVariance, TRADING_DAYS};
+use crate::TalibError;
+
+/// Number of leading bars without a volatility, `time_period - 1`.
+pub fn rogers_satchell_volatility_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20).saturating_sub(1)
+}
+
+/// Calculates the Rogers-Satchell Historical Volatility over plain `open`, `high`, `low` and `close` slices,
+/// the polars free core of [`rogers_satchell_volatility`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0 or `annualization` is not greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::rogers_satchell_volatility_slice;
+///
+/// // a bar that opens at its low and closes at its high
+/// let volatility = rogers_satchell_volatility_slice(&[1.0], &[2f64.exp()], &[1.0], &[2f64.exp()], Some(1), Some(1.0)).unwrap();
+/// assert_eq!(volatility[0], 0.0);
+/// // and one that closes at its low
+/// let volatility = rogers_satchell_volatility_slice(&[1.0], &[2f64.exp()], &[1.0], &[1.0], Some(1), Some(1.0)).unwrap();
+/// assert!((volatility[0] - 2.0).abs() < 1e-12);
+/// ```
+pub fn rogers_satchell_volatility_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = RogersSatchellVolatility::new(time_period, annualization.unwrap_or(TRADING_DAYS))?;
+    let lookback = rogers_satchell_volatility_lookback(Some(time_period));
+    if open.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: open.len(),
+        });
+    }
+    Ok(open
+        .iter()
+        .zip(high.iter())
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|(((o, h), l), c)| state.update(*o, *h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Rogers-Satchell Historical Volatility.
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional number of bars in the window. Defaults to 20 if not provided.
+/// * `annualization` - An optional number of bars per year. Defaults to 252, 1 gives the per-bar volatility.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::rogers_satchell_volatility;
+///
+///  let open = Series::new("open", [9.2, 9.8, 10.4, 9.9, 11.6, 12.1]);
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = rogers_satchell_volatility(&open, &high, &low, &close, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn rogers_satchell_volatility(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Series, TalibError> {
+    let volatility = rogers_satchell_volatility_slice(
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        time_period,
+        annualization,
+    )?;
+    Ok(vec_to_series("data", volatility))
+}
+
+/// Streaming Rogers-Satchell Historical Volatility.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::RogersSatchellVolatility;
+///
+/// let mut volatility = RogersSatchellVolatility::new(2, 252.0).unwrap();
+/// for (open, high, low, close) in [(9.2, 10.0, 9.0, 9.5), (9.8, 11.0, 9.5, 10.5), (10.4, 10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", volatility.update(open, high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RogersSatchellVolatility {
+    annualization: f64,
+    variances: RollingVariance,
+}
+
+impl RogersSatchellVolatility {
+    /// Creates a new Rogers-Satchell volatility state, returns an error if `time_period` is 0
+    /// or `annualization` is not greater than 0.
+    pub fn new(time_period: usize, annualization: f64) -> Result<Self, TalibError> {
+        if time_period == 0 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 0",
+            });
+        }
+        Ok(RogersSatchellVolatility {
+            annualization: check_annualization(annualization)?,
+            variances: RollingVariance::new(time_period),
+        })
+    }
+
+    /// Feeds the next bar and returns the current volatility once the window is full.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<f64> {
+        let variance = (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln();
+        let variance = self.variances.update(variance)?;
+        Some(annualized(variance, self.annualization))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.variances.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rogers_satchell_volatility() -> Result<(), TalibError> {
+        let open = [9.2, 9.8, 10.4, 9.9];
+        let high = [10.0, 11.0, 10.5, 12.0];
+        let low = [9.0, 9.5, 9.0, 10.0];
+        let close = [9.5, 10.5, 10.0, 11.5];
+        let res = rogers_satchell_volatility_slice(&open, &high, &low, &close, Some(3), Some(1.0))?;
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        let variance = |i: usize| {
+            (high[i] / close[i]).ln() * (high[i] / open[i]).ln() + (low[i] / close[i]).ln() * (low[i] / open[i]).ln()
+        };
+        for (i, value) in res.iter().enumerate().skip(2) {
+            let expected = ((variance(i - 2) + variance(i - 1) + variance(i)) / 3.0).sqrt();
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert!(rogers_satchell_volatility_slice(&open, &high, &low, &close[1..], None, None).is_err());
+        assert!(rogers_satchell_volatility_slice(&open, &high, &low, &close, Some(0), None).is_err());
+        assert!(rogers_satchell_volatility_slice(&open, &high, &low, &close, Some(2), Some(-1.0)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/20 19:10
+ * @Email: uyplayer@qq.com
+ * @File: trange.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! True Range
+//!
+//! trange = max(high, previous close) - min(low, previous close)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a TRANGE value, 1 like TA-Lib because the first bar has no previous close.
+pub fn trange_lookback() -> usize {
+    1
+}
+
+/// Calculates the True Range (TRANGE) over plain `high`, `low` and `close` slices,
+/// the polars free core of [`trange`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::trange_slice;
+///
+/// let high = [10.0, 12.0, 11.0, 15.0];
+/// let low = [8.0, 9.0, 10.5, 13.0];
+/// let close = [9.0, 11.0, 10.75, 14.0];
+/// let trange = trange_slice(&high, &low, &close).unwrap();
+/// assert!(trange[0].is_nan());
+/// // a gap up is measured from the previous close
+/// assert_eq!(trange[1..], [3.0, 0.5, 4.25]);
+/// ```
+pub fn trange_slice(high: &[f64], low: &[f64], close: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let lookback = trange_lookback();
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    let mut state = TRange::new();
+    Ok(high
+        .iter()
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|((h, l), c)| state.update(*h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the True Range (TRANGE).
+///
+/// ``` python
+///     #  This Python code produces the same result as the trange function does
+///     import talib
+///     trange = talib.TRANGE(high, low, close)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::trange;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = trange(&high, &low, &close);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn trange(high: &Series, low: &Series, close: &Series) -> Result<Series, TalibError> {
+    let trange = trange_slice(&series_to_vec(high)?, &series_to_vec(low)?, &series_to_vec(close)?)?;
+    Ok(vec_to_series("data", trange))
+}
+
+/// Streaming True Range (TRANGE).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::TRange;
+///
+/// let mut trange = TRange::new();
+/// assert_eq!(trange.update(10.0, 8.0, 9.0), None);
+/// assert_eq!(trange.update(12.0, 9.0, 11.0), Some(3.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TRange {
+    prev_close: Option<f64>,
+}
+
+impl TRange {
+    /// Creates a new TRANGE state.
+    pub fn new() -> Self {
+        TRange::default()
+    }
+
+    /// Feeds the next bar and returns its true range, `None` for the first bar.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let prev_close = self.prev_close.replace(close)?;
+        Some(high.max(prev_close) - low.min(prev_close))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trange() -> Result<(), TalibError> {
+        let high = [10.0, 12.0, 11.0, 15.0, 9.0];
+        let low = [8.0, 9.0, 10.5, 13.0, 8.0];
+        let close = [9.0, 11.0, 10.75, 14.0, 8.5];
+        let res = trange_slice(&high, &low, &close)?;
+        assert!(res[0].is_nan());
+        // a gap down is measured up to the previous close
+        assert_eq!(res[1..], [3.0, 0.5, 4.25, 6.0]);
+        assert!(trange_slice(&high, &low, &close[1..]).is_err());
+        assert!(trange_slice(&high[..1], &low[..1], &close[..1]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/23 18:10
+ * @Email: uyplayer@qq.com
+ * @File: yang_zhang_volatility.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volatility_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Yang-Zhang Historical Volatility
+//!
+//! - overnight return = ln(open / previous close), open-to-close return = ln(close / open)
+//! - k = 0.34 / (1.34 + (time_period + 1) / (time_period - 1))
+//! - variance = overnight sample variance + k * open-to-close sample variance
+//!   + (1 - k) * mean Rogers-Satchell variance, all over `time_period` bars
+//! - volatility = sqrt(annualization * variance)
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::volatility_indicators::realized_variance::{annualized, check_annualization, RollingVariance, TRADING_DAYS};
+use crate::TalibError;
+
+/// Number of leading bars without a volatility, `time_period` because the first bar has no
+/// overnight return.
+pub fn yang_zhang_volatility_lookback(time_period: Option<usize>) -> usize {
+    time_period.unwrap_or(20)
+}
+
+/// Calculates the Yang-Zhang Historical Volatility over plain `open`, `high`, `low` and `close` slices,
+/// the polars free core of [`yang_zhang_volatility`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is less than 2 or `annualization` is not greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::yang_zhang_volatility_slice;
+///
+/// // flat bars that only gap up and down by ln(1.1) have the overnight variance alone
+/// let open = [100.0, 110.0, 100.0];
+/// let close = open;
+/// let volatility = yang_zhang_volatility_slice(&open, &open, &close, &close, Some(2), Some(1.0)).unwrap();
+/// assert!(volatility[1].is_nan());
+/// assert!((volatility[2] - 2f64.sqrt() * 1.1f64.ln()).abs() < 1e-12);
+/// ```
+pub fn yang_zhang_volatility_slice(
+    open: &[f64],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [high.len(), low.len(), close.len()] {
+        if got != open.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: open.len(),
+                got,
+            });
+        }
+    }
+    let time_period = time_period.unwrap_or(20);
+    let mut state = YangZhangVolatility::new(time_period, annualization.unwrap_or(TRADING_DAYS))?;
+    let lookback = yang_zhang_volatility_lookback(Some(time_period));
+    if open.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: open.len(),
+        });
+    }
+    Ok(open
+        .iter()
+        .zip(high.iter())
+        .zip(low.iter())
+        .zip(close.iter())
+        .map(|(((o, h), l), c)| state.update(*o, *h, *l, *c).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Yang-Zhang Historical Volatility.
+///
+/// # Arguments
+///
+/// * `open` - A Series containing open price data.
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `time_period` - An optional number of bars in the window. Defaults to 20 if not provided.
+/// * `annualization` - An optional number of bars per year. Defaults to 252, 1 gives the per-bar volatility.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::yang_zhang_volatility;
+///
+///  let open = Series::new("open", [9.2, 9.8, 10.4, 9.9, 11.6, 12.1]);
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let res = yang_zhang_volatility(&open, &high, &low, &close, Some(3), None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn yang_zhang_volatility(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    time_period: Option<usize>,
+    annualization: Option<f64>,
+) -> Result<Series, TalibError> {
+    let volatility = yang_zhang_volatility_slice(
+        &series_to_vec(open)?,
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        time_period,
+        annualization,
+    )?;
+    Ok(vec_to_series("data", volatility))
+}
+
+/// Streaming Yang-Zhang Historical Volatility.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::YangZhangVolatility;
+///
+/// let mut volatility = YangZhangVolatility::new(2, 252.0).unwrap();
+/// for (open, high, low, close) in [(9.2, 10.0, 9.0, 9.5), (9.8, 11.0, 9.5, 10.5), (10.4, 10.5, 9.0, 10.0)] {
+///     eprintln!("{:?}", volatility.update(open, high, low, close));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct YangZhangVolatility {
+    annualization: f64,
+    k: f64,
+    prev_close: Option<f64>,
+    overnight: RollingVariance,
+    open_to_close: RollingVariance,
+    rogers_satchell: RollingVariance,
+}
+
+impl YangZhangVolatility {
+    /// Creates a new Yang-Zhang volatility state, returns an error if `time_period` is less than 2
+    /// or `annualization` is not greater than 0.
+    pub fn new(time_period: usize, annualization: f64) -> Result<Self, TalibError> {
+        if time_period < 2 {
+            return Err(TalibError::InvalidParameter {
+                name: "time_period",
+                value: time_period as f64,
+                reason: "must be greater than 1",
+            });
+        }
+        let n = time_period as f64;
+        Ok(YangZhangVolatility {
+            annualization: check_annualization(annualization)?,
+            k: 0.34 / (1.34 + (n + 1.0) / (n - 1.0)),
+            prev_close: None,
+            overnight: RollingVariance::new(time_period),
+            open_to_close: RollingVariance::new(time_period),
+            rogers_satchell: RollingVariance::new(time_period),
+        })
+    }
+
+    /// Feeds the next bar and returns the current volatility once the window is full.
+    pub fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<f64> {
+        let prev_close = self.prev_close.replace(close)?;
+        let rogers_satchell = (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln();
+        // the three windows fill up together
+        self.overnight.update((open / prev_close).ln());
+        self.open_to_close.update((close / open).ln());
+        let rogers_satchell = self.rogers_satchell.update(rogers_satchell)?;
+        let variance = self.overnight.sample_variance()
+            + self.k * self.open_to_close.sample_variance()
+            + (1.0 - self.k) * rogers_satchell;
+        Some(annualized(variance, self.annualization))
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+        self.overnight.reset();
+        self.open_to_close.reset();
+        self.rogers_satchell.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yang_zhang_volatility() -> Result<(), TalibError> {
+        let open = [9.2, 9.8, 10.4, 9.9, 11.6];
+        let high = [10.0, 11.0, 10.5, 12.0, 12.5];
+        let low = [9.0, 9.5, 9.0, 9.8, 11.5];
+        let close = [9.5, 10.5, 10.0, 11.5, 12.0];
+        let res = yang_zhang_volatility_slice(&open, &high, &low, &close, Some(3), Some(1.0))?;
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        let sample_variance = |values: &[f64]| {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (values.len() - 1) as f64
+        };
+        let k = 0.34 / (1.34 + 4.0 / 2.0);
+        for (i, value) in res.iter().enumerate().skip(3) {
+            let bars = i - 2..=i;
+            let overnight: Vec<f64> = bars.clone().map(|j| (open[j] / close[j - 1]).ln()).collect();
+            let open_to_close: Vec<f64> = bars.clone().map(|j| (close[j] / open[j]).ln()).collect();
+            let rogers_satchell = crate::rogers_satchell_volatility_slice(&open, &high, &low, &close, Some(3), Some(1.0))?[i];
+            let variance = sample_variance(&overnight) + k * sample_variance(&open_to_close) + (1.0 - k) * rogers_satchell.powi(2);
+            assert!((value - variance.sqrt()).abs() < 1e-12);
+        }
+        assert!(yang_zhang_volatility_slice(&open, &high, &low[1..], &close, None, None).is_err());
+        assert!(yang_zhang_volatility_slice(&open, &high, &low, &close, Some(1), None).is_err());
+        assert!(yang_zhang_volatility_slice(&open, &high, &low, &close, Some(5), None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 14:20
+ * @Email: uyplayer@qq.com
+ * @File: ad.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Chaikin A/D Line
+//!
+//! - money flow multiplier = ((close - low) - (high - close)) / (high - low), 0 when high equals low
+//! - ad = running sum of money flow multiplier * volume
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without an AD value, AD has no warm-up so it is always 0.
+pub fn ad_lookback() -> usize {
+    0
+}
+
+/// Calculates the Chaikin A/D Line (AD) over plain `high`, `low`, `close` and `volume` slices,
+/// the polars free core of [`ad`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ad_slice;
+///
+/// let high = [12.0, 12.0, 11.0];
+/// let low = [8.0, 10.0, 11.0];
+/// let close = [11.0, 10.0, 11.0];
+/// let volume = [100.0, 50.0, 80.0];
+/// let ad = ad_slice(&high, &low, &close, &volume).unwrap();
+/// // a close at the low subtracts the whole volume, a bar without range adds nothing
+/// assert_eq!(ad, [50.0, 0.0, 0.0]);
+/// ```
+pub fn ad_slice(high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len(), volume.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    if high.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Ad::new();
+    Ok((0..high.len())
+        .map(|i| state.update(high[i], low[i], close[i], volume[i]))
+        .collect())
+}
+
+/// Calculates the Chaikin A/D Line (AD).
+///
+/// ``` python
+///     #  This Python code produces the same result as the ad function does
+///     import talib
+///     ad = talib.AD(high, low, close, volume)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::ad;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = ad(&high, &low, &close, &volume);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn ad(high: &Series, low: &Series, close: &Series, volume: &Series) -> Result<Series, TalibError> {
+    let ad = ad_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        &series_to_vec(volume)?,
+    )?;
+    Ok(vec_to_series("data", ad))
+}
+
+/// Streaming Chaikin A/D Line (AD).
+///
+/// AD has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Ad;
+///
+/// let mut ad = Ad::new();
+/// assert_eq!(ad.update(12.0, 8.0, 11.0, 100.0), 50.0);
+/// assert_eq!(ad.update(12.0, 10.0, 10.0, 50.0), 0.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Ad {
+    ad: f64,
+}
+
+impl Ad {
+    /// Creates a new AD state.
+    pub fn new() -> Self {
+        Ad::default()
+    }
+
+    /// Feeds the next bar and returns the A/D line so far.
+    pub fn update(&mut self, high: f64, low: f64, close: f64, volume: f64) -> f64 {
+        let range = high - low;
+        if range > 0.0 {
+            self.ad += (((close - low) - (high - close)) / range) * volume;
+        }
+        self.ad
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.ad = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ad() -> Result<(), TalibError> {
+        let res = ad_slice(&[12.0, 12.0, 11.0, 14.0], &[8.0, 10.0, 11.0, 10.0], &[11.0, 10.0, 11.0, 13.0], &[100.0, 50.0, 80.0, 40.0])?;
+        assert_eq!(res, [50.0, 0.0, 0.0, 20.0]);
+        assert_eq!(ad_lookback(), 0);
+        assert!(ad_slice(&[1.0], &[1.0], &[1.0], &[1.0, 2.0]).is_err());
+        assert!(ad_slice(&[], &[], &[], &[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 15:05
+ * @Email: uyplayer@qq.com
+ * @File: adosc.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Chaikin A/D Oscillator
+//!
+//! adosc = EMA(fast_period) of AD - EMA(slow_period) of AD, both EMAs seeded with the first AD value
+//! like TA-Lib
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{Ad, TalibError};
+
+/// Number of leading bars without an ADOSC value, the longer period minus 1 like TA-Lib.
+pub fn adosc_lookback(fast_period: Option<usize>, slow_period: Option<usize>) -> usize {
+    fast_period.unwrap_or(3).max(slow_period.unwrap_or(10)).saturating_sub(1)
+}
+
+/// Calculates the Chaikin A/D Oscillator (ADOSC) over plain `high`, `low`, `close` and `volume` slices,
+/// the polars free core of [`adosc`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if the inputs have different lengths
+/// and [`TalibError::InvalidParameter`] if `fast_period` or `slow_period` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::adosc_slice;
+///
+/// let high = [12.0, 12.0, 11.0, 14.0];
+/// let low = [8.0, 10.0, 11.0, 10.0];
+/// let close = [11.0, 10.0, 11.0, 13.0];
+/// let volume = [100.0, 50.0, 80.0, 40.0];
+/// let adosc = adosc_slice(&high, &low, &close, &volume, Some(2), Some(3)).unwrap();
+/// assert!(adosc[1].is_nan());
+/// // AD is 50, 0, 0, 20: the 2 and 3 bar EMAs are 50/9 and 12.5 on the third bar
+/// assert!((adosc[2] - (50.0 / 9.0 - 12.5)).abs() < 1e-9);
+/// ```
+pub fn adosc_slice(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+) -> Result<Vec<f64>, TalibError> {
+    for got in [low.len(), close.len(), volume.len()] {
+        if got != high.len() {
+            return Err(TalibError::LengthMismatch {
+                expected: high.len(),
+                got,
+            });
+        }
+    }
+    let mut state = AdOsc::new(fast_period.unwrap_or(3), slow_period.unwrap_or(10))?;
+    let lookback = adosc_lookback(fast_period, slow_period);
+    if high.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: high.len(),
+        });
+    }
+    Ok((0..high.len())
+        .map(|i| state.update(high[i], low[i], close[i], volume[i]).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Chaikin A/D Oscillator (ADOSC).
+///
+/// ``` python
+///     #  This Python code produces the same result as the adosc function does
+///     import talib
+///     adosc = talib.ADOSC(high, low, close, volume, fastperiod=3, slowperiod=10)
+/// ```
+///
+/// # Arguments
+///
+/// * `high` - A Series containing high price data.
+/// * `low` - A Series containing low price data.
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `fast_period` - An optional period of the fast EMA. Defaults to 3.
+/// * `slow_period` - An optional period of the slow EMA. Defaults to 10.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::adosc;
+///
+///  let high = Series::new("high", [10.0, 11.0, 10.5, 12.0, 12.5, 12.0]);
+///  let low = Series::new("low", [9.0, 9.5, 9.0, 10.0, 11.5, 11.0]);
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = adosc(&high, &low, &close, &volume, Some(2), Some(4));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn adosc(
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    volume: &Series,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+) -> Result<Series, TalibError> {
+    let adosc = adosc_slice(
+        &series_to_vec(high)?,
+        &series_to_vec(low)?,
+        &series_to_vec(close)?,
+        &series_to_vec(volume)?,
+        fast_period,
+        slow_period,
+    )?;
+    Ok(vec_to_series("data", adosc))
+}
+
+/// Streaming Chaikin A/D Oscillator (ADOSC).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::AdOsc;
+///
+/// let mut adosc = AdOsc::new(2, 3).unwrap();
+/// for (high, low, close, volume) in [(12.0, 8.0, 11.0, 100.0), (12.0, 10.0, 10.0, 50.0), (11.0, 11.0, 11.0, 80.0)] {
+///     eprintln!("{:?}", adosc.update(high, low, close, volume));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdOsc {
+    ad: Ad,
+    fast_k: f64,
+    slow_k: f64,
+    fast_ema: f64,
+    slow_ema: f64,
+    lookback: usize,
+    count: usize,
+}
+
+impl AdOsc {
+    /// Creates a new ADOSC state, returns an error if `fast_period` or `slow_period` is less than 2.
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self, TalibError> {
+        for (name, value) in [("fast_period", fast_period), ("slow_period", slow_period)] {
+            if value < 2 {
+                return Err(TalibError::InvalidParameter {
+                    name,
+                    value: value as f64,
+                    reason: "must be greater than 1",
+                });
+            }
+        }
+        Ok(AdOsc {
+            ad: Ad::new(),
+            fast_k: 2.0 / (fast_period as f64 + 1.0),
+            slow_k: 2.0 / (slow_period as f64 + 1.0),
+            fast_ema: 0.0,
+            slow_ema: 0.0,
+            lookback: adosc_lookback(Some(fast_period), Some(slow_period)),
+            count: 0,
+        })
+    }
+
+    /// Feeds the next bar and returns the current oscillator once warmed up.
+    pub fn update(&mut self, high: f64, low: f64, close: f64, volume: f64) -> Option<f64> {
+        let ad = self.ad.update(high, low, close, volume);
+        if self.count == 0 {
+            self.fast_ema = ad;
+            self.slow_ema = ad;
+        } else {
+            self.fast_ema = (self.fast_k * ad) + ((1.0 - self.fast_k) * self.fast_ema);
+            self.slow_ema = (self.slow_k * ad) + ((1.0 - self.slow_k) * self.slow_ema);
+        }
+        self.count += 1;
+        if self.count <= self.lookback {
+            return None;
+        }
+        Some(self.fast_ema - self.slow_ema)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.ad.reset();
+        self.fast_ema = 0.0;
+        self.slow_ema = 0.0;
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adosc() -> Result<(), TalibError> {
+        let high = [12.0, 12.0, 11.0, 14.0];
+        let low = [8.0, 10.0, 11.0, 10.0];
+        let close = [11.0, 10.0, 11.0, 13.0];
+        let volume = [100.0, 50.0, 80.0, 40.0];
+        let res = adosc_slice(&high, &low, &close, &volume, Some(2), Some(3))?;
+        assert_eq!(adosc_lookback(Some(2), Some(3)), 2);
+        assert!(res[..2].iter().all(|value| value.is_nan()));
+        // the EMAs start at the first AD value of 50
+        let (mut fast, mut slow) = (50.0, 50.0);
+        for (i, ad) in [0.0, 0.0, 20.0].into_iter().enumerate() {
+            fast = 2.0 / 3.0 * ad + (1.0 - 2.0 / 3.0) * fast;
+            slow = 0.5 * ad + 0.5 * slow;
+            if i > 0 {
+                assert!((res[i + 1] - (fast - slow)).abs() < 1e-9);
+            }
+        }
+        assert!(adosc_slice(&high, &low, &close, &volume, Some(1), None).is_err());
+        assert!(adosc_slice(&high, &low, &close, &volume, None, None).is_err());
+        Ok(())
+    }
+}
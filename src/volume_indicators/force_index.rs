@@ -0,0 +1,153 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 17:30
+ * @Email: uyplayer@qq.com
+ * @File: force_index.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Force Index
+//!
+//! - raw force = (close - previous close) * volume
+//! - force index = EMA(time_period) of the raw force, a period of 1 gives the raw force
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::{exponential_moving_average_lookback, Ema, TalibError};
+
+/// Number of leading bars without a Force Index value, 1 for the first close difference plus
+/// the EMA lookback.
+pub fn force_index_lookback(time_period: Option<usize>) -> usize {
+    1 + exponential_moving_average_lookback(Some(time_period.unwrap_or(13)))
+}
+
+/// Calculates the Force Index over plain `close` and `volume` slices, the polars free core of [`force_index`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `close` and `volume` have different lengths
+/// and [`TalibError::InvalidParameter`] if `time_period` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::force_index_slice;
+///
+/// let force = force_index_slice(&[10.0, 11.0, 10.5, 12.0], &[100.0, 50.0, 80.0, 40.0], Some(2)).unwrap();
+/// assert!(force[1].is_nan());
+/// // the raw force is 50, -40 and 60, the EMA is seeded with the average of the first two
+/// assert_eq!(force[2], 5.0);
+/// assert_eq!(force[3], 5.0 + 2.0 / 3.0 * (60.0 - 5.0));
+/// ```
+pub fn force_index_slice(close: &[f64], volume: &[f64], time_period: Option<usize>) -> Result<Vec<f64>, TalibError> {
+    if volume.len() != close.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: close.len(),
+            got: volume.len(),
+        });
+    }
+    let time_period = time_period.unwrap_or(13);
+    let mut state = ForceIndex::new(time_period)?;
+    let lookback = force_index_lookback(Some(time_period));
+    if close.len() <= lookback {
+        return Err(TalibError::InsufficientData {
+            needed: lookback + 1,
+            got: close.len(),
+        });
+    }
+    Ok(close
+        .iter()
+        .zip(volume.iter())
+        .map(|(c, v)| state.update(*c, *v).unwrap_or(f64::NAN))
+        .collect())
+}
+
+/// Calculates the Force Index.
+///
+/// # Arguments
+///
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `time_period` - An optional period of the EMA. Defaults to 13 if not provided.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::force_index;
+///
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = force_index(&close, &volume, Some(2));
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn force_index(close: &Series, volume: &Series, time_period: Option<usize>) -> Result<Series, TalibError> {
+    let force = force_index_slice(&series_to_vec(close)?, &series_to_vec(volume)?, time_period)?;
+    Ok(vec_to_series("data", force))
+}
+
+/// Streaming Force Index.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::ForceIndex;
+///
+/// let mut force = ForceIndex::new(2).unwrap();
+/// for (close, volume) in [(10.0, 100.0), (11.0, 50.0), (10.5, 80.0)] {
+///     eprintln!("{:?}", force.update(close, volume));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForceIndex {
+    prev_close: Option<f64>,
+    ema: Ema,
+}
+
+impl ForceIndex {
+    /// Creates a new Force Index state, returns an error if `time_period` is 0.
+    pub fn new(time_period: usize) -> Result<Self, TalibError> {
+        Ok(ForceIndex {
+            prev_close: None,
+            ema: Ema::new(time_period)?,
+        })
+    }
+
+    /// Feeds the next close/volume pair and returns the current Force Index once warmed up.
+    pub fn update(&mut self, close: f64, volume: f64) -> Option<f64> {
+        let prev = self.prev_close.replace(close)?;
+        self.ema.update((close - prev) * volume)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+        self.ema.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_force_index() -> Result<(), TalibError> {
+        let close = [10.0, 11.0, 10.5, 12.0, 12.0];
+        let volume = [100.0, 50.0, 80.0, 40.0, 10.0];
+        assert_eq!(force_index_slice(&close, &volume, Some(1))?[1..], [50.0, -40.0, 60.0, 0.0]);
+        let res = force_index_slice(&close, &volume, Some(3))?;
+        assert_eq!(force_index_lookback(Some(3)), 3);
+        assert!(res[..3].iter().all(|value| value.is_nan()));
+        assert!((res[3] - 70.0 / 3.0).abs() < 1e-9);
+        assert!((res[4] - 70.0 / 6.0).abs() < 1e-9);
+        assert!(force_index_slice(&close, &volume[1..], None).is_err());
+        assert!(force_index_slice(&close, &volume, Some(0)).is_err());
+        Ok(())
+    }
+}
@@ -4,7 +4,51 @@
  * @Email: uyplayer@qq.com
  * @File: mod.rs
  * @Software: RustRover
- * @Dir: rusty-talib / src/cycle_indicators
+ * @Dir: rusty-talib / src/volume_indicators
  * @Project_Name: rusty-talib
  * @Description:
- */
\ No newline at end of file
+ */
+
+// | Function    | Description              | Status  |
+// |-------------|--------------------------|---------|
+// | AD          | Chaikin A/D Line         | Done    |
+// | ADOSC       | Chaikin A/D Oscillator   | Done    |
+// | FORCE_INDEX | Force Index              | Done    |
+// | NVI         | Negative Volume Index    | Done    |
+// | OBV         | On Balance Volume        | Done    |
+// | PVI         | Positive Volume Index    | Done    |
+// | PVT         | Price Volume Trend       | Done    |
+
+/// This module contains the volume indicators.
+mod volume_index;
+mod ad;
+mod adosc;
+mod force_index;
+mod nvi;
+mod obv;
+mod pvi;
+mod pvt;
+
+
+pub use ad::{ad_lookback, ad_slice, Ad};
+pub use adosc::{adosc_lookback, adosc_slice, AdOsc};
+pub use force_index::{force_index_lookback, force_index_slice, ForceIndex};
+pub use nvi::{nvi_lookback, nvi_slice, Nvi};
+pub use obv::{obv_lookback, obv_slice, Obv};
+pub use pvi::{pvi_lookback, pvi_slice, Pvi};
+pub use pvt::{pvt_lookback, pvt_slice, Pvt};
+
+#[cfg(feature = "polars")]
+pub use ad::ad;
+#[cfg(feature = "polars")]
+pub use adosc::adosc;
+#[cfg(feature = "polars")]
+pub use force_index::force_index;
+#[cfg(feature = "polars")]
+pub use nvi::nvi;
+#[cfg(feature = "polars")]
+pub use obv::obv;
+#[cfg(feature = "polars")]
+pub use pvi::pvi;
+#[cfg(feature = "polars")]
+pub use pvt::pvt;
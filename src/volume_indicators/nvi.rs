@@ -0,0 +1,140 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 16:50
+ * @Email: uyplayer@qq.com
+ * @File: nvi.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Negative Volume Index
+//!
+//! nvi starts at `start_value` and grows by the percentage change of the close on every bar whose
+//! volume is lower than the one before, other bars leave it unchanged
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::volume_indicators::volume_index::VolumeIndex;
+use crate::TalibError;
+
+/// Number of leading bars without an NVI value, NVI has no warm-up so it is always 0.
+pub fn nvi_lookback() -> usize {
+    0
+}
+
+/// Calculates the Negative Volume Index (NVI) over plain `close` and `volume` slices,
+/// the polars free core of [`nvi`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `close` and `volume` have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::nvi_slice;
+///
+/// let nvi = nvi_slice(&[10.0, 11.0, 12.1, 13.0], &[100.0, 50.0, 80.0, 40.0], Some(1000.0)).unwrap();
+/// assert_eq!(nvi[..3], [1000.0, 1100.0, 1100.0]);
+/// assert!((nvi[3] - 1100.0 * 13.0 / 12.1).abs() < 1e-9);
+/// ```
+pub fn nvi_slice(close: &[f64], volume: &[f64], start_value: Option<f64>) -> Result<Vec<f64>, TalibError> {
+    if volume.len() != close.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: close.len(),
+            got: volume.len(),
+        });
+    }
+    if close.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Nvi::new(start_value.unwrap_or(1000.0));
+    Ok(close
+        .iter()
+        .zip(volume.iter())
+        .map(|(c, v)| state.update(*c, *v))
+        .collect())
+}
+
+/// Calculates the Negative Volume Index (NVI).
+///
+/// # Arguments
+///
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+/// * `start_value` - An optional value of the index on the first bar. Defaults to 1000.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::nvi;
+///
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = nvi(&close, &volume, None);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn nvi(close: &Series, volume: &Series, start_value: Option<f64>) -> Result<Series, TalibError> {
+    let nvi = nvi_slice(&series_to_vec(close)?, &series_to_vec(volume)?, start_value)?;
+    Ok(vec_to_series("data", nvi))
+}
+
+/// Streaming Negative Volume Index (NVI).
+///
+/// NVI has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Nvi;
+///
+/// let mut nvi = Nvi::new(1000.0);
+/// assert_eq!(nvi.update(10.0, 100.0), 1000.0);
+/// assert_eq!(nvi.update(11.0, 50.0), 1100.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nvi {
+    index: VolumeIndex,
+}
+
+impl Nvi {
+    /// Creates a new NVI state starting at `start_value`.
+    pub fn new(start_value: f64) -> Self {
+        Nvi {
+            index: VolumeIndex::new(start_value, false),
+        }
+    }
+
+    /// Feeds the next close/volume pair and returns the NVI so far.
+    pub fn update(&mut self, close: f64, volume: f64) -> f64 {
+        self.index.update(close, volume)
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nvi() -> Result<(), TalibError> {
+        let res = nvi_slice(&[10.0, 11.0, 12.0, 9.0, 0.0, 5.0], &[100.0, 50.0, 50.0, 40.0, 30.0, 20.0], Some(100.0))?;
+        // equal volume leaves it unchanged, so does a previous close of 0
+        assert_eq!(res, [100.0, 110.0, 110.0, 82.5, 0.0, 0.0]);
+        assert_eq!(nvi_slice(&[10.0], &[1.0], None)?, [1000.0]);
+        assert!(nvi_slice(&[1.0, 2.0], &[1.0], None).is_err());
+        assert!(nvi_slice(&[], &[], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 15:40
+ * @Email: uyplayer@qq.com
+ * @File: obv.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! On Balance Volume
+//!
+//! obv starts at the first volume like TA-Lib, then adds the volume of every bar whose close rose
+//! and subtracts the volume of every bar whose close fell
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without an OBV value, OBV has no warm-up so it is always 0.
+pub fn obv_lookback() -> usize {
+    0
+}
+
+/// Calculates the On Balance Volume (OBV) over plain `close` and `volume` slices,
+/// the polars free core of [`obv`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `close` and `volume` have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::obv_slice;
+///
+/// let obv = obv_slice(&[10.0, 11.0, 11.0, 10.5], &[100.0, 50.0, 80.0, 40.0]).unwrap();
+/// assert_eq!(obv, [100.0, 150.0, 150.0, 110.0]);
+/// ```
+pub fn obv_slice(close: &[f64], volume: &[f64]) -> Result<Vec<f64>, TalibError> {
+    if volume.len() != close.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: close.len(),
+            got: volume.len(),
+        });
+    }
+    if close.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Obv::new();
+    Ok(close
+        .iter()
+        .zip(volume.iter())
+        .map(|(c, v)| state.update(*c, *v))
+        .collect())
+}
+
+/// Calculates the On Balance Volume (OBV).
+///
+/// ``` python
+///     #  This Python code produces the same result as the obv function does
+///     import talib
+///     obv = talib.OBV(close, volume)
+/// ```
+///
+/// # Arguments
+///
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::obv;
+///
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = obv(&close, &volume);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn obv(close: &Series, volume: &Series) -> Result<Series, TalibError> {
+    let obv = obv_slice(&series_to_vec(close)?, &series_to_vec(volume)?)?;
+    Ok(vec_to_series("data", obv))
+}
+
+/// Streaming On Balance Volume (OBV).
+///
+/// OBV has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Obv;
+///
+/// let mut obv = Obv::new();
+/// assert_eq!(obv.update(10.0, 100.0), 100.0);
+/// assert_eq!(obv.update(9.0, 30.0), 70.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Obv {
+    prev: Option<(f64, f64)>,
+}
+
+impl Obv {
+    /// Creates a new OBV state.
+    pub fn new() -> Self {
+        Obv::default()
+    }
+
+    /// Feeds the next close/volume pair and returns the OBV so far.
+    pub fn update(&mut self, close: f64, volume: f64) -> f64 {
+        let obv = match self.prev {
+            None => volume,
+            Some((prev_close, obv)) if close > prev_close => obv + volume,
+            Some((prev_close, obv)) if close < prev_close => obv - volume,
+            Some((_, obv)) => obv,
+        };
+        self.prev = Some((close, obv));
+        obv
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obv() -> Result<(), TalibError> {
+        let res = obv_slice(&[10.0, 11.0, 11.0, 10.5, 12.0], &[100.0, 50.0, 80.0, 40.0, 10.0])?;
+        assert_eq!(res, [100.0, 150.0, 150.0, 110.0, 120.0]);
+        assert_eq!(obv_lookback(), 0);
+        assert!(obv_slice(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(obv_slice(&[], &[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 16:10
+ * @Email: uyplayer@qq.com
+ * @File: pvt.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! Price Volume Trend
+//!
+//! pvt starts at 0 and adds volume * (close - previous close) / previous close on every bar,
+//! a previous close of 0 adds nothing
+
+#[cfg(feature = "polars")]
+use polars::prelude::*;
+#[cfg(feature = "polars")]
+use crate::helper::{series_to_vec, vec_to_series};
+use crate::TalibError;
+
+/// Number of leading bars without a PVT value, PVT has no warm-up so it is always 0.
+pub fn pvt_lookback() -> usize {
+    0
+}
+
+/// Calculates the Price Volume Trend (PVT) over plain `close` and `volume` slices,
+/// the polars free core of [`pvt`].
+///
+/// # Errors
+///
+/// Returns [`TalibError::LengthMismatch`] if `close` and `volume` have different lengths
+/// and [`TalibError::InsufficientData`] if they are empty.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::pvt_slice;
+///
+/// let pvt = pvt_slice(&[10.0, 11.0, 11.0, 8.8], &[100.0, 50.0, 80.0, 40.0]).unwrap();
+/// assert_eq!(pvt[..3], [0.0, 5.0, 5.0]);
+/// assert!((pvt[3] + 3.0).abs() < 1e-9);
+/// ```
+pub fn pvt_slice(close: &[f64], volume: &[f64]) -> Result<Vec<f64>, TalibError> {
+    if volume.len() != close.len() {
+        return Err(TalibError::LengthMismatch {
+            expected: close.len(),
+            got: volume.len(),
+        });
+    }
+    if close.is_empty() {
+        return Err(TalibError::InsufficientData { needed: 1, got: 0 });
+    }
+    let mut state = Pvt::new();
+    Ok(close
+        .iter()
+        .zip(volume.iter())
+        .map(|(c, v)| state.update(*c, *v))
+        .collect())
+}
+
+/// Calculates the Price Volume Trend (PVT).
+///
+/// # Arguments
+///
+/// * `close` - A Series containing close price data.
+/// * `volume` - A Series containing volume data, integer volumes are cast to float.
+///
+/// # Examples
+/// ```
+///  use polars::prelude::*;
+///  use rusty_talib::pvt;
+///
+///  let close = Series::new("close", [9.5, 10.5, 10.0, 11.5, 12.0, 11.5]);
+///  let volume = Series::new("volume", [1200i64, 900, 1500, 1100, 800, 1000]);
+///  let res = pvt(&close, &volume);
+///  eprintln!("{:?}", res);
+/// ```
+///
+#[cfg(feature = "polars")]
+pub fn pvt(close: &Series, volume: &Series) -> Result<Series, TalibError> {
+    let pvt = pvt_slice(&series_to_vec(close)?, &series_to_vec(volume)?)?;
+    Ok(vec_to_series("data", pvt))
+}
+
+/// Streaming Price Volume Trend (PVT).
+///
+/// PVT has no warm-up, so `update` returns a value for every bar.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_talib::Pvt;
+///
+/// let mut pvt = Pvt::new();
+/// assert_eq!(pvt.update(10.0, 100.0), 0.0);
+/// assert_eq!(pvt.update(11.0, 50.0), 5.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pvt {
+    prev_close: Option<f64>,
+    pvt: f64,
+}
+
+impl Pvt {
+    /// Creates a new PVT state.
+    pub fn new() -> Self {
+        Pvt::default()
+    }
+
+    /// Feeds the next close/volume pair and returns the PVT so far.
+    pub fn update(&mut self, close: f64, volume: f64) -> f64 {
+        if let Some(prev) = self.prev_close.replace(close) {
+            if prev != 0.0 {
+                self.pvt += volume * (close - prev) / prev;
+            }
+        }
+        self.pvt
+    }
+
+    /// Clears all accumulated values.
+    pub fn reset(&mut self) {
+        self.prev_close = None;
+        self.pvt = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pvt() -> Result<(), TalibError> {
+        let res = pvt_slice(&[10.0, 11.0, 0.0, 5.0, 10.0], &[100.0, 50.0, 80.0, 40.0, 10.0])?;
+        // the drop to 0 loses the whole volume, the rise from 0 adds nothing
+        assert_eq!(res, [0.0, 5.0, -75.0, -75.0, -65.0]);
+        assert_eq!(pvt_lookback(), 0);
+        assert!(pvt_slice(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(pvt_slice(&[], &[]).is_err());
+        Ok(())
+    }
+}
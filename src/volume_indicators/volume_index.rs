@@ -0,0 +1,51 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 16:35
+ * @Email: uyplayer@qq.com
+ * @File: volume_index.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / src/volume_indicators
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+//! The running index shared by NVI and PVI.
+
+/// Index that only follows the close on bars whose volume fell (NVI) or rose (PVI)
+/// against the bar before.
+#[derive(Debug, Clone)]
+pub(crate) struct VolumeIndex {
+    start_value: f64,
+    on_rising_volume: bool,
+    prev: Option<(f64, f64)>,
+    index: f64,
+}
+
+impl VolumeIndex {
+    pub(crate) fn new(start_value: f64, on_rising_volume: bool) -> Self {
+        VolumeIndex {
+            start_value,
+            on_rising_volume,
+            prev: None,
+            index: start_value,
+        }
+    }
+
+    /// Feeds the next close/volume pair and returns the index so far, a previous close of 0
+    /// leaves it unchanged.
+    pub(crate) fn update(&mut self, close: f64, volume: f64) -> f64 {
+        if let Some((prev_close, prev_volume)) = self.prev.replace((close, volume)) {
+            let follows = if self.on_rising_volume { volume > prev_volume } else { volume < prev_volume };
+            if follows && prev_close != 0.0 {
+                self.index += self.index * (close - prev_close) / prev_close;
+            }
+        }
+        self.index
+    }
+
+    /// Clears all accumulated values, the index starts over from its start value.
+    pub(crate) fn reset(&mut self) {
+        self.prev = None;
+        self.index = self.start_value;
+    }
+}
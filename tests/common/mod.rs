@@ -0,0 +1,107 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/10/22 20:12
+ * @Email: uyplayer@qq.com
+ * @File: mod.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests/common
+ * @Project_Name: rusty-talib
+ * @Description: golden-value fixture loader shared by the integration tests
+ */
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Absolute tolerance, scaled by the magnitude of the expected value when it is above 1.
+pub const TOLERANCE: f64 = 1e-8;
+
+/// A CSV fixture from `tests/fixtures`, empty cells are read as `NaN`.
+pub struct Fixture {
+    name: String,
+    columns: HashMap<String, Vec<f64>>,
+}
+
+impl Fixture {
+    /// Loads `tests/fixtures/<path>`.
+    pub fn load(path: &str) -> Self {
+        let file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(path);
+        let content = fs::read_to_string(&file)
+            .unwrap_or_else(|e| panic!("cannot read fixture {}: {}", file.display(), e));
+        let mut lines = content.lines();
+        let header: Vec<String> = lines
+            .next()
+            .unwrap_or_else(|| panic!("fixture {} is empty", path))
+            .split(',')
+            .map(str::to_string)
+            .collect();
+        let mut values = vec![Vec::new(); header.len()];
+        for (row, line) in lines.enumerate() {
+            let cells: Vec<&str> = line.split(',').collect();
+            assert_eq!(cells.len(), header.len(), "fixture {} row {} has a wrong number of cells", path, row);
+            for (column, cell) in values.iter_mut().zip(cells) {
+                column.push(if cell.is_empty() {
+                    f64::NAN
+                } else {
+                    cell.parse()
+                        .unwrap_or_else(|e| panic!("fixture {} row {}: bad value {:?}: {}", path, row, cell, e))
+                });
+            }
+        }
+        Fixture {
+            name: path.to_string(),
+            columns: header.into_iter().zip(values).collect(),
+        }
+    }
+
+    /// Returns a column by name.
+    pub fn column(&self, name: &str) -> &[f64] {
+        self.columns
+            .get(name)
+            .unwrap_or_else(|| panic!("fixture {} has no column {}", self.name, name))
+    }
+
+    /// Compares `actual` with the column `name`, see [`assert_close`].
+    pub fn check(&self, name: &str, actual: &[f64]) {
+        assert_close(&format!("{}:{}", self.name, name), self.column(name), actual);
+    }
+}
+
+/// The shared input bars, `open`, `high`, `low`, `close` and `volume`.
+pub fn input() -> Fixture {
+    Fixture::load("input.csv")
+}
+
+/// Feeds the bars `0..len` to a streaming state through `update`, a bar without output is `NaN` like the
+/// warm-up of the slices.
+pub fn stream(len: usize, update: impl FnMut(usize) -> Option<f64>) -> Vec<f64> {
+    (0..len).map(update).map(|value| value.unwrap_or(f64::NAN)).collect()
+}
+
+/// Like [`stream`] for a state with `N` outputs, returns one column per output.
+pub fn stream_outputs<const N: usize>(len: usize, mut update: impl FnMut(usize) -> Option<[f64; N]>) -> [Vec<f64>; N] {
+    let mut columns: [Vec<f64>; N] = std::array::from_fn(|_| Vec::with_capacity(len));
+    for i in 0..len {
+        let values = update(i).unwrap_or([f64::NAN; N]);
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    columns
+}
+
+/// Asserts that both series have the same warm-up and agree within [`TOLERANCE`],
+/// panicking with the first diverging index.
+pub fn assert_close(label: &str, expected: &[f64], actual: &[f64]) {
+    assert_eq!(expected.len(), actual.len(), "{}: length differs", label);
+    for (i, (e, a)) in expected.iter().zip(actual).enumerate() {
+        let same = if e.is_nan() || a.is_nan() {
+            e.is_nan() && a.is_nan()
+        } else {
+            (e - a).abs() <= TOLERANCE * e.abs().max(1.0)
+        };
+        assert!(same, "{}: first divergence at index {}: expected {}, got {}", label, i, e, a);
+    }
+}
@@ -0,0 +1,62 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/28 10:00
+ * @Email: uyplayer@qq.com
+ * @File: cycle_indicators_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "cycle_indicators")]
+
+mod common;
+
+use common::{input, stream, stream_outputs, Fixture};
+use rusty_talib::*;
+
+#[test]
+fn golden_ht_dc_period_and_phase() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("cycle_indicators/hilbert_transform.csv");
+    fixture.check("dcperiod", &ht_dc_period_slice(&close).unwrap());
+    fixture.check("dcphase", &ht_dc_phase_slice(&close).unwrap());
+    let (mut period, mut phase) = (HtDcPeriod::new(), HtDcPhase::new());
+    fixture.check("dcperiod", &stream(close.len(), |i| period.update(close[i])));
+    fixture.check("dcphase", &stream(close.len(), |i| phase.update(close[i])));
+}
+
+#[test]
+fn golden_ht_phasor_and_sine() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("cycle_indicators/hilbert_transform.csv");
+    let (in_phase, quadrature) = ht_phasor_slice(&close).unwrap();
+    fixture.check("inphase", &in_phase);
+    fixture.check("quadrature", &quadrature);
+    let (sine, lead_sine) = ht_sine_slice(&close).unwrap();
+    fixture.check("sine", &sine);
+    fixture.check("leadsine", &lead_sine);
+    let mut state = HtPhasor::new();
+    let [in_phase, quadrature] = stream_outputs(close.len(), |i| state.update(close[i]).map(|(p, q)| [p, q]));
+    fixture.check("inphase", &in_phase);
+    fixture.check("quadrature", &quadrature);
+    let mut state = HtSine::new();
+    let [sine, lead_sine] = stream_outputs(close.len(), |i| state.update(close[i]).map(|(s, l)| [s, l]));
+    fixture.check("sine", &sine);
+    fixture.check("leadsine", &lead_sine);
+}
+
+#[test]
+fn golden_ht_trend_mode() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("cycle_indicators/hilbert_transform.csv");
+    let mode: Vec<f64> = ht_trend_mode_slice(&close)
+        .unwrap()
+        .into_iter()
+        .map(|mode| mode.map_or(f64::NAN, f64::from))
+        .collect();
+    fixture.check("trendmode", &mode);
+    let mut state = HtTrendMode::new();
+    fixture.check("trendmode", &stream(close.len(), |i| state.update(close[i]).map(f64::from)));
+}
@@ -0,0 +1,945 @@
+"""
+ @Author: uyplayer
+ @Date: 2023/10/22 20:12
+ @Email: uyplayer@qq.com
+ @File: generate.py
+ @Software: RustRover
+ @Dir: rusty-talib / tests/fixtures
+ @Project_Name: rusty-talib
+ @Description: Generates the golden-value fixtures used by the integration tests.
+
+Run from the repository root:
+
+    python3 tests/fixtures/generate.py
+
+The input bars are produced by a fixed linear congruential generator so the
+fixtures are reproducible on any machine. Expected outputs come from the
+`talib` python bindings (and `numpy`), the script refuses to run without
+them so the fixtures can't be overwritten with values of another origin.
+The few indicators TA-Lib does not have (VWMA, VWAP, Keltner channels, the
+realized volatility estimators, ...) and KAMA with other fast/slow constants
+than TA-Lib's fixed 2 and 30 are computed in python on top of the TA-Lib
+functions.
+
+Every fixture is a CSV file with a header; an empty cell is a warm-up (NaN)
+value. Columns are named `<output>_<param>_<param>...`. The versions of the
+talib bindings, of the TA-Lib C library and of numpy the fixtures were written
+with are recorded in `tests/fixtures/PROVENANCE`.
+"""
+
+import math
+import os
+import sys
+
+try:
+    import numpy as np
+    import talib
+    import talib.abstract
+except ImportError as error:
+    sys.exit("generate.py needs the talib and numpy python packages, no fixture was written: %s" % error)
+
+ROOT = os.path.dirname(os.path.abspath(__file__))
+BARS = 300
+NAN = float("nan")
+
+
+# ---------------------------------------------------------------------------
+# input data
+# ---------------------------------------------------------------------------
+
+class Lcg:
+    """Numerical Recipes LCG, enough to get a stable pseudo random walk."""
+
+    def __init__(self, seed):
+        self.state = seed
+
+    def next(self):
+        self.state = (1664525 * self.state + 1013904223) % 2 ** 32
+        return self.state / 2 ** 32
+
+
+def make_bars():
+    rng = Lcg(20231022)
+    close = 100.0
+    bars = []
+    for _ in range(BARS):
+        open_ = round(close + (rng.next() - 0.5), 4)
+        close = round(max(1.0, open_ + (rng.next() - 0.5) * 4.0), 4)
+        high = round(max(open_, close) + rng.next() * 1.5, 4)
+        low = round(min(open_, close) - rng.next() * 1.5, 4)
+        volume = float(1000 + int(rng.next() * 9000))
+        bars.append((open_, high, low, close, volume))
+    return bars
+
+
+# candles of the multi-candle patterns around a price of 10, from the first one to the last one
+STAGED_PATTERNS = [
+    [(10.6, 10.7, 9.5, 9.6), (9.3, 9.4, 9.1, 9.35), (9.5, 10.4, 9.45, 10.3)],
+    [(10.6, 10.7, 9.5, 9.6), (9.3, 9.4, 9.2, 9.32), (9.5, 10.4, 9.45, 10.3)],
+    [(10.6, 10.7, 9.5, 9.6), (9.3, 9.35, 9.25, 9.31), (9.5, 10.4, 9.45, 10.3)],
+    [(10.0, 10.6, 9.6, 10.2), (10.5, 10.5, 9.9, 9.9), (10.2, 10.2, 9.6, 9.6), (9.9, 9.9, 9.3, 9.3)],
+    [(10.0, 10.55, 9.95, 10.5), (10.4, 10.95, 10.35, 10.9), (10.8, 11.35, 10.75, 11.3)],
+    [(9.6, 10.7, 9.5, 10.6), (10.3, 10.4, 10.1, 10.2), (10.2, 10.25, 9.4, 9.5)],
+    [(10.2, 10.3, 9.8, 9.9), (9.8, 10.5, 9.7, 10.4), (10.4, 10.7, 10.3, 10.6)],
+    [(9.6, 10.7, 9.5, 10.6), (10.5, 10.55, 10.35, 10.4), (10.4, 10.45, 10.25, 10.3), (10.3, 10.35, 10.15, 10.2),
+     (10.25, 11.0, 10.2, 10.9)],
+    [(9.6, 10.7, 9.5, 10.6), (10.9, 11.0, 10.7, 10.8), (10.7, 10.75, 10.45, 10.5), (10.5, 10.55, 10.3, 10.4),
+     (10.45, 11.3, 10.4, 11.2)],
+    [(10.6, 10.7, 9.5, 9.6), (9.4, 9.45, 9.2, 9.3), (9.25, 9.35, 9.0, 9.1), (9.1, 9.2, 8.8, 8.9),
+     (8.9, 9.55, 8.85, 9.5)],
+    [(10.4, 10.5, 9.9, 10.0), (10.2, 10.3, 9.7, 9.8), (10.0, 10.1, 9.5, 9.6), (9.7, 9.9, 9.4, 9.5),
+     (9.8, 10.3, 9.75, 10.2)],
+    [(10.6, 10.6, 10.0, 10.0), (10.0, 10.0, 9.5, 9.5), (9.4, 9.6, 9.15, 9.2), (9.65, 9.7, 9.0, 9.05)],
+    [(9.6, 10.7, 9.5, 10.6), (11.0, 11.1, 10.7, 10.8), (10.9, 11.0, 9.9, 10.0)],
+    [(10.0, 10.45, 9.95, 10.4), (10.3, 10.75, 10.25, 10.7), (10.6, 11.05, 10.55, 11.0), (11.1, 11.2, 9.8, 9.9),
+     (10.2, 10.25, 9.75, 9.8), (9.9, 9.95, 9.45, 9.5), (9.6, 9.65, 9.15, 9.2), (9.1, 10.4, 9.0, 10.3)],
+    [(10.5, 10.55, 9.3, 10.0), (10.3, 10.35, 9.6, 10.1), (10.0, 10.02, 9.9, 9.92)],
+    [(9.6, 10.65, 9.55, 10.6), (10.3, 11.1, 10.25, 10.9), (10.7, 11.4, 10.65, 11.0)],
+    [(10.5, 10.55, 9.9, 9.9), (9.9, 9.95, 9.3, 9.3), (9.3, 9.35, 8.7, 8.7)],
+    [(9.6, 10.65, 9.55, 10.6), (10.5, 11.52, 10.45, 11.5), (11.5, 11.7, 11.45, 11.6)],
+    [(10.2, 10.6, 9.8, 10.2), (10.5, 10.9, 10.1, 10.52), (10.3, 10.7, 9.9, 10.32), (10.2, 10.6, 9.8, 10.2),
+     (9.9, 10.3, 9.5, 9.88), (10.1, 10.5, 9.7, 10.08)],
+    [(10.6, 10.7, 9.5, 9.6), (10.2, 10.3, 9.2, 9.8), (9.4, 9.6, 9.35, 9.5)],
+    [(9.6, 10.7, 9.5, 10.6), (11.0, 11.1, 10.8, 10.85), (11.1, 11.2, 10.65, 10.7)],
+    [(10.0, 10.6, 9.9, 10.5), (10.7, 11.2, 10.6, 11.1), (10.9, 11.0, 10.1, 10.2), (10.5, 10.6, 9.9, 10.0),
+     (9.8, 9.9, 9.2, 9.3), (9.5, 10.3, 9.4, 10.2)],
+    [(10.0, 10.5, 9.9, 10.4), (10.6, 11.05, 10.55, 11.0), (10.9, 10.95, 10.45, 10.5), (10.4, 10.45, 9.95, 10.0),
+     (9.8, 9.85, 9.35, 9.4), (9.5, 9.95, 9.45, 9.9)],
+    [(10.0, 10.5, 9.9, 10.4), (10.6, 11.05, 10.55, 11.0), (10.62, 11.1, 10.58, 11.0), (10.4, 10.45, 9.95, 10.0),
+     (9.4, 9.85, 9.35, 9.8), (9.42, 9.85, 9.4, 9.8)],
+    [(10.4, 10.45, 9.95, 10.0), (10.1, 10.55, 10.05, 10.5), (10.45, 10.5, 9.95, 10.01)],
+    [(10.0, 11.0, 9.0, 10.2), (10.0, 10.5, 9.5, 10.2), (9.8, 10.4, 9.3, 9.5), (9.6, 10.0, 9.2, 9.9),
+     (9.9, 10.8, 9.8, 10.7), (10.5, 11.5, 9.5, 10.6), (10.5, 11.0, 10.0, 10.4), (10.6, 11.2, 10.2, 10.9),
+     (10.8, 10.9, 9.8, 9.9)],
+    [(10.0, 11.0, 9.0, 10.2), (10.4, 10.8, 9.2, 9.3), (9.6, 10.6, 9.4, 10.0), (9.8, 10.5, 9.3, 9.5),
+     (9.5, 10.0, 9.4, 9.9), (9.9, 10.8, 9.8, 10.7)],
+]
+
+
+def make_staged_bars():
+    """Quiet candles around 10 with each of `STAGED_PATTERNS`, then its mirror image around 10, in between."""
+    rng = Lcg(20231231)
+    bars = []
+
+    def quiet(count):
+        for _ in range(count):
+            open_ = round(10.0 + (rng.next() - 0.5) * 0.2, 4)
+            close = round(open_ + (0.2 if rng.next() < 0.5 else -0.2), 4)
+            high = round(max(open_, close) + 0.3 + rng.next() * 0.1, 4)
+            low = round(min(open_, close) - 0.3 - rng.next() * 0.1, 4)
+            bars.append((open_, high, low, close))
+
+    for pattern in STAGED_PATTERNS:
+        for candles in (pattern, [(20.0 - o, 20.0 - l, 20.0 - h, 20.0 - c) for o, h, l, c in pattern]):
+            quiet(12)
+            bars.extend(tuple(round(v, 4) for v in candle) for candle in candles)
+    quiet(12)
+    return bars
+
+
+def make_periods():
+    rng = Lcg(42)
+    return [1 + int(rng.next() * 35) for _ in range(BARS)]
+
+
+# ---------------------------------------------------------------------------
+# indicators TA-Lib does not have, built on top of the TA-Lib functions below
+# ---------------------------------------------------------------------------
+
+def ref_kama(src, period, fast, slow):
+    """TA-Lib's KAMA loop with other fast/slow constants than its fixed 2 and 30."""
+    out = [NAN] * len(src)
+    const_max = 2.0 / (slow + 1)
+    const_diff = 2.0 / (fast + 1) - const_max
+    sum_roc = sum(abs(src[k] - src[k + 1]) for k in range(period))
+    prev = src[period - 1]
+    trailing = src[0]
+    for i in range(period, len(src)):
+        if i > period:
+            sum_roc -= abs(trailing - src[i - period])
+            sum_roc += abs(src[i] - src[i - 1])
+            trailing = src[i - period]
+        roc = src[i] - src[i - period]
+        if sum_roc <= roc or abs(sum_roc) < 1e-8:
+            er = 1.0
+        else:
+            er = abs(roc / sum_roc)
+        sc = er * const_diff + const_max
+        sc *= sc
+        prev = (src[i] - prev) * sc + prev
+        out[i] = prev
+    return out
+
+
+def ref_vwma(src, volume, period):
+    """Not in TA-Lib, summed afresh for every window."""
+    out = [NAN] * len(src)
+    for today in range(period - 1, len(src)):
+        window = range(today - period + 1, today + 1)
+        volume_sum = sum(volume[i] for i in window)
+        if volume_sum != 0.0:
+            out[today] = sum(src[i] * volume[i] for i in window) / volume_sum
+    return out
+
+
+def ref_vwap(high, low, close, volume, sessions):
+    """Not in TA-Lib, a new session starts whenever the key changes."""
+    out = []
+    weighted = volume_sum = 0.0
+    for i in range(len(close)):
+        if i > 0 and sessions[i] != sessions[i - 1]:
+            weighted = volume_sum = 0.0
+        weighted += (high[i] + low[i] + close[i]) / 3.0 * volume[i]
+        volume_sum += volume[i]
+        out.append(weighted / volume_sum if volume_sum != 0.0 else NAN)
+    return out
+
+
+def ref_pvt(close, volume):
+    """Not in TA-Lib."""
+    pvt = 0.0
+    out = [pvt]
+    for today in range(1, len(close)):
+        prev = close[today - 1]
+        if prev != 0.0:
+            pvt += volume[today] * (close[today] - prev) / prev
+        out.append(pvt)
+    return out
+
+
+def ref_volume_index(close, volume, start_value, rising):
+    """NVI / PVI, not in TA-Lib."""
+    index = start_value
+    out = [index]
+    for today in range(1, len(close)):
+        prev = close[today - 1]
+        follows = volume[today] > volume[today - 1] if rising else volume[today] < volume[today - 1]
+        if follows and prev != 0.0:
+            index += index * (close[today] - prev) / prev
+        out.append(index)
+    return out
+
+
+def ref_force_index(close, volume, period):
+    """Not in TA-Lib, the TA-Lib EMA of the raw force."""
+    raw = [(close[i] - close[i - 1]) * volume[i] for i in range(1, len(close))]
+    return [NAN] + ema(raw, period)
+
+
+def ref_keltner(high, low, close, period, atr_period, multiplier):
+    middle = ema(close, period)
+    atr_values = atr(high, low, close, atr_period)
+    start = max(period - 1, atr_period)
+    upper = [NAN] * len(close)
+    lower = [NAN] * len(close)
+    for i in range(len(close)):
+        if i < start:
+            middle[i] = NAN
+            continue
+        upper[i] = middle[i] + multiplier * atr_values[i]
+        lower[i] = middle[i] - multiplier * atr_values[i]
+    return middle, upper, lower
+
+
+def ref_donchian(high, low, period):
+    middle = [NAN] * len(high)
+    upper = [NAN] * len(high)
+    lower = [NAN] * len(high)
+    for i in range(period - 1, len(high)):
+        upper[i] = max(high[i - period + 1:i + 1])
+        lower[i] = min(low[i - period + 1:i + 1])
+        middle[i] = (upper[i] + lower[i]) / 2.0
+    return middle, upper, lower
+
+
+def ref_chandelier(high, low, close, period, multiplier):
+    atr_values = atr(high, low, close, period)
+    long_exit = [NAN] * len(close)
+    short_exit = [NAN] * len(close)
+    for i in range(period, len(close)):
+        long_exit[i] = max(high[i - period + 1:i + 1]) - multiplier * atr_values[i]
+        short_exit[i] = min(low[i - period + 1:i + 1]) + multiplier * atr_values[i]
+    return long_exit, short_exit
+
+
+def sample_variance(values):
+    mean = sum(values) / len(values)
+    return sum((v - mean) ** 2 for v in values) / (len(values) - 1)
+
+
+def rolling_volatility(per_bar, period, annualization, start=0):
+    # per_bar[i] is the variance estimate of bar i, the first `start` bars have none
+    out = [NAN] * len(per_bar)
+    for i in range(start + period - 1, len(per_bar)):
+        out[i] = math.sqrt(max(sum(per_bar[i - period + 1:i + 1]) / period, 0.0) * annualization)
+    return out
+
+
+def ref_close_to_close(close, period, annualization):
+    out = [NAN] * len(close)
+    returns = [NAN] + [math.log(close[i] / close[i - 1]) for i in range(1, len(close))]
+    for i in range(period, len(close)):
+        out[i] = math.sqrt(max(sample_variance(returns[i - period + 1:i + 1]), 0.0) * annualization)
+    return out
+
+
+def ref_parkinson(high, low, period, annualization):
+    per_bar = [math.log(h / l) ** 2 / (4.0 * math.log(2.0)) for h, l in zip(high, low)]
+    return rolling_volatility(per_bar, period, annualization)
+
+
+def ref_garman_klass(open_, high, low, close, period, annualization):
+    per_bar = [0.5 * math.log(h / l) ** 2 - (2.0 * math.log(2.0) - 1.0) * math.log(c / o) ** 2
+               for o, h, l, c in zip(open_, high, low, close)]
+    return rolling_volatility(per_bar, period, annualization)
+
+
+def rogers_satchell_variance(o, h, l, c):
+    return math.log(h / c) * math.log(h / o) + math.log(l / c) * math.log(l / o)
+
+
+def ref_rogers_satchell(open_, high, low, close, period, annualization):
+    per_bar = [rogers_satchell_variance(o, h, l, c) for o, h, l, c in zip(open_, high, low, close)]
+    return rolling_volatility(per_bar, period, annualization)
+
+
+def ref_yang_zhang(open_, high, low, close, period, annualization):
+    out = [NAN] * len(close)
+    k = 0.34 / (1.34 + (period + 1.0) / (period - 1.0))
+    for i in range(period, len(close)):
+        bars = range(i - period + 1, i + 1)
+        overnight = sample_variance([math.log(open_[j] / close[j - 1]) for j in bars])
+        open_to_close = sample_variance([math.log(close[j] / open_[j]) for j in bars])
+        rogers_satchell = sum(rogers_satchell_variance(open_[j], high[j], low[j], close[j]) for j in bars) / period
+        variance = overnight + k * open_to_close + (1.0 - k) * rogers_satchell
+        out[i] = math.sqrt(max(variance, 0.0) * annualization)
+    return out
+
+
+def ref_heikin_ashi(open_, high, low, close):
+    ha_close = avgprice(open_, high, low, close)
+    ha_open = [(open_[0] + close[0]) / 2.0]
+    for i in range(1, len(close)):
+        ha_open.append((ha_open[i - 1] + ha_close[i - 1]) / 2.0)
+    ha_high = [max(h, o, c) for h, o, c in zip(high, ha_open, ha_close)]
+    ha_low = [min(l, o, c) for l, o, c in zip(low, ha_open, ha_close)]
+    return ha_open, ha_high, ha_low, ha_close
+
+
+# ---------------------------------------------------------------------------
+# TA-Lib functions
+# ---------------------------------------------------------------------------
+
+def arr(values):
+    return np.asarray(values, dtype=float)
+
+
+def sma(src, period):
+    return list(talib.SMA(arr(src), period))
+
+
+def ema(src, period):
+    return list(talib.EMA(arr(src), period))
+
+
+def dema(src, period):
+    return list(talib.DEMA(arr(src), period))
+
+
+def bbands(src, period, nbdev):
+    return tuple(list(v) for v in talib.BBANDS(arr(src), period, nbdev, nbdev, 0))
+
+
+def kama(src, period, fast, slow):
+    # talib.KAMA has fixed fast/slow constants of 2 and 30, other pairs use the transcription below.
+    if (fast, slow) == (2, 30):
+        return list(talib.KAMA(arr(src), period))
+    return ref_kama(src, period, fast, slow)
+
+
+def ma(src, period, ma_type):
+    return list(talib.MA(arr(src), period, ma_type))
+
+
+def wma(src, period):
+    return list(talib.WMA(arr(src), period))
+
+
+def tema(src, period):
+    return list(talib.TEMA(arr(src), period))
+
+
+def trima(src, period):
+    return list(talib.TRIMA(arr(src), period))
+
+
+def t3(src, period, v_factor):
+    return list(talib.T3(arr(src), period, v_factor))
+
+
+def midpoint(src, period):
+    return list(talib.MIDPOINT(arr(src), period))
+
+
+def midprice(high, low, period):
+    return list(talib.MIDPRICE(arr(high), arr(low), period))
+
+
+def sar(high, low, acceleration, maximum):
+    return list(talib.SAR(arr(high), arr(low), acceleration, maximum))
+
+
+def sarext(high, low, *params):
+    return list(talib.SAREXT(arr(high), arr(low), *params))
+
+
+SAREXT_PARAMS = (
+    (0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2),
+    (0.0, 0.01, 0.01, 0.02, 0.25, 0.03, 0.03, 0.3),
+    (-105.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2),
+)
+
+
+def mavp(src, periods, min_period, max_period):
+    return list(talib.MAVP(arr(src), arr(periods), min_period, max_period, 0))
+
+
+def mama(src, fast_limit, slow_limit):
+    return tuple(list(v) for v in talib.MAMA(arr(src), fast_limit, slow_limit))
+
+
+def ht_trendline(src):
+    return list(talib.HT_TRENDLINE(arr(src)))
+
+
+def plus_dm(high, low, period):
+    return list(talib.PLUS_DM(arr(high), arr(low), period))
+
+
+def minus_dm(high, low, period):
+    return list(talib.MINUS_DM(arr(high), arr(low), period))
+
+
+def plus_di(high, low, close, period):
+    return list(talib.PLUS_DI(arr(high), arr(low), arr(close), period))
+
+
+def minus_di(high, low, close, period):
+    return list(talib.MINUS_DI(arr(high), arr(low), arr(close), period))
+
+
+def dx(high, low, close, period):
+    return list(talib.DX(arr(high), arr(low), arr(close), period))
+
+
+def adx(high, low, close, period):
+    return list(talib.ADX(arr(high), arr(low), arr(close), period))
+
+
+def adxr(high, low, close, period):
+    return list(talib.ADXR(arr(high), arr(low), arr(close), period))
+
+
+def macd(src, fast, slow, signal):
+    return tuple(list(v) for v in talib.MACD(arr(src), fast, slow, signal))
+
+
+def macdfix(src, signal):
+    return tuple(list(v) for v in talib.MACDFIX(arr(src), signal))
+
+
+def macdext(src, *params):
+    return tuple(list(v) for v in talib.MACDEXT(arr(src), *params))
+
+
+MACDEXT_PARAMS = (
+    (12, 1, 26, 1, 9, 1),
+    (12, 0, 26, 0, 9, 0),
+    (5, 3, 13, 2, 4, 5),
+    (20, 8, 10, 6, 3, 4),
+)
+
+
+def rsi(src, period):
+    return list(talib.RSI(arr(src), period))
+
+
+def cmo(src, period):
+    return list(talib.CMO(arr(src), period))
+
+
+def stochrsi(src, period, fastk, fastd, fastd_type):
+    return tuple(list(v) for v in talib.STOCHRSI(arr(src), period, fastk, fastd, fastd_type))
+
+
+def stoch(high, low, close, *params):
+    return tuple(list(v) for v in talib.STOCH(arr(high), arr(low), arr(close), *params))
+
+
+def stochf(high, low, close, fastk, fastd, fastd_type):
+    return tuple(list(v) for v in talib.STOCHF(arr(high), arr(low), arr(close), fastk, fastd, fastd_type))
+
+
+def price_change(name, src, period):
+    # the input never has a zero price, so TA-Lib's 0 for a zero previous value never shows up
+    return list(getattr(talib, name.upper())(arr(src), period))
+
+
+def apo(src, fast, slow, ma_type):
+    return list(talib.APO(arr(src), fast, slow, ma_type))
+
+
+def ppo(src, fast, slow, ma_type):
+    return list(talib.PPO(arr(src), fast, slow, ma_type))
+
+
+def bop(open_, high, low, close):
+    return list(talib.BOP(arr(open_), arr(high), arr(low), arr(close)))
+
+
+def cci(high, low, close, period):
+    return list(talib.CCI(arr(high), arr(low), arr(close), period))
+
+
+def willr(high, low, close, period):
+    return list(talib.WILLR(arr(high), arr(low), arr(close), period))
+
+
+def ultosc(high, low, close, *periods):
+    return list(talib.ULTOSC(arr(high), arr(low), arr(close), *periods))
+
+
+def trix(src, period):
+    return list(talib.TRIX(arr(src), period))
+
+
+def aroon(high, low, period):
+    down, up = talib.AROON(arr(high), arr(low), period)
+    return list(down), list(up), list(talib.AROONOSC(arr(high), arr(low), period))
+
+
+def mfi(high, low, close, volume, period):
+    return list(talib.MFI(arr(high), arr(low), arr(close), arr(volume), period))
+
+
+def ad(high, low, close, volume):
+    return list(talib.AD(arr(high), arr(low), arr(close), arr(volume)))
+
+
+def adosc(high, low, close, volume, fast, slow):
+    return list(talib.ADOSC(arr(high), arr(low), arr(close), arr(volume), fast, slow))
+
+
+def obv(close, volume):
+    return list(talib.OBV(arr(close), arr(volume)))
+
+
+def trange(high, low, close):
+    return list(talib.TRANGE(arr(high), arr(low), arr(close)))
+
+
+def atr(high, low, close, period):
+    return list(talib.ATR(arr(high), arr(low), arr(close), period))
+
+
+def natr(high, low, close, period):
+    return list(talib.NATR(arr(high), arr(low), arr(close), period))
+
+
+def avgprice(open_, high, low, close):
+    return list(talib.AVGPRICE(arr(open_), arr(high), arr(low), arr(close)))
+
+
+def medprice(high, low):
+    return list(talib.MEDPRICE(arr(high), arr(low)))
+
+
+def typprice(high, low, close):
+    return list(talib.TYPPRICE(arr(high), arr(low), arr(close)))
+
+
+def wclprice(high, low, close):
+    return list(talib.WCLPRICE(arr(high), arr(low), arr(close)))
+
+
+def ht_dcperiod(src):
+    return list(talib.HT_DCPERIOD(arr(src)))
+
+
+def ht_dcphase(src):
+    return list(talib.HT_DCPHASE(arr(src)))
+
+
+def ht_phasor(src):
+    return tuple(list(v) for v in talib.HT_PHASOR(arr(src)))
+
+
+def ht_sine(src):
+    return tuple(list(v) for v in talib.HT_SINE(arr(src)))
+
+
+def ht_trendmode(src):
+    return [NAN] * 63 + [float(v) for v in talib.HT_TRENDMODE(arr(src))[63:]]
+
+def cdl(name, open_, high, low, close, *params):
+    """A CDL* pattern, with the lookback bars as NaN like the Rust slices."""
+    fn = getattr(talib, name.upper())
+    values = fn(arr(open_), arr(high), arr(low), arr(close), *params)
+    lookback = getattr(talib.abstract, name.upper()).lookback
+    return [NAN if i < lookback else float(v) for i, v in enumerate(values)]
+
+
+# ---------------------------------------------------------------------------
+# csv output
+# ---------------------------------------------------------------------------
+
+def fmt(value):
+    if isinstance(value, float) and math.isnan(value):
+        return ""
+    return repr(float(value))
+
+
+def write_csv(path, columns):
+    path = os.path.join(ROOT, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    names = list(columns)
+    rows = zip(*(columns[name] for name in names))
+    with open(path, "w") as f:
+        f.write(",".join(names) + "\n")
+        for row in rows:
+            f.write(",".join(fmt(v) for v in row) + "\n")
+
+
+def write_provenance():
+    ta_version = talib.__ta_version__
+    if isinstance(ta_version, bytes):
+        ta_version = ta_version.decode()
+    with open(os.path.join(ROOT, "PROVENANCE"), "w") as f:
+        f.write("talib %s\n" % talib.__version__)
+        f.write("ta-lib %s\n" % ta_version.split()[0])
+        f.write("numpy %s\n" % np.__version__)
+
+
+def overlap_studies(bars):
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+    periods = make_periods()
+
+    write_csv("overlap_studies/sma.csv", {
+        "sma_%d" % p: sma(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/ma.csv", {
+        "ma_%d_%d" % params: ma(close, *params)
+        for params in ((14, 0), (30, 0), (14, 1), (14, 2), (14, 3), (14, 4), (14, 5), (14, 6), (14, 7), (5, 8))
+    })
+    write_csv("overlap_studies/ema.csv", {
+        "ema_%d" % p: ema(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/dema.csv", {
+        "dema_%d" % p: dema(close, p) for p in (5, 14, 30)
+    })
+
+    write_csv("overlap_studies/wma.csv", {
+        "wma_%d" % p: wma(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/tema.csv", {
+        "tema_%d" % p: tema(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/trima.csv", {
+        "trima_%d" % p: trima(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/t3.csv", {
+        "t3_%d_%s" % (p, v): t3(close, p, v) for p, v in ((5, 0.7), (10, 0.7), (5, 0.5))
+    })
+    write_csv("overlap_studies/midpoint.csv", {
+        "midpoint_%d" % p: midpoint(close, p) for p in (5, 14, 30)
+    })
+    write_csv("overlap_studies/midprice.csv", {
+        "midprice_%d" % p: midprice(high, low, p) for p in (5, 14, 30)
+    })
+
+    write_csv("overlap_studies/sar.csv", {
+        "sar_%s_%s" % params: sar(high, low, *params) for params in ((0.02, 0.2), (0.01, 0.1))
+    })
+    write_csv("overlap_studies/sarext.csv", {
+        "sarext_%d" % i: sarext(high, low, *params) for i, params in enumerate(SAREXT_PARAMS)
+    })
+
+    columns = {}
+    for period, nbdev in ((5, 2), (20, 2), (14, 1)):
+        upper, middle, lower = bbands(close, period, nbdev)
+        suffix = "%d_%d" % (period, nbdev)
+        columns["upper_" + suffix] = upper
+        columns["middle_" + suffix] = middle
+        columns["lower_" + suffix] = lower
+    write_csv("overlap_studies/bbands.csv", columns)
+
+    write_csv("overlap_studies/kama.csv", {
+        "kama_%d_%d_%d" % params: kama(close, *params)
+        for params in ((10, 2, 30), (5, 2, 30), (20, 4, 40))
+    })
+
+    columns = {"periods": [float(p) for p in periods]}
+    for min_period, max_period in ((2, 30), (4, 10)):
+        key = "mavp_%d_%d" % (min_period, max_period)
+        columns[key] = mavp(close, periods, min_period, max_period)
+    write_csv("overlap_studies/mavp.csv", columns)
+
+    # MAMA and HT_TRENDLINE take the median price like the rust functions
+    median = [(h + l) / 2.0 for h, l in zip(high, low)]
+    columns = {}
+    for fast_limit, slow_limit in ((0.5, 0.05), (0.3, 0.1), (0.8, 0.02)):
+        suffix = "%s_%s" % (fast_limit, slow_limit)
+        columns["mama_" + suffix], columns["fama_" + suffix] = mama(median, fast_limit, slow_limit)
+    write_csv("overlap_studies/mama.csv", columns)
+    write_csv("overlap_studies/ht_trendline.csv", {"ht_trendline": ht_trendline(median)})
+
+    volume = [b[4] for b in bars]
+    typical = [(h + l + c) / 3.0 for h, l, c in zip(high, low, close)]
+    write_csv("overlap_studies/vwma.csv", {"vwma_%d" % p: ref_vwma(close, volume, p) for p in (1, 5, 20)})
+    write_csv("overlap_studies/vwap.csv", dict(
+        [("vwap", ref_vwap(high, low, close, volume, [0] * len(close))),
+         ("vwap_session_25", ref_vwap(high, low, close, volume, [i // 25 for i in range(len(close))]))]
+        + [("rolling_vwap_%d" % p, ref_vwma(typical, volume, p)) for p in (5, 20)]
+    ))
+
+
+def momentum_indicators(bars):
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+
+    write_csv("momentum_indicators/dm.csv", dict(
+        [("plus_dm_%d" % p, plus_dm(high, low, p)) for p in (1, 5, 14)]
+        + [("minus_dm_%d" % p, minus_dm(high, low, p)) for p in (1, 5, 14)]
+    ))
+    write_csv("momentum_indicators/di.csv", dict(
+        [("plus_di_%d" % p, plus_di(high, low, close, p)) for p in (5, 14)]
+        + [("minus_di_%d" % p, minus_di(high, low, close, p)) for p in (5, 14)]
+    ))
+    write_csv("momentum_indicators/dx.csv", {
+        "dx_%d" % p: dx(high, low, close, p) for p in (5, 14)
+    })
+    write_csv("momentum_indicators/adx.csv", {
+        "adx_%d" % p: adx(high, low, close, p) for p in (5, 14)
+    })
+    write_csv("momentum_indicators/adxr.csv", {
+        "adxr_%d" % p: adxr(high, low, close, p) for p in (5, 14)
+    })
+
+    columns = {}
+    for params in ((12, 26, 9), (5, 13, 4), (26, 12, 1)):
+        suffix = "%d_%d_%d" % params
+        columns["macd_" + suffix], columns["signal_" + suffix], columns["hist_" + suffix] = macd(close, *params)
+    write_csv("momentum_indicators/macd.csv", columns)
+
+    columns = {}
+    for signal in (9, 4):
+        suffix = "%d" % signal
+        columns["macd_" + suffix], columns["signal_" + suffix], columns["hist_" + suffix] = macdfix(close, signal)
+    write_csv("momentum_indicators/macdfix.csv", columns)
+
+    columns = {}
+    for i, params in enumerate(MACDEXT_PARAMS):
+        suffix = "%d" % i
+        columns["macd_" + suffix], columns["signal_" + suffix], columns["hist_" + suffix] = macdext(close, *params)
+    write_csv("momentum_indicators/macdext.csv", columns)
+
+    write_csv("momentum_indicators/rsi.csv", {"rsi_%d" % p: rsi(close, p) for p in (2, 14, 30)})
+    write_csv("momentum_indicators/cmo.csv", {"cmo_%d" % p: cmo(close, p) for p in (2, 14, 30)})
+    columns = {}
+    for params in ((14, 5, 3, 0), (14, 14, 1, 0), (7, 3, 4, 1)):
+        suffix = "%d_%d_%d_%d" % params
+        columns["fastk_" + suffix], columns["fastd_" + suffix] = stochrsi(close, *params)
+    write_csv("momentum_indicators/stochrsi.csv", columns)
+    columns = {}
+    for params in ((5, 3, 0, 3, 0), (14, 3, 1, 5, 2), (9, 1, 0, 4, 3)):
+        suffix = "%d_%d_%d_%d_%d" % params
+        columns["slowk_" + suffix], columns["slowd_" + suffix] = stoch(high, low, close, *params)
+    write_csv("momentum_indicators/stoch.csv", columns)
+    columns = {}
+    for params in ((5, 3, 0), (14, 1, 0), (9, 4, 1)):
+        suffix = "%d_%d_%d" % params
+        columns["fastk_" + suffix], columns["fastd_" + suffix] = stochf(high, low, close, *params)
+    write_csv("momentum_indicators/stochf.csv", columns)
+    for name in ("mom", "roc", "rocp", "rocr", "rocr100"):
+        write_csv("momentum_indicators/%s.csv" % name, {
+            "%s_%d" % (name, p): price_change(name, close, p) for p in (1, 10, 30)
+        })
+    columns = {}
+    for params in ((12, 26, 0), (5, 13, 1), (26, 12, 2)):
+        suffix = "%d_%d_%d" % params
+        columns["apo_" + suffix] = apo(close, *params)
+        columns["ppo_" + suffix] = ppo(close, *params)
+    write_csv("momentum_indicators/po.csv", columns)
+    write_csv("momentum_indicators/bop.csv", {"bop": bop([b[0] for b in bars], high, low, close)})
+    write_csv("momentum_indicators/cci.csv", {"cci_%d" % p: cci(high, low, close, p) for p in (5, 14, 20)})
+    write_csv("momentum_indicators/willr.csv", {"willr_%d" % p: willr(high, low, close, p) for p in (2, 14)})
+    write_csv("momentum_indicators/ultosc.csv", {
+        "ultosc_%d_%d_%d" % params: ultosc(high, low, close, *params) for params in ((7, 14, 28), (10, 3, 5))
+    })
+    write_csv("momentum_indicators/trix.csv", {"trix_%d" % p: trix(close, p) for p in (1, 5, 30)})
+    columns = {}
+    for period in (5, 14, 25):
+        columns["down_%d" % period], columns["up_%d" % period], columns["osc_%d" % period] = aroon(high, low, period)
+    write_csv("momentum_indicators/aroon.csv", columns)
+    volume = [b[4] for b in bars]
+    write_csv("momentum_indicators/mfi.csv", {"mfi_%d" % p: mfi(high, low, close, volume, p) for p in (1, 5, 14)})
+
+
+def volume_indicators(bars):
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+    volume = [b[4] for b in bars]
+
+    write_csv("volume_indicators/ad.csv", {"ad": ad(high, low, close, volume)})
+    write_csv("volume_indicators/adosc.csv", {
+        "adosc_%d_%d" % params: adosc(high, low, close, volume, *params) for params in ((3, 10), (5, 20), (10, 3))
+    })
+    write_csv("volume_indicators/obv.csv", {"obv": obv(close, volume)})
+    write_csv("volume_indicators/pvt.csv", {"pvt": ref_pvt(close, volume)})
+    write_csv("volume_indicators/volume_index.csv", {
+        "nvi": ref_volume_index(close, volume, 1000.0, False),
+        "pvi": ref_volume_index(close, volume, 1000.0, True),
+        "nvi_100": ref_volume_index(close, volume, 100.0, False),
+    })
+    write_csv("volume_indicators/force_index.csv", {
+        "force_index_%d" % p: ref_force_index(close, volume, p) for p in (1, 2, 13)
+    })
+
+
+def volatility_indicators(bars):
+    open_ = [b[0] for b in bars]
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+
+    write_csv("volatility_indicators/trange.csv", {"trange": trange(high, low, close)})
+    write_csv("volatility_indicators/atr.csv", dict(
+        [("atr_%d" % p, atr(high, low, close, p)) for p in (1, 5, 14)]
+        + [("natr_%d" % p, natr(high, low, close, p)) for p in (1, 5, 14)]
+    ))
+
+    # not in TA-Lib
+    columns = {}
+    for period, atr_period, multiplier in ((20, 10, 2.0), (5, 14, 1.5)):
+        middle, upper, lower = ref_keltner(high, low, close, period, atr_period, multiplier)
+        suffix = "%d_%d" % (period, atr_period)
+        columns.update({"middle_" + suffix: middle, "upper_" + suffix: upper, "lower_" + suffix: lower})
+    write_csv("volatility_indicators/keltner_channels.csv", columns)
+    columns = {}
+    for period in (1, 5, 20):
+        middle, upper, lower = ref_donchian(high, low, period)
+        columns.update({"middle_%d" % period: middle, "upper_%d" % period: upper, "lower_%d" % period: lower})
+    write_csv("volatility_indicators/donchian_channels.csv", columns)
+    columns = {}
+    for period, multiplier in ((22, 3.0), (5, 2.0)):
+        long_exit, short_exit = ref_chandelier(high, low, close, period, multiplier)
+        columns.update({"long_%d" % period: long_exit, "short_%d" % period: short_exit})
+    write_csv("volatility_indicators/chandelier_exit.csv", columns)
+    columns = {}
+    for period, annualization in ((20, 252.0), (5, 1.0)):
+        suffix = "%d_%d" % (period, annualization)
+        columns["close_to_close_" + suffix] = ref_close_to_close(close, period, annualization)
+        columns["parkinson_" + suffix] = ref_parkinson(high, low, period, annualization)
+        columns["garman_klass_" + suffix] = ref_garman_klass(open_, high, low, close, period, annualization)
+        columns["rogers_satchell_" + suffix] = ref_rogers_satchell(open_, high, low, close, period, annualization)
+        columns["yang_zhang_" + suffix] = ref_yang_zhang(open_, high, low, close, period, annualization)
+    write_csv("volatility_indicators/historical_volatility.csv", columns)
+
+
+def price_transform(bars):
+    open_ = [b[0] for b in bars]
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+
+    write_csv("price_transform/price_transform.csv", {
+        "avgprice": avgprice(open_, high, low, close),
+        "medprice": medprice(high, low),
+        "typprice": typprice(high, low, close),
+        "wclprice": wclprice(high, low, close),
+    })
+    # not in TA-Lib
+    ha_open, ha_high, ha_low, ha_close = ref_heikin_ashi(open_, high, low, close)
+    write_csv("price_transform/heikin_ashi.csv", {
+        "open": ha_open, "high": ha_high, "low": ha_low, "close": ha_close,
+    })
+
+def cycle_indicators(bars):
+    close = [b[3] for b in bars]
+
+    in_phase, quadrature = ht_phasor(close)
+    sine, lead_sine = ht_sine(close)
+    write_csv("cycle_indicators/hilbert_transform.csv", {
+        "dcperiod": ht_dcperiod(close),
+        "dcphase": ht_dcphase(close),
+        "inphase": in_phase,
+        "quadrature": quadrature,
+        "sine": sine,
+        "leadsine": lead_sine,
+        "trendmode": ht_trendmode(close),
+    })
+
+def pattern_recognition(bars):
+    open_ = [b[0] for b in bars]
+    high = [b[1] for b in bars]
+    low = [b[2] for b in bars]
+    close = [b[3] for b in bars]
+
+    write_csv("pattern_recognition/patterns.csv", {
+        name: cdl(name, open_, high, low, close) for name in (
+            "cdl2crows", "cdl3blackcrows", "cdl3inside", "cdl3linestrike", "cdl3outside", "cdl3starsinsouth",
+            "cdl3whitesoldiers", "cdlabandonedbaby", "cdladvanceblock", "cdlbreakaway", "cdlconcealbabyswall",
+            "cdldoji", "cdldragonflydoji", "cdlengulfing", "cdleveningdojistar", "cdleveningstar",
+            "cdlgapsidesidewhite", "cdlgravestonedoji", "cdlhammer", "cdlhangingman", "cdlharami", "cdlharamicross",
+            "cdlhikkake", "cdlhikkakemod", "cdlidentical3crows", "cdlinvertedhammer", "cdlladderbottom",
+            "cdllongleggeddoji", "cdllongline", "cdlmarubozu", "cdlmathold", "cdlmorningdojistar", "cdlmorningstar",
+            "cdlrisefall3methods", "cdlshootingstar", "cdlshortline", "cdlspinningtop", "cdlstalledpattern",
+            "cdlsticksandwich", "cdltasukigap", "cdltristar", "cdlunique3river", "cdlupsidegap2crows",
+            "cdlxsidegap3methods",
+        )
+    })
+
+    staged = make_staged_bars()
+    open_ = [b[0] for b in staged]
+    high = [b[1] for b in staged]
+    low = [b[2] for b in staged]
+    close = [b[3] for b in staged]
+    columns = {"open": open_, "high": high, "low": low, "close": close}
+    columns.update({
+        name: cdl(name, open_, high, low, close) for name in (
+            "cdl2crows", "cdl3blackcrows", "cdl3inside", "cdl3linestrike", "cdl3outside", "cdl3starsinsouth",
+            "cdl3whitesoldiers", "cdlabandonedbaby", "cdladvanceblock", "cdlbreakaway", "cdlconcealbabyswall",
+            "cdleveningdojistar", "cdleveningstar", "cdlgapsidesidewhite", "cdlhikkake", "cdlhikkakemod",
+            "cdlidentical3crows", "cdlladderbottom", "cdlmathold", "cdlmorningdojistar", "cdlmorningstar",
+            "cdlrisefall3methods", "cdlstalledpattern", "cdlsticksandwich", "cdltasukigap", "cdltristar",
+            "cdlunique3river", "cdlupsidegap2crows", "cdlxsidegap3methods",
+        )
+    })
+    write_csv("pattern_recognition/staged_patterns.csv", columns)
+
+
+def main():
+    bars = make_bars()
+    write_csv("input.csv", {
+        "open": [b[0] for b in bars],
+        "high": [b[1] for b in bars],
+        "low": [b[2] for b in bars],
+        "close": [b[3] for b in bars],
+        "volume": [b[4] for b in bars],
+    })
+    overlap_studies(bars)
+    momentum_indicators(bars)
+    volume_indicators(bars)
+    volatility_indicators(bars)
+    price_transform(bars)
+    cycle_indicators(bars)
+    pattern_recognition(bars)
+    write_provenance()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,392 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/11/26 19:00
+ * @Email: uyplayer@qq.com
+ * @File: momentum_indicators_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "momentum_indicators")]
+
+mod common;
+
+use common::{input, stream, stream_outputs, Fixture};
+use rusty_talib::*;
+
+/// Returns the `(high, low, close)` columns of the input bars.
+fn hlc() -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let input = input();
+    (
+        input.column("high").to_vec(),
+        input.column("low").to_vec(),
+        input.column("close").to_vec(),
+    )
+}
+
+#[test]
+fn golden_dm() {
+    let (high, low, _) = hlc();
+    let fixture = Fixture::load("momentum_indicators/dm.csv");
+    for period in [1, 5, 14] {
+        let actual = plus_dm_slice(&high, &low, Some(period)).unwrap();
+        fixture.check(&format!("plus_dm_{}", period), &actual);
+        let mut state = PlusDm::new(period).unwrap();
+        fixture.check(&format!("plus_dm_{}", period), &stream(high.len(), |i| state.update(high[i], low[i])));
+        let actual = minus_dm_slice(&high, &low, Some(period)).unwrap();
+        fixture.check(&format!("minus_dm_{}", period), &actual);
+        let mut state = MinusDm::new(period).unwrap();
+        fixture.check(&format!("minus_dm_{}", period), &stream(high.len(), |i| state.update(high[i], low[i])));
+    }
+}
+
+#[test]
+fn golden_di() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/di.csv");
+    for period in [5, 14] {
+        let actual = plus_di_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("plus_di_{}", period), &actual);
+        let mut state = PlusDi::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("plus_di_{}", period), &actual);
+        let actual = minus_di_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("minus_di_{}", period), &actual);
+        let mut state = MinusDi::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("minus_di_{}", period), &actual);
+    }
+}
+
+#[test]
+fn golden_dx() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/dx.csv");
+    for period in [5, 14] {
+        let actual = dx_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("dx_{}", period), &actual);
+        let mut state = Dx::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("dx_{}", period), &actual);
+    }
+}
+
+#[test]
+fn golden_adx() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/adx.csv");
+    for period in [5, 14] {
+        let actual = adx_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("adx_{}", period), &actual);
+        let mut state = Adx::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("adx_{}", period), &actual);
+    }
+}
+
+#[test]
+fn golden_adxr() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/adxr.csv");
+    for period in [5, 14] {
+        let actual = adxr_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("adxr_{}", period), &actual);
+        let mut state = Adxr::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("adxr_{}", period), &actual);
+    }
+}
+
+/// Checks the `macd_`, `signal_` and `hist_` columns of a MACD fixture.
+fn check_macd(fixture: &Fixture, suffix: &str, (macd, signal, hist): (Vec<f64>, Vec<f64>, Vec<f64>)) {
+    fixture.check(&format!("macd_{}", suffix), &macd);
+    fixture.check(&format!("signal_{}", suffix), &signal);
+    fixture.check(&format!("hist_{}", suffix), &hist);
+}
+
+/// Runs a MACD state over `close`, returns the `(macd, signal, hist)` columns [`check_macd`] takes.
+fn stream_macd<F>(close: &[f64], mut update: F) -> (Vec<f64>, Vec<f64>, Vec<f64>)
+where
+    F: FnMut(f64) -> Option<(f64, f64, f64)>,
+{
+    let [macd, signal, hist] = stream_outputs(close.len(), |i| update(close[i]).map(|(m, s, h)| [m, s, h]));
+    (macd, signal, hist)
+}
+
+#[test]
+fn golden_macd() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/macd.csv");
+    for (fast, slow, signal) in [(12, 26, 9), (5, 13, 4), (26, 12, 1)] {
+        let actual = macd_slice(&close, Some(fast), Some(slow), Some(signal)).unwrap();
+        check_macd(&fixture, &format!("{}_{}_{}", fast, slow, signal), actual);
+        let mut state = Macd::new(fast, slow, signal).unwrap();
+        let actual = stream_macd(&close, |value| state.update(value));
+        check_macd(&fixture, &format!("{}_{}_{}", fast, slow, signal), actual);
+    }
+}
+
+#[test]
+fn golden_macdfix() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/macdfix.csv");
+    for signal in [9, 4] {
+        let actual = macdfix_slice(&close, Some(signal)).unwrap();
+        check_macd(&fixture, &signal.to_string(), actual);
+        let mut state = MacdFix::new(signal).unwrap();
+        check_macd(&fixture, &signal.to_string(), stream_macd(&close, |value| state.update(value)));
+    }
+}
+
+#[test]
+fn golden_macdext() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/macdext.csv");
+    let params = [
+        (12, MaType::Ema, 26, MaType::Ema, 9, MaType::Ema),
+        (12, MaType::Sma, 26, MaType::Sma, 9, MaType::Sma),
+        (5, MaType::Dema, 13, MaType::Wma, 4, MaType::Trima),
+        (20, MaType::T3, 10, MaType::Kama, 3, MaType::Tema),
+    ];
+    for (i, (fast, fast_type, slow, slow_type, signal, signal_type)) in params.into_iter().enumerate() {
+        let actual = macdext_slice(
+            &close,
+            Some(fast),
+            Some(fast_type),
+            Some(slow),
+            Some(slow_type),
+            Some(signal),
+            Some(signal_type),
+        )
+        .unwrap();
+        check_macd(&fixture, &i.to_string(), actual);
+        let mut state = MacdExt::new(fast, fast_type, slow, slow_type, signal, signal_type).unwrap();
+        check_macd(&fixture, &i.to_string(), stream_macd(&close, |value| state.update(value)));
+    }
+}
+
+#[test]
+fn golden_rsi() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/rsi.csv");
+    for period in [2, 14, 30] {
+        let actual = rsi_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("rsi_{}", period), &actual);
+        let mut state = Rsi::new(period).unwrap();
+        fixture.check(&format!("rsi_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_cmo() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/cmo.csv");
+    for period in [2, 14, 30] {
+        let actual = cmo_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("cmo_{}", period), &actual);
+        let mut state = Cmo::new(period).unwrap();
+        fixture.check(&format!("cmo_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_stochrsi() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/stochrsi.csv");
+    for (period, fastk, fastd, fastd_type) in [(14, 5, 3, MaType::Sma), (14, 14, 1, MaType::Sma), (7, 3, 4, MaType::Ema)] {
+        let (k, d) = stochrsi_slice(&close, Some(period), Some(fastk), Some(fastd), Some(fastd_type)).unwrap();
+        let suffix = format!("{}_{}_{}_{}", period, fastk, fastd, fastd_type as i32);
+        fixture.check(&format!("fastk_{}", suffix), &k);
+        fixture.check(&format!("fastd_{}", suffix), &d);
+        let mut state = StochRsi::new(period, fastk, fastd, fastd_type).unwrap();
+        let [k, d] = stream_outputs(close.len(), |i| state.update(close[i]).map(|(k, d)| [k, d]));
+        fixture.check(&format!("fastk_{}", suffix), &k);
+        fixture.check(&format!("fastd_{}", suffix), &d);
+    }
+}
+
+#[test]
+fn golden_stoch() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/stoch.csv");
+    let params = [
+        (5, 3, MaType::Sma, 3, MaType::Sma),
+        (14, 3, MaType::Ema, 5, MaType::Wma),
+        (9, 1, MaType::Sma, 4, MaType::Dema),
+    ];
+    for (fastk, slowk, slowk_type, slowd, slowd_type) in params {
+        let (k, d) = stoch_slice(&high, &low, &close, Some(fastk), Some(slowk), Some(slowk_type), Some(slowd), Some(slowd_type)).unwrap();
+        let suffix = format!("{}_{}_{}_{}_{}", fastk, slowk, slowk_type as i32, slowd, slowd_type as i32);
+        fixture.check(&format!("slowk_{}", suffix), &k);
+        fixture.check(&format!("slowd_{}", suffix), &d);
+        let mut state = Stoch::new(fastk, slowk, slowk_type, slowd, slowd_type).unwrap();
+        let [k, d] = stream_outputs(close.len(), |i| state.update(high[i], low[i], close[i]).map(|(k, d)| [k, d]));
+        fixture.check(&format!("slowk_{}", suffix), &k);
+        fixture.check(&format!("slowd_{}", suffix), &d);
+    }
+}
+
+#[test]
+fn golden_stochf() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/stochf.csv");
+    for (fastk, fastd, fastd_type) in [(5, 3, MaType::Sma), (14, 1, MaType::Sma), (9, 4, MaType::Ema)] {
+        let (k, d) = stochf_slice(&high, &low, &close, Some(fastk), Some(fastd), Some(fastd_type)).unwrap();
+        let suffix = format!("{}_{}_{}", fastk, fastd, fastd_type as i32);
+        fixture.check(&format!("fastk_{}", suffix), &k);
+        fixture.check(&format!("fastd_{}", suffix), &d);
+        let mut state = StochF::new(fastk, fastd, fastd_type).unwrap();
+        let [k, d] = stream_outputs(close.len(), |i| state.update(high[i], low[i], close[i]).map(|(k, d)| [k, d]));
+        fixture.check(&format!("fastk_{}", suffix), &k);
+        fixture.check(&format!("fastd_{}", suffix), &d);
+    }
+}
+
+#[test]
+fn golden_price_change() {
+    let close = input().column("close").to_vec();
+    type Indicator = fn(&[f64], Option<usize>) -> Result<Vec<f64>, TalibError>;
+    let indicators: [(&str, Indicator); 5] = [
+        ("mom", mom_slice),
+        ("roc", roc_slice),
+        ("rocp", rocp_slice),
+        ("rocr", rocr_slice),
+        ("rocr100", rocr100_slice),
+    ];
+    for (name, indicator) in indicators {
+        let fixture = Fixture::load(&format!("momentum_indicators/{}.csv", name));
+        for period in [1, 10, 30] {
+            let actual = indicator(&close, Some(period)).unwrap();
+            fixture.check(&format!("{}_{}", name, period), &actual);
+        }
+    }
+    for period in [1, 10, 30] {
+        let (mut mom, mut roc) = (Mom::new(period).unwrap(), Roc::new(period).unwrap());
+        let (mut rocp, mut rocr) = (Rocp::new(period).unwrap(), Rocr::new(period).unwrap());
+        let mut rocr100 = Rocr100::new(period).unwrap();
+        let streamed = [
+            ("mom", stream(close.len(), |i| mom.update(close[i]))),
+            ("roc", stream(close.len(), |i| roc.update(close[i]))),
+            ("rocp", stream(close.len(), |i| rocp.update(close[i]))),
+            ("rocr", stream(close.len(), |i| rocr.update(close[i]))),
+            ("rocr100", stream(close.len(), |i| rocr100.update(close[i]))),
+        ];
+        for (name, actual) in streamed {
+            let fixture = Fixture::load(&format!("momentum_indicators/{}.csv", name));
+            fixture.check(&format!("{}_{}", name, period), &actual);
+        }
+    }
+}
+
+#[test]
+fn golden_po() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/po.csv");
+    for (fast, slow, ma_type) in [(12, 26, MaType::Sma), (5, 13, MaType::Ema), (26, 12, MaType::Wma)] {
+        let suffix = format!("{}_{}_{}", fast, slow, ma_type as i32);
+        let actual = apo_slice(&close, Some(fast), Some(slow), Some(ma_type)).unwrap();
+        fixture.check(&format!("apo_{}", suffix), &actual);
+        let actual = ppo_slice(&close, Some(fast), Some(slow), Some(ma_type)).unwrap();
+        fixture.check(&format!("ppo_{}", suffix), &actual);
+        let mut state = Apo::new(fast, slow, ma_type).unwrap();
+        fixture.check(&format!("apo_{}", suffix), &stream(close.len(), |i| state.update(close[i])));
+        let mut state = Ppo::new(fast, slow, ma_type).unwrap();
+        fixture.check(&format!("ppo_{}", suffix), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_bop() {
+    let (high, low, close) = hlc();
+    let open = input().column("open").to_vec();
+    let fixture = Fixture::load("momentum_indicators/bop.csv");
+    fixture.check("bop", &bop_slice(&open, &high, &low, &close).unwrap());
+    let mut state = Bop::new();
+    fixture.check("bop", &stream(close.len(), |i| Some(state.update(open[i], high[i], low[i], close[i]))));
+}
+
+#[test]
+fn golden_cci() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/cci.csv");
+    for period in [5, 14, 20] {
+        let actual = cci_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("cci_{}", period), &actual);
+        let mut state = Cci::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("cci_{}", period), &actual);
+    }
+}
+
+#[test]
+fn golden_willr() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/willr.csv");
+    for period in [2, 14] {
+        let actual = willr_slice(&high, &low, &close, Some(period)).unwrap();
+        fixture.check(&format!("willr_{}", period), &actual);
+        let mut state = Willr::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("willr_{}", period), &actual);
+    }
+}
+
+#[test]
+fn golden_ultosc() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("momentum_indicators/ultosc.csv");
+    for (period1, period2, period3) in [(7, 14, 28), (10, 3, 5)] {
+        let actual = ultosc_slice(&high, &low, &close, Some(period1), Some(period2), Some(period3)).unwrap();
+        fixture.check(&format!("ultosc_{}_{}_{}", period1, period2, period3), &actual);
+        let mut state = UltOsc::new(period1, period2, period3).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i]));
+        fixture.check(&format!("ultosc_{}_{}_{}", period1, period2, period3), &actual);
+    }
+}
+
+#[test]
+fn golden_trix() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("momentum_indicators/trix.csv");
+    for period in [1, 5, 30] {
+        let actual = trix_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("trix_{}", period), &actual);
+        let mut state = Trix::new(period).unwrap();
+        fixture.check(&format!("trix_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_aroon() {
+    let (high, low, _) = hlc();
+    let fixture = Fixture::load("momentum_indicators/aroon.csv");
+    for period in [5, 14, 25] {
+        let (down, up) = aroon_slice(&high, &low, Some(period)).unwrap();
+        fixture.check(&format!("down_{}", period), &down);
+        fixture.check(&format!("up_{}", period), &up);
+        let actual = aroonosc_slice(&high, &low, Some(period)).unwrap();
+        fixture.check(&format!("osc_{}", period), &actual);
+        let mut state = Aroon::new(period).unwrap();
+        let [down, up] = stream_outputs(high.len(), |i| state.update(high[i], low[i]).map(|(d, u)| [d, u]));
+        fixture.check(&format!("down_{}", period), &down);
+        fixture.check(&format!("up_{}", period), &up);
+        let mut state = AroonOsc::new(period).unwrap();
+        fixture.check(&format!("osc_{}", period), &stream(high.len(), |i| state.update(high[i], low[i])));
+    }
+}
+
+#[test]
+fn golden_mfi() {
+    let (high, low, close) = hlc();
+    let volume = input().column("volume").to_vec();
+    let fixture = Fixture::load("momentum_indicators/mfi.csv");
+    for period in [1, 5, 14] {
+        let actual = mfi_slice(&high, &low, &close, &volume, Some(period)).unwrap();
+        fixture.check(&format!("mfi_{}", period), &actual);
+        let mut state = Mfi::new(period).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i], volume[i]));
+        fixture.check(&format!("mfi_{}", period), &actual);
+    }
+}
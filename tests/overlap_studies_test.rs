@@ -8,3 +8,295 @@
  * @Project_Name: rusty-talib
  * @Description:
  */
+
+#![cfg(feature = "overlap_studies")]
+
+mod common;
+
+use common::{input, stream, stream_outputs, Fixture};
+use rusty_talib::*;
+
+#[test]
+fn golden_sma() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/sma.csv");
+    for period in [5, 14, 30] {
+        let actual = simple_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("sma_{}", period), &actual);
+        let mut state = Sma::new(period).unwrap();
+        fixture.check(&format!("sma_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_ma() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/ma.csv");
+    let params = [
+        (14, MaType::Sma),
+        (30, MaType::Sma),
+        (14, MaType::Ema),
+        (14, MaType::Wma),
+        (14, MaType::Dema),
+        (14, MaType::Tema),
+        (14, MaType::Trima),
+        (14, MaType::Kama),
+        (14, MaType::Mama),
+        (5, MaType::T3),
+    ];
+    for (period, ma_type) in params {
+        let actual = moving_average_slice(&close, Some(period), Some(ma_type)).unwrap();
+        fixture.check(&format!("ma_{}_{}", period, ma_type as i32), &actual);
+        let mut state = Ma::new(period, ma_type).unwrap();
+        fixture.check(&format!("ma_{}_{}", period, ma_type as i32), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_ema() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/ema.csv");
+    for period in [5, 14, 30] {
+        let actual = exponential_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("ema_{}", period), &actual);
+        let mut state = Ema::new(period).unwrap();
+        fixture.check(&format!("ema_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_dema() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/dema.csv");
+    for period in [5, 14, 30] {
+        let actual = double_exponential_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("dema_{}", period), &actual);
+        let mut state = Dema::new(period).unwrap();
+        fixture.check(&format!("dema_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_wma() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/wma.csv");
+    for period in [5, 14, 30] {
+        let actual = weighted_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("wma_{}", period), &actual);
+        let mut state = Wma::new(period).unwrap();
+        fixture.check(&format!("wma_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_tema() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/tema.csv");
+    for period in [5, 14, 30] {
+        let actual = triple_exponential_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("tema_{}", period), &actual);
+        let mut state = Tema::new(period).unwrap();
+        fixture.check(&format!("tema_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_trima() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/trima.csv");
+    for period in [5, 14, 30] {
+        let actual = triangular_moving_average_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("trima_{}", period), &actual);
+        let mut state = Trima::new(period).unwrap();
+        fixture.check(&format!("trima_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_t3() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/t3.csv");
+    for (period, v_factor) in [(5, 0.7), (10, 0.7), (5, 0.5)] {
+        let actual = t3_slice(&close, Some(period), Some(v_factor)).unwrap();
+        fixture.check(&format!("t3_{}_{}", period, v_factor), &actual);
+        let mut state = T3::new(period, v_factor).unwrap();
+        fixture.check(&format!("t3_{}_{}", period, v_factor), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_midpoint() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/midpoint.csv");
+    for period in [5, 14, 30] {
+        let actual = midpoint_slice(&close, Some(period)).unwrap();
+        fixture.check(&format!("midpoint_{}", period), &actual);
+        let mut state = MidPoint::new(period).unwrap();
+        fixture.check(&format!("midpoint_{}", period), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_midprice() {
+    let input = input();
+    let fixture = Fixture::load("overlap_studies/midprice.csv");
+    for period in [5, 14, 30] {
+        let actual = midprice_slice(input.column("high"), input.column("low"), Some(period)).unwrap();
+        fixture.check(&format!("midprice_{}", period), &actual);
+        let mut state = MidPrice::new(period).unwrap();
+        let (high, low) = (input.column("high"), input.column("low"));
+        fixture.check(&format!("midprice_{}", period), &stream(high.len(), |i| state.update(high[i], low[i])));
+    }
+}
+
+#[test]
+fn golden_sar() {
+    let input = input();
+    let fixture = Fixture::load("overlap_studies/sar.csv");
+    for (acceleration, maximum) in [(0.02, 0.2), (0.01, 0.1)] {
+        let actual = sar_slice(input.column("high"), input.column("low"), Some(acceleration), Some(maximum)).unwrap();
+        fixture.check(&format!("sar_{}_{}", acceleration, maximum), &actual);
+        let mut state = Sar::new(acceleration, maximum).unwrap();
+        let (high, low) = (input.column("high"), input.column("low"));
+        let actual = stream(high.len(), |i| state.update(high[i], low[i]));
+        fixture.check(&format!("sar_{}_{}", acceleration, maximum), &actual);
+    }
+}
+
+#[test]
+fn golden_sarext() {
+    let input = input();
+    let fixture = Fixture::load("overlap_studies/sarext.csv");
+    let params = [
+        (0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2),
+        (0.0, 0.01, 0.01, 0.02, 0.25, 0.03, 0.03, 0.3),
+        (-105.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2),
+    ];
+    for (i, (start, offset, init_long, long, max_long, init_short, short, max_short)) in params.into_iter().enumerate() {
+        let actual = sarext_slice(
+            input.column("high"),
+            input.column("low"),
+            Some(start),
+            Some(offset),
+            Some(init_long),
+            Some(long),
+            Some(max_long),
+            Some(init_short),
+            Some(short),
+            Some(max_short),
+        )
+        .unwrap();
+        fixture.check(&format!("sarext_{}", i), &actual);
+        let mut state = Sarext::new(start, offset, init_long, long, max_long, init_short, short, max_short).unwrap();
+        let (high, low) = (input.column("high"), input.column("low"));
+        fixture.check(&format!("sarext_{}", i), &stream(high.len(), |bar| state.update(high[bar], low[bar])));
+    }
+}
+
+#[test]
+fn golden_bbands() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/bbands.csv");
+    for (period, nbdev) in [(5, 2), (20, 2), (14, 1)] {
+        let (middle, upper, lower) = b_bands_slice(&close, Some(period), Some(nbdev), None).unwrap();
+        fixture.check(&format!("upper_{}_{}", period, nbdev), &upper);
+        fixture.check(&format!("middle_{}_{}", period, nbdev), &middle);
+        fixture.check(&format!("lower_{}_{}", period, nbdev), &lower);
+        let mut state = BBands::new(period, nbdev, MaType::Sma).unwrap();
+        let [middle, upper, lower] = stream_outputs(close.len(), |i| state.update(close[i]).map(|(m, u, l)| [m, u, l]));
+        fixture.check(&format!("upper_{}_{}", period, nbdev), &upper);
+        fixture.check(&format!("middle_{}_{}", period, nbdev), &middle);
+        fixture.check(&format!("lower_{}_{}", period, nbdev), &lower);
+    }
+}
+
+#[test]
+fn golden_kama() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/kama.csv");
+    for (period, fast, slow) in [(10, 2, 30), (5, 2, 30), (20, 4, 40)] {
+        let actual = kama_slice(&close, Some(period), Some(fast), Some(slow)).unwrap();
+        fixture.check(&format!("kama_{}_{}_{}", period, fast, slow), &actual);
+        let mut state = Kama::new(period, fast, slow).unwrap();
+        fixture.check(&format!("kama_{}_{}_{}", period, fast, slow), &stream(close.len(), |i| state.update(close[i])));
+    }
+}
+
+#[test]
+fn golden_mavp() {
+    let close = input().column("close").to_vec();
+    let fixture = Fixture::load("overlap_studies/mavp.csv");
+    let periods = fixture.column("periods");
+    for (min_period, max_period) in [(2, 30), (4, 10)] {
+        let actual = mavp_slice(&close, periods, Some(min_period), Some(max_period), None).unwrap();
+        fixture.check(&format!("mavp_{}_{}", min_period, max_period), &actual);
+        let mut state = Mavp::new(min_period, max_period, MaType::Sma).unwrap();
+        let actual = stream(close.len(), |i| state.update(close[i], periods[i]));
+        fixture.check(&format!("mavp_{}_{}", min_period, max_period), &actual);
+    }
+}
+
+#[test]
+fn golden_mama() {
+    let input = input();
+    let (high, low) = (input.column("high").to_vec(), input.column("low").to_vec());
+    let fixture = Fixture::load("overlap_studies/mama.csv");
+    for (fast_limit, slow_limit) in [(0.5, 0.05), (0.3, 0.1), (0.8, 0.02)] {
+        let (mama, fama) = mama_slice(&high, &low, Some(fast_limit), Some(slow_limit)).unwrap();
+        fixture.check(&format!("mama_{}_{}", fast_limit, slow_limit), &mama);
+        fixture.check(&format!("fama_{}_{}", fast_limit, slow_limit), &fama);
+        let mut state = Mama::new(fast_limit, slow_limit).unwrap();
+        let [mama, fama] = stream_outputs(high.len(), |i| state.update(high[i], low[i]).map(|(m, f)| [m, f]));
+        fixture.check(&format!("mama_{}_{}", fast_limit, slow_limit), &mama);
+        fixture.check(&format!("fama_{}_{}", fast_limit, slow_limit), &fama);
+    }
+}
+
+#[test]
+fn golden_ht_trendline() {
+    let input = input();
+    let (high, low) = (input.column("high").to_vec(), input.column("low").to_vec());
+    let fixture = Fixture::load("overlap_studies/ht_trendline.csv");
+    let actual = ht_trend_line_slice(&high, &low).unwrap();
+    fixture.check("ht_trendline", &actual);
+    let mut state = HtTrendLine::new();
+    fixture.check("ht_trendline", &stream(high.len(), |i| state.update(high[i], low[i])));
+}
+
+#[test]
+fn golden_vwma() {
+    let input = input();
+    let fixture = Fixture::load("overlap_studies/vwma.csv");
+    for period in [1, 5, 20] {
+        let actual = vwma_slice(input.column("close"), input.column("volume"), Some(period)).unwrap();
+        fixture.check(&format!("vwma_{}", period), &actual);
+        let mut state = Vwma::new(period).unwrap();
+        let (close, volume) = (input.column("close"), input.column("volume"));
+        fixture.check(&format!("vwma_{}", period), &stream(close.len(), |i| state.update(close[i], volume[i])));
+    }
+}
+
+#[test]
+fn golden_vwap() {
+    let input = input();
+    let (high, low, close, volume) = (input.column("high"), input.column("low"), input.column("close"), input.column("volume"));
+    let fixture = Fixture::load("overlap_studies/vwap.csv");
+    fixture.check("vwap", &vwap_slice(high, low, close, volume, None).unwrap());
+    let sessions: Vec<i64> = (0..high.len() as i64).map(|i| i / 25).collect();
+    fixture.check("vwap_session_25", &vwap_slice(high, low, close, volume, Some(&sessions)).unwrap());
+    let mut state = Vwap::new();
+    let actual = stream(high.len(), |i| {
+        if i > 0 && sessions[i] != sessions[i - 1] {
+            state.reset();
+        }
+        state.update(high[i], low[i], close[i], volume[i])
+    });
+    fixture.check("vwap_session_25", &actual);
+    for period in [5, 20] {
+        let actual = rolling_vwap_slice(high, low, close, volume, Some(period)).unwrap();
+        fixture.check(&format!("rolling_vwap_{}", period), &actual);
+        let mut state = RollingVwap::new(period).unwrap();
+        let actual = stream(high.len(), |i| state.update(high[i], low[i], close[i], volume[i]));
+        fixture.check(&format!("rolling_vwap_{}", period), &actual);
+    }
+}
@@ -0,0 +1,271 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/29 16:00
+ * @Email: uyplayer@qq.com
+ * @File: pattern_recognition_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "pattern_recognition")]
+
+mod common;
+
+use common::{input, stream, Fixture};
+use rusty_talib::*;
+
+/// Returns the `(open, high, low, close)` columns of the input bars.
+fn ohlc() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let input = input();
+    (
+        input.column("open").to_vec(),
+        input.column("high").to_vec(),
+        input.column("low").to_vec(),
+        input.column("close").to_vec(),
+    )
+}
+
+/// Pattern values as `f64`, `NaN` for the lookback, to compare with the fixture.
+fn values(pattern: Result<Vec<Option<i32>>, TalibError>) -> Vec<f64> {
+    pattern.unwrap().into_iter().map(|value| value.map_or(f64::NAN, f64::from)).collect()
+}
+
+/// The `update` method every streaming pattern state has.
+trait PatternState: Clone {
+    fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32>;
+}
+
+macro_rules! pattern_states {
+    ($($state:ty),* $(,)?) => {
+        $(impl PatternState for $state {
+            fn update(&mut self, open: f64, high: f64, low: f64, close: f64) -> Option<i32> {
+                <$state>::update(self, open, high, low, close)
+            }
+        })*
+    };
+}
+
+pattern_states!(
+    CdlLongLine, CdlShortLine, CdlDoji, CdlDragonflyDoji, CdlGravestoneDoji, CdlLongLeggedDoji, CdlHammer,
+    CdlHangingMan, CdlInvertedHammer, CdlShootingStar, CdlEngulfing, CdlHarami, CdlHaramiCross, CdlMarubozu,
+    CdlSpinningTop, CdlMorningStar, CdlEveningStar, CdlMorningDojiStar, CdlEveningDojiStar, CdlAbandonedBaby,
+    Cdl3BlackCrows, Cdl3WhiteSoldiers, Cdl3Inside, Cdl3Outside, CdlConcealBabySwallow, CdlRiseFall3Methods,
+    CdlMatHold, CdlBreakaway, CdlLadderBottom, Cdl2Crows, CdlUpsideGap2Crows, CdlIdentical3Crows, Cdl3LineStrike,
+    Cdl3StarsInSouth, CdlAdvanceBlock, CdlStalledPattern, CdlTristar, CdlUnique3River, CdlStickSandwich,
+    CdlXSideGap3Methods, CdlTasukiGap, CdlGapSideSideWhite, CdlHikkake, CdlHikkakeMod,
+);
+
+/// Feeds the bars one by one to a fresh copy of `state`, laid out like [`values`].
+fn streamed<S: PatternState>(state: &S, open: &[f64], high: &[f64], low: &[f64], close: &[f64]) -> Vec<f64> {
+    let mut state = state.clone();
+    stream(open.len(), |i| state.update(open[i], high[i], low[i], close[i]).map(f64::from))
+}
+
+/// Checks a multi-candle `pattern` and its streaming `state` against the column `name` of both the input bars
+/// fixture and the staged one, where the pattern candles are laid out between quiet bars.
+fn check_multi_candle<F, S>(name: &str, pattern: F, state: S)
+where
+    F: Fn(&[f64], &[f64], &[f64], &[f64]) -> Result<Vec<Option<i32>>, TalibError>,
+    S: PatternState,
+{
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check(name, &values(pattern(&open, &high, &low, &close)));
+    fixture.check(name, &streamed(&state, &open, &high, &low, &close));
+    let staged = Fixture::load("pattern_recognition/staged_patterns.csv");
+    let (open, high) = (staged.column("open"), staged.column("high"));
+    let (low, close) = (staged.column("low"), staged.column("close"));
+    staged.check(name, &values(pattern(open, high, low, close)));
+    staged.check(name, &streamed(&state, open, high, low, close));
+}
+
+#[test]
+fn golden_long_and_short_line() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check("cdllongline", &values(cdl_long_line_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdllongline", &streamed(&CdlLongLine::new(), &open, &high, &low, &close));
+    fixture.check("cdlshortline", &values(cdl_short_line_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlshortline", &streamed(&CdlShortLine::new(), &open, &high, &low, &close));
+}
+
+#[test]
+fn golden_doji_family() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check("cdldoji", &values(cdl_doji_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdldoji", &streamed(&CdlDoji::new(), &open, &high, &low, &close));
+    fixture.check("cdldragonflydoji", &values(cdl_dragonfly_doji_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdldragonflydoji", &streamed(&CdlDragonflyDoji::new(), &open, &high, &low, &close));
+    fixture.check("cdlgravestonedoji", &values(cdl_gravestone_doji_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlgravestonedoji", &streamed(&CdlGravestoneDoji::new(), &open, &high, &low, &close));
+    fixture.check("cdllongleggeddoji", &values(cdl_long_legged_doji_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdllongleggeddoji", &streamed(&CdlLongLeggedDoji::new(), &open, &high, &low, &close));
+}
+
+#[test]
+fn golden_hammer_family() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check("cdlhammer", &values(cdl_hammer_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlhammer", &streamed(&CdlHammer::new(), &open, &high, &low, &close));
+    fixture.check("cdlhangingman", &values(cdl_hanging_man_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlhangingman", &streamed(&CdlHangingMan::new(), &open, &high, &low, &close));
+    fixture.check("cdlinvertedhammer", &values(cdl_inverted_hammer_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlinvertedhammer", &streamed(&CdlInvertedHammer::new(), &open, &high, &low, &close));
+    fixture.check("cdlshootingstar", &values(cdl_shooting_star_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlshootingstar", &streamed(&CdlShootingStar::new(), &open, &high, &low, &close));
+}
+
+#[test]
+fn golden_two_candle_patterns() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check("cdlengulfing", &values(cdl_engulfing_slice(&open, &high, &low, &close)));
+    fixture.check("cdlengulfing", &streamed(&CdlEngulfing::new(), &open, &high, &low, &close));
+    fixture.check("cdlharami", &values(cdl_harami_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlharami", &streamed(&CdlHarami::new(), &open, &high, &low, &close));
+    fixture.check("cdlharamicross", &values(cdl_harami_cross_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlharamicross", &streamed(&CdlHaramiCross::new(), &open, &high, &low, &close));
+}
+
+#[test]
+fn golden_body_shapes() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("pattern_recognition/patterns.csv");
+    fixture.check("cdlmarubozu", &values(cdl_marubozu_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlmarubozu", &streamed(&CdlMarubozu::new(), &open, &high, &low, &close));
+    fixture.check("cdlspinningtop", &values(cdl_spinning_top_slice(&open, &high, &low, &close, None)));
+    fixture.check("cdlspinningtop", &streamed(&CdlSpinningTop::new(), &open, &high, &low, &close));
+}
+
+#[test]
+fn golden_star_patterns() {
+    check_multi_candle(
+        "cdlmorningstar",
+        |o, h, l, c| cdl_morning_star_slice(o, h, l, c, None, None),
+        CdlMorningStar::new(0.3).unwrap(),
+    );
+    check_multi_candle(
+        "cdleveningstar",
+        |o, h, l, c| cdl_evening_star_slice(o, h, l, c, None, None),
+        CdlEveningStar::new(0.3).unwrap(),
+    );
+    check_multi_candle(
+        "cdlmorningdojistar",
+        |o, h, l, c| cdl_morning_doji_star_slice(o, h, l, c, None, None),
+        CdlMorningDojiStar::new(0.3).unwrap(),
+    );
+    check_multi_candle(
+        "cdleveningdojistar",
+        |o, h, l, c| cdl_evening_doji_star_slice(o, h, l, c, None, None),
+        CdlEveningDojiStar::new(0.3).unwrap(),
+    );
+    check_multi_candle(
+        "cdlabandonedbaby",
+        |o, h, l, c| cdl_abandoned_baby_slice(o, h, l, c, None, None),
+        CdlAbandonedBaby::new(0.3).unwrap(),
+    );
+}
+
+#[test]
+fn golden_three_candle_patterns() {
+    check_multi_candle("cdl3blackcrows", |o, h, l, c| cdl_3_black_crows_slice(o, h, l, c, None), Cdl3BlackCrows::new());
+    check_multi_candle(
+        "cdl3whitesoldiers",
+        |o, h, l, c| cdl_3_white_soldiers_slice(o, h, l, c, None),
+        Cdl3WhiteSoldiers::new(),
+    );
+    check_multi_candle("cdl3inside", |o, h, l, c| cdl_3_inside_slice(o, h, l, c, None), Cdl3Inside::new());
+    check_multi_candle("cdl3outside", cdl_3_outside_slice, Cdl3Outside::new());
+    check_multi_candle(
+        "cdlconcealbabyswall",
+        |o, h, l, c| cdl_conceal_baby_swallow_slice(o, h, l, c, None),
+        CdlConcealBabySwallow::new(),
+    );
+    check_multi_candle("cdl2crows", |o, h, l, c| cdl_2_crows_slice(o, h, l, c, None), Cdl2Crows::new());
+    check_multi_candle(
+        "cdlidentical3crows",
+        |o, h, l, c| cdl_identical_3_crows_slice(o, h, l, c, None),
+        CdlIdentical3Crows::new(),
+    );
+    check_multi_candle(
+        "cdl3linestrike",
+        |o, h, l, c| cdl_3_line_strike_slice(o, h, l, c, None),
+        Cdl3LineStrike::new(),
+    );
+    check_multi_candle(
+        "cdl3starsinsouth",
+        |o, h, l, c| cdl_3_stars_in_south_slice(o, h, l, c, None),
+        Cdl3StarsInSouth::new(),
+    );
+    check_multi_candle(
+        "cdladvanceblock",
+        |o, h, l, c| cdl_advance_block_slice(o, h, l, c, None),
+        CdlAdvanceBlock::new(),
+    );
+    check_multi_candle(
+        "cdlstalledpattern",
+        |o, h, l, c| cdl_stalled_pattern_slice(o, h, l, c, None),
+        CdlStalledPattern::new(),
+    );
+    check_multi_candle("cdltristar", |o, h, l, c| cdl_tristar_slice(o, h, l, c, None), CdlTristar::new());
+    check_multi_candle(
+        "cdlunique3river",
+        |o, h, l, c| cdl_unique_3_river_slice(o, h, l, c, None),
+        CdlUnique3River::new(),
+    );
+    check_multi_candle(
+        "cdlsticksandwich",
+        |o, h, l, c| cdl_stick_sandwich_slice(o, h, l, c, None),
+        CdlStickSandwich::new(),
+    );
+}
+
+#[test]
+fn golden_gap_patterns() {
+    check_multi_candle(
+        "cdlupsidegap2crows",
+        |o, h, l, c| cdl_upside_gap_2_crows_slice(o, h, l, c, None),
+        CdlUpsideGap2Crows::new(),
+    );
+    check_multi_candle("cdlxsidegap3methods", cdl_xside_gap_3_methods_slice, CdlXSideGap3Methods::new());
+    check_multi_candle("cdltasukigap", |o, h, l, c| cdl_tasuki_gap_slice(o, h, l, c, None), CdlTasukiGap::new());
+    check_multi_candle(
+        "cdlgapsidesidewhite",
+        |o, h, l, c| cdl_gap_side_side_white_slice(o, h, l, c, None),
+        CdlGapSideSideWhite::new(),
+    );
+}
+
+#[test]
+fn golden_hikkake_patterns() {
+    check_multi_candle("cdlhikkake", cdl_hikkake_slice, CdlHikkake::new());
+    check_multi_candle(
+        "cdlhikkakemod",
+        |o, h, l, c| cdl_hikkake_mod_slice(o, h, l, c, None),
+        CdlHikkakeMod::new(),
+    );
+}
+
+#[test]
+fn golden_five_candle_patterns() {
+    check_multi_candle(
+        "cdlrisefall3methods",
+        |o, h, l, c| cdl_rise_fall_3_methods_slice(o, h, l, c, None),
+        CdlRiseFall3Methods::new(),
+    );
+    check_multi_candle(
+        "cdlmathold",
+        |o, h, l, c| cdl_mat_hold_slice(o, h, l, c, None, None),
+        CdlMatHold::new(0.5).unwrap(),
+    );
+    check_multi_candle("cdlbreakaway", |o, h, l, c| cdl_breakaway_slice(o, h, l, c, None), CdlBreakaway::new());
+    check_multi_candle(
+        "cdlladderbottom",
+        |o, h, l, c| cdl_ladder_bottom_slice(o, h, l, c, None),
+        CdlLadderBottom::new(),
+    );
+}
@@ -0,0 +1,64 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/24 17:00
+ * @Email: uyplayer@qq.com
+ * @File: price_transform_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "price_transform")]
+
+mod common;
+
+use common::{input, stream, stream_outputs, Fixture};
+use rusty_talib::*;
+
+/// Returns the `(open, high, low, close)` columns of the input bars.
+fn ohlc() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let input = input();
+    (
+        input.column("open").to_vec(),
+        input.column("high").to_vec(),
+        input.column("low").to_vec(),
+        input.column("close").to_vec(),
+    )
+}
+
+#[test]
+fn golden_price_transform() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("price_transform/price_transform.csv");
+    fixture.check("avgprice", &avgprice_slice(&open, &high, &low, &close).unwrap());
+    fixture.check("medprice", &medprice_slice(&high, &low).unwrap());
+    fixture.check("typprice", &typprice_slice(&high, &low, &close).unwrap());
+    fixture.check("wclprice", &wclprice_slice(&high, &low, &close).unwrap());
+    let (mut avg, mut med) = (AvgPrice::new(), MedPrice::new());
+    let (mut typ, mut wcl) = (TypPrice::new(), WclPrice::new());
+    fixture.check("avgprice", &stream(close.len(), |i| Some(avg.update(open[i], high[i], low[i], close[i]))));
+    fixture.check("medprice", &stream(close.len(), |i| Some(med.update(high[i], low[i]))));
+    fixture.check("typprice", &stream(close.len(), |i| Some(typ.update(high[i], low[i], close[i]))));
+    fixture.check("wclprice", &stream(close.len(), |i| Some(wcl.update(high[i], low[i], close[i]))));
+}
+
+#[test]
+fn golden_heikin_ashi() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("price_transform/heikin_ashi.csv");
+    let (ha_open, ha_high, ha_low, ha_close) = heikin_ashi_slice(&open, &high, &low, &close).unwrap();
+    fixture.check("open", &ha_open);
+    fixture.check("high", &ha_high);
+    fixture.check("low", &ha_low);
+    fixture.check("close", &ha_close);
+    let mut state = HeikinAshi::new();
+    let [ha_open, ha_high, ha_low, ha_close] = stream_outputs(close.len(), |i| {
+        let (o, h, l, c) = state.update(open[i], high[i], low[i], close[i]);
+        Some([o, h, l, c])
+    });
+    fixture.check("open", &ha_open);
+    fixture.check("high", &ha_high);
+    fixture.check("low", &ha_low);
+    fixture.check("close", &ha_close);
+}
@@ -0,0 +1,148 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/20 21:00
+ * @Email: uyplayer@qq.com
+ * @File: volatility_indicators_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "volatility_indicators")]
+
+mod common;
+
+use common::{input, stream, stream_outputs, Fixture};
+use rusty_talib::*;
+
+/// Returns the `(high, low, close)` columns of the input bars.
+fn hlc() -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let (_, high, low, close) = ohlc();
+    (high, low, close)
+}
+
+/// Returns the `(open, high, low, close)` columns of the input bars.
+fn ohlc() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let input = input();
+    (
+        input.column("open").to_vec(),
+        input.column("high").to_vec(),
+        input.column("low").to_vec(),
+        input.column("close").to_vec(),
+    )
+}
+
+#[test]
+fn golden_trange() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("volatility_indicators/trange.csv");
+    fixture.check("trange", &trange_slice(&high, &low, &close).unwrap());
+    let mut state = TRange::new();
+    fixture.check("trange", &stream(close.len(), |i| state.update(high[i], low[i], close[i])));
+}
+
+#[test]
+fn golden_atr() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("volatility_indicators/atr.csv");
+    for period in [1, 5, 14] {
+        fixture.check(&format!("atr_{period}"), &atr_slice(&high, &low, &close, Some(period)).unwrap());
+        let mut state = Atr::new(period).unwrap();
+        fixture.check(&format!("atr_{period}"), &stream(close.len(), |i| state.update(high[i], low[i], close[i])));
+    }
+}
+
+#[test]
+fn golden_natr() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("volatility_indicators/atr.csv");
+    for period in [1, 5, 14] {
+        fixture.check(&format!("natr_{period}"), &natr_slice(&high, &low, &close, Some(period)).unwrap());
+        let mut state = Natr::new(period).unwrap();
+        fixture.check(&format!("natr_{period}"), &stream(close.len(), |i| state.update(high[i], low[i], close[i])));
+    }
+}
+
+#[test]
+fn golden_keltner_channels() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("volatility_indicators/keltner_channels.csv");
+    for (period, atr_period, multiplier) in [(20, 10, 2.0), (5, 14, 1.5)] {
+        let (middle, upper, lower) =
+            keltner_channels_slice(&high, &low, &close, Some(period), Some(atr_period), Some(multiplier)).unwrap();
+        fixture.check(&format!("middle_{period}_{atr_period}"), &middle);
+        fixture.check(&format!("upper_{period}_{atr_period}"), &upper);
+        fixture.check(&format!("lower_{period}_{atr_period}"), &lower);
+        let mut state = KeltnerChannels::new(period, atr_period, multiplier).unwrap();
+        let [middle, upper, lower] =
+            stream_outputs(close.len(), |i| state.update(high[i], low[i], close[i]).map(|(m, u, l)| [m, u, l]));
+        fixture.check(&format!("middle_{period}_{atr_period}"), &middle);
+        fixture.check(&format!("upper_{period}_{atr_period}"), &upper);
+        fixture.check(&format!("lower_{period}_{atr_period}"), &lower);
+    }
+}
+
+#[test]
+fn golden_donchian_channels() {
+    let (high, low, _) = hlc();
+    let fixture = Fixture::load("volatility_indicators/donchian_channels.csv");
+    for period in [1, 5, 20] {
+        let (middle, upper, lower) = donchian_channels_slice(&high, &low, Some(period)).unwrap();
+        fixture.check(&format!("middle_{period}"), &middle);
+        fixture.check(&format!("upper_{period}"), &upper);
+        fixture.check(&format!("lower_{period}"), &lower);
+        let mut state = DonchianChannels::new(period).unwrap();
+        let [middle, upper, lower] =
+            stream_outputs(high.len(), |i| state.update(high[i], low[i]).map(|(m, u, l)| [m, u, l]));
+        fixture.check(&format!("middle_{period}"), &middle);
+        fixture.check(&format!("upper_{period}"), &upper);
+        fixture.check(&format!("lower_{period}"), &lower);
+    }
+}
+
+#[test]
+fn golden_chandelier_exit() {
+    let (high, low, close) = hlc();
+    let fixture = Fixture::load("volatility_indicators/chandelier_exit.csv");
+    for (period, multiplier) in [(22, 3.0), (5, 2.0)] {
+        let (long_exit, short_exit) = chandelier_exit_slice(&high, &low, &close, Some(period), Some(multiplier)).unwrap();
+        fixture.check(&format!("long_{period}"), &long_exit);
+        fixture.check(&format!("short_{period}"), &short_exit);
+        let mut state = ChandelierExit::new(period, multiplier).unwrap();
+        let [long_exit, short_exit] =
+            stream_outputs(close.len(), |i| state.update(high[i], low[i], close[i]).map(|(l, s)| [l, s]));
+        fixture.check(&format!("long_{period}"), &long_exit);
+        fixture.check(&format!("short_{period}"), &short_exit);
+    }
+}
+
+#[test]
+fn golden_historical_volatility() {
+    let (open, high, low, close) = ohlc();
+    let fixture = Fixture::load("volatility_indicators/historical_volatility.csv");
+    for (period, annualization) in [(20, 252.0), (5, 1.0)] {
+        let suffix = format!("{period}_{annualization}");
+        let (period, annualization) = (Some(period), Some(annualization));
+        fixture.check(
+            &format!("close_to_close_{suffix}"),
+            &close_to_close_volatility_slice(&close, period, annualization).unwrap(),
+        );
+        fixture.check(
+            &format!("parkinson_{suffix}"),
+            &parkinson_volatility_slice(&high, &low, period, annualization).unwrap(),
+        );
+        fixture.check(
+            &format!("garman_klass_{suffix}"),
+            &garman_klass_volatility_slice(&open, &high, &low, &close, period, annualization).unwrap(),
+        );
+        fixture.check(
+            &format!("rogers_satchell_{suffix}"),
+            &rogers_satchell_volatility_slice(&open, &high, &low, &close, period, annualization).unwrap(),
+        );
+        fixture.check(
+            &format!("yang_zhang_{suffix}"),
+            &yang_zhang_volatility_slice(&open, &high, &low, &close, period, annualization).unwrap(),
+        );
+    }
+}
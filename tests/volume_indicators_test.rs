@@ -0,0 +1,93 @@
+/*
+ * @Author: uyplayer
+ * @Date: 2023/12/16 18:00
+ * @Email: uyplayer@qq.com
+ * @File: volume_indicators_test.rs
+ * @Software: RustRover
+ * @Dir: rusty-talib / tests
+ * @Project_Name: rusty-talib
+ * @Description:
+ */
+
+#![cfg(feature = "volume_indicators")]
+
+mod common;
+
+use common::{input, stream, Fixture};
+use rusty_talib::*;
+
+/// Returns the `(high, low, close, volume)` columns of the input bars.
+fn hlcv() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let input = input();
+    (
+        input.column("high").to_vec(),
+        input.column("low").to_vec(),
+        input.column("close").to_vec(),
+        input.column("volume").to_vec(),
+    )
+}
+
+#[test]
+fn golden_ad() {
+    let (high, low, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/ad.csv");
+    fixture.check("ad", &ad_slice(&high, &low, &close, &volume).unwrap());
+    let mut state = Ad::new();
+    fixture.check("ad", &stream(close.len(), |i| Some(state.update(high[i], low[i], close[i], volume[i]))));
+}
+
+#[test]
+fn golden_adosc() {
+    let (high, low, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/adosc.csv");
+    for (fast, slow) in [(3, 10), (5, 20), (10, 3)] {
+        let actual = adosc_slice(&high, &low, &close, &volume, Some(fast), Some(slow)).unwrap();
+        fixture.check(&format!("adosc_{}_{}", fast, slow), &actual);
+        let mut state = AdOsc::new(fast, slow).unwrap();
+        let actual = stream(close.len(), |i| state.update(high[i], low[i], close[i], volume[i]));
+        fixture.check(&format!("adosc_{}_{}", fast, slow), &actual);
+    }
+}
+
+#[test]
+fn golden_obv() {
+    let (_, _, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/obv.csv");
+    fixture.check("obv", &obv_slice(&close, &volume).unwrap());
+    let mut state = Obv::new();
+    fixture.check("obv", &stream(close.len(), |i| Some(state.update(close[i], volume[i]))));
+}
+
+#[test]
+fn golden_pvt() {
+    let (_, _, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/pvt.csv");
+    fixture.check("pvt", &pvt_slice(&close, &volume).unwrap());
+    let mut state = Pvt::new();
+    fixture.check("pvt", &stream(close.len(), |i| Some(state.update(close[i], volume[i]))));
+}
+
+#[test]
+fn golden_volume_index() {
+    let (_, _, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/volume_index.csv");
+    fixture.check("nvi", &nvi_slice(&close, &volume, None).unwrap());
+    fixture.check("pvi", &pvi_slice(&close, &volume, None).unwrap());
+    fixture.check("nvi_100", &nvi_slice(&close, &volume, Some(100.0)).unwrap());
+    let (mut nvi, mut pvi, mut nvi_100) = (Nvi::new(1000.0), Pvi::new(1000.0), Nvi::new(100.0));
+    fixture.check("nvi", &stream(close.len(), |i| Some(nvi.update(close[i], volume[i]))));
+    fixture.check("pvi", &stream(close.len(), |i| Some(pvi.update(close[i], volume[i]))));
+    fixture.check("nvi_100", &stream(close.len(), |i| Some(nvi_100.update(close[i], volume[i]))));
+}
+
+#[test]
+fn golden_force_index() {
+    let (_, _, close, volume) = hlcv();
+    let fixture = Fixture::load("volume_indicators/force_index.csv");
+    for period in [1, 2, 13] {
+        let actual = force_index_slice(&close, &volume, Some(period)).unwrap();
+        fixture.check(&format!("force_index_{}", period), &actual);
+        let mut state = ForceIndex::new(period).unwrap();
+        fixture.check(&format!("force_index_{}", period), &stream(close.len(), |i| state.update(close[i], volume[i])));
+    }
+}